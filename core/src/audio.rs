@@ -0,0 +1,36 @@
+//! Play sound cues for user interface events.
+use std::fmt;
+
+/// A sound cue for a user interface event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sound {
+    /// Some element was clicked or activated.
+    Click,
+    /// Some operation failed.
+    Error,
+    /// Something requires the attention of the user.
+    Notification,
+}
+
+/// A backend that plays [`Sound`] cues.
+///
+/// An application can provide an [`Audio`] backend to the shell, which will
+/// use it to play the sounds requested by commands.
+pub trait Audio {
+    /// Plays the given [`Sound`].
+    fn play(&self, sound: Sound);
+}
+
+impl fmt::Debug for dyn Audio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Audio")
+    }
+}
+
+/// A null implementation of the [`Audio`] trait.
+#[derive(Debug, Clone, Copy)]
+pub struct Null;
+
+impl Audio for Null {
+    fn play(&self, _sound: Sound) {}
+}
@@ -1,6 +1,6 @@
 //! Access the clipboard.
 
-use std::{any::Any, sync::Arc};
+use std::{any::Any, borrow::Cow, sync::Arc};
 
 use dnd::{DndAction, DndDestinationRectangle, DndSurface};
 use mime::{self, AllowedMimeTypes, AsMimeTypes, ClipboardStoreData};
@@ -39,6 +39,17 @@ pub trait Clipboard {
     ) {
     }
 
+    /// Writes the given HTML contents to the [`Clipboard`], along with a
+    /// plain text alternative for applications that cannot paste HTML.
+    fn write_html(&mut self, html: String, alt: String) {
+        self.write_data(ClipboardStoreData(Box::new(Html { html, alt })));
+    }
+
+    /// Writes the given [`Image`] to the [`Clipboard`].
+    fn write_image(&mut self, image: Image) {
+        self.write_data(ClipboardStoreData(Box::new(image)));
+    }
+
     /// Consider using [`read_primary_data`] instead
     /// Reads the current content of the primary [`Clipboard`] as text.
     fn read_primary_data(
@@ -157,6 +168,106 @@ pub fn peek_dnd<T: AllowedMimeTypes>(
         .and_then(|data| T::try_from(data).ok())
 }
 
+/// The MIME types of plain text.
+const TEXT_MIME_TYPES: [&str; 3] =
+    ["text/plain;charset=utf-8", "text/plain", "UTF8_STRING"];
+
+/// HTML contents of the [`Clipboard`], along with a plain text alternative.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Html {
+    /// The HTML markup.
+    pub html: String,
+    /// The plain text alternative of the markup.
+    pub alt: String,
+}
+
+impl AsMimeTypes for Html {
+    fn available(&self) -> Cow<'static, [String]> {
+        std::iter::once("text/html")
+            .chain(TEXT_MIME_TYPES)
+            .map(String::from)
+            .collect()
+    }
+
+    fn as_bytes(&self, mime_type: &str) -> Option<Cow<'static, [u8]>> {
+        if mime_type == "text/html" {
+            Some(Cow::Owned(self.html.clone().into_bytes()))
+        } else if TEXT_MIME_TYPES.contains(&mime_type) {
+            Some(Cow::Owned(self.alt.clone().into_bytes()))
+        } else {
+            None
+        }
+    }
+}
+
+impl AllowedMimeTypes for Html {
+    fn allowed() -> Cow<'static, [String]> {
+        Cow::Owned(vec![String::from("text/html")])
+    }
+}
+
+impl TryFrom<(Vec<u8>, String)> for Html {
+    type Error = std::string::FromUtf8Error;
+
+    fn try_from(
+        (bytes, _mime_type): (Vec<u8>, String),
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            html: String::from_utf8(bytes)?,
+            alt: String::new(),
+        })
+    }
+}
+
+/// An encoded image in the [`Clipboard`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    /// The encoded bytes of the image.
+    pub bytes: Vec<u8>,
+    /// The MIME type of the encoding, like `image/png`.
+    pub mime_type: String,
+}
+
+impl Image {
+    /// The MIME types of the images that can be read from the [`Clipboard`].
+    pub const MIME_TYPES: [&'static str; 4] =
+        ["image/png", "image/jpeg", "image/webp", "image/bmp"];
+
+    /// Creates a new [`Image`] from the bytes of a PNG file.
+    pub fn png(bytes: impl Into<Vec<u8>>) -> Self {
+        Self {
+            bytes: bytes.into(),
+            mime_type: String::from("image/png"),
+        }
+    }
+}
+
+impl AsMimeTypes for Image {
+    fn available(&self) -> Cow<'static, [String]> {
+        Cow::Owned(vec![self.mime_type.clone()])
+    }
+
+    fn as_bytes(&self, mime_type: &str) -> Option<Cow<'static, [u8]>> {
+        (mime_type == self.mime_type).then(|| Cow::Owned(self.bytes.clone()))
+    }
+}
+
+impl AllowedMimeTypes for Image {
+    fn allowed() -> Cow<'static, [String]> {
+        Self::MIME_TYPES.into_iter().map(String::from).collect()
+    }
+}
+
+impl TryFrom<(Vec<u8>, String)> for Image {
+    type Error = std::convert::Infallible;
+
+    fn try_from(
+        (bytes, mime_type): (Vec<u8>, String),
+    ) -> Result<Self, Self::Error> {
+        Ok(Self { bytes, mime_type })
+    }
+}
+
 /// Source of a DnD operation.
 #[derive(Debug, Clone)]
 pub enum DndSource {
@@ -34,6 +34,9 @@ impl<'a, Message, Theme, Renderer> Element<'a, Message, Theme, Renderer> {
     where
         Renderer: crate::Renderer,
     {
+        #[cfg(feature = "debug")]
+        let widget = widget::profile::Profiled::new(widget);
+
         Self {
             widget: Box::new(widget),
         }
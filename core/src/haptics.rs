@@ -0,0 +1,27 @@
+//! Trigger haptic feedback on supported hardware.
+use std::fmt;
+
+/// A backend that triggers haptic feedback on supported devices, like
+/// gamepads or trackpads.
+///
+/// An application can provide a [`Haptics`] backend to the shell, which will
+/// use it to trigger the impulses requested by commands.
+pub trait Haptics {
+    /// Triggers a haptic impulse with the given strength, between `0.0` and
+    /// `1.0`.
+    fn impulse(&self, strength: f32);
+}
+
+impl fmt::Debug for dyn Haptics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Haptics")
+    }
+}
+
+/// A null implementation of the [`Haptics`] trait.
+#[derive(Debug, Clone, Copy)]
+pub struct Null;
+
+impl Haptics for Null {
+    fn impulse(&self, _strength: f32) {}
+}
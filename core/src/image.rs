@@ -1,5 +1,5 @@
 //! Load and draw raster graphics.
-use crate::{Hasher, Rectangle, Size};
+use crate::{Hasher, Radians, Rectangle, Size};
 
 use std::hash::{Hash, Hasher as _};
 use std::path::PathBuf;
@@ -187,12 +187,15 @@ pub trait Renderer: crate::Renderer {
     fn dimensions(&self, handle: &Self::Handle) -> Size<u32>;
 
     /// Draws an image with the given [`Handle`] and inside the provided
-    /// `bounds`.
+    /// `bounds`, rotated clockwise around its center by `rotation` and with
+    /// the given `opacity`.
     fn draw(
         &mut self,
         handle: Self::Handle,
         filter_method: FilterMethod,
         bounds: Rectangle,
+        rotation: Radians,
+        opacity: f32,
         border_radius: [f32; 4],
     );
 }
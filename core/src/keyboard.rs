@@ -2,10 +2,14 @@
 pub mod key;
 
 mod event;
+mod ime;
 mod location;
 mod modifiers;
+mod shortcut;
 
 pub use event::Event;
+pub use ime::Ime;
 pub use key::Key;
 pub use location::Location;
 pub use modifiers::Modifiers;
+pub use shortcut::Shortcut;
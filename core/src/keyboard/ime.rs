@@ -0,0 +1,27 @@
+/// An event produced by an input method editor, used to compose text that
+/// cannot be typed with single key presses, like Chinese, Japanese, or Korean.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ime {
+    /// The input method was enabled.
+    ///
+    /// Composition events will follow, until it is disabled.
+    Enabled,
+
+    /// The text being composed has changed.
+    ///
+    /// An empty text means the composition was cleared.
+    Preedit {
+        /// The text being composed.
+        text: String,
+
+        /// The byte range of the cursor within the text, if it should be
+        /// shown.
+        cursor: Option<(usize, usize)>,
+    },
+
+    /// The composition has finished, producing the given text.
+    Commit(String),
+
+    /// The input method was disabled.
+    Disabled,
+}
@@ -33,6 +33,18 @@ impl Key {
     }
 }
 
+impl From<Named> for Key {
+    fn from(named: Named) -> Self {
+        Key::Named(named)
+    }
+}
+
+impl From<&str> for Key {
+    fn from(character: &str) -> Self {
+        Key::Character(character.into())
+    }
+}
+
 /// A named key.
 ///
 /// This is mostly the `NamedKey` type found in [`winit`].
@@ -84,4 +84,15 @@ impl Modifiers {
 
         is_pressed
     }
+
+    /// Returns true if scrolling with the [`Modifiers`] should zoom instead.
+    ///
+    /// This is the case while the [`CTRL`] key is pressed. Widgets that can
+    /// zoom only do it when scrolled with these [`Modifiers`], and widgets
+    /// that scroll ignore them, so both can be nested predictably.
+    ///
+    /// [`CTRL`]: Self::CTRL
+    pub fn zoom(self) -> bool {
+        self.control()
+    }
 }
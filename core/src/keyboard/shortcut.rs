@@ -0,0 +1,86 @@
+use crate::keyboard::{Key, Modifiers};
+
+use std::fmt;
+
+/// A combination of [`Modifiers`] and a [`Key`] that triggers an action,
+/// like <kbd>Ctrl</kbd>+<kbd>S</kbd>.
+///
+/// Character keys are compared case-insensitively, so a [`Shortcut`] with
+/// [`Modifiers::SHIFT`] matches regardless of the case of the character the
+/// keyboard reports.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    /// The [`Modifiers`] that must be held.
+    pub modifiers: Modifiers,
+    /// The [`Key`] that must be pressed.
+    pub key: Key,
+}
+
+impl Shortcut {
+    /// Creates a [`Shortcut`] from the given [`Modifiers`] and [`Key`].
+    pub fn new(modifiers: Modifiers, key: impl Into<Key>) -> Self {
+        Self {
+            modifiers,
+            key: normalize(key.into()),
+        }
+    }
+
+    /// Creates a [`Shortcut`] of the given [`Key`] with the platform
+    /// [`Modifiers::COMMAND`] key.
+    pub fn command(key: impl Into<Key>) -> Self {
+        Self::new(Modifiers::COMMAND, key)
+    }
+
+    /// Returns whether the given [`Key`] pressed with the given
+    /// [`Modifiers`] triggers the [`Shortcut`].
+    pub fn matches(&self, key: &Key, modifiers: Modifiers) -> bool {
+        self.modifiers == modifiers
+            && match (&self.key, key) {
+                (Key::Character(a), Key::Character(b)) => {
+                    a.as_str() == b.to_lowercase()
+                }
+                (a, b) => a == b,
+            }
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = if cfg!(target_os = "macos") { "" } else { "+" };
+
+        let modifiers = if cfg!(target_os = "macos") {
+            [
+                (Modifiers::CTRL, "⌃"),
+                (Modifiers::ALT, "⌥"),
+                (Modifiers::SHIFT, "⇧"),
+                (Modifiers::LOGO, "⌘"),
+            ]
+        } else {
+            [
+                (Modifiers::CTRL, "Ctrl"),
+                (Modifiers::ALT, "Alt"),
+                (Modifiers::SHIFT, "Shift"),
+                (Modifiers::LOGO, "Super"),
+            ]
+        };
+
+        for (modifier, name) in modifiers {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}{separator}")?;
+            }
+        }
+
+        match &self.key {
+            Key::Character(c) => write!(f, "{}", c.to_uppercase()),
+            Key::Named(named) => write!(f, "{named:?}"),
+            Key::Unidentified => write!(f, "?"),
+        }
+    }
+}
+
+fn normalize(key: Key) -> Key {
+    match key {
+        Key::Character(c) => Key::Character(c.to_lowercase().into()),
+        key => key,
+    }
+}
@@ -74,6 +74,10 @@ pub fn resolve<Message, Theme, Renderer>(
 where
     Renderer: crate::Renderer,
 {
+    let scale = renderer.spacing_scale();
+    let padding = padding.scale(scale);
+    let spacing = spacing * scale;
+
     let limits = limits.width(width).height(height).shrink(padding);
     let total_spacing = spacing * items.len().saturating_sub(1) as f32;
     let max_cross = axis.cross(limits.max());
@@ -18,11 +18,13 @@
     rust_2018_idioms
 )]
 pub mod alignment;
+pub mod audio;
 pub mod border;
 pub mod clipboard;
 pub mod event;
 pub mod font;
 pub mod gradient;
+pub mod haptics;
 pub mod image;
 pub mod keyboard;
 pub mod layout;
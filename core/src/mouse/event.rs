@@ -1,4 +1,4 @@
-use crate::Point;
+use crate::{Point, Vector};
 
 use super::Button;
 
@@ -22,6 +22,17 @@ pub enum Event {
         position: Point,
     },
 
+    /// The mouse was moved while the cursor is [`Locked`].
+    ///
+    /// The delta is the raw movement reported by the device, which is not
+    /// in logical pixels and is not affected by pointer acceleration.
+    ///
+    /// [`Locked`]: super::Interaction::Locked
+    Motion {
+        /// The relative movement of the mouse.
+        delta: Vector,
+    },
+
     /// A mouse button was pressed.
     ButtonPressed(Button),
 
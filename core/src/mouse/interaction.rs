@@ -12,5 +12,19 @@ pub enum Interaction {
     Grabbing,
     ResizingHorizontally,
     ResizingVertically,
+    ResizingDiagonallyUp,
+    ResizingDiagonallyDown,
+    ResizingAll,
+    Move,
     NotAllowed,
+    ZoomIn,
+    ZoomOut,
+    /// The cursor is hidden and locked in place.
+    ///
+    /// While locked, the relative movements of the mouse are reported as
+    /// [`Event::Motion`] and the cursor position does not change. This is
+    /// useful for orbiting a 3D viewport or dragging a knob without limits.
+    ///
+    /// [`Event::Motion`]: super::Event::Motion
+    Locked,
 }
@@ -87,6 +87,16 @@ impl Padding {
             right: self.right.min(available.width - new_left),
         }
     }
+
+    /// Scales every side of the [`Padding`] by the given factor.
+    pub fn scale(self, factor: f32) -> Self {
+        Padding {
+            top: self.top * factor,
+            right: self.right * factor,
+            bottom: self.bottom * factor,
+            left: self.left * factor,
+        }
+    }
 }
 
 impl From<u16> for Padding {
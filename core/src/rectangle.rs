@@ -1,4 +1,4 @@
-use crate::{Point, Size, Vector};
+use crate::{Point, Radians, Size, Vector};
 
 /// A rectangle.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -177,6 +177,22 @@ impl Rectangle<f32> {
             height: self.height + amount * 2.0,
         }
     }
+
+    /// Rotates the [`Rectangle`] around its center and returns the smallest
+    /// [`Rectangle`] containing the result.
+    pub fn rotate(self, rotation: Radians) -> Self {
+        let (sin, cos) = rotation.0.sin_cos();
+        let width = (self.width * cos).abs() + (self.height * sin).abs();
+        let height = (self.width * sin).abs() + (self.height * cos).abs();
+        let center = self.center();
+
+        Self {
+            x: center.x - width / 2.0,
+            y: center.y - height / 2.0,
+            width,
+            height,
+        }
+    }
 }
 
 impl std::ops::Mul<f32> for Rectangle<f32> {
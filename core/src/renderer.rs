@@ -26,6 +26,14 @@ pub trait Renderer: Sized {
 
     /// Clears all of the recorded primitives in the [`Renderer`].
     fn clear(&mut self);
+
+    /// Returns the factor that the built-in widgets should apply to their
+    /// spacing, padding, and gaps.
+    ///
+    /// By default, it is `1.0`.
+    fn spacing_scale(&self) -> f32 {
+        1.0
+    }
 }
 
 /// A polygon with four sides.
@@ -4,6 +4,7 @@ use crate::text::{self, Text};
 use crate::{Background, Color, Font, Pixels, Point, Rectangle, Size, Vector};
 
 use std::borrow::Cow;
+use std::ops::Range;
 
 /// A renderer that does nothing.
 ///
@@ -93,6 +94,12 @@ impl text::Paragraph for () {
 
     fn with_text(_text: Text<'_, Self::Font>) -> Self {}
 
+    fn with_spans<Link>(
+        _spans: &[text::Span<'_, Link, Self::Font>],
+        _text: Text<'_, Self::Font>,
+    ) -> Self {
+    }
+
     fn resize(&mut self, _new_bounds: Size) {}
 
     fn compare(&self, _text: Text<'_, Self::Font>) -> text::Difference {
@@ -118,6 +125,22 @@ impl text::Paragraph for () {
     fn hit_test(&self, _point: Point) -> Option<text::Hit> {
         None
     }
+
+    fn hit_span(&self, _point: Point) -> Option<usize> {
+        None
+    }
+
+    fn span_bounds(&self, _index: usize) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
+    fn hit_offset(&self, _point: Point) -> Option<usize> {
+        None
+    }
+
+    fn range_bounds(&self, _range: Range<usize>) -> Vec<Rectangle> {
+        Vec::new()
+    }
 }
 
 impl text::Editor for () {
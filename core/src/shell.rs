@@ -1,4 +1,5 @@
 use crate::window;
+use crate::Rectangle;
 
 /// A connection to the state of a shell.
 ///
@@ -10,6 +11,7 @@ use crate::window;
 pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
     redraw_request: Option<window::RedrawRequest>,
+    ime_request: Option<Rectangle>,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
 }
@@ -20,6 +22,7 @@ impl<'a, Message> Shell<'a, Message> {
         Self {
             messages,
             redraw_request: None,
+            ime_request: None,
             is_layout_invalid: false,
             are_widgets_invalid: false,
         }
@@ -53,6 +56,21 @@ impl<'a, Message> Shell<'a, Message> {
         self.redraw_request
     }
 
+    /// Requests the input method editor to be enabled, placing its candidate
+    /// window next to the given cursor area.
+    ///
+    /// Focused text inputs should request it on every redraw; it is disabled
+    /// as soon as no widget requests it.
+    pub fn request_ime(&mut self, cursor: Rectangle) {
+        self.ime_request = Some(cursor);
+    }
+
+    /// Returns the cursor area the input method editor was requested for, if
+    /// any.
+    pub fn ime_request(&self) -> Option<Rectangle> {
+        self.ime_request
+    }
+
     /// Returns whether the current layout is invalid or not.
     pub fn is_layout_invalid(&self) -> bool {
         self.is_layout_invalid
@@ -99,6 +117,10 @@ impl<'a, Message> Shell<'a, Message> {
             self.request_redraw(at);
         }
 
+        if let Some(cursor) = other.ime_request {
+            self.request_ime(cursor);
+        }
+
         self.is_layout_invalid =
             self.is_layout_invalid || other.is_layout_invalid;
 
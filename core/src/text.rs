@@ -40,6 +40,73 @@ pub struct Text<'a, Font> {
 
     /// The [`Shaping`] strategy of the [`Text`].
     pub shaping: Shaping,
+
+    /// The [`Wrap`] strategy of the [`Text`].
+    pub wrap: Wrap,
+
+    /// The [`Truncate`] strategy of the [`Text`].
+    pub truncate: Truncate,
+}
+
+/// A span of text with its own style, part of a rich [`Paragraph`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span<'a, Link = (), Font = crate::Font> {
+    /// The content of the [`Span`].
+    pub text: Cow<'a, str>,
+    /// The font of the [`Span`], if different from the paragraph.
+    pub font: Option<Font>,
+    /// The [`Color`] of the [`Span`], if different from the paragraph.
+    pub color: Option<Color>,
+    /// The link of the [`Span`], produced when it is clicked.
+    pub link: Option<Link>,
+    /// Whether the [`Span`] is underlined.
+    pub underline: bool,
+    /// Whether the [`Span`] is struck through.
+    pub strikethrough: bool,
+}
+
+impl<'a, Link, Font> Span<'a, Link, Font> {
+    /// Creates a new [`Span`] with the given text.
+    pub fn new(text: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            text: text.into(),
+            font: None,
+            color: None,
+            link: None,
+            underline: false,
+            strikethrough: false,
+        }
+    }
+
+    /// Sets the font of the [`Span`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the link of the [`Span`].
+    pub fn link(mut self, link: impl Into<Link>) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+
+    /// Sets whether the [`Span`] is underlined.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets whether the [`Span`] is struck through.
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.strikethrough = strikethrough;
+        self
+    }
 }
 
 /// The shaping strategy of some text.
@@ -66,6 +133,34 @@ pub enum Shaping {
     Advanced,
 }
 
+/// The wrapping strategy of some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Wrap {
+    /// No wrapping; lines only break at explicit line breaks.
+    None,
+    /// Wraps at word boundaries.
+    ///
+    /// This is the default.
+    #[default]
+    Word,
+    /// Wraps at any glyph.
+    Glyph,
+}
+
+/// The truncation strategy of some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Truncate {
+    /// Text is never truncated and may overflow its bounds.
+    ///
+    /// This is the default.
+    #[default]
+    None,
+    /// Lines wider than the bounds are cut short and end with an ellipsis.
+    ///
+    /// Truncation happens before wrapping, so truncated lines never wrap.
+    Ellipsis,
+}
+
 /// The height of a line of text in a paragraph.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LineHeight {
@@ -198,6 +293,15 @@ pub trait Renderer: crate::Renderer {
     /// Loads a [`Self::Font`] from its bytes.
     fn load_font(&mut self, font: Cow<'static, [u8]>);
 
+    /// Measures the minimum [`Size`] needed to fit the given [`Text`] within
+    /// its bounds, using the actual glyph metrics.
+    ///
+    /// Widgets that lay out text repeatedly should keep a [`Paragraph`]
+    /// instead, since it reuses the shaping between calls.
+    fn measure(&self, text: Text<'_, Self::Font>) -> Size {
+        Self::Paragraph::with_text(text).min_bounds()
+    }
+
     /// Draws the given [`Paragraph`] at the given position and with the given
     /// [`Color`].
     fn fill_paragraph(
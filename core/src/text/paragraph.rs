@@ -1,6 +1,8 @@
 use crate::alignment;
-use crate::text::{Difference, Hit, Text};
-use crate::{Point, Size};
+use crate::text::{Difference, Hit, Span, Text};
+use crate::{Point, Rectangle, Size};
+
+use std::ops::Range;
 
 /// A text paragraph.
 pub trait Paragraph: Sized + Default {
@@ -10,6 +12,15 @@ pub trait Paragraph: Sized + Default {
     /// Creates a new [`Paragraph`] laid out with the given [`Text`].
     fn with_text(text: Text<'_, Self::Font>) -> Self;
 
+    /// Creates a new [`Paragraph`] laid out with the given [`Span`]s.
+    ///
+    /// The content of the [`Text`] is ignored, while the rest of its
+    /// attributes apply to every [`Span`] that does not override them.
+    fn with_spans<Link>(
+        spans: &[Span<'_, Link, Self::Font>],
+        text: Text<'_, Self::Font>,
+    ) -> Self;
+
     /// Lays out the [`Paragraph`] with some new boundaries.
     fn resize(&mut self, new_bounds: Size);
 
@@ -34,6 +45,21 @@ pub trait Paragraph: Sized + Default {
     /// Returns the distance to the given grapheme index in the [`Paragraph`].
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point>;
 
+    /// Returns the index of the [`Span`] under the given point, if any.
+    fn hit_span(&self, point: Point) -> Option<usize>;
+
+    /// Returns the bounds of every line fragment of the [`Span`] with the
+    /// given index.
+    fn span_bounds(&self, index: usize) -> Vec<Rectangle>;
+
+    /// Returns the byte offset in the content of the [`Paragraph`] of the
+    /// character boundary nearest to the given point, if any.
+    fn hit_offset(&self, point: Point) -> Option<usize>;
+
+    /// Returns the bounds of every line fragment covering the given byte
+    /// range of the content of the [`Paragraph`].
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle>;
+
     /// Updates the [`Paragraph`] to match the given [`Text`], if needed.
     fn update(&mut self, text: Text<'_, Self::Font>) {
         match self.compare(text) {
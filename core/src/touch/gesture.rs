@@ -0,0 +1,210 @@
+//! Recognize multi-touch gestures.
+use crate::time::Instant;
+use crate::touch::{self, Finger};
+use crate::{Point, Radians, Vector};
+
+use std::f32::consts::PI;
+
+/// A two-finger gesture, produced by a [`Recognizer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    /// A second finger touched down, starting a gesture at the given
+    /// center point between both fingers.
+    Started {
+        /// The center point between both fingers.
+        center: Point,
+    },
+
+    /// Both fingers moved together.
+    Pan {
+        /// The movement of the center point since the last [`Gesture`].
+        delta: Vector,
+        /// The current velocity of the center point, in pixels per second.
+        velocity: Vector,
+    },
+
+    /// The fingers moved apart or closer together.
+    Pinch {
+        /// The factor the distance between the fingers changed by since the
+        /// last [`Gesture`].
+        scale: f32,
+        /// The center point between both fingers.
+        center: Point,
+    },
+
+    /// The fingers rotated around each other.
+    Rotate {
+        /// The angle the fingers rotated by since the last [`Gesture`],
+        /// clockwise.
+        angle: Radians,
+        /// The center point between both fingers.
+        center: Point,
+    },
+
+    /// A finger was lifted, ending the gesture.
+    Ended {
+        /// The velocity of the center point when the gesture ended, in
+        /// pixels per second; useful for kinetic panning.
+        velocity: Vector,
+    },
+}
+
+/// Turns touch events into [`Gesture`]s.
+///
+/// Only the first two fingers take part in a gesture; single-finger
+/// touches produce no [`Gesture`] at all.
+#[derive(Debug, Clone, Default)]
+pub struct Recognizer {
+    fingers: Vec<(Finger, Point)>,
+    last: Option<Sample>,
+    velocity: Vector,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    center: Point,
+    distance: f32,
+    angle: f32,
+    at: Instant,
+}
+
+impl Recognizer {
+    /// The smoothing factor applied to the velocity between events.
+    const VELOCITY_SMOOTHING: f32 = 0.6;
+
+    /// Creates a new [`Recognizer`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether a [`Gesture`] is in progress.
+    pub fn is_active(&self) -> bool {
+        self.last.is_some()
+    }
+
+    /// Returns the fingers currently tracked by the [`Recognizer`], along
+    /// with their last known position.
+    pub fn fingers(&self) -> impl Iterator<Item = (Finger, Point)> + '_ {
+        self.fingers.iter().copied()
+    }
+
+    /// Processes a touch event, producing the [`Gesture`]s it completes.
+    pub fn update(&mut self, event: touch::Event) -> Vec<Gesture> {
+        match event {
+            touch::Event::FingerPressed { id, position } => {
+                if self.fingers.len() >= 2 {
+                    return Vec::new();
+                }
+
+                self.fingers.push((id, position));
+
+                self.sample(Instant::now())
+                    .map(|sample| {
+                        self.last = Some(sample);
+                        self.velocity = Vector::new(0.0, 0.0);
+
+                        vec![Gesture::Started {
+                            center: sample.center,
+                        }]
+                    })
+                    .unwrap_or_default()
+            }
+            touch::Event::FingerMoved { id, position } => {
+                let Some(finger) =
+                    self.fingers.iter_mut().find(|(finger, _)| *finger == id)
+                else {
+                    return Vec::new();
+                };
+
+                finger.1 = position;
+
+                let (Some(last), Some(sample)) =
+                    (self.last, self.sample(Instant::now()))
+                else {
+                    return Vec::new();
+                };
+
+                self.last = Some(sample);
+                self.gestures(last, sample)
+            }
+            touch::Event::FingerLifted { id, .. }
+            | touch::Event::FingerLost { id, .. } => {
+                let Some(index) =
+                    self.fingers.iter().position(|(finger, _)| *finger == id)
+                else {
+                    return Vec::new();
+                };
+
+                let _ = self.fingers.remove(index);
+
+                if self.last.take().is_some() {
+                    vec![Gesture::Ended {
+                        velocity: self.velocity,
+                    }]
+                } else {
+                    Vec::new()
+                }
+            }
+        }
+    }
+
+    fn sample(&self, at: Instant) -> Option<Sample> {
+        let [(_, a), (_, b)] = self.fingers.as_slice() else {
+            return None;
+        };
+
+        Some(Sample {
+            center: Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0),
+            distance: a.distance(*b),
+            angle: (b.y - a.y).atan2(b.x - a.x),
+            at,
+        })
+    }
+
+    fn gestures(&mut self, last: Sample, sample: Sample) -> Vec<Gesture> {
+        let mut gestures = Vec::new();
+
+        let delta = sample.center - last.center;
+        let elapsed = (sample.at - last.at).as_secs_f32();
+
+        if elapsed > 0.0 {
+            let velocity = delta * (1.0 / elapsed);
+
+            self.velocity = velocity * Self::VELOCITY_SMOOTHING
+                + self.velocity * (1.0 - Self::VELOCITY_SMOOTHING);
+        }
+
+        if delta != Vector::new(0.0, 0.0) {
+            gestures.push(Gesture::Pan {
+                delta,
+                velocity: self.velocity,
+            });
+        }
+
+        if last.distance > 0.0 && sample.distance != last.distance {
+            gestures.push(Gesture::Pinch {
+                scale: sample.distance / last.distance,
+                center: sample.center,
+            });
+        }
+
+        // Wrap the difference around, so crossing the -π/π boundary is a
+        // small rotation
+        let mut angle = sample.angle - last.angle;
+
+        if angle > PI {
+            angle -= 2.0 * PI;
+        } else if angle < -PI {
+            angle += 2.0 * PI;
+        }
+
+        if angle != 0.0 {
+            gestures.push(Gesture::Rotate {
+                angle: Radians(angle),
+                center: sample.center,
+            });
+        }
+
+        gestures
+    }
+}
@@ -1,5 +1,7 @@
 //! Create custom widgets and operate on them.
 pub mod operation;
+#[cfg(feature = "debug")]
+pub mod profile;
 pub mod text;
 pub mod tree;
 
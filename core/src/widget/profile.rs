@@ -0,0 +1,249 @@
+//! Attribute the time spent laying out and drawing to widget types.
+use crate::event::{self, Event};
+use crate::layout::{self, Layout};
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::time::{Duration, Instant};
+use crate::widget::{Id, Operation, OperationOutputWrapper, Tree};
+use crate::{Clipboard, Length, Rectangle, Shell, Size, Widget};
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// A stage of the lifetime of a [`Widget`] that can be profiled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    /// The [`Widget`] is being laid out.
+    Layout,
+    /// The [`Widget`] is being drawn.
+    Draw,
+}
+
+/// The time spent by a widget type in a [`Stage`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timing {
+    /// The name of the widget type.
+    pub widget: &'static str,
+    /// The measured [`Stage`].
+    pub stage: Stage,
+    /// The time spent by the widget type itself, excluding its children.
+    pub duration: Duration,
+}
+
+#[derive(Debug, Default)]
+struct Profiler {
+    durations: HashMap<(&'static str, Stage), Duration>,
+    children: Vec<Duration>,
+}
+
+thread_local! {
+    static PROFILER: RefCell<Profiler> = RefCell::new(Profiler::default());
+}
+
+/// Takes the [`Timing`]s recorded since the last call, grouped by widget type
+/// and [`Stage`].
+pub fn take() -> Vec<Timing> {
+    PROFILER.with(|profiler| {
+        let mut profiler = profiler.borrow_mut();
+        let mut timings: Vec<Timing> = Vec::new();
+
+        for ((type_name, stage), duration) in profiler.durations.drain() {
+            let widget = short_name(type_name);
+
+            if let Some(timing) = timings
+                .iter_mut()
+                .find(|timing| timing.widget == widget && timing.stage == stage)
+            {
+                timing.duration += duration;
+            } else {
+                timings.push(Timing {
+                    widget,
+                    stage,
+                    duration,
+                });
+            }
+        }
+
+        timings
+    })
+}
+
+fn measure<T>(
+    type_name: &'static str,
+    stage: Stage,
+    f: impl FnOnce() -> T,
+) -> T {
+    PROFILER.with(|profiler| {
+        profiler.borrow_mut().children.push(Duration::ZERO);
+    });
+
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+
+    PROFILER.with(|profiler| {
+        let mut profiler = profiler.borrow_mut();
+        let children = profiler.children.pop().unwrap_or_default();
+
+        if let Some(parent) = profiler.children.last_mut() {
+            *parent += elapsed;
+        }
+
+        *profiler.durations.entry((type_name, stage)).or_default() +=
+            elapsed.saturating_sub(children);
+    });
+
+    result
+}
+
+/// Strips the module path and the generic parameters of a type name.
+fn short_name(type_name: &'static str) -> &'static str {
+    let path = type_name.split('<').next().unwrap_or(type_name);
+
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+/// A [`Widget`] that records the time spent laying out and drawing the
+/// [`Widget`] it wraps.
+pub(crate) struct Profiled<W> {
+    widget: W,
+}
+
+impl<W> Profiled<W> {
+    pub(crate) fn new(widget: W) -> Self {
+        Self { widget }
+    }
+}
+
+impl<W, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Profiled<W>
+where
+    W: Widget<Message, Theme, Renderer>,
+    Renderer: crate::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.widget.size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.widget.size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        measure(std::any::type_name::<W>(), Stage::Layout, || {
+            self.widget.layout(tree, renderer, limits)
+        })
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        measure(std::any::type_name::<W>(), Stage::Draw, || {
+            self.widget
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        });
+    }
+
+    fn tag(&self) -> crate::widget::tree::Tag {
+        self.widget.tag()
+    }
+
+    fn state(&self) -> crate::widget::tree::State {
+        self.widget.state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.widget.children()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.widget.diff(tree);
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.widget.operate(state, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.widget.on_event(
+            state, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.widget
+            .mouse_interaction(state, layout, cursor, viewport, renderer)
+    }
+
+    fn overlay<'a>(
+        &'a mut self,
+        state: &'a mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'a, Message, Theme, Renderer>> {
+        self.widget.overlay(state, layout, renderer)
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        self.widget.a11y_nodes(layout, state, cursor)
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.widget.id()
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.widget.set_id(id);
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut crate::clipboard::DndDestinationRectangles,
+    ) {
+        self.widget.drag_destinations(state, layout, dnd_rectangles);
+    }
+}
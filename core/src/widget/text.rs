@@ -1,17 +1,25 @@
 //! Write some text for your users to read.
+pub mod rich;
+
+pub use rich::Rich;
+
 use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text::{self, Paragraph};
 use crate::widget::tree::{self, Tree};
 use crate::{
-    Color, Element, Layout, Length, Pixels, Point, Rectangle, Size, Widget,
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Vector, Widget,
 };
 
 use std::borrow::Cow;
+use std::ops::Range;
 
-pub use text::{LineHeight, Shaping};
+pub use text::{LineHeight, Shaping, Span, Truncate, Wrap};
 
 /// A paragraph of text.
 #[allow(missing_debug_implementations)]
@@ -30,6 +38,9 @@ where
     vertical_alignment: alignment::Vertical,
     font: Option<Renderer::Font>,
     shaping: Shaping,
+    wrap: Wrap,
+    truncate: Truncate,
+    selectable: bool,
     style: Theme::Style,
 }
 
@@ -51,10 +62,23 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             shaping: Shaping::Advanced,
+            wrap: Wrap::default(),
+            truncate: Truncate::default(),
+            selectable: false,
             style: Default::default(),
         }
     }
 
+    /// Creates a new [`Rich`] text with the given [`Span`]s.
+    pub fn rich<Link, Message>(
+        spans: impl IntoIterator<Item = Span<'a, Link, Renderer::Font>>,
+    ) -> Rich<'a, Link, Message, Theme, Renderer>
+    where
+        Link: Clone,
+    {
+        Rich::new(spans)
+    }
+
     /// Sets the size of the [`Text`].
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
         self.size = Some(size.into());
@@ -116,11 +140,49 @@ where
         self.shaping = shaping;
         self
     }
+
+    /// Sets the [`Wrap`] strategy of the [`Text`].
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets the [`Truncate`] strategy of the [`Text`].
+    pub fn truncate(mut self, truncate: Truncate) -> Self {
+        self.truncate = truncate;
+        self
+    }
+
+    /// Sets whether the [`Text`] can be selected with the mouse and copied.
+    ///
+    /// By default, it cannot.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
 }
 
 /// The internal state of a [`Text`] widget.
 #[derive(Debug, Default)]
-pub struct State<P: Paragraph>(P);
+pub struct State<P: Paragraph> {
+    paragraph: P,
+    selection: Option<Selection>,
+    is_selecting: bool,
+}
+
+/// A selected byte range of some text, from where the selection started to
+/// where it currently ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Selection {
+    anchor: usize,
+    head: usize,
+}
+
+impl Selection {
+    fn range(self) -> Range<usize> {
+        self.anchor.min(self.head)..self.anchor.max(self.head)
+    }
+}
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Text<'a, Theme, Renderer>
@@ -133,7 +195,7 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State(Renderer::Paragraph::default()))
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -162,9 +224,108 @@ where
             self.horizontal_alignment,
             self.vertical_alignment,
             self.shaping,
+            self.wrap,
+            self.truncate,
         )
     }
 
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if !self.selectable {
+            return event::Status::Ignored;
+        }
+
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let offset = paragraph_offset(layout.bounds(), &state.paragraph);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(layout.bounds())
+                else {
+                    state.selection = None;
+
+                    return event::Status::Ignored;
+                };
+
+                state.selection = state
+                    .paragraph
+                    .hit_offset(position - offset)
+                    .map(|offset| Selection {
+                        anchor: offset,
+                        head: offset,
+                    });
+
+                state.is_selecting = state.selection.is_some();
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+                if state.is_selecting =>
+            {
+                if let (Some(selection), Some(head)) = (
+                    &mut state.selection,
+                    state.paragraph.hit_offset(position - offset),
+                ) {
+                    selection.head = head;
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.is_selecting =>
+            {
+                state.is_selecting = false;
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) if modifiers.command()
+                && key.as_ref() == keyboard::Key::Character("c") =>
+            {
+                let selected = state
+                    .selection
+                    .and_then(|selection| self.content.get(selection.range()))
+                    .filter(|selected| !selected.is_empty());
+
+                if let Some(selected) = selected {
+                    clipboard.write(selected.to_owned());
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.selectable && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
     fn draw(
         &self,
         tree: &Tree,
@@ -246,6 +407,8 @@ pub fn layout<Renderer>(
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     shaping: Shaping,
+    wrap: Wrap,
+    truncate: Truncate,
 ) -> layout::Node
 where
     Renderer: text::Renderer,
@@ -256,9 +419,7 @@ where
         let size = size.unwrap_or_else(|| renderer.default_size());
         let font = font.unwrap_or_else(|| renderer.default_font());
 
-        let State(ref mut paragraph) = state;
-
-        paragraph.update(text::Text {
+        let text = text::Text {
             content,
             bounds,
             size,
@@ -267,9 +428,22 @@ where
             horizontal_alignment,
             vertical_alignment,
             shaping,
-        });
+            wrap,
+            truncate,
+        };
+
+        match state.paragraph.compare(text) {
+            text::Difference::None => {}
+            text::Difference::Bounds => {
+                state.paragraph.resize(bounds);
+            }
+            text::Difference::Shape => {
+                state.paragraph = Renderer::Paragraph::with_text(text);
+                state.selection = None;
+            }
+        }
 
-        paragraph.min_bounds()
+        state.paragraph.min_bounds()
     })
 }
 
@@ -293,9 +467,27 @@ pub fn draw<Renderer>(
 ) where
     Renderer: text::Renderer,
 {
-    let State(ref paragraph) = state;
+    let paragraph = &state.paragraph;
     let bounds = layout.bounds();
 
+    if let Some(selection) = state.selection {
+        let offset = paragraph_offset(bounds, paragraph);
+        let color = appearance.selection.unwrap_or(Color {
+            a: 0.3,
+            ..appearance.color.unwrap_or(style.text_color)
+        });
+
+        for bounds in paragraph.range_bounds(selection.range()) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bounds + offset,
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+
     let x = match paragraph.horizontal_alignment() {
         alignment::Horizontal::Left => bounds.x,
         alignment::Horizontal::Center => bounds.center_x(),
@@ -316,6 +508,34 @@ pub fn draw<Renderer>(
     );
 }
 
+/// Returns the position of the top-left corner of the paragraph, given the
+/// bounds of its [`Layout`] and its alignment.
+fn paragraph_offset<P: Paragraph>(bounds: Rectangle, paragraph: &P) -> Vector {
+    let min_bounds = paragraph.min_bounds();
+
+    let x = match paragraph.horizontal_alignment() {
+        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Center => {
+            bounds.center_x() - min_bounds.width / 2.0
+        }
+        alignment::Horizontal::Right => {
+            bounds.x + bounds.width - min_bounds.width
+        }
+    };
+
+    let y = match paragraph.vertical_alignment() {
+        alignment::Vertical::Top => bounds.y,
+        alignment::Vertical::Center => {
+            bounds.center_y() - min_bounds.height / 2.0
+        }
+        alignment::Vertical::Bottom => {
+            bounds.y + bounds.height - min_bounds.height
+        }
+    };
+
+    Vector::new(x, y)
+}
+
 impl<'a, Message, Theme, Renderer> From<Text<'a, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
@@ -347,6 +567,9 @@ where
             font: self.font,
             style: self.style.clone(),
             shaping: self.shaping,
+            wrap: self.wrap,
+            truncate: self.truncate,
+            selectable: self.selectable,
         }
     }
 }
@@ -388,4 +611,9 @@ pub struct Appearance {
     ///
     /// The default, `None`, means using the inherited color.
     pub color: Option<Color>,
+
+    /// The [`Color`] of the selection of some selectable text.
+    ///
+    /// The default, `None`, means using a translucent text color.
+    pub selection: Option<Color>,
 }
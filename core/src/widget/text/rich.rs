@@ -0,0 +1,445 @@
+//! Write rich text with inline styles and links.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, LineHeight, Paragraph, Shaping, Span};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Vector, Widget,
+};
+
+use super::{Appearance, StyleSheet};
+
+/// A paragraph of [`Span`]s, each with its own font, color, and decorations.
+///
+/// Clicking a [`Span`] with a link produces the message set with
+/// [`Rich::on_link_click`].
+#[allow(missing_debug_implementations)]
+pub struct Rich<'a, Link, Message, Theme, Renderer>
+where
+    Link: Clone,
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    spans: Vec<Span<'a, Link, Renderer::Font>>,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    width: Length,
+    height: Length,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    font: Option<Renderer::Font>,
+    style: Theme::Style,
+    on_link_click: Option<Box<dyn Fn(Link) -> Message + 'a>>,
+}
+
+impl<'a, Link, Message, Theme, Renderer>
+    Rich<'a, Link, Message, Theme, Renderer>
+where
+    Link: Clone,
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Rich`] text with the given [`Span`]s.
+    pub fn new(
+        spans: impl IntoIterator<Item = Span<'a, Link, Renderer::Font>>,
+    ) -> Self {
+        Self {
+            spans: spans.into_iter().collect(),
+            size: None,
+            line_height: LineHeight::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            font: None,
+            style: Default::default(),
+            on_link_click: None,
+        }
+    }
+
+    /// Sets the size of the [`Rich`] text.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Rich`] text.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the default font of the [`Rich`] text, used by the [`Span`]s
+    /// without their own font.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Rich`] text.
+    pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the width of the [`Rich`] text boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Rich`] text boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`alignment::Horizontal`] of the [`Rich`] text.
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: alignment::Horizontal,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`alignment::Vertical`] of the [`Rich`] text.
+    pub fn vertical_alignment(
+        mut self,
+        alignment: alignment::Vertical,
+    ) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Span`] with a link
+    /// is clicked.
+    pub fn on_link_click(
+        mut self,
+        on_link_click: impl Fn(Link) -> Message + 'a,
+    ) -> Self {
+        self.on_link_click = Some(Box::new(on_link_click));
+        self
+    }
+}
+
+/// The internal state of a [`Rich`] text.
+struct State<P: Paragraph> {
+    paragraph: P,
+    spans: Vec<(String, Option<P::Font>, Option<Color>)>,
+    hovered_span: Option<usize>,
+    pressed_span: Option<usize>,
+}
+
+impl<'a, Link, Message, Theme, Renderer>
+    Rich<'a, Link, Message, Theme, Renderer>
+where
+    Link: Clone,
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    /// Returns the position of the top-left corner of the paragraph.
+    fn origin(paragraph: &Renderer::Paragraph, bounds: Rectangle) -> Point {
+        let min_bounds = paragraph.min_bounds();
+
+        let x = match paragraph.horizontal_alignment() {
+            alignment::Horizontal::Left => bounds.x,
+            alignment::Horizontal::Center => {
+                bounds.center_x() - min_bounds.width / 2.0
+            }
+            alignment::Horizontal::Right => {
+                bounds.x + bounds.width - min_bounds.width
+            }
+        };
+
+        let y = match paragraph.vertical_alignment() {
+            alignment::Vertical::Top => bounds.y,
+            alignment::Vertical::Center => {
+                bounds.center_y() - min_bounds.height / 2.0
+            }
+            alignment::Vertical::Bottom => {
+                bounds.y + bounds.height - min_bounds.height
+            }
+        };
+
+        Point::new(x, y)
+    }
+
+    fn link_under(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        let position = cursor.position_over(layout.bounds())?;
+        let origin = Self::origin(&state.paragraph, layout.bounds());
+
+        state
+            .paragraph
+            .hit_span(position - Vector::new(origin.x, origin.y))
+            .filter(|index| {
+                self.spans
+                    .get(*index)
+                    .is_some_and(|span| span.link.is_some())
+            })
+    }
+}
+
+impl<'a, Link, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Rich<'a, Link, Message, Theme, Renderer>
+where
+    Link: Clone,
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            paragraph: Renderer::Paragraph::default(),
+            spans: Vec::new(),
+            hovered_span: None,
+            pressed_span: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        layout::sized(limits, self.width, self.height, |limits| {
+            let text = text::Text {
+                content: "",
+                bounds: limits.max(),
+                size: self.size.unwrap_or_else(|| renderer.default_size()),
+                line_height: self.line_height,
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                horizontal_alignment: self.horizontal_alignment,
+                vertical_alignment: self.vertical_alignment,
+                shaping: Shaping::Advanced,
+                wrap: text::Wrap::default(),
+                truncate: text::Truncate::default(),
+            };
+
+            let spans_changed = state.spans.len() != self.spans.len()
+                || state.spans.iter().zip(&self.spans).any(
+                    |((content, font, color), span)| {
+                        content.as_str() != span.text
+                            || *font != span.font
+                            || *color != span.color
+                    },
+                );
+
+            if spans_changed {
+                state.spans = self
+                    .spans
+                    .iter()
+                    .map(|span| (span.text.to_string(), span.font, span.color))
+                    .collect();
+            }
+
+            let content = state
+                .spans
+                .iter()
+                .map(|(content, _, _)| content.as_str())
+                .collect::<String>();
+
+            match state.paragraph.compare(text::Text {
+                content: &content,
+                ..text
+            }) {
+                text::Difference::None if !spans_changed => {}
+                text::Difference::Bounds if !spans_changed => {
+                    state.paragraph.resize(text.bounds);
+                }
+                _ => {
+                    state.paragraph =
+                        Renderer::Paragraph::with_spans(&self.spans, text);
+                }
+            }
+
+            state.paragraph.min_bounds()
+        })
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let Some(on_link_click) = &self.on_link_click else {
+            return event::Status::Ignored;
+        };
+
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let link = self.link_under(state, layout, cursor);
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                state.hovered_span = link;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if link.is_some() {
+                    state.pressed_span = link;
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(pressed) = state.pressed_span.take() {
+                    if link == Some(pressed) {
+                        if let Some(link) = self
+                            .spans
+                            .get(pressed)
+                            .and_then(|span| span.link.clone())
+                        {
+                            shell.publish(on_link_click(link));
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        if self.on_link_click.is_some()
+            && self.link_under(state, layout, cursor).is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let Appearance { color } = theme.appearance(self.style.clone());
+        let color = color.unwrap_or(style.text_color);
+
+        let origin = Self::origin(&state.paragraph, bounds);
+        let translation = Vector::new(origin.x, origin.y);
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let thickness = (size.0 / 14.0).max(1.0);
+
+        for (index, span) in self.spans.iter().enumerate() {
+            let is_hovered_link = state.hovered_span == Some(index);
+
+            if !(span.underline || span.strikethrough || is_hovered_link) {
+                continue;
+            }
+
+            let span_color = span.color.unwrap_or(color);
+
+            for span_bounds in state.paragraph.span_bounds(index) {
+                let span_bounds = span_bounds + translation;
+                let mut lines = Vec::with_capacity(2);
+
+                if span.underline || is_hovered_link {
+                    lines.push(span_bounds.y + span_bounds.height * 0.85);
+                }
+
+                if span.strikethrough {
+                    lines.push(span_bounds.center_y());
+                }
+
+                for y in lines {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: span_bounds.x,
+                                y: y - thickness / 2.0,
+                                width: span_bounds.width,
+                                height: thickness,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        span_color,
+                    );
+                }
+            }
+        }
+
+        let x = match state.paragraph.horizontal_alignment() {
+            alignment::Horizontal::Left => bounds.x,
+            alignment::Horizontal::Center => bounds.center_x(),
+            alignment::Horizontal::Right => bounds.x + bounds.width,
+        };
+
+        let y = match state.paragraph.vertical_alignment() {
+            alignment::Vertical::Top => bounds.y,
+            alignment::Vertical::Center => bounds.center_y(),
+            alignment::Vertical::Bottom => bounds.y + bounds.height,
+        };
+
+        renderer.fill_paragraph(
+            &state.paragraph,
+            Point::new(x, y),
+            color,
+            *viewport,
+        );
+    }
+}
+
+impl<'a, Link, Message, Theme, Renderer>
+    From<Rich<'a, Link, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Link: Clone + 'a,
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        rich: Rich<'a, Link, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(rich)
+    }
+}
@@ -2,6 +2,8 @@
 pub mod icon;
 pub mod settings;
 
+mod color_scheme;
+mod direction;
 mod event;
 mod id;
 mod level;
@@ -10,6 +12,8 @@ mod position;
 mod redraw_request;
 mod user_attention;
 
+pub use color_scheme::ColorScheme;
+pub use direction::Direction;
 pub use event::Event;
 pub use icon::Icon;
 pub use id::Id;
@@ -0,0 +1,10 @@
+/// The color scheme preferred by the user for the windows of an application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorScheme {
+    /// A light color scheme.
+    #[default]
+    Light,
+
+    /// A dark color scheme.
+    Dark,
+}
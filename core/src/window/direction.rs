@@ -0,0 +1,20 @@
+/// An edge or a corner of a window, from which it can be resized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// The top edge.
+    North,
+    /// The bottom edge.
+    South,
+    /// The right edge.
+    East,
+    /// The left edge.
+    West,
+    /// The top-right corner.
+    NorthEast,
+    /// The top-left corner.
+    NorthWest,
+    /// The bottom-right corner.
+    SouthEast,
+    /// The bottom-left corner.
+    SouthWest,
+}
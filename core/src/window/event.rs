@@ -1,4 +1,5 @@
 use crate::time::Instant;
+use crate::window::ColorScheme;
 use crate::{Point, Size};
 
 use std::path::PathBuf;
@@ -69,4 +70,10 @@ pub enum Event {
     /// There will be a single `FilesHoveredLeft` event triggered even if
     /// multiple files were hovered.
     FilesHoveredLeft,
+
+    /// The color scheme preferred by the user has changed.
+    ///
+    /// This event is also produced once when a window is opened, if the
+    /// platform reports a preference.
+    ColorSchemeChanged(ColorScheme),
 }
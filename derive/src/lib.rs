@@ -0,0 +1,282 @@
+//! Derive macros for iced.
+//!
+//! Enable the `form` feature of `iced` to use them.
+#![forbid(unsafe_code, rust_2018_idioms)]
+#![deny(
+    missing_debug_implementations,
+    missing_docs,
+    unused_results,
+    rustdoc::broken_intra_doc_links
+)]
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{
+    Data, DeriveInput, Expr, Fields, GenericArgument, Ident, LitStr,
+    PathArguments, Type,
+};
+
+/// Derives a `Form` for a struct of `Field`s.
+///
+/// A `<Struct>Message` enum is generated alongside it, with a variant
+/// carrying the `Update` of every field.
+///
+/// Each field is shown with its label, a widget fitting its type, and its
+/// latest validation error. Fields of `bool` become checkboxes, and any
+/// other field becomes a text input, unless configured otherwise:
+///
+/// - `#[form(label = "...")]` replaces the label derived from its name.
+/// - `#[form(placeholder = "...")]` sets the placeholder of a text input.
+/// - `#[form(slider = 0.0..=1.0)]` shows a slider over the given range.
+/// - `#[form(skip)]` leaves a field out of the form; it does not need to be
+///   a `Field`.
+///
+/// ```ignore
+/// use iced::widget::binding::Field;
+/// use iced::Form;
+///
+/// #[derive(Form)]
+/// struct Settings {
+///     #[form(slider = 0.0..=100.0)]
+///     volume: Field<f32>,
+///     #[form(label = "Server port", placeholder = "8080")]
+///     port: Field<u16>,
+///     notifications: Field<bool>,
+/// }
+///
+/// // `settings.view()` produces `SettingsMessage`s, which are applied with
+/// // `settings.update(message)`.
+/// ```
+#[proc_macro_derive(Form, attributes(form))]
+pub fn derive_form(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+
+    form(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// A field of a form, along with its configuration.
+struct FormField {
+    ident: Ident,
+    variant: Ident,
+    value: Type,
+    label: String,
+    placeholder: String,
+    slider: Option<Expr>,
+}
+
+fn form(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "`Form` can only be derived for structs",
+        ));
+    };
+
+    let Fields::Named(named) = &data.fields else {
+        return Err(syn::Error::new(
+            data.fields.span(),
+            "`Form` can only be derived for structs with named fields",
+        ));
+    };
+
+    let mut fields = Vec::new();
+
+    for field in &named.named {
+        if let Some(field) = form_field(field)? {
+            fields.push(field);
+        }
+    }
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let message = format_ident!("{}Message", name);
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    let variants = fields.iter().map(|field| {
+        let FormField { variant, value, .. } = field;
+        let doc = format!("The `{}` field was updated.", field.ident);
+
+        quote! {
+            #[doc = #doc]
+            #variant(::iced::widget::binding::Update<#value>)
+        }
+    });
+
+    let views = fields.iter().map(|field| {
+        let FormField {
+            ident,
+            variant,
+            value,
+            label,
+            placeholder,
+            slider,
+        } = field;
+
+        let bind = quote! {
+            ::iced::widget::binding::bind(&self.#ident, #message::#variant)
+        };
+
+        let (label, widget) = if let Some(range) = slider {
+            (label.as_str(), quote! { #bind.slider(#range) })
+        } else if is_bool(value) {
+            ("", quote! { #bind.checkbox(#label) })
+        } else {
+            (label.as_str(), quote! { #bind.text_input(#placeholder) })
+        };
+
+        quote! {
+            .push(::iced::widget::binding::labeled(#label, &self.#ident, #widget))
+        }
+    });
+
+    let updates = fields.iter().map(|FormField { ident, variant, .. }| {
+        quote! {
+            #message::#variant(update) => self.#ident.update(update),
+        }
+    });
+
+    let doc = format!("A message produced by the form of a [`{name}`].");
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone)]
+        #vis enum #message {
+            #(#variants,)*
+        }
+
+        impl #impl_generics ::iced::widget::binding::Form for #name #ty_generics
+        #where_clause
+        {
+            type Message = #message;
+
+            fn view(&self) -> ::iced::Element<'_, #message> {
+                ::iced::widget::Column::new()
+                    .spacing(10)
+                    #(#views)*
+                    .into()
+            }
+
+            fn update(&mut self, message: #message) {
+                match message {
+                    #(#updates)*
+                }
+            }
+        }
+    })
+}
+
+fn form_field(field: &syn::Field) -> syn::Result<Option<FormField>> {
+    let ident = field.ident.clone().expect("Named field");
+
+    let mut label = None;
+    let mut placeholder = None;
+    let mut slider = None;
+    let mut skip = false;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("form"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("label") {
+                label = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("placeholder") {
+                placeholder = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("slider") {
+                slider = Some(meta.value()?.parse::<Expr>()?);
+            } else if meta.path.is_ident("skip") {
+                skip = true;
+            } else {
+                return Err(meta.error("unsupported `form` attribute"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    if skip {
+        return Ok(None);
+    }
+
+    let Some(value) = field_value(&field.ty) else {
+        return Err(syn::Error::new(
+            field.ty.span(),
+            "fields of a `Form` must be a `Field<T>`, or be marked with \
+             `#[form(skip)]`",
+        ));
+    };
+
+    let name = ident.to_string();
+
+    Ok(Some(FormField {
+        variant: Ident::new(&pascal_case(&name), ident.span()),
+        label: label.unwrap_or_else(|| sentence_case(&name)),
+        placeholder: placeholder.unwrap_or_default(),
+        value: value.clone(),
+        slider,
+        ident,
+    }))
+}
+
+/// Returns the `T` of a `Field<T>` type.
+fn field_value(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+
+    let segment = path.path.segments.last()?;
+
+    if segment.ident != "Field" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+
+    match arguments.args.first()? {
+        GenericArgument::Type(value) => Some(value),
+        _ => None,
+    }
+}
+
+fn is_bool(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.path.is_ident("bool"))
+}
+
+/// Turns `max_connections` into `MaxConnections`.
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+
+            chars
+                .next()
+                .map(|first| {
+                    first.to_uppercase().chain(chars).collect::<String>()
+                })
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Turns `max_connections` into `Max connections`.
+fn sentence_case(name: &str) -> String {
+    let words = name
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut chars = words.chars();
+
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+        .unwrap_or_default()
+}
@@ -1,10 +1,13 @@
 use iced::alignment::{self, Alignment};
+use iced::event::{self, Event};
+use iced::executor;
 use iced::mouse;
 use iced::widget::canvas::{self, Canvas, Frame, Geometry, Path};
-use iced::widget::{column, row, text, Slider};
+use iced::widget::{button, column, row, text, Slider};
+use iced::window;
 use iced::{
-    Color, Element, Length, Pixels, Point, Rectangle, Renderer, Sandbox,
-    Settings, Size, Vector,
+    Application, Color, Command, Element, Length, Pixels, Point, Rectangle,
+    Renderer, Settings, Size, Subscription, Vector,
 };
 use palette::{
     self, convert::FromColor, rgb::Rgb, Darken, Hsl, Lighten, ShiftHue,
@@ -28,9 +31,18 @@ pub struct ColorPalette {
     hwb: ColorPicker<palette::Hwb>,
     lab: ColorPicker<palette::Lab>,
     lch: ColorPicker<palette::Lch>,
+    eyedropper: Eyedropper,
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Samples the color of any pixel of the window.
+#[derive(Debug, Default)]
+struct Eyedropper {
+    is_picking: bool,
+    cursor: Point,
+    window_size: Option<Size>,
+}
+
+#[derive(Debug, Clone)]
 pub enum Message {
     RgbColorChanged(Color),
     HslColorChanged(palette::Hsl),
@@ -38,20 +50,32 @@ pub enum Message {
     HwbColorChanged(palette::Hwb),
     LabColorChanged(palette::Lab),
     LchColorChanged(palette::Lch),
+    EyedropperToggled,
+    CursorMoved(Point),
+    WindowResized(Size),
+    Sampled,
+    Screenshotted(window::Screenshot, Point),
+    #[cfg(target_os = "linux")]
+    ScreenPickRequested,
+    #[cfg(target_os = "linux")]
+    ScreenPicked(Option<Color>),
 }
 
-impl Sandbox for ColorPalette {
+impl Application for ColorPalette {
+    type Executor = executor::Default;
     type Message = Message;
+    type Theme = iced::Theme;
+    type Flags = ();
 
-    fn new() -> Self {
-        Self::default()
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (Self::default(), Command::none())
     }
 
     fn title(&self) -> String {
         String::from("Color palette - Iced")
     }
 
-    fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) -> Command<Message> {
         let srgb = match message {
             Message::RgbColorChanged(rgb) => Rgb::from(rgb),
             Message::HslColorChanged(hsl) => Rgb::from_color(hsl),
@@ -59,9 +83,93 @@ impl Sandbox for ColorPalette {
             Message::HwbColorChanged(hwb) => Rgb::from_color(hwb),
             Message::LabColorChanged(lab) => Rgb::from_color(lab),
             Message::LchColorChanged(lch) => Rgb::from_color(lch),
+            Message::EyedropperToggled => {
+                self.eyedropper.is_picking = !self.eyedropper.is_picking;
+
+                return Command::none();
+            }
+            Message::CursorMoved(position) => {
+                self.eyedropper.cursor = position;
+
+                return Command::none();
+            }
+            Message::WindowResized(size) => {
+                self.eyedropper.window_size = Some(size);
+
+                return Command::none();
+            }
+            Message::Sampled => {
+                self.eyedropper.is_picking = false;
+
+                let position = self.eyedropper.cursor;
+
+                return window::screenshot(
+                    window::Id::MAIN,
+                    move |screenshot| {
+                        Message::Screenshotted(screenshot, position)
+                    },
+                );
+            }
+            Message::Screenshotted(screenshot, position) => {
+                // The screenshot is in physical pixels
+                let scale = self.eyedropper.window_size.map_or(1.0, |size| {
+                    screenshot.size.width as f32 / size.width
+                });
+
+                let Some(color) = screenshot.pixel(
+                    (position.x * scale) as u32,
+                    (position.y * scale) as u32,
+                ) else {
+                    return Command::none();
+                };
+
+                Rgb::from(Color { a: 1.0, ..color })
+            }
+            #[cfg(target_os = "linux")]
+            Message::ScreenPickRequested => {
+                return Command::perform(
+                    async { iced::portal::pick_color().await.ok().flatten() },
+                    Message::ScreenPicked,
+                );
+            }
+            #[cfg(target_os = "linux")]
+            Message::ScreenPicked(color) => {
+                let Some(color) = color else {
+                    return Command::none();
+                };
+
+                Rgb::from(color)
+            }
         };
 
         self.theme = Theme::new(srgb);
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        let resizes = event::listen_with(|event, _status| match event {
+            Event::Window(_, window::Event::Resized { width, height }) => Some(
+                Message::WindowResized(Size::new(width as f32, height as f32)),
+            ),
+            _ => None,
+        });
+
+        if !self.eyedropper.is_picking {
+            return resizes;
+        }
+
+        let samples = event::listen_with(|event, _status| match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                Some(Message::CursorMoved(position))
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                Some(Message::Sampled)
+            }
+            _ => None,
+        });
+
+        Subscription::batch([resizes, samples])
     }
 
     fn view(&self) -> Element<Message> {
@@ -74,7 +182,22 @@ impl Sandbox for ColorPalette {
         let lab = palette::Lab::from_color(srgb);
         let lch = palette::Lch::from_color(srgb);
 
+        let eyedropper = button(if self.eyedropper.is_picking {
+            "Click anywhere to sample its color"
+        } else {
+            "Eyedropper"
+        })
+        .on_press(Message::EyedropperToggled);
+
+        #[cfg(target_os = "linux")]
+        let eyedropper = row![
+            eyedropper,
+            button("Pick from screen").on_press(Message::ScreenPickRequested),
+        ]
+        .spacing(10);
+
         column![
+            eyedropper,
             self.rgb.view(base).map(Message::RgbColorChanged),
             self.hsl.view(hsl).map(Message::HslColorChanged),
             self.hsv.view(hsv).map(Message::HsvColorChanged),
@@ -0,0 +1,616 @@
+use iced::alignment;
+use iced::theme::{self, Platform, Theme};
+use iced::widget::pane_grid::{self, PaneGrid};
+use iced::widget::{
+    button, checkbox, column, combo_box, container, horizontal_rule,
+    horizontal_space, pick_list, progress_bar, radio, row, scrollable, slider,
+    text, text_editor, text_input, toggler, tooltip, vertical_slider,
+    vertical_space,
+};
+use iced::window::ColorScheme;
+use iced::{Alignment, Element, Length, Sandbox, Settings};
+
+use std::fmt;
+
+pub fn main() -> iced::Result {
+    Gallery::run(Settings::default())
+}
+
+struct Gallery {
+    page: Page,
+    theme: ThemeChoice,
+    controls: Controls,
+    editor: text_editor::Content,
+    panes: pane_grid::State<usize>,
+    panes_created: usize,
+    fruits: combo_box::State<Fruit>,
+    fruit: Option<Fruit>,
+    language: Option<Language>,
+}
+
+#[derive(Default)]
+struct Controls {
+    input: String,
+    slider: f32,
+    vertical_slider: f32,
+    checkbox: bool,
+    toggler: bool,
+    radio: Option<Choice>,
+    presses: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    PageSelected(Page),
+    ThemeSelected(ThemeChoice),
+    InputChanged(String),
+    ButtonPressed,
+    SliderChanged(f32),
+    VerticalSliderChanged(f32),
+    CheckboxToggled(bool),
+    TogglerToggled(bool),
+    RadioSelected(Choice),
+    Edit(text_editor::Action),
+    Split(pane_grid::Axis, pane_grid::Pane),
+    Close(pane_grid::Pane),
+    Maximize(pane_grid::Pane),
+    Restore,
+    Dragged(pane_grid::DragEvent),
+    Resized(pane_grid::ResizeEvent),
+    FruitSelected(Fruit),
+    LanguageSelected(Language),
+}
+
+impl Sandbox for Gallery {
+    type Message = Message;
+
+    fn new() -> Self {
+        let (panes, _) = pane_grid::State::new(0);
+
+        Self {
+            page: Page::Controls,
+            theme: ThemeChoice::Light,
+            controls: Controls::default(),
+            editor: text_editor::Content::with_text(
+                "Edit me!\n\nThe editor supports selection, scrolling, \
+                 and the usual keyboard shortcuts.",
+            ),
+            panes,
+            panes_created: 1,
+            fruits: combo_box::State::new(Fruit::ALL.to_vec()),
+            fruit: None,
+            language: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        format!("{} - Gallery - Iced", self.page)
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::PageSelected(page) => self.page = page,
+            Message::ThemeSelected(theme) => self.theme = theme,
+            Message::InputChanged(input) => self.controls.input = input,
+            Message::ButtonPressed => self.controls.presses += 1,
+            Message::SliderChanged(value) => self.controls.slider = value,
+            Message::VerticalSliderChanged(value) => {
+                self.controls.vertical_slider = value;
+            }
+            Message::CheckboxToggled(value) => self.controls.checkbox = value,
+            Message::TogglerToggled(value) => self.controls.toggler = value,
+            Message::RadioSelected(choice) => {
+                self.controls.radio = Some(choice);
+            }
+            Message::Edit(action) => self.editor.perform(action),
+            Message::Split(axis, pane) => {
+                let _ = self.panes.split(axis, pane, self.panes_created);
+
+                self.panes_created += 1;
+            }
+            Message::Close(pane) => {
+                let _ = self.panes.close(pane);
+            }
+            Message::Maximize(pane) => self.panes.maximize(pane),
+            Message::Restore => self.panes.restore(),
+            Message::Dragged(pane_grid::DragEvent::Dropped {
+                pane,
+                target,
+            }) => {
+                self.panes.drop(pane, target);
+            }
+            Message::Dragged(_) => {}
+            Message::Resized(pane_grid::ResizeEvent { split, ratio }) => {
+                self.panes.resize(split, ratio);
+            }
+            Message::FruitSelected(fruit) => self.fruit = Some(fruit),
+            Message::LanguageSelected(language) => {
+                self.language = Some(language);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let sidebar = Page::ALL.iter().fold(
+            column![text("Gallery").size(24)].spacing(5).width(160),
+            |column, page| {
+                column.push(
+                    button(text(page.to_string()))
+                        .width(Length::Fill)
+                        .style(if *page == self.page {
+                            theme::Button::Primary
+                        } else {
+                            theme::Button::Text
+                        })
+                        .on_press(Message::PageSelected(*page)),
+                )
+            },
+        );
+
+        let sidebar = column![
+            sidebar,
+            vertical_space(Length::Fill),
+            text("Theme"),
+            pick_list(
+                ThemeChoice::ALL,
+                Some(self.theme),
+                Message::ThemeSelected
+            ),
+        ]
+        .spacing(10)
+        .padding(10);
+
+        let page = match self.page {
+            Page::Controls => self.controls(),
+            Page::Text => self.text(),
+            Page::Panes => self.panes(),
+            Page::Scrolling => scrolling(),
+            Page::Overlays => self.overlays(),
+        };
+
+        row![
+            sidebar,
+            container(page)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(20)
+        ]
+        .into()
+    }
+
+    fn theme(&self) -> Theme {
+        self.theme.theme()
+    }
+}
+
+impl Gallery {
+    fn controls(&self) -> Element<'_, Message> {
+        let controls = &self.controls;
+
+        let buttons = row![
+            button("Primary").on_press(Message::ButtonPressed),
+            button("Secondary")
+                .style(theme::Button::Secondary)
+                .on_press(Message::ButtonPressed),
+            button("Positive")
+                .style(theme::Button::Positive)
+                .on_press(Message::ButtonPressed),
+            button("Destructive")
+                .style(theme::Button::Destructive)
+                .on_press(Message::ButtonPressed),
+            button("Text")
+                .style(theme::Button::Text)
+                .on_press(Message::ButtonPressed),
+            button("Disabled"),
+            text(format!("Pressed {} times", controls.presses)),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        let radios =
+            Choice::ALL.iter().fold(row![].spacing(20), |row, choice| {
+                row.push(radio(
+                    choice.to_string(),
+                    *choice,
+                    controls.radio,
+                    Message::RadioSelected,
+                ))
+            });
+
+        let sliders = row![
+            column![
+                slider(0.0..=100.0, controls.slider, Message::SliderChanged),
+                progress_bar(0.0..=100.0, controls.slider),
+            ]
+            .spacing(10),
+            vertical_slider(
+                0.0..=100.0,
+                controls.vertical_slider,
+                Message::VerticalSliderChanged
+            )
+            .height(100),
+        ]
+        .spacing(20);
+
+        column![
+            section("Buttons", buttons),
+            section(
+                "Text input",
+                column![
+                    text_input("Type something...", &controls.input)
+                        .on_input(Message::InputChanged)
+                        .on_submit(Message::ButtonPressed),
+                    text_input("Secure", &controls.input)
+                        .on_input(Message::InputChanged)
+                        .password(),
+                    text_input("Disabled", &controls.input),
+                ]
+                .spacing(10)
+            ),
+            section(
+                "Toggles",
+                row![
+                    checkbox(
+                        "Checkbox",
+                        controls.checkbox,
+                        Message::CheckboxToggled
+                    ),
+                    toggler(
+                        String::from("Toggler"),
+                        controls.toggler,
+                        Message::TogglerToggled
+                    )
+                    .width(Length::Shrink),
+                ]
+                .spacing(20)
+            ),
+            section("Radio buttons", radios),
+            section("Sliders", sliders),
+        ]
+        .spacing(20)
+        .max_width(700)
+        .into()
+    }
+
+    fn text(&self) -> Element<'_, Message> {
+        let sizes = [32, 24, 16, 12].into_iter().fold(
+            column![].spacing(5),
+            |column, size| {
+                column.push(text(format!("{size} pixels")).size(size))
+            },
+        );
+
+        let alignment = column![
+            text("Left").width(Length::Fill),
+            text("Center")
+                .width(Length::Fill)
+                .horizontal_alignment(alignment::Horizontal::Center),
+            text("Right")
+                .width(Length::Fill)
+                .horizontal_alignment(alignment::Horizontal::Right),
+        ];
+
+        column![
+            section("Sizes", sizes),
+            section("Alignment", alignment),
+            section(
+                "Editor",
+                text_editor(&self.editor)
+                    .height(200)
+                    .on_action(Message::Edit)
+            ),
+        ]
+        .spacing(20)
+        .max_width(700)
+        .into()
+    }
+
+    fn panes(&self) -> Element<'_, Message> {
+        let total_panes = self.panes.len();
+
+        let pane_grid = PaneGrid::new(&self.panes, |pane, id, is_maximized| {
+            let small = |label| text(label).size(14);
+
+            let mut controls = row![].spacing(5);
+
+            if total_panes > 1 {
+                controls = controls.push(if is_maximized {
+                    button(small("Restore"))
+                        .style(theme::Button::Secondary)
+                        .padding(3)
+                        .on_press(Message::Restore)
+                } else {
+                    button(small("Maximize"))
+                        .style(theme::Button::Secondary)
+                        .padding(3)
+                        .on_press(Message::Maximize(pane))
+                });
+
+                controls = controls.push(
+                    button(small("Close"))
+                        .style(theme::Button::Destructive)
+                        .padding(3)
+                        .on_press(Message::Close(pane)),
+                );
+            }
+
+            let content = column![
+                button("Split horizontally").on_press(Message::Split(
+                    pane_grid::Axis::Horizontal,
+                    pane
+                )),
+                button("Split vertically")
+                    .on_press(Message::Split(pane_grid::Axis::Vertical, pane)),
+            ]
+            .spacing(5);
+
+            pane_grid::Content::new(
+                container(content)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .center_x()
+                    .center_y(),
+            )
+            .title_bar(
+                pane_grid::TitleBar::new(text(format!("Pane {id}")))
+                    .controls(controls)
+                    .padding(5)
+                    .style(theme::Container::Box),
+            )
+            .style(theme::Container::Box)
+        })
+        .spacing(10)
+        .on_drag(Message::Dragged)
+        .on_resize(10, Message::Resized);
+
+        column![
+            text("Drag the title bars to dock panes; drag the gaps to resize"),
+            pane_grid,
+        ]
+        .spacing(10)
+        .into()
+    }
+
+    fn overlays(&self) -> Element<'_, Message> {
+        let tooltips = [
+            ("Top", tooltip::Position::Top),
+            ("Bottom", tooltip::Position::Bottom),
+            ("Left", tooltip::Position::Left),
+            ("Right", tooltip::Position::Right),
+            ("Follow cursor", tooltip::Position::FollowCursor),
+        ]
+        .into_iter()
+        .fold(row![].spacing(10), |row, (label, position)| {
+            row.push(
+                tooltip(
+                    button(label).style(theme::Button::Secondary),
+                    format!("A tooltip on the {}", label.to_lowercase()),
+                    position,
+                )
+                .style(theme::Container::Elevated(theme::Elevation::Level2)),
+            )
+        });
+
+        column![
+            section("Tooltips", tooltips),
+            section(
+                "Pick list",
+                pick_list(
+                    Language::ALL,
+                    self.language,
+                    Message::LanguageSelected
+                )
+                .placeholder("Choose a language...")
+            ),
+            section(
+                "Combo box",
+                combo_box(
+                    &self.fruits,
+                    "Type a fruit...",
+                    self.fruit.as_ref(),
+                    Message::FruitSelected
+                )
+            ),
+        ]
+        .spacing(20)
+        .max_width(700)
+        .into()
+    }
+}
+
+fn scrolling<'a>() -> Element<'a, Message> {
+    let vertical = scrollable(
+        (1..=50).fold(column![].spacing(5).width(Length::Fill), |column, i| {
+            column.push(text(format!("Row {i}")))
+        }),
+    )
+    .height(200);
+
+    let horizontal = scrollable((1..=50).fold(row![].spacing(20), |row, i| {
+        row.push(text(format!("Column {i}")))
+    }))
+    .direction(scrollable::Direction::Horizontal(
+        scrollable::Properties::default(),
+    ));
+
+    let both = scrollable((1..=30).fold(column![].spacing(5), |column, i| {
+        column.push((1..=20).fold(row![].spacing(20), |row, j| {
+            row.push(text(format!("({i}, {j})")))
+        }))
+    }))
+    .direction(scrollable::Direction::Both {
+        vertical: scrollable::Properties::default(),
+        horizontal: scrollable::Properties::default(),
+    })
+    .height(200);
+
+    column![
+        section("Vertical", vertical),
+        section("Horizontal", horizontal),
+        section("Both", both),
+    ]
+    .spacing(20)
+    .into()
+}
+
+fn section<'a>(
+    title: &str,
+    content: impl Into<Element<'a, Message>>,
+) -> Element<'a, Message> {
+    column![
+        row![
+            text(title.to_owned()).size(20),
+            horizontal_space(Length::Fill)
+        ],
+        horizontal_rule(1),
+        content.into(),
+    ]
+    .spacing(10)
+    .into()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Page {
+    Controls,
+    Text,
+    Panes,
+    Scrolling,
+    Overlays,
+}
+
+impl Page {
+    const ALL: [Self; 5] = [
+        Self::Controls,
+        Self::Text,
+        Self::Panes,
+        Self::Scrolling,
+        Self::Overlays,
+    ];
+}
+
+impl fmt::Display for Page {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Controls => "Controls",
+            Self::Text => "Text",
+            Self::Panes => "Panes",
+            Self::Scrolling => "Scrolling",
+            Self::Overlays => "Overlays",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ThemeChoice {
+    Light,
+    Dark,
+    Platform(Platform, ColorScheme),
+}
+
+impl ThemeChoice {
+    const ALL: &'static [Self] = &[
+        Self::Light,
+        Self::Dark,
+        Self::Platform(Platform::Pop, ColorScheme::Light),
+        Self::Platform(Platform::Pop, ColorScheme::Dark),
+        Self::Platform(Platform::Fluent, ColorScheme::Light),
+        Self::Platform(Platform::Fluent, ColorScheme::Dark),
+        Self::Platform(Platform::Aqua, ColorScheme::Light),
+        Self::Platform(Platform::Aqua, ColorScheme::Dark),
+    ];
+
+    fn theme(self) -> Theme {
+        match self {
+            Self::Light => Theme::Light,
+            Self::Dark => Theme::Dark,
+            Self::Platform(platform, color_scheme) => {
+                Theme::platform(platform, color_scheme)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ThemeChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.theme())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Choice {
+    A,
+    B,
+    C,
+}
+
+impl Choice {
+    const ALL: [Self; 3] = [Self::A, Self::B, Self::C];
+}
+
+impl fmt::Display for Choice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::A => "Option A",
+            Self::B => "Option B",
+            Self::C => "Option C",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Language {
+    Rust,
+    Elm,
+    Haskell,
+    Other,
+}
+
+impl Language {
+    const ALL: &'static [Self] =
+        &[Self::Rust, Self::Elm, Self::Haskell, Self::Other];
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Rust => "Rust",
+            Self::Elm => "Elm",
+            Self::Haskell => "Haskell",
+            Self::Other => "Some other language",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fruit {
+    Apple,
+    Banana,
+    Cherry,
+    Mango,
+    Orange,
+    Strawberry,
+}
+
+impl Fruit {
+    const ALL: [Self; 6] = [
+        Self::Apple,
+        Self::Banana,
+        Self::Cherry,
+        Self::Mango,
+        Self::Orange,
+        Self::Strawberry,
+    ];
+}
+
+impl fmt::Display for Fruit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Apple => "Apple",
+            Self::Banana => "Banana",
+            Self::Cherry => "Cherry",
+            Self::Mango => "Mango",
+            Self::Orange => "Orange",
+            Self::Strawberry => "Strawberry",
+        })
+    }
+}
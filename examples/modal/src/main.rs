@@ -85,17 +85,6 @@ impl Application for App {
                 Command::none()
             }
             Message::Event(event) => match event {
-                Event::Keyboard(keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Named(key::Named::Tab),
-                    modifiers,
-                    ..
-                }) => {
-                    if modifiers.shift() {
-                        widget::focus_previous()
-                    } else {
-                        widget::focus_next()
-                    }
-                }
                 Event::Keyboard(keyboard::Event::KeyPressed {
                     key: keyboard::Key::Named(key::Named::Escape),
                     ..
@@ -179,7 +168,7 @@ impl Application for App {
             )
             .width(300)
             .padding(10)
-            .style(theme::Container::Box);
+            .style(theme::Container::Elevated(theme::Elevation::Level5));
 
             Modal::new(content, modal)
                 .on_blur(Message::HideModal)
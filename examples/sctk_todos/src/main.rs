@@ -5,15 +5,14 @@ use iced::theme::{self, Theme};
 use iced::wayland::actions::window::SctkWindowSettings;
 use iced::wayland::InitialSurface;
 use iced::widget::{
-    self, button, checkbox, column, container, row, scrollable, text,
-    text_input, Text,
+    button, checkbox, column, container, row, scrollable, text, text_input,
+    Text,
 };
 use iced::{window, Application, Element};
 use iced::{Color, Command, Font, Length, Settings, Subscription};
+use iced_core::id;
 use iced_core::id::Id;
-use iced_core::keyboard::key::Named;
 use iced_core::layout::Limits;
-use iced_core::{id, keyboard};
 
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
@@ -59,7 +58,6 @@ enum Message {
     CreateTask,
     FilterChanged(Filter),
     TaskMessage(usize, TaskMessage),
-    TabPressed { shift: bool },
     CloseRequested(window::Id),
     Ignore,
 }
@@ -75,9 +73,6 @@ impl Debug for Message {
             Message::TaskMessage(_, _) => {
                 write!(f, "Message::TaskMessage(_, _)")
             }
-            Message::TabPressed { shift: _ } => {
-                write!(f, "Message::TabPressed {{ shift: _ }}")
-            }
             Message::CloseRequested(_) => {
                 write!(f, "Message::CloseRequested(_)")
             }
@@ -187,13 +182,6 @@ impl Application for Todos {
 
                         Command::none()
                     }
-                    Message::TabPressed { shift } => {
-                        if shift {
-                            widget::focus_previous()
-                        } else {
-                            widget::focus_next()
-                        }
-                    }
                     Message::CloseRequested(_) => {
                         std::process::exit(0);
                     }
@@ -300,16 +288,6 @@ impl Application for Todos {
 
     fn subscription(&self) -> Subscription<Message> {
         listen_raw(|event, status| match (event, status) {
-            (
-                Event::Keyboard(keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Named(Named::Tab),
-                    modifiers,
-                    ..
-                }),
-                event::Status::Ignored,
-            ) => Some(Message::TabPressed {
-                shift: modifiers.shift(),
-            }),
             (
                 Event::PlatformSpecific(event::PlatformSpecific::Wayland(
                     event::wayland::Event::Window(e, s, id),
@@ -4,8 +4,8 @@ use iced::keyboard::{self, Modifiers};
 use iced::subscription;
 use iced::theme::{self, Theme};
 use iced::widget::{
-    self, button, checkbox, column, container, keyed_column, row, scrollable,
-    text, text_input, Text,
+    button, checkbox, column, container, keyed_column, row, scrollable, text,
+    text_input, Text,
 };
 use iced::window;
 use iced::{Application, Element};
@@ -55,7 +55,6 @@ enum Message {
     CreateTask,
     FilterChanged(Filter),
     TaskMessage(usize, TaskMessage),
-    TabPressed { shift: bool },
     ToggleFullscreen(window::Mode),
 }
 
@@ -160,13 +159,6 @@ impl Application for Todos {
 
                         Command::none()
                     }
-                    Message::TabPressed { shift } => {
-                        if shift {
-                            widget::focus_previous()
-                        } else {
-                            widget::focus_next()
-                        }
-                    }
                     Message::ToggleFullscreen(mode) => {
                         window::change_mode(window::Id::MAIN, mode)
                     }
@@ -271,9 +263,6 @@ impl Application for Todos {
             };
 
             match (key, modifiers) {
-                (key::Named::Tab, _) => Some(Message::TabPressed {
-                    shift: modifiers.shift(),
-                }),
                 (key::Named::ArrowUp, keyboard::Modifiers::SHIFT) => {
                     Some(Message::ToggleFullscreen(window::Mode::Fullscreen))
                 }
@@ -31,6 +31,20 @@ pub mod time {
         Subscription::from_recipe(Every(duration))
     }
 
+    /// Returns a [`Stream`] that produces a tick every `duration`.
+    ///
+    /// Unlike [`every`], it can be combined with other streams inside of a
+    /// [`Subscription`].
+    ///
+    /// [`Stream`]: futures::Stream
+    pub fn ticks(
+        duration: std::time::Duration,
+    ) -> futures::stream::BoxStream<'static, ()> {
+        use futures::stream::StreamExt;
+
+        async_std::stream::interval(duration).boxed()
+    }
+
     #[derive(Debug)]
     struct Every(std::time::Duration);
 
@@ -30,6 +30,20 @@ pub mod time {
         Subscription::from_recipe(Every(duration))
     }
 
+    /// Returns a [`Stream`] that produces a tick every `duration`.
+    ///
+    /// Unlike [`every`], it can be combined with other streams inside of a
+    /// [`Subscription`].
+    ///
+    /// [`Stream`]: futures::Stream
+    pub fn ticks(
+        duration: std::time::Duration,
+    ) -> futures::stream::BoxStream<'static, ()> {
+        use futures::stream::StreamExt;
+
+        smol::Timer::interval(duration).map(|_| ()).boxed()
+    }
+
     #[derive(Debug)]
     struct Every(std::time::Duration);
 
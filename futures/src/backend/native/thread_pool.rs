@@ -16,4 +16,29 @@ impl crate::Executor for Executor {
 
 pub mod time {
     //! Listen and react to time.
+
+    /// Returns a [`Stream`] that produces a tick every `duration`.
+    ///
+    /// A `ThreadPool` has no timer, so the ticks are produced by a thread
+    /// that sleeps until the [`Stream`] is dropped.
+    ///
+    /// [`Stream`]: futures::Stream
+    pub fn ticks(
+        duration: std::time::Duration,
+    ) -> futures::stream::BoxStream<'static, ()> {
+        use futures::channel::mpsc;
+        use futures::stream::StreamExt;
+
+        let (sender, receiver) = mpsc::unbounded();
+
+        let _ = std::thread::spawn(move || loop {
+            std::thread::sleep(duration);
+
+            if sender.unbounded_send(()).is_err() {
+                break;
+            }
+        });
+
+        receiver.boxed()
+    }
 }
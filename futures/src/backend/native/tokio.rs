@@ -35,6 +35,30 @@ pub mod time {
         Subscription::from_recipe(Every(duration))
     }
 
+    /// Returns a [`Stream`] that produces a tick every `duration`.
+    ///
+    /// Unlike [`every`], it can be combined with other streams inside of a
+    /// [`Subscription`].
+    ///
+    /// [`Stream`]: futures::Stream
+    pub fn ticks(
+        duration: std::time::Duration,
+    ) -> futures::stream::BoxStream<'static, ()> {
+        use futures::stream::StreamExt;
+
+        let start = tokio::time::Instant::now() + duration;
+
+        futures::stream::unfold(
+            tokio::time::interval_at(start, duration),
+            |mut interval| async move {
+                let _ = interval.tick().await;
+
+                Some(((), interval))
+            },
+        )
+        .boxed()
+    }
+
     #[derive(Debug)]
     struct Every(std::time::Duration);
 
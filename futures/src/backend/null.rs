@@ -19,4 +19,12 @@ impl crate::Executor for Executor {
 
 pub mod time {
     //! Listen and react to time.
+
+    /// Returns a [`Stream`] that never produces a tick, since there is no
+    /// executor to run it.
+    ///
+    /// [`Stream`]: futures::Stream
+    pub fn ticks(_duration: std::time::Duration) -> crate::BoxStream<()> {
+        crate::boxed_stream(futures::stream::pending())
+    }
 }
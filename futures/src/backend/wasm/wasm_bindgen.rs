@@ -30,6 +30,18 @@ pub mod time {
         Subscription::from_recipe(Every(duration))
     }
 
+    /// Returns a [`Stream`] that produces a tick every `duration`.
+    ///
+    /// Unlike [`every`], it can be combined with other streams inside of a
+    /// [`Subscription`].
+    ///
+    /// [`Stream`]: futures::Stream
+    pub fn ticks(duration: std::time::Duration) -> BoxStream<()> {
+        use futures::stream::StreamExt;
+
+        wasm_timer::Interval::new(duration).boxed_local()
+    }
+
     #[derive(Debug)]
     struct Every(std::time::Duration);
 
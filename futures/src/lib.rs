@@ -24,6 +24,12 @@ pub mod executor;
 pub mod keyboard;
 pub mod subscription;
 
+#[cfg(feature = "network")]
+pub mod network;
+
+#[cfg(feature = "power")]
+pub mod power;
+
 pub use executor::Executor;
 pub use maybe::{MaybeSend, MaybeSync};
 pub use platform::*;
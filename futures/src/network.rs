@@ -0,0 +1,231 @@
+//! Listen to the network connectivity of the device.
+use crate::subscription::{self, Subscription};
+
+use std::any::TypeId;
+
+/// A network interface of the device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interface {
+    /// The name of the [`Interface`].
+    pub name: String,
+
+    /// Whether the [`Interface`] is up and connected.
+    pub is_up: bool,
+}
+
+/// A network connectivity event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The device is connected to a network.
+    Online,
+
+    /// The device is not connected to any network.
+    Offline,
+
+    /// The network interfaces of the device have changed.
+    InterfacesChanged(Vec<Interface>),
+}
+
+/// Returns a [`Subscription`] that produces network connectivity [`Event`]s.
+///
+/// The connectivity is produced when it is first read and every time it
+/// changes. Only physical interfaces are listed; loopback, bridges, tunnels,
+/// and other virtual interfaces are ignored.
+///
+/// On Linux, the connectivity is provided by the `NetworkManager` service
+/// through the system D-Bus, which notifies every change. If the service is
+/// not running, or on other platforms, no events are produced.
+pub fn changes() -> Subscription<Event> {
+    struct Changes;
+
+    subscription::channel(TypeId::of::<Changes>(), 1, |output| async move {
+        #[cfg(target_os = "linux")]
+        {
+            let mut output = output;
+
+            if let Err(error) = network_manager::listen(&mut output).await {
+                log::warn!("Network connectivity unavailable: {error}");
+            }
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        let _ = output;
+
+        futures::future::pending().await
+    })
+}
+
+#[cfg(target_os = "linux")]
+mod network_manager {
+    use super::{Event, Interface};
+
+    use futures::channel::mpsc;
+    use futures::stream::{self, StreamExt};
+    use futures::SinkExt;
+    use zbus::zvariant::OwnedObjectPath;
+
+    const SERVICE: &str = "org.freedesktop.NetworkManager";
+
+    /// `NM_STATE_CONNECTED_LOCAL`, the first state with a connection.
+    const CONNECTED: u32 = 50;
+
+    /// `NM_DEVICE_STATE_ACTIVATED`.
+    const ACTIVATED: u32 = 100;
+
+    /// The `NMDeviceType` of physical interfaces: Ethernet, Wi-Fi,
+    /// Bluetooth, and modems.
+    const PHYSICAL: [u32; 4] = [1, 2, 5, 8];
+
+    /// Sends connectivity [`Event`]s every time `NetworkManager` notifies a
+    /// change of its state or the state of its devices.
+    pub async fn listen(output: &mut mpsc::Sender<Event>) -> zbus::Result<()> {
+        enum Change {
+            State,
+            Devices,
+        }
+
+        let connection = zbus::Connection::system().await?;
+
+        let network_manager = zbus::Proxy::new(
+            &connection,
+            SERVICE,
+            "/org/freedesktop/NetworkManager",
+            "org.freedesktop.NetworkManager",
+        )
+        .await?;
+
+        let mut manager_changes = stream::select(
+            network_manager
+                .receive_property_changed::<u32>("State")
+                .await
+                .map(|_| Change::State),
+            network_manager
+                .receive_property_changed::<Vec<OwnedObjectPath>>("Devices")
+                .await
+                .map(|_| Change::Devices),
+        )
+        .boxed();
+
+        let mut last: Option<(bool, Vec<Interface>)> = None;
+
+        loop {
+            let devices =
+                physical_devices(&connection, &network_manager).await?;
+
+            let mut device_changes = Vec::with_capacity(devices.len());
+
+            for device in &devices {
+                device_changes.push(
+                    device
+                        .receive_property_changed::<u32>("State")
+                        .await
+                        .map(|_| Change::State)
+                        .boxed(),
+                );
+            }
+
+            let mut changes = stream::select(
+                &mut manager_changes,
+                stream::select_all(device_changes),
+            );
+
+            loop {
+                let (is_online, interfaces) =
+                    read(&network_manager, &devices).await?;
+
+                let mut events = Vec::new();
+
+                match &last {
+                    Some((was_online, last_interfaces)) => {
+                        if *last_interfaces != interfaces {
+                            events.push(Event::InterfacesChanged(
+                                interfaces.clone(),
+                            ));
+                        }
+
+                        if *was_online != is_online {
+                            events.push(connectivity(is_online));
+                        }
+                    }
+                    None => {
+                        events.push(connectivity(is_online));
+                    }
+                }
+
+                for event in events {
+                    if output.send(event).await.is_err() {
+                        return Ok(());
+                    }
+                }
+
+                last = Some((is_online, interfaces));
+
+                match changes.next().await {
+                    Some(Change::State) => {}
+                    Some(Change::Devices) => break,
+                    None => return Ok(()),
+                }
+            }
+        }
+    }
+
+    /// Returns the devices of `NetworkManager` with a physical interface.
+    async fn physical_devices(
+        connection: &zbus::Connection,
+        network_manager: &zbus::Proxy<'_>,
+    ) -> zbus::Result<Vec<zbus::Proxy<'static>>> {
+        let mut devices = Vec::new();
+
+        for path in network_manager
+            .get_property::<Vec<OwnedObjectPath>>("Devices")
+            .await?
+        {
+            let device = zbus::Proxy::new(
+                connection,
+                SERVICE,
+                path,
+                "org.freedesktop.NetworkManager.Device",
+            )
+            .await?;
+
+            if PHYSICAL
+                .contains(&device.get_property::<u32>("DeviceType").await?)
+            {
+                devices.push(device);
+            }
+        }
+
+        Ok(devices)
+    }
+
+    /// Returns whether the device is connected, together with the state of
+    /// the given devices.
+    async fn read(
+        network_manager: &zbus::Proxy<'_>,
+        devices: &[zbus::Proxy<'_>],
+    ) -> zbus::Result<(bool, Vec<Interface>)> {
+        let is_online =
+            network_manager.get_property::<u32>("State").await? >= CONNECTED;
+
+        let mut interfaces = Vec::with_capacity(devices.len());
+
+        for device in devices {
+            interfaces.push(Interface {
+                name: device.get_property::<String>("Interface").await?,
+                is_up: device.get_property::<u32>("State").await? == ACTIVATED,
+            });
+        }
+
+        interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok((is_online, interfaces))
+    }
+
+    fn connectivity(is_online: bool) -> Event {
+        if is_online {
+            Event::Online
+        } else {
+            Event::Offline
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Listen to the power status of the device.
+use crate::subscription::{self, Subscription};
+
+use std::any::TypeId;
+
+/// The power status of the device.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Status {
+    /// The charge of the batteries, between `0.0` and `1.0`, if the device
+    /// has any.
+    pub battery: Option<f32>,
+
+    /// Whether the device is connected to an external power source.
+    pub is_plugged_in: bool,
+}
+
+impl Status {
+    /// Returns whether the device is running on a battery with a charge
+    /// below the given threshold, between `0.0` and `1.0`.
+    pub fn is_low(&self, threshold: f32) -> bool {
+        !self.is_plugged_in
+            && self.battery.is_some_and(|battery| battery < threshold)
+    }
+}
+
+/// Returns a [`Subscription`] that produces the power [`Status`] of the
+/// device when it is first read and every time it changes.
+///
+/// On Linux, the status is provided by the `UPower` service through the
+/// system D-Bus, which notifies every change. If the service is not
+/// running, or on other platforms, no status is produced.
+pub fn changes() -> Subscription<Status> {
+    struct Changes;
+
+    subscription::channel(TypeId::of::<Changes>(), 1, |output| async move {
+        #[cfg(target_os = "linux")]
+        {
+            let mut output = output;
+
+            if let Err(error) = upower::listen(&mut output).await {
+                log::warn!("Power status unavailable: {error}");
+            }
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        let _ = output;
+
+        futures::future::pending().await
+    })
+}
+
+#[cfg(target_os = "linux")]
+mod upower {
+    use super::Status;
+
+    use futures::channel::mpsc;
+    use futures::stream::{self, StreamExt};
+    use futures::SinkExt;
+
+    const SERVICE: &str = "org.freedesktop.UPower";
+
+    /// Sends the [`Status`] of the device every time `UPower` notifies a
+    /// change of its properties.
+    pub async fn listen(output: &mut mpsc::Sender<Status>) -> zbus::Result<()> {
+        let connection = zbus::Connection::system().await?;
+
+        let upower = zbus::Proxy::new(
+            &connection,
+            SERVICE,
+            "/org/freedesktop/UPower",
+            "org.freedesktop.UPower",
+        )
+        .await?;
+
+        // The display device combines all the batteries of the system
+        let device = zbus::Proxy::new(
+            &connection,
+            SERVICE,
+            "/org/freedesktop/UPower/devices/DisplayDevice",
+            "org.freedesktop.UPower.Device",
+        )
+        .await?;
+
+        let mut changes = stream::select_all([
+            upower
+                .receive_property_changed::<bool>("OnBattery")
+                .await
+                .map(|_| ())
+                .boxed(),
+            device
+                .receive_property_changed::<bool>("IsPresent")
+                .await
+                .map(|_| ())
+                .boxed(),
+            device
+                .receive_property_changed::<f64>("Percentage")
+                .await
+                .map(|_| ())
+                .boxed(),
+        ]);
+
+        let mut last = None;
+
+        loop {
+            let status = read(&upower, &device).await?;
+
+            if last != Some(status) {
+                last = Some(status);
+
+                if output.send(status).await.is_err() {
+                    return Ok(());
+                }
+            }
+
+            if changes.next().await.is_none() {
+                return Ok(());
+            }
+        }
+    }
+
+    async fn read(
+        upower: &zbus::Proxy<'_>,
+        device: &zbus::Proxy<'_>,
+    ) -> zbus::Result<Status> {
+        let on_battery = upower.get_property::<bool>("OnBattery").await?;
+        let is_present = device.get_property::<bool>("IsPresent").await?;
+        let percentage = device.get_property::<f64>("Percentage").await?;
+
+        Ok(Status {
+            battery: is_present
+                .then(|| (percentage / 100.0).clamp(0.0, 1.0) as f32),
+            is_plugged_in: !on_battery,
+        })
+    }
+}
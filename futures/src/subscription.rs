@@ -4,6 +4,7 @@ mod tracker;
 pub use tracker::Tracker;
 
 use crate::core::event::{self, Event};
+use crate::core::time::{Duration, Instant};
 use crate::core::Hasher;
 use crate::futures::{Future, Stream};
 use crate::{BoxStream, MaybeSend};
@@ -300,6 +301,78 @@ where
     })
 }
 
+/// A change in the activity of the user, produced by [`user_idle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Idle {
+    /// No input events have occurred for the given period.
+    Started,
+    /// Input events occurred again after the user was idle.
+    Ended,
+}
+
+/// Returns a [`Subscription`] that produces [`Idle::Started`] when no
+/// keyboard, mouse or touch events have occurred for the given period, and
+/// [`Idle::Ended`] as soon as they occur again.
+pub fn user_idle(timeout: Duration) -> Subscription<Idle> {
+    use crate::backend::default::time;
+    use futures::future;
+    use futures::stream::{self, StreamExt};
+
+    struct UserIdle;
+
+    enum Input {
+        Activity,
+        Check,
+    }
+
+    Subscription::from_recipe(Runner {
+        id: (TypeId::of::<UserIdle>(), timeout),
+        spawn: move |events: EventStream| {
+            let activity = events.filter_map(|(event, _status)| {
+                future::ready(
+                    matches!(
+                        event,
+                        Event::Keyboard(_) | Event::Mouse(_) | Event::Touch(_)
+                    )
+                    .then_some(Input::Activity),
+                )
+            });
+
+            let checks =
+                time::ticks((timeout / 10).max(Duration::from_millis(10)))
+                    .map(|()| Input::Check);
+
+            stream::select(activity, checks)
+                .scan(
+                    (Instant::now(), false),
+                    move |(last_activity, is_idle), input| {
+                        let change = match input {
+                            Input::Activity => {
+                                *last_activity = Instant::now();
+
+                                std::mem::take(is_idle).then_some(Idle::Ended)
+                            }
+                            Input::Check => {
+                                if !*is_idle
+                                    && last_activity.elapsed() >= timeout
+                                {
+                                    *is_idle = true;
+
+                                    Some(Idle::Started)
+                                } else {
+                                    None
+                                }
+                            }
+                        };
+
+                        future::ready(Some(change))
+                    },
+                )
+                .filter_map(future::ready)
+        },
+    })
+}
+
 /// Creates a [`Subscription`] that publishes the events sent from a [`Future`]
 /// to an [`mpsc::Sender`] with the given bounds.
 ///
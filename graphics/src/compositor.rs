@@ -11,7 +11,7 @@ use thiserror::Error;
 /// A graphics compositor that can draw to windows.
 pub trait Compositor: Sized {
     /// The settings of the backend.
-    type Settings: Default;
+    type Settings: Default + Clone;
 
     /// The iced renderer of the backend.
     type Renderer: iced_core::Renderer;
@@ -51,6 +51,15 @@ pub trait Compositor: Sized {
     /// Returns [`Information`] used by this [`Compositor`].
     fn fetch_information(&self) -> Information;
 
+    /// Returns whether the device of the [`Compositor`] was lost, like after
+    /// a GPU reset.
+    ///
+    /// A lost [`Compositor`] cannot render anymore. It must be created again,
+    /// along with all of its renderers and surfaces.
+    fn is_lost(&self) -> bool {
+        false
+    }
+
     /// Presents the [`Renderer`] primitives to the next frame of the given [`Surface`].
     ///
     /// [`Renderer`]: Self::Renderer
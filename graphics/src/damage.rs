@@ -1,6 +1,6 @@
 //! Track and compute the damage of graphical primitives.
 use crate::core::alignment;
-use crate::core::{Rectangle, Size};
+use crate::core::{Point, Rectangle, Size};
 use crate::Primitive;
 
 use std::sync::Arc;
@@ -78,6 +78,29 @@ impl<T: Damage> Damage for Primitive<T> {
                 // damage bounds (?)
                 raw.clip_bounds.expand(1.5)
             }
+            Self::Grid {
+                position,
+                cell_size,
+                rows,
+                clip_bounds,
+                ..
+            } => {
+                let bounds = Rectangle::new(
+                    Point::new(
+                        clip_bounds.x,
+                        position.y + rows.start as f32 * cell_size.height,
+                    ),
+                    Size::new(
+                        clip_bounds.width,
+                        rows.len() as f32 * cell_size.height,
+                    ),
+                );
+
+                bounds
+                    .intersection(clip_bounds)
+                    .unwrap_or(Rectangle::with_size(Size::ZERO))
+                    .expand(1.5)
+            }
             Self::Quad { bounds, shadow, .. } if shadow.color.a > 0.0 => {
                 let bounds_with_shadow = Rectangle {
                     x: bounds.x + shadow.offset.x.min(0.0) - shadow.blur_radius,
@@ -92,9 +115,13 @@ impl<T: Damage> Damage for Primitive<T> {
 
                 bounds_with_shadow.expand(1.0)
             }
-            Self::Quad { bounds, .. }
-            | Self::Image { bounds, .. }
-            | Self::Svg { bounds, .. } => bounds.expand(1.0),
+            Self::Quad { bounds, .. } => bounds.expand(1.0),
+            Self::Image {
+                bounds, rotation, ..
+            }
+            | Self::Svg {
+                bounds, rotation, ..
+            } => bounds.rotate(*rotation).expand(1.0),
             Self::Clip { bounds, .. } => bounds.expand(1.0),
             Self::Group { primitives } => primitives
                 .iter()
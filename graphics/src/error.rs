@@ -14,6 +14,10 @@ pub enum Error {
     GraphicsAdapterNotFound,
 
     /// An error occured in the context's internal backend
-    #[error("an error occured in the context's internal backend")]
+    #[error("an error occured in the context's internal backend: {0}")]
     BackendError(String),
+
+    /// The graphics device was lost and could not be recovered.
+    #[error("the graphics device was lost and could not be recovered")]
+    DeviceLost,
 }
@@ -4,11 +4,14 @@ use crate::core::image;
 use crate::core::svg;
 use crate::core::text;
 use crate::core::{
-    Background, Border, Color, Font, Pixels, Point, Rectangle, Shadow, Vector,
+    Background, Border, Color, Font, Pixels, Point, Radians, Rectangle, Shadow,
+    Size, Vector,
 };
 use crate::text::editor;
+use crate::text::grid;
 use crate::text::paragraph;
 
+use std::ops::Range;
 use std::sync::Arc;
 
 /// A rendering primitive.
@@ -34,6 +37,10 @@ pub enum Primitive<T> {
         vertical_alignment: alignment::Vertical,
         /// The shaping strategy of the text.
         shaping: text::Shaping,
+        /// The wrapping strategy of the text.
+        wrap: text::Wrap,
+        /// The truncation strategy of the text.
+        truncate: text::Truncate,
         /// The clip bounds of the text.
         clip_bounds: Rectangle,
     },
@@ -61,6 +68,21 @@ pub enum Primitive<T> {
     },
     /// A raw `cosmic-text` primitive
     RawText(crate::text::Raw),
+    /// A grid primitive
+    Grid {
+        /// The [`grid::Weak`] reference.
+        grid: grid::Weak,
+        /// The position of the grid.
+        position: Point,
+        /// The size of a cell of the grid.
+        cell_size: Size,
+        /// The rows of the grid to draw.
+        rows: Range<usize>,
+        /// The default color of the characters of the grid.
+        color: Color,
+        /// The clip bounds of the grid.
+        clip_bounds: Rectangle,
+    },
     /// A quad primitive
     Quad {
         /// The bounds of the quad
@@ -80,6 +102,10 @@ pub enum Primitive<T> {
         filter_method: image::FilterMethod,
         /// The bounds of the image
         bounds: Rectangle,
+        /// The clockwise rotation of the image around its center
+        rotation: Radians,
+        /// The opacity of the image
+        opacity: f32,
         /// The border radii of the image
         border_radius: [f32; 4],
     },
@@ -93,6 +119,12 @@ pub enum Primitive<T> {
 
         /// The bounds of the viewport
         bounds: Rectangle,
+
+        /// The clockwise rotation of the SVG around its center
+        rotation: Radians,
+
+        /// The opacity of the SVG
+        opacity: f32,
     },
     /// A group of primitives
     Group {
@@ -6,12 +6,13 @@ use crate::core::renderer;
 use crate::core::svg;
 use crate::core::text::Text;
 use crate::core::{
-    Background, Color, Font, Pixels, Point, Rectangle, Size, Vector,
+    Background, Color, Font, Pixels, Point, Radians, Rectangle, Size, Vector,
 };
 use crate::text;
 use crate::Primitive;
 
 use std::borrow::Cow;
+use std::ops::Range;
 
 /// A backend-agnostic renderer that supports all the built-in widgets.
 #[derive(Debug)]
@@ -19,7 +20,11 @@ pub struct Renderer<B: Backend> {
     backend: B,
     default_font: Font,
     default_text_size: Pixels,
+    spacing_scale: f32,
+    border_radius_scale: f32,
     primitives: Vec<Primitive<B::Primitive>>,
+    previous: Vec<Primitive<B::Primitive>>,
+    generation: u64,
 }
 
 impl<B: Backend> Renderer<B> {
@@ -33,10 +38,34 @@ impl<B: Backend> Renderer<B> {
             backend,
             default_font,
             default_text_size,
+            spacing_scale: 1.0,
+            border_radius_scale: 1.0,
             primitives: Vec::new(),
+            previous: Vec::new(),
+            generation: 0,
         }
     }
 
+    /// Sets the factor applied to the spacing and padding of the built-in
+    /// layouts drawn by the [`Renderer`].
+    pub fn with_spacing_scale(mut self, spacing_scale: f32) -> Self {
+        self.spacing_scale = spacing_scale;
+        self
+    }
+
+    /// Sets the factor applied to the border radius of every quad drawn by
+    /// the [`Renderer`].
+    ///
+    /// Radii that already round a whole side of a quad are left untouched,
+    /// so circles and pills keep their shape.
+    pub fn with_border_radius_scale(
+        mut self,
+        border_radius_scale: f32,
+    ) -> Self {
+        self.border_radius_scale = border_radius_scale;
+        self
+    }
+
     /// Returns a reference to the [`Backend`] of the [`Renderer`].
     pub fn backend(&self) -> &B {
         &self.backend
@@ -56,6 +85,30 @@ impl<B: Backend> Renderer<B> {
         f(&mut self.backend, &self.primitives)
     }
 
+    /// Runs the given closure with the [`Backend`], the recorded primitives
+    /// of the [`Renderer`], and the primitives recorded before it was last
+    /// cleared.
+    ///
+    /// Comparing both lists tells what changed between two frames, without
+    /// keeping a copy of the previous one around.
+    pub fn with_previous_primitives<O>(
+        &mut self,
+        f: impl FnOnce(
+            &mut B,
+            &[Primitive<B::Primitive>],
+            &[Primitive<B::Primitive>],
+        ) -> O,
+    ) -> O {
+        f(&mut self.backend, &self.primitives, &self.previous)
+    }
+
+    /// Returns the generation of the recorded primitives of the [`Renderer`].
+    ///
+    /// It increases every time the [`Renderer`] is cleared.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
     /// Starts recording a new layer.
     pub fn start_layer(&mut self) -> Vec<Primitive<B::Primitive>> {
         std::mem::take(&mut self.primitives)
@@ -116,16 +169,40 @@ impl<B: Backend> iced_core::Renderer for Renderer<B> {
         quad: renderer::Quad,
         background: impl Into<Background>,
     ) {
+        let mut border = quad.border;
+
+        if self.border_radius_scale != 1.0 {
+            let max_radius = quad.bounds.width.min(quad.bounds.height) / 2.0;
+            let radii: [f32; 4] = border.radius.into();
+
+            border.radius = radii
+                .map(|radius| {
+                    if radius >= max_radius {
+                        radius
+                    } else {
+                        (radius * self.border_radius_scale).min(max_radius)
+                    }
+                })
+                .into();
+        }
+
         self.primitives.push(Primitive::Quad {
             bounds: quad.bounds,
             background: background.into(),
-            border: quad.border,
+            border,
             shadow: quad.shadow,
         });
     }
 
+    fn spacing_scale(&self) -> f32 {
+        self.spacing_scale
+    }
+
     fn clear(&mut self) {
+        std::mem::swap(&mut self.primitives, &mut self.previous);
+
         self.primitives.clear();
+        self.generation += 1;
     }
 }
 
@@ -205,6 +282,32 @@ where
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            wrap: text.wrap,
+            truncate: text.truncate,
+            clip_bounds,
+        });
+    }
+}
+
+impl<B> text::grid::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn fill_grid(
+        &mut self,
+        grid: &text::Grid,
+        position: Point,
+        cell_size: Size,
+        rows: Range<usize>,
+        color: Color,
+        clip_bounds: Rectangle,
+    ) {
+        self.primitives.push(Primitive::Grid {
+            grid: grid.downgrade(),
+            position,
+            cell_size,
+            rows,
+            color,
             clip_bounds,
         });
     }
@@ -225,12 +328,16 @@ where
         handle: image::Handle,
         filter_method: image::FilterMethod,
         bounds: Rectangle,
+        rotation: Radians,
+        opacity: f32,
         border_radius: [f32; 4],
     ) {
         self.primitives.push(Primitive::Image {
             handle,
             filter_method,
             bounds,
+            rotation,
+            opacity,
             border_radius,
         });
     }
@@ -249,11 +356,15 @@ where
         handle: svg::Handle,
         color: Option<Color>,
         bounds: Rectangle,
+        rotation: Radians,
+        opacity: f32,
     ) {
         self.primitives.push(Primitive::Svg {
             handle,
             color,
             bounds,
+            rotation,
+            opacity,
         });
     }
 }
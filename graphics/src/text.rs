@@ -1,16 +1,18 @@
 //! Draw text.
 pub mod cache;
 pub mod editor;
+pub mod grid;
 pub mod paragraph;
 
 pub use cache::Cache;
 pub use editor::Editor;
+pub use grid::Grid;
 pub use paragraph::Paragraph;
 
 pub use cosmic_text;
 
 use crate::core::font::{self, Font};
-use crate::core::text::Shaping;
+use crate::core::text::{Shaping, Truncate, Wrap};
 use crate::core::{Color, Point, Rectangle, Size};
 
 use once_cell::sync::OnceCell;
@@ -106,6 +108,78 @@ pub fn measure(buffer: &cosmic_text::Buffer) -> Size {
     )
 }
 
+/// Cuts short every line of the given content that does not fit in the
+/// given width, ending it with an ellipsis.
+pub fn ellipsize<'a>(
+    font_system: &mut cosmic_text::FontSystem,
+    content: &'a str,
+    metrics: cosmic_text::Metrics,
+    attributes: cosmic_text::Attrs<'_>,
+    shaping: cosmic_text::Shaping,
+    max_width: f32,
+) -> Cow<'a, str> {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    const ELLIPSIS: char = '\u{2026}';
+
+    if !max_width.is_finite() {
+        return Cow::Borrowed(content);
+    }
+
+    let mut buffer = cosmic_text::Buffer::new(font_system, metrics);
+    buffer.set_size(font_system, f32::INFINITY, f32::INFINITY);
+    buffer.set_wrap(font_system, cosmic_text::Wrap::None);
+
+    let mut fits = |line: &str| {
+        buffer.set_text(font_system, line, attributes, shaping);
+        measure(&buffer).width <= max_width
+    };
+
+    let mut truncated = false;
+    let mut lines = Vec::new();
+
+    for line in content.split('\n') {
+        if fits(line) {
+            lines.push(Cow::Borrowed(line));
+            continue;
+        }
+
+        truncated = true;
+
+        let boundaries: Vec<usize> = line
+            .grapheme_indices(true)
+            .map(|(index, _)| index)
+            .collect();
+
+        let shortened = |graphemes: usize| {
+            let end = boundaries.get(graphemes).copied().unwrap_or(line.len());
+
+            format!("{}{ELLIPSIS}", line[..end].trim_end())
+        };
+
+        // Binary search for the longest prefix that fits
+        let (mut low, mut high) = (0, boundaries.len());
+
+        while low < high {
+            let middle = (low + high + 1) / 2;
+
+            if fits(&shortened(middle)) {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        lines.push(Cow::Owned(shortened(low)));
+    }
+
+    if truncated {
+        Cow::Owned(lines.join("\n"))
+    } else {
+        Cow::Borrowed(content)
+    }
+}
+
 /// Returns the attributes of the given [`Font`].
 pub fn to_attributes(font: Font) -> cosmic_text::Attrs<'static> {
     cosmic_text::Attrs::new()
@@ -170,6 +244,15 @@ pub fn to_shaping(shaping: Shaping) -> cosmic_text::Shaping {
     }
 }
 
+/// Converts some [`Wrap`] strategy to a [`cosmic_text::Wrap`] strategy.
+pub fn to_wrap(wrap: Wrap) -> cosmic_text::Wrap {
+    match wrap {
+        Wrap::None => cosmic_text::Wrap::None,
+        Wrap::Word => cosmic_text::Wrap::Word,
+        Wrap::Glyph => cosmic_text::Wrap::Glyph,
+    }
+}
+
 /// Converts some [`Color`] to a [`cosmic_text::Color`].
 pub fn to_color(color: Color) -> cosmic_text::Color {
     let [r, g, b, a] = color.into_rgba8();
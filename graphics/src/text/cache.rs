@@ -3,6 +3,7 @@ use crate::core::{Font, Size};
 use crate::text;
 
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::borrow::Cow;
 use std::collections::hash_map;
 use std::hash::{BuildHasher, Hash, Hasher};
 
@@ -55,9 +56,23 @@ impl Cache {
                 key.bounds.width,
                 key.bounds.height.max(key.line_height),
             );
+            let content = match key.truncate {
+                text::Truncate::None => Cow::Borrowed(key.content),
+                text::Truncate::Ellipsis => text::ellipsize(
+                    font_system,
+                    key.content,
+                    metrics,
+                    text::to_attributes(key.font),
+                    text::to_shaping(key.shaping),
+                    key.bounds.width,
+                ),
+            };
+
+            buffer.set_wrap(font_system, text::to_wrap(key.wrap));
+
             buffer.set_text(
                 font_system,
-                key.content,
+                &content,
                 text::to_attributes(key.font),
                 text::to_shaping(key.shaping),
             );
@@ -118,6 +133,10 @@ pub struct Key<'a> {
     pub bounds: Size,
     /// The shaping strategy of the text.
     pub shaping: text::Shaping,
+    /// The wrapping strategy of the text.
+    pub wrap: text::Wrap,
+    /// The truncation strategy of the text.
+    pub truncate: text::Truncate,
 }
 
 impl Key<'_> {
@@ -129,6 +148,8 @@ impl Key<'_> {
         self.bounds.width.to_bits().hash(&mut hasher);
         self.bounds.height.to_bits().hash(&mut hasher);
         self.shaping.hash(&mut hasher);
+        self.wrap.hash(&mut hasher);
+        self.truncate.hash(&mut hasher);
 
         hasher.finish()
     }
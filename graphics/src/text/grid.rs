@@ -0,0 +1,330 @@
+//! Draw a grid of monospace characters, cell by cell.
+use crate::core::{self, Color, Font, Pixels, Point, Rectangle, Size};
+use crate::text;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::hash_map;
+use std::ops::Range;
+use std::sync::{self, Arc};
+
+/// A grid of monospace characters.
+///
+/// A [`Grid`] only keeps the [`Cell`]s to draw. Renderers draw every cell on
+/// its own, with the glyph of its character placed at the start of the cell,
+/// so fallback and wide glyphs never shift the rest of a row off the columns
+/// of the grid. The glyph of every character is only shaped once, and then
+/// cached by the renderer.
+#[derive(Debug, Clone, Default)]
+pub struct Grid(Arc<Internal>);
+
+#[derive(Debug, PartialEq)]
+struct Internal {
+    cells: Vec<Cell>,
+    columns: usize,
+    font: Font,
+    size: Pixels,
+    line_height: Pixels,
+}
+
+impl Default for Internal {
+    fn default() -> Self {
+        Self {
+            cells: Vec::new(),
+            columns: 1,
+            font: Font::MONOSPACE,
+            size: Pixels(16.0),
+            line_height: Pixels(16.0),
+        }
+    }
+}
+
+/// A single character of a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    /// The character of the [`Cell`].
+    pub character: char,
+
+    /// The color of the character, if different from the default text color.
+    pub foreground: Option<Color>,
+
+    /// The background color of the [`Cell`], if any.
+    pub background: Option<Color>,
+}
+
+impl Grid {
+    /// Creates a new empty [`Grid`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the [`Grid`] with the given cells, laid out in row-major order
+    /// with the given amount of columns.
+    ///
+    /// Every cell is turned into a [`Cell`] with the given function.
+    ///
+    /// The cells are only replaced if something has changed since the last
+    /// update. This way, renderers can tell an unchanged [`Grid`] apart and
+    /// keep what they uploaded for it.
+    pub fn update<T>(
+        &mut self,
+        cells: &[T],
+        columns: usize,
+        cell: impl Fn(&T) -> Cell,
+        font: Font,
+        size: Pixels,
+        line_height: Pixels,
+    ) {
+        let columns = columns.max(1);
+        let internal = &self.0;
+
+        if internal.columns == columns
+            && internal.font == font
+            && internal.size == size
+            && internal.line_height == line_height
+            && internal.cells.len() == cells.len()
+            && internal.cells.iter().copied().eq(cells.iter().map(&cell))
+        {
+            return;
+        }
+
+        self.0 = Arc::new(Internal {
+            cells: cells.iter().map(cell).collect(),
+            columns,
+            font,
+            size,
+            line_height,
+        });
+    }
+
+    /// Returns the [`Cell`]s of the [`Grid`], in row-major order.
+    pub fn cells(&self) -> &[Cell] {
+        &self.0.cells
+    }
+
+    /// Returns the amount of columns of the [`Grid`].
+    pub fn columns(&self) -> usize {
+        self.0.columns
+    }
+
+    /// Returns the amount of rows of the [`Grid`].
+    pub fn rows(&self) -> usize {
+        self.0.cells.len().div_ceil(self.0.columns)
+    }
+
+    /// Returns the [`Font`] of the [`Grid`].
+    pub fn font(&self) -> Font {
+        self.0.font
+    }
+
+    /// Returns the text size of the [`Grid`].
+    pub fn size(&self) -> Pixels {
+        self.0.size
+    }
+
+    /// Returns the line height of the [`Grid`], which is also the height of
+    /// its cells.
+    pub fn line_height(&self) -> Pixels {
+        self.0.line_height
+    }
+
+    /// Returns the [`Cell`]s of the given rows of the [`Grid`], with their
+    /// column and row.
+    pub fn visible_cells(
+        &self,
+        rows: Range<usize>,
+    ) -> impl Iterator<Item = (usize, usize, &Cell)> + '_ {
+        self.0
+            .cells
+            .chunks(self.0.columns)
+            .enumerate()
+            .take(rows.end)
+            .skip(rows.start)
+            .flat_map(|(row, cells)| {
+                cells
+                    .iter()
+                    .enumerate()
+                    .map(move |(column, cell)| (column, row, cell))
+            })
+    }
+
+    /// Creates a [`Weak`] reference to the [`Grid`].
+    ///
+    /// This is useful to avoid cloning the [`Grid`] when referential
+    /// guarantees are unnecessary. For instance, when creating a rendering
+    /// tree.
+    pub fn downgrade(&self) -> Weak {
+        Weak {
+            raw: Arc::downgrade(&self.0),
+        }
+    }
+}
+
+/// A weak reference to a [`Grid`].
+#[derive(Debug, Clone)]
+pub struct Weak {
+    raw: sync::Weak<Internal>,
+}
+
+impl Weak {
+    /// Tries to update the reference into a [`Grid`].
+    pub fn upgrade(&self) -> Option<Grid> {
+        self.raw.upgrade().map(Grid)
+    }
+}
+
+impl PartialEq for Weak {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw.ptr_eq(&other.raw)
+    }
+}
+
+/// A renderer capable of drawing a [`Grid`].
+pub trait Renderer: core::text::Renderer {
+    /// Draws the given rows of the [`Grid`] at the given position, with cells
+    /// of the given [`Size`].
+    ///
+    /// Cells without a foreground color use the given [`Color`].
+    fn fill_grid(
+        &mut self,
+        grid: &Grid,
+        position: Point,
+        cell_size: Size,
+        rows: Range<usize>,
+        color: Color,
+        clip_bounds: Rectangle,
+    );
+}
+
+/// A store of the recently used glyphs of the characters of some [`Grid`]s.
+#[allow(missing_debug_implementations)]
+#[derive(Default)]
+pub struct Glyphs {
+    entries: FxHashMap<Key, Option<Glyph>>,
+    recently_used: FxHashSet<Key>,
+}
+
+/// The glyph drawing a character of a [`Grid`] at some scale factor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Glyph {
+    /// The cache key of the glyph, ready for rasterization.
+    pub cache_key: cosmic_text::CacheKey,
+
+    /// The horizontal offset of the glyph from the start of its cell, in
+    /// physical pixels.
+    pub x: i32,
+
+    /// The offset of the baseline of the glyph from the top of its cell, in
+    /// physical pixels.
+    pub baseline: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Key {
+    character: char,
+    font: Font,
+    size: u32,
+    line_height: u32,
+    scale_factor: u32,
+}
+
+impl Glyphs {
+    /// Creates a new empty [`Glyphs`] store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the [`Glyph`] drawing the given character of the [`Grid`] at
+    /// the given scale factor, shaping it if it is not already present.
+    ///
+    /// Returns `None` if the character draws nothing.
+    pub fn get(
+        &mut self,
+        font_system: &mut cosmic_text::FontSystem,
+        grid: &Grid,
+        character: char,
+        scale_factor: f32,
+    ) -> Option<Glyph> {
+        let key = Key {
+            character,
+            font: grid.font(),
+            size: grid.size().0.to_bits(),
+            line_height: grid.line_height().0.to_bits(),
+            scale_factor: scale_factor.to_bits(),
+        };
+
+        let _ = self.recently_used.insert(key);
+
+        match self.entries.entry(key) {
+            hash_map::Entry::Occupied(entry) => *entry.get(),
+            hash_map::Entry::Vacant(entry) => *entry.insert(
+                shape(
+                    font_system,
+                    character,
+                    grid.font(),
+                    grid.size(),
+                    grid.line_height(),
+                )
+                .map(|(glyph, line_y)| {
+                    let physical = glyph.physical((0.0, 0.0), scale_factor);
+
+                    Glyph {
+                        cache_key: physical.cache_key,
+                        x: physical.x,
+                        baseline: (line_y * scale_factor).round() as i32,
+                    }
+                }),
+            ),
+        }
+    }
+
+    /// Trims the [`Glyphs`] store.
+    ///
+    /// This will clear the glyphs that have not been used since the last
+    /// `trim`.
+    pub fn trim(&mut self) {
+        self.entries
+            .retain(|key, _| self.recently_used.contains(key));
+
+        self.recently_used.clear();
+    }
+}
+
+/// Shapes the given character with the [`Font`], text size, and line height
+/// of a [`Grid`].
+///
+/// Returns the glyph drawing the character, if any, and the offset of its
+/// baseline from the top of its cell. Characters outside of ASCII may be
+/// drawn with a fallback font.
+fn shape(
+    font_system: &mut cosmic_text::FontSystem,
+    character: char,
+    font: Font,
+    size: Pixels,
+    line_height: Pixels,
+) -> Option<(cosmic_text::LayoutGlyph, f32)> {
+    if character.is_whitespace() || character.is_control() {
+        return None;
+    }
+
+    let mut buffer = cosmic_text::Buffer::new(
+        font_system,
+        cosmic_text::Metrics::new(size.0, line_height.0.max(f32::MIN_POSITIVE)),
+    );
+
+    buffer.set_size(font_system, f32::INFINITY, f32::INFINITY);
+    buffer.set_text(
+        font_system,
+        character.encode_utf8(&mut [0; 4]),
+        text::to_attributes(font),
+        if character.is_ascii() {
+            cosmic_text::Shaping::Basic
+        } else {
+            cosmic_text::Shaping::Advanced
+        },
+    );
+
+    let run = buffer.layout_runs().next()?;
+    let glyph = run.glyphs.first()?.clone();
+
+    Some((glyph, run.line_y))
+}
@@ -1,11 +1,13 @@
 //! Draw paragraphs.
 use crate::core;
 use crate::core::alignment;
-use crate::core::text::{Hit, LineHeight, Shaping, Text};
-use crate::core::{Font, Pixels, Point, Size};
+use crate::core::text::{Hit, LineHeight, Shaping, Span, Text, Truncate, Wrap};
+use crate::core::{Font, Pixels, Point, Rectangle, Size};
 use crate::text;
 
+use std::borrow::Cow;
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc};
 
 /// A bunch of text.
@@ -15,8 +17,11 @@ pub struct Paragraph(Option<Arc<Internal>>);
 struct Internal {
     buffer: cosmic_text::Buffer,
     content: String, // TODO: Reuse from `buffer` (?)
+    spans: Vec<(String, cosmic_text::Attrs<'static>)>,
     font: Font,
     shaping: Shaping,
+    wrap: Wrap,
+    truncate: Truncate,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     bounds: Size,
@@ -51,6 +56,59 @@ impl Paragraph {
         }
     }
 
+    fn with_rich_text(
+        spans: Vec<(String, cosmic_text::Attrs<'static>)>,
+        text: Text<'_, Font>,
+    ) -> Self {
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
+
+        let mut buffer = cosmic_text::Buffer::new(
+            font_system.raw(),
+            cosmic_text::Metrics::new(
+                text.size.into(),
+                text.line_height.to_absolute(text.size).into(),
+            ),
+        );
+
+        buffer.set_size(
+            font_system.raw(),
+            text.bounds.width,
+            text.bounds.height,
+        );
+
+        buffer.set_wrap(font_system.raw(), text::to_wrap(text.wrap));
+
+        buffer.set_rich_text(
+            font_system.raw(),
+            spans
+                .iter()
+                .map(|(content, attributes)| (content.as_str(), *attributes)),
+            text::to_attributes(text.font),
+            text::to_shaping(text.shaping),
+        );
+
+        let min_bounds = text::measure(&buffer);
+
+        Self(Some(Arc::new(Internal {
+            buffer,
+            content: spans
+                .iter()
+                .map(|(content, _)| content.as_str())
+                .collect(),
+            spans,
+            font: text.font,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+            shaping: text.shaping,
+            wrap: text.wrap,
+            truncate: text.truncate,
+            bounds: text.bounds,
+            min_bounds,
+            version: font_system.version(),
+        })))
+    }
+
     fn internal(&self) -> &Arc<Internal> {
         self.0
             .as_ref()
@@ -81,9 +139,23 @@ impl core::text::Paragraph for Paragraph {
             text.bounds.height,
         );
 
+        let content = match text.truncate {
+            Truncate::None => Cow::Borrowed(text.content),
+            Truncate::Ellipsis => text::ellipsize(
+                font_system.raw(),
+                text.content,
+                buffer.metrics(),
+                text::to_attributes(text.font),
+                text::to_shaping(text.shaping),
+                text.bounds.width,
+            ),
+        };
+
+        buffer.set_wrap(font_system.raw(), text::to_wrap(text.wrap));
+
         buffer.set_text(
             font_system.raw(),
-            text.content,
+            &content,
             text::to_attributes(text.font),
             text::to_shaping(text.shaping),
         );
@@ -93,16 +165,43 @@ impl core::text::Paragraph for Paragraph {
         Self(Some(Arc::new(Internal {
             buffer,
             content: text.content.to_owned(),
+            spans: Vec::new(),
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            wrap: text.wrap,
+            truncate: text.truncate,
             bounds: text.bounds,
             min_bounds,
             version: font_system.version(),
         })))
     }
 
+    fn with_spans<Link>(
+        spans: &[Span<'_, Link, Font>],
+        text: Text<'_, Font>,
+    ) -> Self {
+        let spans = spans
+            .iter()
+            .enumerate()
+            .map(|(index, span)| {
+                let attributes =
+                    text::to_attributes(span.font.unwrap_or(text.font))
+                        .metadata(index);
+
+                let attributes = match span.color {
+                    Some(color) => attributes.color(text::to_color(color)),
+                    None => attributes,
+                };
+
+                (span.text.to_string(), attributes)
+            })
+            .collect();
+
+        Self::with_rich_text(spans, text)
+    }
+
     fn resize(&mut self, new_bounds: Size) {
         let paragraph = self
             .0
@@ -110,6 +209,13 @@ impl core::text::Paragraph for Paragraph {
             .expect("paragraph should always be initialized");
 
         match Arc::try_unwrap(paragraph) {
+            // Truncation depends on the bounds, so the text is shaped again
+            Ok(internal)
+                if internal.truncate != Truncate::None
+                    && internal.spans.is_empty() =>
+            {
+                *self = Self::with_text(internal.text(new_bounds));
+            }
             Ok(mut internal) => {
                 let mut font_system =
                     text::font_system().write().expect("Write font system");
@@ -125,23 +231,16 @@ impl core::text::Paragraph for Paragraph {
 
                 self.0 = Some(Arc::new(internal));
             }
+            Err(internal) if !internal.spans.is_empty() => {
+                *self = Self::with_rich_text(
+                    internal.spans.clone(),
+                    internal.text(new_bounds),
+                );
+            }
             Err(internal) => {
-                let metrics = internal.buffer.metrics();
-
                 // If there is a strong reference somewhere, we recompute the
                 // buffer from scratch
-                *self = Self::with_text(Text {
-                    content: &internal.content,
-                    bounds: internal.bounds,
-                    size: Pixels(metrics.font_size),
-                    line_height: LineHeight::Absolute(Pixels(
-                        metrics.line_height,
-                    )),
-                    font: internal.font,
-                    horizontal_alignment: internal.horizontal_alignment,
-                    vertical_alignment: internal.vertical_alignment,
-                    shaping: internal.shaping,
-                });
+                *self = Self::with_text(internal.text(internal.bounds));
             }
         }
     }
@@ -157,6 +256,8 @@ impl core::text::Paragraph for Paragraph {
             || metrics.line_height != text.line_height.to_absolute(text.size).0
             || paragraph.font != text.font
             || paragraph.shaping != text.shaping
+            || paragraph.wrap != text.wrap
+            || paragraph.truncate != text.truncate
             || paragraph.horizontal_alignment != text.horizontal_alignment
             || paragraph.vertical_alignment != text.vertical_alignment
         {
@@ -227,6 +328,100 @@ impl core::text::Paragraph for Paragraph {
             glyph.y - glyph.y_offset * glyph.font_size,
         ))
     }
+
+    fn hit_span(&self, point: Point) -> Option<usize> {
+        let internal = self.internal();
+        let line_height = internal.buffer.metrics().line_height;
+
+        internal.buffer.layout_runs().find_map(|run| {
+            if point.y < run.line_top || point.y >= run.line_top + line_height {
+                return None;
+            }
+
+            run.glyphs
+                .iter()
+                .find(|glyph| point.x >= glyph.x && point.x < glyph.x + glyph.w)
+                .map(|glyph| glyph.metadata)
+        })
+    }
+
+    fn span_bounds(&self, index: usize) -> Vec<Rectangle> {
+        let internal = self.internal();
+        let line_height = internal.buffer.metrics().line_height;
+        let mut bounds = Vec::new();
+
+        for run in internal.buffer.layout_runs() {
+            let mut current: Option<Rectangle> = None;
+
+            for glyph in run.glyphs {
+                if glyph.metadata == index {
+                    let glyph_bounds = Rectangle::new(
+                        Point::new(glyph.x, run.line_top),
+                        Size::new(glyph.w, line_height),
+                    );
+
+                    current = Some(match current {
+                        Some(current) => current.union(&glyph_bounds),
+                        None => glyph_bounds,
+                    });
+                } else if let Some(current) = current.take() {
+                    bounds.push(current);
+                }
+            }
+
+            bounds.extend(current);
+        }
+
+        bounds
+    }
+
+    fn hit_offset(&self, point: Point) -> Option<usize> {
+        let buffer = &self.internal().buffer;
+        let cursor = buffer.hit(point.x, point.y)?;
+
+        Some(line_offsets(buffer).get(cursor.line)? + cursor.index)
+    }
+
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle> {
+        let buffer = &self.internal().buffer;
+        let line_height = buffer.metrics().line_height;
+        let offsets = line_offsets(buffer);
+
+        buffer
+            .layout_runs()
+            .filter_map(|run| {
+                let offset = *offsets.get(run.line_i)?;
+
+                run.glyphs
+                    .iter()
+                    .filter(|glyph| {
+                        offset + glyph.start < range.end
+                            && offset + glyph.end > range.start
+                    })
+                    .map(|glyph| {
+                        Rectangle::new(
+                            Point::new(glyph.x, run.line_top),
+                            Size::new(glyph.w, line_height),
+                        )
+                    })
+                    .reduce(|a, b| a.union(&b))
+            })
+            .collect()
+    }
+}
+
+/// Returns the byte offset of every line of the buffer in its content.
+fn line_offsets(buffer: &cosmic_text::Buffer) -> Vec<usize> {
+    buffer
+        .lines
+        .iter()
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.text().len() + 1;
+
+            Some(start)
+        })
+        .collect()
 }
 
 impl Default for Paragraph {
@@ -243,6 +438,8 @@ impl fmt::Debug for Paragraph {
             .field("content", &paragraph.content)
             .field("font", &paragraph.font)
             .field("shaping", &paragraph.shaping)
+            .field("wrap", &paragraph.wrap)
+            .field("truncate", &paragraph.truncate)
             .field("horizontal_alignment", &paragraph.horizontal_alignment)
             .field("vertical_alignment", &paragraph.vertical_alignment)
             .field("bounds", &paragraph.bounds)
@@ -251,11 +448,33 @@ impl fmt::Debug for Paragraph {
     }
 }
 
+impl Internal {
+    fn text(&self, bounds: Size) -> Text<'_, Font> {
+        let metrics = self.buffer.metrics();
+
+        Text {
+            content: &self.content,
+            bounds,
+            size: Pixels(metrics.font_size),
+            line_height: LineHeight::Absolute(Pixels(metrics.line_height)),
+            font: self.font,
+            horizontal_alignment: self.horizontal_alignment,
+            vertical_alignment: self.vertical_alignment,
+            shaping: self.shaping,
+            wrap: self.wrap,
+            truncate: self.truncate,
+        }
+    }
+}
+
 impl PartialEq for Internal {
     fn eq(&self, other: &Self) -> bool {
         self.content == other.content
+            && self.spans == other.spans
             && self.font == other.font
             && self.shaping == other.shaping
+            && self.wrap == other.wrap
+            && self.truncate == other.truncate
             && self.horizontal_alignment == other.horizontal_alignment
             && self.vertical_alignment == other.vertical_alignment
             && self.bounds == other.bounds
@@ -272,8 +491,11 @@ impl Default for Internal {
                 line_height: 1.0,
             }),
             content: String::new(),
+            spans: Vec::new(),
             font: Font::default(),
             shaping: Shaping::default(),
+            wrap: Wrap::default(),
+            truncate: Truncate::default(),
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             bounds: Size::ZERO,
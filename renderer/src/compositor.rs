@@ -14,7 +14,7 @@ pub enum Compositor {
 pub enum Surface {
     TinySkia(iced_tiny_skia::window::Surface),
     #[cfg(feature = "wgpu")]
-    Wgpu(iced_wgpu::window::Surface<'static>),
+    Wgpu(iced_wgpu::window::Surface),
 }
 
 impl crate::graphics::Compositor for Compositor {
@@ -35,6 +35,10 @@ impl crate::graphics::Compositor for Compositor {
             match candidate.build(settings, compatible_window.clone()) {
                 Ok(compositor) => return Ok(compositor),
                 Err(new_error) => {
+                    log::warn!(
+                        "{candidate:?} renderer unavailable: {new_error}"
+                    );
+
                     error = new_error;
                 }
             }
@@ -101,6 +105,14 @@ impl crate::graphics::Compositor for Compositor {
         }
     }
 
+    fn is_lost(&self) -> bool {
+        match self {
+            Self::TinySkia(compositor) => compositor.is_lost(),
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(compositor) => compositor.is_lost(),
+        }
+    }
+
     fn present<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -129,17 +141,14 @@ impl crate::graphics::Compositor for Compositor {
                 Self::Wgpu(compositor),
                 crate::Renderer::Wgpu(renderer),
                 Surface::Wgpu(surface),
-            ) => renderer.with_primitives(|backend, primitives| {
-                iced_wgpu::window::compositor::present(
-                    compositor,
-                    backend,
-                    surface,
-                    primitives,
-                    viewport,
-                    background_color,
-                    overlay,
-                )
-            }),
+            ) => iced_wgpu::window::compositor::present(
+                compositor,
+                renderer,
+                surface,
+                viewport,
+                background_color,
+                overlay,
+            ),
             #[allow(unreachable_patterns)]
             _ => panic!(
                 "The provided renderer or surface are not compatible \
@@ -195,13 +204,14 @@ impl crate::graphics::Compositor for Compositor {
     }
 }
 
-enum Candidate {
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Candidate {
     Wgpu,
     TinySkia,
 }
 
 impl Candidate {
-    fn default_list() -> Vec<Self> {
+    pub(crate) fn default_list() -> Vec<Self> {
         vec![
             #[cfg(feature = "wgpu")]
             Self::Wgpu,
@@ -209,7 +219,7 @@ impl Candidate {
         ]
     }
 
-    fn list_from_env() -> Option<Vec<Self>> {
+    pub(crate) fn list_from_env() -> Option<Vec<Self>> {
         let backends = env::var("ICED_BACKEND").ok()?;
 
         Some(
@@ -236,6 +246,8 @@ impl Candidate {
                     iced_tiny_skia::Settings {
                         default_font: settings.default_font,
                         default_text_size: settings.default_text_size,
+                        spacing_scale: settings.spacing_scale,
+                        border_radius_scale: settings.border_radius_scale,
                     },
                     _compatible_window,
                 );
@@ -248,6 +260,8 @@ impl Candidate {
                     iced_wgpu::Settings {
                         default_font: settings.default_font,
                         default_text_size: settings.default_text_size,
+                        spacing_scale: settings.spacing_scale,
+                        border_radius_scale: settings.border_radius_scale,
                         antialiasing: settings.antialiasing,
                         ..iced_wgpu::Settings::from_env()
                     },
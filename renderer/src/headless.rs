@@ -0,0 +1,134 @@
+//! Render without a window.
+use crate::compositor::Candidate;
+use crate::core::Color;
+use crate::graphics::{Error, Viewport};
+use crate::{Renderer, Settings};
+
+/// A [`Renderer`] that draws to offscreen images instead of windows.
+///
+/// Draw the user interface with [`Headless::renderer`], then read back the
+/// pixels with [`Headless::screenshot`]. No display server is needed, so a
+/// [`Headless`] renderer can export images, generate thumbnails, and run
+/// pixel comparisons in tests.
+///
+/// The backend is chosen like the one of a window, following the
+/// `ICED_BACKEND` environment variable.
+#[allow(missing_debug_implementations)]
+pub struct Headless {
+    renderer: Renderer,
+    #[cfg(feature = "wgpu")]
+    compositor: Option<iced_wgpu::window::Compositor>,
+}
+
+impl Headless {
+    /// Creates a [`Headless`] renderer with the given [`Settings`].
+    pub fn new(settings: Settings) -> Result<Self, Error> {
+        let candidates =
+            Candidate::list_from_env().unwrap_or(Candidate::default_list());
+
+        let mut error = Error::GraphicsAdapterNotFound;
+
+        for candidate in candidates {
+            match Self::build(candidate, settings) {
+                Ok(headless) => return Ok(headless),
+                Err(new_error) => {
+                    log::warn!(
+                        "{candidate:?} renderer unavailable: {new_error}"
+                    );
+
+                    error = new_error;
+                }
+            }
+        }
+
+        Err(error)
+    }
+
+    fn build(candidate: Candidate, settings: Settings) -> Result<Self, Error> {
+        match candidate {
+            Candidate::TinySkia => Ok(Self {
+                renderer: Renderer::TinySkia(
+                    iced_tiny_skia::Renderer::new(
+                        iced_tiny_skia::Backend::new(),
+                        settings.default_font,
+                        settings.default_text_size,
+                    )
+                    .with_spacing_scale(settings.spacing_scale)
+                    .with_border_radius_scale(settings.border_radius_scale),
+                ),
+                #[cfg(feature = "wgpu")]
+                compositor: None,
+            }),
+            #[cfg(feature = "wgpu")]
+            Candidate::Wgpu => {
+                use crate::graphics::Compositor as _;
+
+                let compositor = iced_wgpu::window::compositor::headless(
+                    iced_wgpu::Settings {
+                        default_font: settings.default_font,
+                        default_text_size: settings.default_text_size,
+                        spacing_scale: settings.spacing_scale,
+                        border_radius_scale: settings.border_radius_scale,
+                        antialiasing: settings.antialiasing,
+                        ..iced_wgpu::Settings::from_env()
+                    },
+                )?;
+
+                Ok(Self {
+                    renderer: Renderer::Wgpu(compositor.create_renderer()),
+                    compositor: Some(compositor),
+                })
+            }
+            #[cfg(not(feature = "wgpu"))]
+            Candidate::Wgpu => Err(Error::BackendError(String::from(
+                "the wgpu feature is not enabled",
+            ))),
+        }
+    }
+
+    /// Returns the [`Renderer`] to draw with.
+    pub fn renderer(&mut self) -> &mut Renderer {
+        &mut self.renderer
+    }
+
+    /// Renders what was drawn with the [`Renderer`] and returns the bytes of
+    /// the image, ordered as `RGBA` in the `sRGB` color space.
+    pub fn screenshot<T: AsRef<str>>(
+        &mut self,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        match &mut self.renderer {
+            Renderer::TinySkia(renderer) => {
+                renderer.with_primitives(|backend, primitives| {
+                    iced_tiny_skia::window::compositor::offscreen(
+                        backend,
+                        primitives,
+                        viewport,
+                        background_color,
+                        overlay,
+                    )
+                })
+            }
+            #[cfg(feature = "wgpu")]
+            Renderer::Wgpu(renderer) => {
+                let compositor = self
+                    .compositor
+                    .as_ref()
+                    .expect("Headless wgpu renderer without a compositor");
+
+                renderer.with_primitives(|backend, primitives| {
+                    iced_wgpu::window::compositor::screenshot(
+                        compositor,
+                        backend,
+                        primitives,
+                        viewport,
+                        background_color,
+                        overlay,
+                    )
+                })
+            }
+        }
+    }
+}
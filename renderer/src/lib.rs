@@ -5,6 +5,7 @@
 pub use iced_wgpu as wgpu;
 
 pub mod compositor;
+pub mod headless;
 
 #[cfg(feature = "geometry")]
 pub mod geometry;
@@ -15,6 +16,7 @@ pub use iced_graphics as graphics;
 pub use iced_graphics::core;
 
 pub use compositor::Compositor;
+pub use headless::Headless;
 pub use settings::Settings;
 
 #[cfg(feature = "geometry")]
@@ -143,6 +145,10 @@ impl core::Renderer for Renderer {
     fn clear(&mut self) {
         delegate!(self, renderer, renderer.clear());
     }
+
+    fn spacing_scale(&self) -> f32 {
+        delegate!(self, renderer, renderer.spacing_scale())
+    }
 }
 
 impl text::Renderer for Renderer {
@@ -214,6 +220,31 @@ impl text::Renderer for Renderer {
     }
 }
 
+impl crate::graphics::text::grid::Renderer for Renderer {
+    fn fill_grid(
+        &mut self,
+        grid: &crate::graphics::text::Grid,
+        position: Point,
+        cell_size: core::Size,
+        rows: std::ops::Range<usize>,
+        color: Color,
+        clip_bounds: Rectangle,
+    ) {
+        delegate!(
+            self,
+            renderer,
+            renderer.fill_grid(
+                grid,
+                position,
+                cell_size,
+                rows,
+                color,
+                clip_bounds
+            )
+        );
+    }
+}
+
 #[cfg(feature = "image")]
 impl crate::core::image::Renderer for Renderer {
     type Handle = crate::core::image::Handle;
@@ -230,12 +261,21 @@ impl crate::core::image::Renderer for Renderer {
         handle: crate::core::image::Handle,
         filter_method: crate::core::image::FilterMethod,
         bounds: Rectangle,
+        rotation: core::Radians,
+        opacity: f32,
         border_radius: [f32; 4],
     ) {
         delegate!(
             self,
             renderer,
-            renderer.draw(handle, filter_method, bounds, border_radius)
+            renderer.draw(
+                handle,
+                filter_method,
+                bounds,
+                rotation,
+                opacity,
+                border_radius
+            )
         );
     }
 }
@@ -251,8 +291,14 @@ impl crate::core::svg::Renderer for Renderer {
         handle: crate::core::svg::Handle,
         color: Option<crate::core::Color>,
         bounds: Rectangle,
+        rotation: core::Radians,
+        opacity: f32,
     ) {
-        delegate!(self, renderer, renderer.draw(handle, color, bounds));
+        delegate!(
+            self,
+            renderer,
+            renderer.draw(handle, color, bounds, rotation, opacity)
+        );
     }
 }
 
@@ -16,6 +16,17 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The factor applied to the spacing and padding of the built-in widgets.
+    ///
+    /// By default, it is `1.0`.
+    pub spacing_scale: f32,
+
+    /// The factor applied to the border radius of the quads drawn by the
+    /// built-in widgets.
+    ///
+    /// By default, it is `1.0`.
+    pub border_radius_scale: f32,
 }
 
 impl Default for Settings {
@@ -24,6 +35,8 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(14.0),
             antialiasing: None,
+            spacing_scale: 1.0,
+            border_radius_scale: 1.0,
         }
     }
 }
@@ -0,0 +1,8 @@
+//! Play sound cues for user interface events.
+use crate::command::{self, Command};
+use crate::core::audio::Sound;
+
+/// Plays the given [`Sound`] with the audio backend of the application.
+pub fn play<Message>(sound: Sound) -> Command<Message> {
+    Command::single(command::Action::Audio(sound))
+}
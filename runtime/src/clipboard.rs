@@ -2,6 +2,7 @@
 use window_clipboard::mime::{AllowedMimeTypes, AsMimeTypes};
 
 use crate::command::{self, Command};
+use crate::core::clipboard::{Html, Image};
 use crate::futures::MaybeSend;
 
 use std::fmt;
@@ -113,6 +114,17 @@ pub fn write_data<Message>(
     ))))
 }
 
+/// Write the given HTML contents to the clipboard, along with a plain text
+/// alternative for applications that cannot paste HTML.
+pub fn write_html<Message>(html: String, alt: String) -> Command<Message> {
+    write_data(Html { html, alt })
+}
+
+/// Write the given [`Image`] to the clipboard.
+pub fn write_image<Message>(image: Image) -> Command<Message> {
+    write_data(image)
+}
+
 /// Read the current contents of the clipboard.
 pub fn read_primary_data<
     T: AllowedMimeTypes + Send + Sync + 'static,
@@ -1,4 +1,5 @@
 use crate::clipboard;
+use crate::core::audio::Sound;
 use crate::core::widget;
 use crate::font;
 use crate::system;
@@ -33,6 +34,12 @@ pub enum Action<T> {
     /// Run a system action.
     System(system::Action<T>),
 
+    /// Play a sound cue.
+    Audio(Sound),
+
+    /// Trigger a haptic impulse with the given strength.
+    Haptics(f32),
+
     /// Run a widget action.
     Widget(Box<dyn widget::Operation<T>>),
 
@@ -72,6 +79,8 @@ impl<T> Action<T> {
             Self::Clipboard(action) => Action::Clipboard(action.map(f)),
             Self::Window(window) => Action::Window(window.map(f)),
             Self::System(system) => Action::System(system.map(f)),
+            Self::Audio(sound) => Action::Audio(sound),
+            Self::Haptics(strength) => Action::Haptics(strength),
             Self::Widget(operation) => {
                 Action::Widget(Box::new(widget::operation::map(operation, f)))
             }
@@ -101,6 +110,10 @@ impl<T> fmt::Debug for Action<T> {
                 write!(f, "Action::Window({action:?})")
             }
             Self::System(action) => write!(f, "Action::System({action:?})"),
+            Self::Audio(sound) => write!(f, "Action::Audio({sound:?})"),
+            Self::Haptics(strength) => {
+                write!(f, "Action::Haptics({strength})")
+            }
             Self::Widget(_action) => write!(f, "Action::Widget"),
             Self::LoadFont { .. } => write!(f, "Action::LoadFont"),
             Self::PlatformSpecific(action) => {
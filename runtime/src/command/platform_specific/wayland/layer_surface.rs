@@ -2,6 +2,7 @@ use std::fmt;
 use std::marker::PhantomData;
 
 use iced_core::layout::Limits;
+use iced_core::Rectangle;
 use iced_futures::MaybeSend;
 use sctk::{
     reexports::client::protocol::wl_output::WlOutput,
@@ -146,6 +147,14 @@ pub enum Action<T> {
         /// layer of the layer surface
         layer: Layer,
     },
+    /// input region of the layer surface
+    InputRegion {
+        /// id of the layer surface
+        id: Id,
+        /// the logical rectangles that accept pointer and touch input, or
+        /// `None` to accept input on the whole surface
+        region: Option<Vec<Rectangle<i32>>>,
+    },
 }
 
 impl<T> Action<T> {
@@ -179,6 +188,9 @@ impl<T> Action<T> {
                 keyboard_interactivity,
             },
             Action::Layer { id, layer } => Action::Layer { id, layer },
+            Action::InputRegion { id, region } => {
+                Action::InputRegion { id, region }
+            }
         }
     }
 }
@@ -219,6 +231,10 @@ impl<T> fmt::Debug for Action<T> {
                 f,
                 "Action::LayerSurfaceAction::Margin {{ id: {:#?}, layer: {:?} }}", id, layer
             ),
+            Action::InputRegion { id, region } => write!(
+                f,
+                "Action::LayerSurfaceAction::InputRegion {{ id: {:#?}, region: {:?} }}", id, region
+            ),
         }
     }
 }
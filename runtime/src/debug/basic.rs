@@ -1,5 +1,6 @@
 #![allow(missing_docs)]
 use crate::core::time;
+use crate::core::widget::profile;
 
 use std::collections::VecDeque;
 
@@ -31,6 +32,8 @@ pub struct Debug {
 
     message_count: usize,
     last_messages: VecDeque<String>,
+
+    widget_timings: Vec<profile::Timing>,
 }
 
 impl Debug {
@@ -63,6 +66,8 @@ impl Debug {
 
             message_count: 0,
             last_messages: VecDeque::new(),
+
+            widget_timings: Vec::new(),
         }
     }
 
@@ -100,6 +105,7 @@ impl Debug {
 
     pub fn layout_finished(&mut self) {
         self.layout_durations.push(self.layout_start.elapsed());
+        self.record_widget_timings();
     }
 
     pub fn event_processing_started(&mut self) {
@@ -116,6 +122,7 @@ impl Debug {
 
     pub fn draw_finished(&mut self) {
         self.draw_durations.push(self.draw_start.elapsed());
+        self.record_widget_timings();
     }
 
     pub fn render_started(&mut self) {
@@ -126,6 +133,20 @@ impl Debug {
         self.render_durations.push(self.render_start.elapsed());
     }
 
+    fn record_widget_timings(&mut self) {
+        for timing in profile::take() {
+            if let Some(current) = self
+                .widget_timings
+                .iter_mut()
+                .find(|t| t.widget == timing.widget && t.stage == timing.stage)
+            {
+                *current = timing;
+            } else {
+                self.widget_timings.push(timing);
+            }
+        }
+    }
+
     pub fn log_message<Message: std::fmt::Debug>(&mut self, message: &Message) {
         self.last_messages.push_back(format!("{message:?}"));
 
@@ -166,6 +187,12 @@ impl Debug {
             self.draw_durations.average(),
         ));
         lines.push(key_value("Render:", self.render_durations.average()));
+        lines.push(String::from("Slowest widgets (layout / draw):"));
+        lines.extend(self.slowest_widgets(5).into_iter().map(
+            |(widget, layout, draw)| {
+                format!("    {widget}: {layout:?} / {draw:?}")
+            },
+        ));
         lines.push(key_value("Message count:", self.message_count));
         lines.push(String::from("Last messages:"));
         lines.extend(self.last_messages.iter().map(|msg| {
@@ -180,6 +207,43 @@ impl Debug {
     }
 }
 
+impl Debug {
+    fn slowest_widgets(
+        &self,
+        amount: usize,
+    ) -> Vec<(&'static str, time::Duration, time::Duration)> {
+        let mut widgets: Vec<(&'static str, time::Duration, time::Duration)> =
+            Vec::new();
+
+        for timing in &self.widget_timings {
+            let index = widgets
+                .iter()
+                .position(|(widget, _, _)| *widget == timing.widget)
+                .unwrap_or_else(|| {
+                    widgets.push((
+                        timing.widget,
+                        time::Duration::ZERO,
+                        time::Duration::ZERO,
+                    ));
+
+                    widgets.len() - 1
+                });
+
+            match timing.stage {
+                profile::Stage::Layout => widgets[index].1 = timing.duration,
+                profile::Stage::Draw => widgets[index].2 = timing.duration,
+            }
+        }
+
+        widgets.sort_by(|(_, a_layout, a_draw), (_, b_layout, b_draw)| {
+            (*b_layout + *b_draw).cmp(&(*a_layout + *a_draw))
+        });
+        widgets.truncate(amount);
+
+        widgets
+    }
+}
+
 impl Default for Debug {
     fn default() -> Self {
         Self::new()
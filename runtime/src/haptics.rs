@@ -0,0 +1,8 @@
+//! Trigger haptic feedback on supported hardware.
+use crate::command::{self, Command};
+
+/// Triggers a haptic impulse with the given strength, between `0.0` and
+/// `1.0`, with the haptics backend of the application.
+pub fn impulse<Message>(strength: f32) -> Command<Message> {
+    Command::single(command::Action::Haptics(strength.clamp(0.0, 1.0)))
+}
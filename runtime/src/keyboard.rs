@@ -1,2 +1,5 @@
 //! Track keyboard events.
+mod shortcuts;
+
 pub use iced_core::keyboard::*;
+pub use shortcuts::{Conflict, Shortcuts};
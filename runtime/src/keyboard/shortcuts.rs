@@ -0,0 +1,109 @@
+use crate::core::event::Event;
+use crate::core::keyboard::{self, Shortcut};
+
+use std::fmt;
+
+/// A registry of keyboard [`Shortcut`]s of an application.
+///
+/// Shells check the [`Shortcuts`] of a program before dispatching key
+/// presses to its widgets. A key press that triggers a [`Shortcut`] produces
+/// its message and never reaches any widget.
+pub struct Shortcuts<Message> {
+    entries: Vec<(Shortcut, Box<dyn Fn() -> Message>)>,
+}
+
+impl<Message> Shortcuts<Message> {
+    /// Creates an empty set of [`Shortcuts`].
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Registers a [`Shortcut`] that produces the given message.
+    ///
+    /// If the [`Shortcut`] is already registered, the first registration
+    /// is kept and a warning is logged. Use [`Shortcuts::insert`] to handle
+    /// conflicts yourself.
+    pub fn on(mut self, shortcut: Shortcut, message: Message) -> Self
+    where
+        Message: Clone + 'static,
+    {
+        if let Err(conflict) = self.insert(shortcut, message) {
+            log::warn!("{conflict}");
+        }
+
+        self
+    }
+
+    /// Registers a [`Shortcut`] that produces the given message, unless
+    /// it is already registered.
+    pub fn insert(
+        &mut self,
+        shortcut: Shortcut,
+        message: Message,
+    ) -> Result<(), Conflict>
+    where
+        Message: Clone + 'static,
+    {
+        if self.contains(&shortcut) {
+            return Err(Conflict { shortcut });
+        }
+
+        self.entries
+            .push((shortcut, Box::new(move || message.clone())));
+
+        Ok(())
+    }
+
+    /// Returns whether the given [`Shortcut`] is registered.
+    pub fn contains(&self, shortcut: &Shortcut) -> bool {
+        self.entries
+            .iter()
+            .any(|(registered, _)| registered == shortcut)
+    }
+
+    /// Returns an iterator over the registered [`Shortcut`]s, in order of
+    /// registration.
+    ///
+    /// A [`Shortcut`] can be displayed next to its action in a menu.
+    pub fn iter(&self) -> impl Iterator<Item = &Shortcut> {
+        self.entries.iter().map(|(shortcut, _)| shortcut)
+    }
+
+    /// Returns the message of the [`Shortcut`] triggered by the given
+    /// [`Event`], if any.
+    pub fn handle(&self, event: &Event) -> Option<Message> {
+        let Event::Keyboard(keyboard::Event::KeyPressed {
+            key, modifiers, ..
+        }) = event
+        else {
+            return None;
+        };
+
+        self.entries
+            .iter()
+            .find(|(shortcut, _)| shortcut.matches(key, *modifiers))
+            .map(|(_, message)| message())
+    }
+}
+
+impl<Message> Default for Shortcuts<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message> fmt::Debug for Shortcuts<Message> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// A [`Shortcut`] registered more than once.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("the shortcut {shortcut} is already registered")]
+pub struct Conflict {
+    /// The conflicting [`Shortcut`].
+    pub shortcut: Shortcut,
+}
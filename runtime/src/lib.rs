@@ -16,10 +16,12 @@
     rustdoc::broken_intra_doc_links
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub mod audio;
 pub mod clipboard;
 pub mod command;
 pub mod dnd;
 pub mod font;
+pub mod haptics;
 pub mod keyboard;
 pub mod overlay;
 pub mod program;
@@ -2,6 +2,7 @@
 use crate::core::text;
 use crate::core::window;
 use crate::core::{Element, Renderer};
+use crate::keyboard;
 use crate::Command;
 
 /// The core of a user interface for a multi-window application following The Elm Architecture.
@@ -32,4 +33,16 @@ pub trait Program: Sized {
         &self,
         window: window::Id,
     ) -> Element<'_, Self::Message, Self::Theme, Self::Renderer>;
+
+    /// Returns the keyboard [`Shortcuts`] of the [`Program`].
+    ///
+    /// Key presses that trigger a shortcut produce its message before any
+    /// widget sees them.
+    ///
+    /// By default, it returns no shortcuts.
+    ///
+    /// [`Shortcuts`]: keyboard::Shortcuts
+    fn shortcuts(&self) -> keyboard::Shortcuts<Self::Message> {
+        keyboard::Shortcuts::new()
+    }
 }
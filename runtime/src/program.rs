@@ -1,4 +1,5 @@
 //! Build interactive programs using The Elm Architecture.
+use crate::keyboard;
 use crate::Command;
 
 use iced_core::text;
@@ -33,4 +34,16 @@ pub trait Program: Sized {
     ///
     /// These widgets can produce __messages__ based on user interaction.
     fn view(&self) -> Element<'_, Self::Message, Self::Theme, Self::Renderer>;
+
+    /// Returns the keyboard [`Shortcuts`] of the [`Program`].
+    ///
+    /// Key presses that trigger a shortcut produce its message before any
+    /// widget sees them.
+    ///
+    /// By default, it returns no shortcuts.
+    ///
+    /// [`Shortcuts`]: keyboard::Shortcuts
+    fn shortcuts(&self) -> keyboard::Shortcuts<Self::Message> {
+        keyboard::Shortcuts::new()
+    }
 }
@@ -1,8 +1,10 @@
 //! Implement your own event loop to drive a user interface.
 use iced_core::clipboard::DndDestinationRectangles;
+use iced_core::widget::operation::{self, focusable};
 use iced_core::widget::{Operation, OperationOutputWrapper};
 
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
@@ -192,6 +194,7 @@ where
 
         let mut outdated = false;
         let mut redraw_request = None;
+        let mut ime_request = None;
 
         let mut manual_overlay = ManuallyDrop::new(
             self.root
@@ -232,6 +235,10 @@ where
                     _ => {}
                 }
 
+                if let Some(cursor) = shell.ime_request() {
+                    ime_request = Some(cursor);
+                }
+
                 if shell.is_layout_invalid() {
                     let _ = ManuallyDrop::into_inner(manual_overlay);
 
@@ -302,7 +309,7 @@ where
 
         let _ = ManuallyDrop::into_inner(manual_overlay);
 
-        let event_statuses = events
+        let mut event_statuses: Vec<event::Status> = events
             .iter()
             .cloned()
             .zip(overlay_statuses)
@@ -338,6 +345,10 @@ where
                     _ => {}
                 }
 
+                if let Some(cursor) = shell.ime_request() {
+                    ime_request = Some(cursor);
+                }
+
                 shell.revalidate_layout(|| {
                     self.base = self.root.as_widget().layout(
                         &mut self.state,
@@ -356,11 +367,31 @@ where
             })
             .collect();
 
+        // Tab and Shift+Tab move the focus along the focus chain, unless a
+        // widget has used them already
+        for (current, status) in events.iter().zip(event_statuses.iter_mut()) {
+            if let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Tab),
+                modifiers,
+                ..
+            }) = current
+            {
+                if *status == event::Status::Ignored {
+                    self.traverse_focus(renderer, modifiers.shift());
+
+                    *status = event::Status::Captured;
+                }
+            }
+        }
+
         (
             if outdated {
                 State::Outdated
             } else {
-                State::Updated { redraw_request }
+                State::Updated {
+                    redraw_request,
+                    ime_request,
+                }
             },
             event_statuses,
         )
@@ -597,6 +628,31 @@ where
         }
     }
 
+    /// Moves the focus to the next focusable widget of the [`UserInterface`],
+    /// or to the previous one if `backwards` is true.
+    ///
+    /// The focus chain follows the order in which widgets are laid out, and
+    /// wraps around at both ends.
+    pub fn traverse_focus(&mut self, renderer: &Renderer, backwards: bool) {
+        if backwards {
+            self.operate_chain(renderer, &mut focusable::focus_previous());
+        } else {
+            self.operate_chain(renderer, &mut focusable::focus_next());
+        }
+    }
+
+    fn operate_chain(
+        &mut self,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.operate(renderer, operation);
+
+        if let operation::Outcome::Chain(mut next) = operation.finish() {
+            self.operate_chain(renderer, next.as_mut());
+        }
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
@@ -677,5 +733,9 @@ pub enum State {
     Updated {
         /// The [`window::RedrawRequest`] when a redraw should be performed.
         redraw_request: Option<window::RedrawRequest>,
+
+        /// The cursor area of the input method editor, if any widget
+        /// requested it.
+        ime_request: Option<Rectangle>,
     },
 }
@@ -9,11 +9,15 @@ pub use action::Action;
 pub use screenshot::Screenshot;
 
 use crate::command::{self, Command};
-use crate::core::time::Instant;
-use crate::core::window::{Event, Icon, Level, Mode, Settings, UserAttention};
+use crate::core::time::{Duration, Instant};
+use crate::core::window::{
+    ColorScheme, Direction, Event, Icon, Level, Mode, Settings, UserAttention,
+};
 use crate::core::{Point, Size};
 use crate::futures::event;
-use crate::futures::Subscription;
+use crate::futures::subscription::{self, Subscription};
+
+use std::collections::HashMap;
 
 /// Subscribes to the frames of the window of the running application.
 ///
@@ -32,6 +36,115 @@ pub fn frames() -> Subscription<(Id, Instant)> {
     })
 }
 
+/// Subscribes to the color scheme preferred by the user for the windows of the
+/// running application.
+///
+/// An item is produced when a window is opened, if the platform reports a
+/// preference, and every time the preference changes afterwards. A
+/// [`ColorScheme`] can be converted into the built-in light or dark `Theme`
+/// to follow the preference of the operating system.
+pub fn color_scheme_changes() -> Subscription<(Id, ColorScheme)> {
+    event::listen_raw(|event, _status| match event {
+        iced_core::Event::Window(id, Event::ColorSchemeChanged(scheme)) => {
+            Some((id, scheme))
+        }
+        _ => None,
+    })
+}
+
+/// Subscribes to all the [`Event`]s of the windows of the running
+/// application, except for redraw requests.
+///
+/// This is useful to react to the window itself; like pausing animations
+/// when it loses focus, or accepting files dropped on it.
+pub fn events() -> Subscription<(Id, Event)> {
+    event::listen_raw(|event, _status| match event {
+        iced_core::Event::Window(_, Event::RedrawRequested(_)) => None,
+        iced_core::Event::Window(id, event) => Some((id, event)),
+        _ => None,
+    })
+}
+
+/// Subscribes to the close requests of the windows of the running
+/// application.
+///
+/// Windows are not closed on request when `exit_on_close_request` is
+/// disabled in their [`Settings`]; this [`Subscription`] lets you confirm
+/// with the user before calling [`close`].
+pub fn close_requests() -> Subscription<Id> {
+    event::listen_raw(|event, _status| match event {
+        iced_core::Event::Window(id, Event::CloseRequested) => Some(id),
+        _ => None,
+    })
+}
+
+/// Subscribes to the focus changes of the windows of the running
+/// application, producing `true` when a window gains focus and `false` when
+/// it loses it.
+pub fn focus_changes() -> Subscription<(Id, bool)> {
+    event::listen_raw(|event, _status| match event {
+        iced_core::Event::Window(id, Event::Focused) => Some((id, true)),
+        iced_core::Event::Window(id, Event::Unfocused) => Some((id, false)),
+        _ => None,
+    })
+}
+
+/// Subscribes to the frames of the windows of the running application,
+/// producing the [`Duration`] elapsed since the previous frame of each window.
+///
+/// Like [`frames`], this [`Subscription`] keeps the application redrawing
+/// only while it is active, which makes it a good fit for custom animations
+/// or simulations that need to advance by a time step every frame.
+///
+/// The first frame of a window after subscribing produces a zero
+/// [`Duration`].
+pub fn frame_deltas() -> Subscription<(Id, Duration)> {
+    Subscription::from_recipe(FrameDeltas)
+}
+
+struct FrameDeltas;
+
+impl subscription::Recipe for FrameDeltas {
+    type Output = (Id, Duration);
+
+    fn hash(&self, state: &mut crate::core::Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        input: subscription::EventStream,
+    ) -> crate::futures::BoxStream<Self::Output> {
+        use crate::futures::futures::future;
+        use crate::futures::futures::stream::StreamExt;
+
+        let mut last_frames = HashMap::new();
+
+        crate::futures::boxed_stream(input.filter_map(
+            move |(event, _status)| {
+                let delta = match event {
+                    iced_core::Event::Window(
+                        id,
+                        Event::RedrawRequested(at),
+                    ) => {
+                        let delta = last_frames
+                            .insert(id, at)
+                            .map(|last| at.saturating_duration_since(last))
+                            .unwrap_or_default();
+
+                        Some((id, delta))
+                    }
+                    _ => None,
+                };
+
+                future::ready(delta)
+            },
+        ))
+    }
+}
+
 #[cfg(feature = "wayland")]
 /// Subscribes to the frames of the window of the running application.
 ///
@@ -71,15 +184,52 @@ pub fn close<Message>(id: Id) -> Command<Message> {
 }
 
 /// Begins dragging the window while the left mouse button is held.
+///
+/// Produce it when a custom title bar is pressed, for instance with the
+/// `on_press` message of a `MouseArea`, to move a window without
+/// decorations.
 pub fn drag<Message>(id: Id) -> Command<Message> {
     Command::single(command::Action::Window(Action::Drag(id)))
 }
 
+/// Begins resizing the window from the given [`Direction`] while the left
+/// mouse button is held.
+///
+/// Like [`drag`], it lets the widgets of a window without decorations act
+/// as its edges and corners.
+pub fn drag_resize<Message>(id: Id, direction: Direction) -> Command<Message> {
+    Command::single(command::Action::Window(Action::DragResize(id, direction)))
+}
+
 /// Resizes the window to the given logical dimensions.
 pub fn resize<Message>(id: Id, new_size: Size) -> Command<Message> {
     Command::single(command::Action::Window(Action::Resize(id, new_size)))
 }
 
+/// Changes the minimum logical dimensions of the window.
+///
+/// The window can be shrunk freely again with `None`.
+pub fn change_min_size<Message>(
+    id: Id,
+    min_size: Option<Size>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::ChangeMinSize(
+        id, min_size,
+    )))
+}
+
+/// Changes the maximum logical dimensions of the window.
+///
+/// The window can be grown freely again with `None`.
+pub fn change_max_size<Message>(
+    id: Id,
+    max_size: Option<Size>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::ChangeMaxSize(
+        id, max_size,
+    )))
+}
+
 /// Fetches the window's size in logical dimensions.
 pub fn fetch_size<Message>(
     id: Id,
@@ -179,6 +329,44 @@ pub fn change_level<Message>(id: Id, level: Level) -> Command<Message> {
     Command::single(command::Action::Window(Action::ChangeLevel(id, level)))
 }
 
+/// Changes whether the window lets mouse input pass through to the windows
+/// below it, which is useful for click-through overlays.
+pub fn change_click_through<Message>(
+    id: Id,
+    click_through: bool,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::ChangeClickThrough(
+        id,
+        click_through,
+    )))
+}
+
+/// Fetches the last known logical position of the cursor inside the window.
+///
+/// The position is `None` if the cursor is not over the window.
+pub fn fetch_cursor_position<Message>(
+    id: Id,
+    f: impl FnOnce(Option<Point>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FetchCursorPosition(
+        id,
+        Box::new(f),
+    )))
+}
+
+/// Moves the cursor to the given logical coordinates inside the window.
+///
+/// This is only supported on some platforms. See
+/// [`Action::SetCursorPosition`] for details.
+pub fn set_cursor_position<Message>(
+    id: Id,
+    position: Point,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetCursorPosition(
+        id, position,
+    )))
+}
+
 /// Show window menu at cursor position.
 pub fn show_window_menu<Message>(id: Id) -> Command<Message> {
     Command::single(command::Action::Window(Action::ShowWindowMenu(id)))
@@ -1,4 +1,6 @@
-use crate::core::window::{Icon, Id, Level, Mode, Settings, UserAttention};
+use crate::core::window::{
+    Direction, Icon, Id, Level, Mode, Settings, UserAttention,
+};
 use crate::core::{Point, Size};
 use crate::futures::MaybeSend;
 use crate::window::Screenshot;
@@ -17,8 +19,24 @@ pub enum Action<T> {
     /// There’s no guarantee that this will work unless the left mouse
     /// button was pressed immediately before this function is called.
     Drag(Id),
+    /// Resize the window from the given edge or corner with the left mouse
+    /// button until the button is released.
+    ///
+    /// There’s no guarantee that this will work unless the left mouse
+    /// button was pressed immediately before this function is called.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web / Orbital:** Unsupported.
+    DragResize(Id, Direction),
     /// Resize the window to the given logical dimensions.
     Resize(Id, Size),
+    /// Change the minimum logical dimensions of the window, or remove
+    /// them with `None`.
+    ChangeMinSize(Id, Option<Size>),
+    /// Change the maximum logical dimensions of the window, or remove
+    /// them with `None`.
+    ChangeMaxSize(Id, Option<Size>),
     /// Fetch the current logical dimensions of the window.
     FetchSize(Id, Box<dyn FnOnce(Size) -> T + 'static>),
     /// Fetch if the current window is maximized or not.
@@ -103,6 +121,23 @@ pub enum Action<T> {
     ChangeIcon(Id, Icon),
     /// Screenshot the viewport of the window.
     Screenshot(Id, Box<dyn FnOnce(Screenshot) -> T + 'static>),
+    /// Change whether the window lets mouse input pass through to the
+    /// windows below it.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web / Orbital:** Unsupported.
+    ChangeClickThrough(Id, bool),
+    /// Fetch the last known logical position of the cursor inside the
+    /// window, if it is over the window.
+    FetchCursorPosition(Id, Box<dyn FnOnce(Option<Point>) -> T + 'static>),
+    /// Move the cursor to the given logical coordinates inside the window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Only works while the cursor is locked.
+    /// - **iOS / Android / Web / Orbital:** Unsupported.
+    SetCursorPosition(Id, Point),
 }
 
 impl<T> Action<T> {
@@ -118,7 +153,12 @@ impl<T> Action<T> {
             Self::Spawn(id, settings) => Action::Spawn(id, settings),
             Self::Close(id) => Action::Close(id),
             Self::Drag(id) => Action::Drag(id),
+            Self::DragResize(id, direction) => {
+                Action::DragResize(id, direction)
+            }
             Self::Resize(id, size) => Action::Resize(id, size),
+            Self::ChangeMinSize(id, size) => Action::ChangeMinSize(id, size),
+            Self::ChangeMaxSize(id, size) => Action::ChangeMaxSize(id, size),
             Self::FetchSize(id, o) => {
                 Action::FetchSize(id, Box::new(move |s| f(o(s))))
             }
@@ -151,6 +191,15 @@ impl<T> Action<T> {
                 id,
                 Box::new(move |screenshot| f(tag(screenshot))),
             ),
+            Self::ChangeClickThrough(id, click_through) => {
+                Action::ChangeClickThrough(id, click_through)
+            }
+            Self::FetchCursorPosition(id, o) => {
+                Action::FetchCursorPosition(id, Box::new(move |s| f(o(s))))
+            }
+            Self::SetCursorPosition(id, position) => {
+                Action::SetCursorPosition(id, position)
+            }
         }
     }
 }
@@ -163,9 +212,18 @@ impl<T> fmt::Debug for Action<T> {
             }
             Self::Close(id) => write!(f, "Action::Close({id:?})"),
             Self::Drag(id) => write!(f, "Action::Drag({id:?})"),
+            Self::DragResize(id, direction) => {
+                write!(f, "Action::DragResize({id:?}, {direction:?})")
+            }
             Self::Resize(id, size) => {
                 write!(f, "Action::Resize({id:?}, {size:?})")
             }
+            Self::ChangeMinSize(id, size) => {
+                write!(f, "Action::ChangeMinSize({id:?}, {size:?})")
+            }
+            Self::ChangeMaxSize(id, size) => {
+                write!(f, "Action::ChangeMaxSize({id:?}, {size:?})")
+            }
             Self::FetchSize(id, _) => write!(f, "Action::FetchSize({id:?})"),
             Self::FetchMaximized(id, _) => {
                 write!(f, "Action::FetchMaximized({id:?})")
@@ -207,6 +265,15 @@ impl<T> fmt::Debug for Action<T> {
                 write!(f, "Action::ChangeIcon({id:?})")
             }
             Self::Screenshot(id, _) => write!(f, "Action::Screenshot({id:?})"),
+            Self::ChangeClickThrough(id, click_through) => {
+                write!(f, "Action::ChangeClickThrough({id:?}, {click_through})")
+            }
+            Self::FetchCursorPosition(id, _) => {
+                write!(f, "Action::FetchCursorPosition({id:?})")
+            }
+            Self::SetCursorPosition(id, position) => {
+                write!(f, "Action::SetCursorPosition({id:?}, {position})")
+            }
         }
     }
 }
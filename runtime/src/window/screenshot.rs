@@ -1,5 +1,5 @@
 //! Take screenshots of a window.
-use crate::core::{Rectangle, Size};
+use crate::core::{Color, Rectangle, Size};
 
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
@@ -72,6 +72,44 @@ impl Screenshot {
             size: Size::new(region.width, region.height),
         })
     }
+
+    /// Splits a [`Screenshot`] into pages of the given height, from top to
+    /// bottom. The last page may be shorter.
+    pub fn paginate(&self, page_height: u32) -> Vec<Self> {
+        if page_height == 0 || self.size.width == 0 {
+            return Vec::new();
+        }
+
+        (0..self.size.height)
+            .step_by(page_height as usize)
+            .filter_map(|y| {
+                self.crop(Rectangle {
+                    x: 0,
+                    y,
+                    width: self.size.width,
+                    height: page_height.min(self.size.height - y),
+                })
+                .ok()
+            })
+            .collect()
+    }
+
+    /// Returns the [`Color`] of the pixel at the given coordinates, relative
+    /// to the top-left corner of the [`Screenshot`].
+    ///
+    /// Returns `None` if the coordinates are out of bounds.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<Color> {
+        if x >= self.size.width || y >= self.size.height {
+            return None;
+        }
+
+        let index = (y as usize * self.size.width as usize + x as usize) * 4;
+        let [r, g, b, a] = self.bytes.get(index..index + 4)? else {
+            return None;
+        };
+
+        Some(Color::from_rgba8(*r, *g, *b, f32::from(*a) / 255.0))
+    }
 }
 
 impl AsRef<[u8]> for Screenshot {
@@ -49,7 +49,10 @@ use iced_runtime::{
             wayland::{data_device::DndIcon, popup},
         },
     },
-    core::{mouse::Interaction, Color, Point, Size},
+    core::{
+        audio::Audio, haptics::Haptics, mouse::Interaction, Color, Point,
+        Rectangle, Size,
+    },
     multi_window::Program,
     system, user_interface,
     window::Id as SurfaceId,
@@ -97,6 +100,9 @@ pub enum Event<Message> {
     SessionLock(platform_specific::wayland::session_lock::Action<Message>),
     /// request sctk to set the cursor of the active pointer
     SetCursor(Interaction),
+    /// request sctk to enable the input method editor of a surface next to
+    /// the given cursor area, or to disable it
+    SetIme(WlSurface, Option<Rectangle>),
     /// Application Message
     Message(Message),
 }
@@ -203,6 +209,26 @@ where
     fn scale_factor(&self, window: SurfaceId) -> f64 {
         1.0
     }
+
+    /// Returns the [`Audio`] backend used to play the sounds requested with
+    /// [`audio::play`].
+    ///
+    /// By default, it returns `None` and no sounds are played.
+    ///
+    /// [`audio::play`]: iced_runtime::audio::play
+    fn audio(&self) -> Option<&dyn Audio> {
+        None
+    }
+
+    /// Returns the [`Haptics`] backend used to trigger the impulses requested
+    /// with [`haptics::impulse`].
+    ///
+    /// By default, it returns `None` and no impulses are triggered.
+    ///
+    /// [`haptics::impulse`]: iced_runtime::haptics::impulse
+    fn haptics(&self) -> Option<&dyn Haptics> {
+        None
+    }
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -232,6 +258,9 @@ where
         (Runtime::new(executor, ev_proxy.clone()), ev_proxy)
     };
 
+    #[cfg(feature = "color-scheme")]
+    crate::color_scheme::listen(ev_proxy.clone());
+
     let (application, init_command) = {
         let flags = settings.flags;
 
@@ -265,7 +294,8 @@ where
     };
 
     #[allow(unsafe_code)]
-    let compositor = C::new(compositor_settings, wrapper.clone()).unwrap();
+    let compositor =
+        C::new(compositor_settings.clone(), wrapper.clone()).unwrap();
     let renderer = compositor.create_renderer();
 
     let auto_size_surfaces = HashMap::new();
@@ -279,6 +309,7 @@ where
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
         compositor,
+        compositor_settings,
         renderer,
         runtime,
         ev_proxy,
@@ -299,8 +330,9 @@ where
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
+    let mut result = Ok(());
 
-    let _ = event_loop.run_return(move |event, _, control_flow| {
+    let _ = event_loop.run_return(|event, _, control_flow| {
         if let ControlFlow::ExitWithCode(_) = control_flow {
             return;
         }
@@ -315,13 +347,15 @@ where
                     *control_flow = flow
                 }
             }
-            task::Poll::Ready(_) => {
+            task::Poll::Ready(outcome) => {
+                result = outcome;
+
                 *control_flow = ControlFlow::ExitWithCode(1)
             }
         };
     });
 
-    Ok(())
+    result
 }
 
 fn subscription_map<A, E, C>(e: A::Message) -> Event<A::Message>
@@ -338,6 +372,7 @@ where
 async fn run_instance<A, E, C>(
     mut application: A,
     mut compositor: C,
+    compositor_settings: C::Settings,
     mut renderer: A::Renderer,
     mut runtime: Runtime<E, proxy::Proxy<Event<A::Message>>, Event<A::Message>>,
     mut ev_proxy: proxy::Proxy<Event<A::Message>>,
@@ -404,6 +439,7 @@ where
     > = HashMap::new();
 
     let mut messages: Vec<A::Message> = Vec::new();
+    let mut shortcuts = application.shortcuts();
     #[cfg(feature = "a11y")]
     let mut commands: Vec<Command<A::Message>> = Vec::new();
     let mut redraw_pending = false;
@@ -416,6 +452,10 @@ where
     let mut mods: Modifiers = Modifiers::default();
     let mut destroyed_surface_ids: HashMap<ObjectId, SurfaceIdWrapper> =
         Default::default();
+    let mut color_scheme: Option<crate::core::window::ColorScheme> = None;
+    let mut out_of_memory_errors = 0;
+    let mut is_device_lost = false;
+    let mut result = Ok(());
 
     'main: while let Some(event) = receiver.next().await {
         match event {
@@ -432,6 +472,19 @@ where
             }
             IcedSctkEvent::SctkEvent(event) => {
                 sctk_events.push(event.clone());
+                if let (Some(color_scheme), Some(id)) =
+                    (color_scheme, created_surface(&event))
+                {
+                    runtime.broadcast(
+                        CoreEvent::Window(
+                            id,
+                            crate::core::window::Event::ColorSchemeChanged(
+                                color_scheme,
+                            ),
+                        ),
+                        Status::Ignored,
+                    );
+                }
                 match event {
                     SctkEvent::SeatEvent { .. } => {} // TODO Ashley: handle later possibly if multiseat support is wanted
                     SctkEvent::PointerEvent {
@@ -468,6 +521,30 @@ where
                             | PointerEventKind::Axis { .. } => {}
                         }
                     }
+                    SctkEvent::TouchEvent {
+                        variant,
+                        surface,
+                        ..
+                    } => {
+                        let mut offset = (0., 0.);
+                        let state = match surface_ids
+                            .get(&surface.id())
+                            .and_then(|id| states.get_mut(&id.inner()))
+                        {
+                            Some(s) => s,
+                            None => {
+                                if let Some((x_offset, y_offset, id)) = subsurface_ids.get(&surface.id()) {
+                                    offset = (f64::from(*x_offset), f64::from(*y_offset));
+                                    states.get_mut(&id.inner()).unwrap()
+                                } else {
+                                    continue
+                                }
+                            },
+                        };
+                        // Touch points drive the cursor, like they do with winit
+                        let (x, y) = variant.position();
+                        state.set_cursor_position(Some(LogicalPosition { x: x + offset.0, y: y + offset.1 }));
+                    }
                     SctkEvent::KeyboardEvent { variant, .. } => match variant {
                         KeyboardEventVariant::Leave(_) => {
                             kbd_surface_id.take();
@@ -574,7 +651,6 @@ where
                                 backend: backend.clone(),
                                 wl_surface: wl_surface.clone()
                             }));
-
                         }
                         LayerSurfaceEventVariant::Done => {
                             if let Some(surface_id) = surface_ids.remove(&wl_surface.id()) {
@@ -798,6 +874,9 @@ where
                             destroyed_surface_ids.insert(surface.id(), surface_id);
                         }
                     }
+                    SctkEvent::ColorSchemeChanged(new_color_scheme) => {
+                        color_scheme = Some(new_color_scheme);
+                    }
                     _ => {}
                 }
             }
@@ -972,6 +1051,8 @@ where
                         &mut simple_clipboard,
                     );
 
+                    shortcuts = application.shortcuts();
+
                     interfaces = ManuallyDrop::new(build_user_interfaces(
                         &application,
                         &mut renderer,
@@ -1054,6 +1135,25 @@ where
                         let has_events =
                             has_events || !native_events.is_empty();
 
+                        let is_shortcut: Vec<bool> = native_events
+                            .iter()
+                            .map(|event| match shortcuts.handle(event) {
+                                Some(message) => {
+                                    messages.push(message);
+
+                                    true
+                                }
+                                None => false,
+                            })
+                            .collect();
+
+                        let widget_events: Vec<_> = native_events
+                            .iter()
+                            .zip(&is_shortcut)
+                            .filter(|(_, is_shortcut)| !**is_shortcut)
+                            .map(|(event, _)| event.clone())
+                            .collect();
+
                         let (interface_state, statuses) = {
                             let Some(user_interface) =
                                 interfaces.get_mut(&surface_id.inner())
@@ -1061,7 +1161,7 @@ where
                                 continue;
                             };
                             user_interface.update(
-                                native_events.as_slice(),
+                                widget_events.as_slice(),
                                 state.cursor(),
                                 &mut renderer,
                                 &mut simple_clipboard,
@@ -1070,9 +1170,19 @@ where
                         };
                         state.interface_state = interface_state;
                         debug.event_processing_finished();
-                        for (event, status) in
-                            native_events.into_iter().zip(statuses.into_iter())
+                        let mut statuses = statuses.into_iter();
+
+                        // Shortcuts are broadcast as captured, in their
+                        // original order
+                        for (event, is_shortcut) in
+                            native_events.into_iter().zip(is_shortcut)
                         {
+                            let status = if is_shortcut {
+                                Status::Captured
+                            } else {
+                                statuses.next().unwrap_or(Status::Ignored)
+                            };
+
                             runtime.broadcast(event, status);
                         }
 
@@ -1134,6 +1244,8 @@ where
                                 &mut simple_clipboard,
                             );
 
+                            shortcuts = application.shortcuts();
+
                             pure_states.insert(surface_id.inner(), cache);
 
                             // Update state
@@ -1199,6 +1311,21 @@ where
 
                         runtime.broadcast(redraw_event, Status::Ignored);
 
+                        if let user_interface::State::Updated {
+                            ime_request,
+                            ..
+                        } = interface_state
+                        {
+                            if state.ime_request != ime_request {
+                                state.ime_request = ime_request;
+
+                                ev_proxy.send_event(Event::SetIme(
+                                    state.wrapper.wl_surface.clone(),
+                                    ime_request,
+                                ));
+                            }
+                        }
+
                         ev_proxy.send_event(Event::SctkEvent(
                             IcedSctkEvent::RedrawRequested(object_id.clone()),
                         ));
@@ -1208,6 +1335,7 @@ where
                                 .start_send(match interface_state {
                                 user_interface::State::Updated {
                                     redraw_request: Some(redraw_request),
+                                    ..
                                 } => {
                                     match redraw_request {
                                         crate::core::window::RedrawRequest::NextFrame => {
@@ -1250,6 +1378,7 @@ where
                             | SctkEvent::PopupEvent { .. }
                             | SctkEvent::LayerSurfaceEvent { .. }
                             | SctkEvent::WindowEvent { .. }
+                            | SctkEvent::ColorSchemeChanged(_)
                     );
                     if remove {
                         let event = sctk_events.remove(i);
@@ -1271,6 +1400,58 @@ where
                 destroyed_surface_ids.clear();
             }
             IcedSctkEvent::RedrawRequested(object_id) => {
+                if is_device_lost || compositor.is_lost() {
+                    tracing::warn!(
+                        "The graphics device was lost, recreating it."
+                    );
+
+                    let Some(wrapper) = surface_ids
+                        .get(&object_id)
+                        .and_then(|id| states.get(&id.inner()))
+                        .map(|state| state.wrapper.clone())
+                    else {
+                        continue;
+                    };
+
+                    match C::new(compositor_settings.clone(), wrapper) {
+                        Ok(new_compositor) => {
+                            // The old surfaces must be released before the
+                            // new ones are created
+                            let presented: Vec<_> = states
+                                .iter_mut()
+                                .filter_map(|(id, state)| {
+                                    state.surface.take().map(|_| *id)
+                                })
+                                .collect();
+
+                            compositor = new_compositor;
+                            renderer = compositor.create_renderer();
+
+                            for id in presented {
+                                let Some(state) = states.get_mut(&id) else {
+                                    continue;
+                                };
+
+                                let physical_size = state.physical_size();
+
+                                state.surface =
+                                    Some(compositor.create_surface(
+                                        state.wrapper.clone(),
+                                        physical_size.width,
+                                        physical_size.height,
+                                    ));
+                                state.set_needs_redraw(true);
+                            }
+
+                            is_device_lost = false;
+                        }
+                        Err(error) => {
+                            result = Err(error.into());
+                            break 'main;
+                        }
+                    }
+                }
+
                 if let Some((
                     native_id,
                     Some(mut user_interface),
@@ -1428,13 +1609,70 @@ where
                             state.wrapper.wl_surface.clone(),
                         );
                     }
-                    let _ = compositor.present(
+                    match compositor.present(
                         &mut renderer,
                         &mut comp_surface,
                         state.viewport(),
                         state.background_color(),
                         &debug.overlay(),
-                    );
+                    ) {
+                        Ok(()) => {
+                            out_of_memory_errors = 0;
+                        }
+                        Err(error @ compositor::SurfaceError::OutOfMemory) => {
+                            out_of_memory_errors += 1;
+
+                            error!(
+                                "Error {error:?} when presenting surface \
+                                ({out_of_memory_errors} in a row)."
+                            );
+
+                            // Recreate the surface first, then the whole
+                            // device, before giving up
+                            match out_of_memory_errors {
+                                1 => {
+                                    let physical_size = state.physical_size();
+
+                                    drop(comp_surface);
+
+                                    comp_surface = compositor.create_surface(
+                                        state.wrapper.clone(),
+                                        physical_size.width,
+                                        physical_size.height,
+                                    );
+                                }
+                                2 => {
+                                    is_device_lost = true;
+                                }
+                                _ => {
+                                    result =
+                                        Err(Error::GraphicsCreationFailed(
+                                            iced_graphics::Error::DeviceLost,
+                                        ));
+                                    break 'main;
+                                }
+                            }
+
+                            state.set_needs_redraw(true);
+                        }
+                        Err(
+                            compositor::SurfaceError::Outdated
+                            | compositor::SurfaceError::Lost,
+                        ) => {
+                            // Recreate the swap chain, so the next frame can
+                            // be presented.
+                            let physical_size = state.physical_size();
+
+                            compositor.configure_surface(
+                                &mut comp_surface,
+                                physical_size.width,
+                                physical_size.height,
+                            );
+                        }
+                        Err(error) => {
+                            error!("Error {error:?} when presenting surface.");
+                        }
+                    }
                     // Need commit to get frame event, and update subsurfaces, even if main surface wasn't changed
                     state.wrapper.wl_surface.commit();
                     state.frame_pending = false;
@@ -1523,7 +1761,7 @@ where
         }
     }
 
-    Ok(())
+    result
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -1659,6 +1897,7 @@ where
     first: bool,
     wp_viewport: Option<WpViewport>,
     interface_state: user_interface::State,
+    ime_request: Option<Rectangle>,
     surface: Option<C::Surface>,
     wrapper: SurfaceDisplayWrapper,
     subsurfaces: Vec<SubsurfaceInstance>,
@@ -1699,6 +1938,7 @@ where
             first: true,
             wp_viewport: None,
             interface_state: user_interface::State::Outdated,
+            ime_request: None,
             surface: None,
             wrapper,
             subsurfaces: Vec::new(),
@@ -2068,6 +2308,16 @@ where
                     }
                 }
             },
+            command::Action::Audio(sound) => {
+                if let Some(audio) = application.audio() {
+                    audio.play(sound);
+                }
+            }
+            command::Action::Haptics(strength) => {
+                if let Some(haptics) = application.haptics() {
+                    haptics.impulse(strength);
+                }
+            }
             command::Action::Widget(action) => {
                 let state = match state {
                     Some(s) => s,
@@ -2275,10 +2525,19 @@ where
                     .iter()
                     .any(|s| s.wl_surface.id() == event_object_id)
         }
+        SctkEvent::TouchEvent { surface, .. } => {
+            let event_object_id = surface.id();
+            &event_object_id == object_id
+                || state
+                    .subsurfaces
+                    .iter()
+                    .any(|s| s.wl_surface.id() == event_object_id)
+        }
         SctkEvent::KeyboardEvent { variant, .. } => match variant {
             KeyboardEventVariant::Leave(id) => &id.id() == object_id,
             _ => has_kbd_focus,
         },
+        SctkEvent::ImeEvent { surface, .. } => &surface.id() == object_id,
         SctkEvent::WindowEvent { id, .. } => &id.id() == object_id,
         SctkEvent::LayerSurfaceEvent { id, .. } => &id.id() == object_id,
         SctkEvent::PopupEvent { id, .. } => &id.id() == object_id,
@@ -2307,5 +2566,25 @@ where
             &surface.id() == object_id
         }
         SctkEvent::SessionUnlocked => false,
+        SctkEvent::ColorSchemeChanged(_) => false,
+    }
+}
+
+// Determine the id of the surface created by the `SctkEvent`, if any.
+fn created_surface(evt: &SctkEvent) -> Option<SurfaceId> {
+    match evt {
+        SctkEvent::WindowEvent {
+            variant: crate::sctk_event::WindowEventVariant::Created(_, id),
+            ..
+        }
+        | SctkEvent::LayerSurfaceEvent {
+            variant: LayerSurfaceEventVariant::Created(_, id),
+            ..
+        }
+        | SctkEvent::PopupEvent {
+            variant: PopupEventVariant::Created(_, id),
+            ..
+        } => Some(*id),
+        _ => None,
     }
 }
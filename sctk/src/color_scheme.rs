@@ -0,0 +1,86 @@
+//! Follow the color scheme preferred by the user through the settings portal.
+use crate::application::Event;
+use crate::event_loop::proxy;
+use crate::sctk_event::{IcedSctkEvent, SctkEvent};
+
+use iced_runtime::core::window::ColorScheme;
+use zbus::zvariant::Value;
+
+const NAMESPACE: &str = "org.freedesktop.appearance";
+const KEY: &str = "color-scheme";
+
+/// Reports the color scheme preferred by the user, and every change to it
+/// afterwards, as [`SctkEvent::ColorSchemeChanged`] events.
+///
+/// The settings portal is listened to from a separate thread. Nothing is
+/// reported if the portal is not available.
+pub(crate) fn listen<T: 'static + Send>(proxy: proxy::Proxy<Event<T>>) {
+    let _ = std::thread::Builder::new()
+        .name(String::from("iced_sctk color scheme"))
+        .spawn(move || {
+            if let Err(error) = run(&proxy) {
+                tracing::warn!("Failed to follow the color scheme: {error}");
+            }
+        });
+}
+
+fn run<T: 'static>(proxy: &proxy::Proxy<Event<T>>) -> zbus::Result<()> {
+    let send = |color_scheme| {
+        proxy.send_event(Event::SctkEvent(IcedSctkEvent::SctkEvent(
+            SctkEvent::ColorSchemeChanged(color_scheme),
+        )));
+    };
+
+    let connection = zbus::blocking::Connection::session()?;
+    let settings = zbus::blocking::Proxy::new(
+        &connection,
+        "org.freedesktop.portal.Desktop",
+        "/org/freedesktop/portal/desktop",
+        "org.freedesktop.portal.Settings",
+    )?;
+
+    // Subscribe before reading, so no change is missed in between
+    let changes = settings.receive_signal("SettingChanged")?;
+
+    let initial: zbus::Result<zbus::zvariant::OwnedValue> = settings
+        .call("ReadOne", &(NAMESPACE, KEY))
+        // Older portals only implement the deprecated `Read` method
+        .or_else(|_| settings.call("Read", &(NAMESPACE, KEY)));
+
+    if let Some(color_scheme) =
+        initial.ok().as_deref().and_then(to_color_scheme)
+    {
+        send(color_scheme);
+    }
+
+    for message in changes {
+        let body = message.body();
+
+        let Ok((namespace, key, value)) =
+            body.deserialize::<(&str, &str, Value<'_>)>()
+        else {
+            continue;
+        };
+
+        if namespace != NAMESPACE || key != KEY {
+            continue;
+        }
+
+        if let Some(color_scheme) = to_color_scheme(&value) {
+            send(color_scheme);
+        }
+    }
+
+    Ok(())
+}
+
+/// Converts the `color-scheme` setting of the portal, which has no preference
+/// as `0`, dark as `1`, and light as `2`. A missing preference is light.
+fn to_color_scheme(value: &Value<'_>) -> Option<ColorScheme> {
+    match value {
+        Value::U32(1) => Some(ColorScheme::Dark),
+        Value::U32(_) => Some(ColorScheme::Light),
+        Value::Value(value) => to_color_scheme(value),
+        _ => None,
+    }
+}
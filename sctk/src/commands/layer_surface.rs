@@ -12,6 +12,7 @@ use iced_runtime::command::{
     },
     Command,
 };
+use iced_runtime::core::Rectangle;
 use iced_runtime::window::Id as SurfaceId;
 
 pub use sctk::shell::wlr_layer::{Anchor, KeyboardInteractivity, Layer};
@@ -121,3 +122,20 @@ pub fn set_layer<Message>(id: SurfaceId, layer: Layer) -> Command<Message> {
         )),
     ))
 }
+
+/// Sets the parts of the layer surface that accept pointer and touch input,
+/// in logical coordinates. Input outside of the region passes through to the
+/// surfaces below, which is useful for docks and panels that only react to a
+/// part of their surface. `None` makes the whole surface accept input again.
+///
+/// <https://wayland.app/protocols/wayland#wl_surface:request:set_input_region>
+pub fn set_input_region<Message>(
+    id: SurfaceId,
+    region: Option<Vec<Rectangle<i32>>>,
+) -> Command<Message> {
+    Command::single(command::Action::PlatformSpecific(
+        platform_specific::Action::Wayland(wayland::Action::LayerSurface(
+            wayland::layer_surface::Action::InputRegion { id, region },
+        )),
+    ))
+}
@@ -91,6 +91,13 @@ pub(crate) fn cursor_icon(cursor: Interaction) -> CursorIcon {
         Interaction::Grabbing => CursorIcon::Grabbing,
         Interaction::ResizingHorizontally => CursorIcon::EwResize,
         Interaction::ResizingVertically => CursorIcon::NsResize,
+        Interaction::ResizingDiagonallyUp => CursorIcon::NeswResize,
+        Interaction::ResizingDiagonallyDown => CursorIcon::NwseResize,
+        Interaction::ResizingAll => CursorIcon::AllScroll,
+        Interaction::Move => CursorIcon::Move,
         Interaction::NotAllowed => CursorIcon::NotAllowed,
+        Interaction::ZoomIn => CursorIcon::ZoomIn,
+        Interaction::ZoomOut => CursorIcon::ZoomOut,
+        Interaction::Locked => CursorIcon::Default,
     }
 }
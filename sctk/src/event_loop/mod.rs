@@ -11,7 +11,7 @@ use crate::{
     conversion,
     dpi::LogicalSize,
     handlers::{
-        activation::IcedRequestData,
+        activation::IcedRequestData, text_input::TextInputManager,
         wp_fractional_scaling::FractionalScalingManager,
         wp_viewporter::ViewporterState,
     },
@@ -211,6 +211,8 @@ where
                 dnd_offer: None,
                 fractional_scaling_manager,
                 viewporter_state,
+                text_input_manager: TextInputManager::new(&globals, &qh).ok(),
+                ime_requests: HashMap::new(),
                 compositor_updates: Default::default(),
             },
             _features: Default::default(),
@@ -798,7 +800,25 @@ where
 
                             }
                         },
+                        platform_specific::wayland::layer_surface::Action::InputRegion { id, region } => {
+                            if let Some(layer_surface) = self.state.layer_surfaces.iter().find(|l| l.id == id) {
+                                if let Some(rectangles) = region {
+                                    let wl_region = self.state.compositor_state.wl_compositor().create_region(&self.state.queue_handle, ());
+                                    for rectangle in rectangles {
+                                        wl_region.add(rectangle.x, rectangle.y, rectangle.width, rectangle.height);
+                                    }
+                                    layer_surface.surface.set_input_region(Some(&wl_region));
+                                    wl_region.destroy();
+                                } else {
+                                    layer_surface.surface.set_input_region(None);
+                                }
+                                to_commit.insert(id, layer_surface.surface.wl_surface().clone());
+                            }
+                        },
                     },
+                    Event::SetIme(surface, request) => {
+                        self.state.request_ime(surface, request);
+                    }
                     Event::SetCursor(iced_icon) => {
                         if let Some(ptr) = self.state.seats.get(0).and_then(|s| s.ptr.as_ref()) {
                             let icon = conversion::cursor_icon(iced_icon);
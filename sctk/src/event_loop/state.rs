@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     fmt::{Debug, Formatter},
     num::NonZeroU32,
 };
@@ -7,6 +8,7 @@ use crate::{
     application::Event,
     dpi::LogicalSize,
     handlers::{
+        text_input::{TextInput, TextInputManager},
         wp_fractional_scaling::FractionalScalingManager,
         wp_viewporter::ViewporterState,
     },
@@ -26,6 +28,7 @@ use iced_runtime::{
             window::SctkWindowSettings,
         },
     },
+    core::Rectangle,
     keyboard::Modifiers,
     window,
 };
@@ -41,6 +44,7 @@ use sctk::{
     reexports::{
         calloop::{LoopHandle, RegistrationToken},
         client::{
+            backend::ObjectId,
             delegate_noop,
             protocol::{
                 wl_keyboard::WlKeyboard,
@@ -92,10 +96,14 @@ pub(crate) struct SctkSeat {
     pub(crate) ptr: Option<ThemedPointer>,
     pub(crate) ptr_focus: Option<WlSurface>,
     pub(crate) last_ptr_press: Option<(u32, u32, u32)>, // (time, button, serial)
-    pub(crate) _touch: Option<WlTouch>,
+    pub(crate) touch: Option<WlTouch>,
+    /// The surface and last position of every ongoing touch point.
+    pub(crate) touch_points: HashMap<i32, (WlSurface, (f64, f64))>,
     pub(crate) _modifiers: Modifiers,
     pub(crate) data_device: DataDevice,
     pub(crate) icon: Option<CursorIcon>,
+    /// The input method of the seat, if supported by the compositor.
+    pub(crate) text_input: Option<TextInput>,
 }
 
 #[derive(Debug, Clone)]
@@ -311,6 +319,9 @@ pub struct SctkState<T> {
     /// Viewporter state on the given window.
     pub viewporter_state: Option<ViewporterState<T>>,
     pub(crate) fractional_scaling_manager: Option<FractionalScalingManager<T>>,
+    pub(crate) text_input_manager: Option<TextInputManager<T>>,
+    /// The cursor area of the input method requested by every surface.
+    pub(crate) ime_requests: HashMap<ObjectId, Rectangle>,
     pub(crate) registry_state: RegistryState,
     pub(crate) seat_state: SeatState,
     pub(crate) output_state: OutputState,
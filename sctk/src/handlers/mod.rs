@@ -7,6 +7,7 @@ pub mod seat;
 pub mod session_lock;
 pub mod shell;
 pub mod subcompositor;
+pub mod text_input;
 pub mod wp_fractional_scaling;
 pub mod wp_viewporter;
 
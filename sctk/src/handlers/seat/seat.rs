@@ -8,7 +8,7 @@ use sctk::{
     reexports::client::{protocol::wl_keyboard::WlKeyboard, Proxy},
     seat::{pointer::ThemeSpec, SeatHandler},
 };
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug};
 
 impl<T: Debug> SeatHandler for SctkState<T>
 where
@@ -30,11 +30,16 @@ where
         });
         let data_device =
             self.data_device_manager_state.get_data_device(qh, &seat);
+        let text_input = self
+            .text_input_manager
+            .as_ref()
+            .map(|manager| manager.text_input(&seat, qh));
         self.seats.push(SctkSeat {
             seat,
             kbd: None,
             ptr: None,
-            _touch: None,
+            touch: None,
+            touch_points: HashMap::new(),
             data_device,
             _modifiers: Modifiers::default(),
             kbd_focus: None,
@@ -42,6 +47,7 @@ where
             last_ptr_press: None,
             last_kbd_press: None,
             icon: None,
+            text_input,
         });
     }
 
@@ -59,7 +65,8 @@ where
                     seat: seat.clone(),
                     kbd: None,
                     ptr: None,
-                    _touch: None,
+                    touch: None,
+                    touch_points: HashMap::new(),
                     data_device: self
                         .data_device_manager_state
                         .get_data_device(qh, &seat),
@@ -69,6 +76,10 @@ where
                     last_ptr_press: None,
                     last_kbd_press: None,
                     icon: None,
+                    text_input: self
+                        .text_input_manager
+                        .as_ref()
+                        .map(|manager| manager.text_input(&seat, qh)),
                 });
                 self.seats.last_mut().unwrap()
             }
@@ -121,7 +132,16 @@ where
                 }
             }
             sctk::seat::Capability::Touch => {
-                // TODO touch
+                if let Ok(touch) = self.seat_state.get_touch(qh, &seat) {
+                    self.sctk_events.push(SctkEvent::SeatEvent {
+                        variant: SeatEventVariant::NewCapability(
+                            capability,
+                            touch.id(),
+                        ),
+                        id: seat.clone(),
+                    });
+                    my_seat.touch.replace(touch);
+                }
             }
             _ => unimplemented!(),
         }
@@ -165,8 +185,17 @@ where
                 }
             }
             sctk::seat::Capability::Touch => {
-                // TODO touch
-                // my_seat.touch = self.seat_state.get_touch(qh, &seat).ok();
+                if let Some(touch) = my_seat.touch.take() {
+                    my_seat.touch_points.clear();
+
+                    self.sctk_events.push(SctkEvent::SeatEvent {
+                        variant: SeatEventVariant::RemoveCapability(
+                            capability,
+                            touch.id(),
+                        ),
+                        id: seat.clone(),
+                    });
+                }
             }
             _ => unimplemented!(),
         }
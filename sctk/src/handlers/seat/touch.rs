@@ -1 +1,154 @@
-// TODO
+use crate::{
+    event_loop::state::SctkState,
+    sctk_event::{SctkEvent, TouchEventVariant},
+};
+use sctk::{
+    delegate_touch,
+    reexports::client::{
+        protocol::{wl_surface::WlSurface, wl_touch::WlTouch},
+        Connection, QueueHandle,
+    },
+    seat::touch::TouchHandler,
+};
+use std::fmt::Debug;
+
+impl<T: Debug> TouchHandler for SctkState<T> {
+    fn down(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        touch: &WlTouch,
+        _serial: u32,
+        _time: u32,
+        surface: WlSurface,
+        id: i32,
+        position: (f64, f64),
+    ) {
+        let Some(my_seat) = self
+            .seats
+            .iter_mut()
+            .find(|s| s.touch.as_ref() == Some(touch))
+        else {
+            return;
+        };
+
+        let _ = my_seat.touch_points.insert(id, (surface.clone(), position));
+
+        self.sctk_events.push(SctkEvent::TouchEvent {
+            variant: TouchEventVariant::Down { id, position },
+            touch_id: touch.clone(),
+            seat_id: my_seat.seat.clone(),
+            surface,
+        });
+    }
+
+    fn up(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        touch: &WlTouch,
+        _serial: u32,
+        _time: u32,
+        id: i32,
+    ) {
+        let Some(my_seat) = self
+            .seats
+            .iter_mut()
+            .find(|s| s.touch.as_ref() == Some(touch))
+        else {
+            return;
+        };
+
+        // `up` events carry neither a surface nor a position
+        let Some((surface, position)) = my_seat.touch_points.remove(&id) else {
+            return;
+        };
+
+        self.sctk_events.push(SctkEvent::TouchEvent {
+            variant: TouchEventVariant::Up { id, position },
+            touch_id: touch.clone(),
+            seat_id: my_seat.seat.clone(),
+            surface,
+        });
+    }
+
+    fn motion(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        touch: &WlTouch,
+        _time: u32,
+        id: i32,
+        position: (f64, f64),
+    ) {
+        let Some(my_seat) = self
+            .seats
+            .iter_mut()
+            .find(|s| s.touch.as_ref() == Some(touch))
+        else {
+            return;
+        };
+
+        let Some((surface, last_position)) = my_seat.touch_points.get_mut(&id)
+        else {
+            return;
+        };
+
+        *last_position = position;
+
+        self.sctk_events.push(SctkEvent::TouchEvent {
+            variant: TouchEventVariant::Motion { id, position },
+            touch_id: touch.clone(),
+            seat_id: my_seat.seat.clone(),
+            surface: surface.clone(),
+        });
+    }
+
+    fn shape(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _touch: &WlTouch,
+        _id: i32,
+        _major: f64,
+        _minor: f64,
+    ) {
+    }
+
+    fn orientation(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _touch: &WlTouch,
+        _id: i32,
+        _orientation: f64,
+    ) {
+    }
+
+    fn cancel(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        touch: &WlTouch,
+    ) {
+        let Some(my_seat) = self
+            .seats
+            .iter_mut()
+            .find(|s| s.touch.as_ref() == Some(touch))
+        else {
+            return;
+        };
+
+        // the compositor took over every ongoing touch point
+        for (id, (surface, position)) in my_seat.touch_points.drain() {
+            self.sctk_events.push(SctkEvent::TouchEvent {
+                variant: TouchEventVariant::Cancel { id, position },
+                touch_id: touch.clone(),
+                seat_id: my_seat.seat.clone(),
+                surface,
+            });
+        }
+    }
+}
+
+delegate_touch!(@<T: 'static + Debug> SctkState<T>);
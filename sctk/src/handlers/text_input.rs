@@ -0,0 +1,277 @@
+//! Handling of the text-input-unstable-v3 protocol, used by input method
+//! editors.
+
+use std::marker::PhantomData;
+
+use iced_runtime::core::keyboard::Ime;
+use iced_runtime::core::Rectangle;
+use sctk::reexports::client::globals::{BindError, GlobalList};
+use sctk::reexports::client::protocol::wl_seat::WlSeat;
+use sctk::reexports::client::protocol::wl_surface::WlSurface;
+use sctk::reexports::client::Dispatch;
+use sctk::reexports::client::{
+    delegate_dispatch, Connection, Proxy, QueueHandle,
+};
+use sctk::reexports::protocols::wp::text_input::zv3::client::zwp_text_input_manager_v3::ZwpTextInputManagerV3;
+use sctk::reexports::protocols::wp::text_input::zv3::client::zwp_text_input_v3::{
+    ContentHint, ContentPurpose, Event as TextInputEvent, ZwpTextInputV3,
+};
+
+use sctk::globals::GlobalData;
+
+use crate::event_loop::state::SctkState;
+use crate::sctk_event::SctkEvent;
+
+/// Text input manager.
+#[derive(Debug)]
+pub struct TextInputManager<T> {
+    manager: ZwpTextInputManagerV3,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: 'static> TextInputManager<T> {
+    /// Create new text input manager.
+    pub fn new(
+        globals: &GlobalList,
+        queue_handle: &QueueHandle<SctkState<T>>,
+    ) -> Result<Self, BindError> {
+        let manager = globals.bind(queue_handle, 1..=1, GlobalData)?;
+        Ok(Self {
+            manager,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Get the text input of the given seat.
+    pub fn text_input(
+        &self,
+        seat: &WlSeat,
+        queue_handle: &QueueHandle<SctkState<T>>,
+    ) -> TextInput {
+        TextInput {
+            raw: self.manager.get_text_input(seat, queue_handle, GlobalData),
+            focus: None,
+            is_enabled: false,
+            preedit: None,
+            commit: None,
+        }
+    }
+}
+
+/// The text input of a seat.
+#[derive(Debug)]
+pub struct TextInput {
+    raw: ZwpTextInputV3,
+    focus: Option<WlSurface>,
+    is_enabled: bool,
+    preedit: Option<(String, Option<(usize, usize)>)>,
+    commit: Option<String>,
+}
+
+impl TextInput {
+    /// Enables the input method on the focused surface, placing its
+    /// candidate window next to the given cursor area, or disables it.
+    ///
+    /// Returns the [`Ime`] event to notify, if the input method was enabled
+    /// or disabled.
+    pub(crate) fn update(&mut self, request: Option<Rectangle>) -> Option<Ime> {
+        let event = match request {
+            Some(cursor) => {
+                let event = if self.is_enabled {
+                    None
+                } else {
+                    self.raw.enable();
+                    self.raw.set_content_type(
+                        ContentHint::None,
+                        ContentPurpose::Normal,
+                    );
+                    self.is_enabled = true;
+
+                    Some(Ime::Enabled)
+                };
+
+                self.raw.set_cursor_rectangle(
+                    cursor.x as i32,
+                    cursor.y as i32,
+                    cursor.width.ceil() as i32,
+                    cursor.height.ceil() as i32,
+                );
+
+                event
+            }
+            None => {
+                if !self.is_enabled {
+                    return None;
+                }
+
+                self.raw.disable();
+                self.is_enabled = false;
+                self.preedit = None;
+                self.commit = None;
+
+                Some(Ime::Disabled)
+            }
+        };
+
+        self.raw.commit();
+
+        event
+    }
+
+    /// Returns the surface the text input is focused on, if any.
+    pub(crate) fn focus(&self) -> Option<&WlSurface> {
+        self.focus.as_ref()
+    }
+}
+
+impl Drop for TextInput {
+    fn drop(&mut self) {
+        self.raw.destroy();
+    }
+}
+
+impl<T: 'static> SctkState<T> {
+    /// Enables, moves, or disables the input method of the given surface,
+    /// following the latest request of its widgets.
+    pub(crate) fn request_ime(
+        &mut self,
+        surface: WlSurface,
+        request: Option<Rectangle>,
+    ) {
+        match request {
+            Some(cursor) => {
+                let _ = self.ime_requests.insert(surface.id(), cursor);
+            }
+            None => {
+                let _ = self.ime_requests.remove(&surface.id());
+            }
+        }
+
+        for seat in &mut self.seats {
+            let Some(text_input) = seat.text_input.as_mut() else {
+                continue;
+            };
+
+            if text_input.focus() != Some(&surface) {
+                continue;
+            }
+
+            if let Some(variant) = text_input.update(request) {
+                self.sctk_events.push(SctkEvent::ImeEvent {
+                    variant,
+                    surface: surface.clone(),
+                });
+            }
+        }
+    }
+}
+
+impl<T: 'static> Dispatch<ZwpTextInputManagerV3, GlobalData, SctkState<T>>
+    for TextInputManager<T>
+{
+    fn event(
+        _: &mut SctkState<T>,
+        _: &ZwpTextInputManagerV3,
+        _: <ZwpTextInputManagerV3 as Proxy>::Event,
+        _: &GlobalData,
+        _: &Connection,
+        _: &QueueHandle<SctkState<T>>,
+    ) {
+        // No events.
+    }
+}
+
+impl<T: 'static> Dispatch<ZwpTextInputV3, GlobalData, SctkState<T>>
+    for TextInputManager<T>
+{
+    fn event(
+        state: &mut SctkState<T>,
+        text_input: &ZwpTextInputV3,
+        event: <ZwpTextInputV3 as Proxy>::Event,
+        _: &GlobalData,
+        _: &Connection,
+        _: &QueueHandle<SctkState<T>>,
+    ) {
+        let Some(seat_text_input) = state
+            .seats
+            .iter_mut()
+            .filter_map(|seat| seat.text_input.as_mut())
+            .find(|seat_text_input| &seat_text_input.raw == text_input)
+        else {
+            return;
+        };
+
+        match event {
+            TextInputEvent::Enter { surface } => {
+                seat_text_input.focus = Some(surface.clone());
+
+                let request = state.ime_requests.get(&surface.id()).copied();
+
+                if let Some(variant) = seat_text_input.update(request) {
+                    state
+                        .sctk_events
+                        .push(SctkEvent::ImeEvent { variant, surface });
+                }
+            }
+            TextInputEvent::Leave { surface } => {
+                if let Some(variant) = seat_text_input.update(None) {
+                    state
+                        .sctk_events
+                        .push(SctkEvent::ImeEvent { variant, surface });
+                }
+
+                seat_text_input.focus = None;
+            }
+            TextInputEvent::PreeditString {
+                text,
+                cursor_begin,
+                cursor_end,
+            } => {
+                // A negative cursor means it should be hidden
+                let cursor = usize::try_from(cursor_begin)
+                    .ok()
+                    .zip(usize::try_from(cursor_end).ok());
+
+                seat_text_input.preedit =
+                    Some((text.unwrap_or_default(), cursor));
+            }
+            TextInputEvent::CommitString { text } => {
+                seat_text_input.commit = text;
+            }
+            TextInputEvent::Done { .. } => {
+                let Some(surface) = seat_text_input.focus.clone() else {
+                    return;
+                };
+
+                // The preedit is cleared before the commit is inserted
+                if let Some(text) = seat_text_input.commit.take() {
+                    state.sctk_events.push(SctkEvent::ImeEvent {
+                        variant: Ime::Preedit {
+                            text: String::new(),
+                            cursor: None,
+                        },
+                        surface: surface.clone(),
+                    });
+
+                    state.sctk_events.push(SctkEvent::ImeEvent {
+                        variant: Ime::Commit(text),
+                        surface: surface.clone(),
+                    });
+                }
+
+                let (text, cursor) =
+                    seat_text_input.preedit.take().unwrap_or_default();
+
+                state.sctk_events.push(SctkEvent::ImeEvent {
+                    variant: Ime::Preedit { text, cursor },
+                    surface,
+                });
+            }
+            // Surrounding text is never sent, so there is nothing to delete
+            _ => {}
+        }
+    }
+}
+
+delegate_dispatch!(@<T: 'static> SctkState<T>: [ZwpTextInputManagerV3: GlobalData] => TextInputManager<T>);
+delegate_dispatch!(@<T: 'static> SctkState<T>: [ZwpTextInputV3: GlobalData] => TextInputManager<T>);
@@ -1,5 +1,7 @@
 pub mod application;
 pub mod clipboard;
+#[cfg(feature = "color-scheme")]
+mod color_scheme;
 pub mod commands;
 pub mod conversion;
 pub mod dpi;
@@ -14,7 +14,7 @@ use iced_futures::core::event::{
 };
 use iced_runtime::{
     command::platform_specific::wayland::data_device::DndIcon,
-    core::{event::wayland, keyboard, mouse, window, Point},
+    core::{event::wayland, keyboard, mouse, touch, window, Point},
     keyboard::{key, Key, Location},
     window::Id as SurfaceId,
 };
@@ -25,7 +25,7 @@ use sctk::{
         protocol::{
             wl_data_device_manager::DndAction, wl_keyboard::WlKeyboard,
             wl_output::WlOutput, wl_pointer::WlPointer, wl_seat::WlSeat,
-            wl_surface::WlSurface,
+            wl_surface::WlSurface, wl_touch::WlTouch,
         },
         Proxy,
     },
@@ -146,7 +146,19 @@ pub enum SctkEvent {
         kbd_id: WlKeyboard,
         seat_id: WlSeat,
     },
-    // TODO data device & touch
+    TouchEvent {
+        variant: TouchEventVariant,
+        touch_id: WlTouch,
+        seat_id: WlSeat,
+        /// the surface the touch point went down on
+        surface: WlSurface,
+    },
+    ImeEvent {
+        variant: keyboard::Ime,
+        /// the surface the text input is focused on
+        surface: WlSurface,
+    },
+    // TODO data device
 
     //
     // Surface Events
@@ -210,6 +222,11 @@ pub enum SctkEvent {
         surface: WlSurface,
     },
     SessionUnlocked,
+    //
+    // desktop settings events
+    //
+    /// The color scheme preferred by the user changed.
+    ColorSchemeChanged(window::ColorScheme),
 }
 
 #[derive(Debug, Clone)]
@@ -291,6 +308,25 @@ pub enum KeyboardEventVariant {
     Modifiers(Modifiers),
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum TouchEventVariant {
+    Down { id: i32, position: (f64, f64) },
+    Motion { id: i32, position: (f64, f64) },
+    Up { id: i32, position: (f64, f64) },
+    Cancel { id: i32, position: (f64, f64) },
+}
+
+impl TouchEventVariant {
+    pub fn position(&self) -> (f64, f64) {
+        match *self {
+            TouchEventVariant::Down { position, .. }
+            | TouchEventVariant::Motion { position, .. }
+            | TouchEventVariant::Up { position, .. }
+            | TouchEventVariant::Cancel { position, .. } => position,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum WindowEventVariant {
     Created(ObjectId, SurfaceId),
@@ -467,6 +503,54 @@ impl SctkEvent {
                     .into_iter()
                     .collect(), // TODO Ashley: conversion
             },
+            SctkEvent::TouchEvent {
+                variant, surface, ..
+            } => {
+                let offset = if let Some((x_offset, y_offset, _)) =
+                    subsurface_ids.get(&surface.id())
+                {
+                    (*x_offset, *y_offset)
+                } else {
+                    (0, 0)
+                };
+                let (x, y) = variant.position();
+                let position = Point::new(
+                    x as f32 + offset.0 as f32,
+                    y as f32 + offset.1 as f32,
+                );
+
+                vec![iced_runtime::core::Event::Touch(match variant {
+                    TouchEventVariant::Down { id, .. } => {
+                        touch::Event::FingerPressed {
+                            id: touch::Finger(id as u64),
+                            position,
+                        }
+                    }
+                    TouchEventVariant::Motion { id, .. } => {
+                        touch::Event::FingerMoved {
+                            id: touch::Finger(id as u64),
+                            position,
+                        }
+                    }
+                    TouchEventVariant::Up { id, .. } => {
+                        touch::Event::FingerLifted {
+                            id: touch::Finger(id as u64),
+                            position,
+                        }
+                    }
+                    TouchEventVariant::Cancel { id, .. } => {
+                        touch::Event::FingerLost {
+                            id: touch::Finger(id as u64),
+                            position,
+                        }
+                    }
+                })]
+            }
+            SctkEvent::ImeEvent { variant, .. } => {
+                vec![iced_runtime::core::Event::Keyboard(keyboard::Event::Ime(
+                    variant,
+                ))]
+            }
             SctkEvent::KeyboardEvent {
                 variant,
                 kbd_id: _,
@@ -960,6 +1044,16 @@ impl SctkEvent {
                 .into_iter()
                 .collect()
             }
+            SctkEvent::ColorSchemeChanged(color_scheme) => surface_ids
+                .values()
+                .filter(|id| !matches!(id, SurfaceIdWrapper::Dnd(_)))
+                .map(|id| {
+                    iced_runtime::core::Event::Window(
+                        id.inner(),
+                        window::Event::ColorSchemeChanged(color_scheme),
+                    )
+                })
+                .collect(),
         }
     }
 }
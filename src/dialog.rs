@@ -0,0 +1,122 @@
+//! Ask the user to pick files with the native dialogs of the platform.
+//!
+//! The dialogs are plain futures that can be turned into a [`Command`] with
+//! [`Command::perform`], so the user interface keeps running while they are
+//! open.
+//!
+//! [`Command`]: crate::Command
+//! [`Command::perform`]: crate::Command::perform
+use std::path::PathBuf;
+
+/// The options of a file dialog.
+#[derive(Debug, Clone, Default)]
+pub struct FileDialog {
+    title: Option<String>,
+    directory: Option<PathBuf>,
+    file_name: Option<String>,
+    filters: Vec<(String, Vec<String>)>,
+}
+
+impl FileDialog {
+    /// Creates a [`FileDialog`] with the default options of the platform.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title of the [`FileDialog`].
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the directory shown when the [`FileDialog`] opens.
+    pub fn directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// Sets the file name suggested by the [`FileDialog`] when saving.
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Adds a filter that only shows the files with the given extensions.
+    ///
+    /// The user can switch between the filters of the [`FileDialog`] by
+    /// their name.
+    pub fn filter(
+        mut self,
+        name: impl Into<String>,
+        extensions: &[&str],
+    ) -> Self {
+        self.filters.push((
+            name.into(),
+            extensions.iter().map(ToString::to_string).collect(),
+        ));
+        self
+    }
+
+    fn build(self) -> rfd::AsyncFileDialog {
+        let mut dialog = rfd::AsyncFileDialog::new();
+
+        if let Some(title) = self.title {
+            dialog = dialog.set_title(title);
+        }
+
+        if let Some(directory) = self.directory {
+            dialog = dialog.set_directory(directory);
+        }
+
+        if let Some(file_name) = self.file_name {
+            dialog = dialog.set_file_name(file_name);
+        }
+
+        for (name, extensions) in self.filters {
+            dialog = dialog.add_filter(name, &extensions);
+        }
+
+        dialog
+    }
+}
+
+/// Asks the user to pick a file to open.
+///
+/// Returns `None` if the user cancelled the dialog.
+pub async fn open_file(dialog: FileDialog) -> Option<PathBuf> {
+    let file = dialog.build().pick_file().await?;
+
+    Some(file.path().to_owned())
+}
+
+/// Asks the user to pick one or more files to open.
+///
+/// Returns an empty list if the user cancelled the dialog.
+pub async fn open_files(dialog: FileDialog) -> Vec<PathBuf> {
+    dialog
+        .build()
+        .pick_files()
+        .await
+        .unwrap_or_default()
+        .iter()
+        .map(|file| file.path().to_owned())
+        .collect()
+}
+
+/// Asks the user to pick a folder.
+///
+/// Returns `None` if the user cancelled the dialog.
+pub async fn open_folder(dialog: FileDialog) -> Option<PathBuf> {
+    let folder = dialog.build().pick_folder().await?;
+
+    Some(folder.path().to_owned())
+}
+
+/// Asks the user to pick where to save a file.
+///
+/// Returns `None` if the user cancelled the dialog.
+pub async fn save_file(dialog: FileDialog) -> Option<PathBuf> {
+    let file = dialog.build().save_file().await?;
+
+    Some(file.path().to_owned())
+}
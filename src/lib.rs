@@ -179,6 +179,8 @@ use shell::core;
 #[cfg(any(feature = "winit", feature = "wayland"))]
 use shell::runtime;
 
+#[cfg(feature = "form")]
+pub use iced_derive::Form;
 #[cfg(feature = "highlighter")]
 pub use iced_highlighter as highlighter;
 #[cfg(not(any(feature = "winit", feature = "wayland")))]
@@ -208,6 +210,33 @@ pub use wayland::Application;
 #[cfg(feature = "advanced")]
 pub mod advanced;
 
+#[cfg(feature = "dialog")]
+pub mod dialog;
+
+#[cfg(feature = "notification")]
+pub mod notification;
+
+#[cfg(feature = "portal")]
+pub mod portal;
+
+#[cfg(all(feature = "single-instance", unix))]
+pub mod single_instance;
+
+#[cfg(all(
+    feature = "tray",
+    any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    )
+))]
+pub mod tray;
+
+#[cfg(all(feature = "print", unix))]
+pub mod print;
+
 #[cfg(all(feature = "winit", feature = "multi-window"))]
 pub mod multi_window;
 
@@ -221,12 +250,19 @@ pub use crate::core::{
     id, layout::Limits, Alignment, Background, Color, ContentFit, Degrees,
     Gradient, Length, Padding, Pixels, Point, Radians, Rectangle, Size, Vector,
 };
+pub use iced_widget::binding::Form;
+
+pub mod audio {
+    //! Play sound cues for user interface events.
+    pub use crate::core::audio::{Audio, Null, Sound};
+    pub use crate::runtime::audio::play;
+}
 
 pub mod clipboard {
     //! Access the clipboard.
-    pub use crate::runtime::clipboard::{read, write};
+    pub use crate::runtime::clipboard::{read, write, write_html, write_image};
     pub use dnd;
-    pub use iced_core::clipboard::{read_data, read_primary_data};
+    pub use iced_core::clipboard::{read_data, read_primary_data, Html, Image};
     pub use mime;
 }
 
@@ -261,10 +297,19 @@ pub mod event {
     pub use iced_futures::event::{listen, listen_raw, listen_with};
 }
 
+pub mod haptics {
+    //! Trigger haptic feedback on supported hardware.
+    pub use crate::core::haptics::{Haptics, Null};
+    pub use crate::runtime::haptics::impulse;
+}
+
 pub mod keyboard {
     //! Listen and react to keyboard events.
     pub use crate::core::keyboard::key;
-    pub use crate::core::keyboard::{Event, Key, Location, Modifiers};
+    pub use crate::core::keyboard::{
+        Event, Ime, Key, Location, Modifiers, Shortcut,
+    };
+    pub use crate::runtime::keyboard::{Conflict, Shortcuts};
     pub use iced_futures::keyboard::{on_key_press, on_key_release};
 }
 
@@ -283,10 +328,22 @@ pub mod command {
 pub mod subscription {
     //! Listen to external events in your application.
     pub use iced_futures::subscription::{
-        channel, run, run_with_id, unfold, Subscription,
+        channel, run, run_with_id, unfold, user_idle, Idle, Subscription,
     };
 }
 
+#[cfg(feature = "network")]
+pub mod network {
+    //! Listen to the network connectivity of the device.
+    pub use iced_futures::network::{changes, Event, Interface};
+}
+
+#[cfg(feature = "power")]
+pub mod power {
+    //! Listen to the power status of the device.
+    pub use iced_futures::power::{changes, Status};
+}
+
 #[cfg(feature = "system")]
 pub mod system {
     //! Retrieve system information.
@@ -314,6 +371,7 @@ pub mod overlay {
 
 pub mod touch {
     //! Listen and react to touch events.
+    pub use crate::core::touch::gesture::{self, Gesture};
     pub use crate::core::touch::{Event, Finger};
 }
 
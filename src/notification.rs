@@ -0,0 +1,57 @@
+//! Notify the user through the notification service of the desktop.
+//!
+//! Notifications are shown with [`show`], a plain future that can be turned
+//! into a [`Command`] with [`Command::perform`]. The notification service is
+//! reached from a separate thread, so the user interface never waits for it.
+//!
+//! [`Command`]: crate::Command
+//! [`Command::perform`]: crate::Command::perform
+use crate::futures::channel::oneshot;
+
+use std::sync::Arc;
+
+/// An error produced while showing a notification.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The notification service rejected the notification.
+    #[error("the notification could not be shown: {0}")]
+    Rejected(Arc<notify_rust::error::Error>),
+
+    /// The notification was dropped before reaching the service.
+    #[error("the notification was interrupted")]
+    Interrupted,
+}
+
+impl From<notify_rust::error::Error> for Error {
+    fn from(error: notify_rust::error::Error) -> Self {
+        Self::Rejected(Arc::new(error))
+    }
+}
+
+/// Shows a notification with the given title and body.
+///
+/// The icon is the name of an icon in the icon theme or the path to an
+/// image. Platforms without notification icons ignore it.
+pub async fn show(
+    title: String,
+    body: String,
+    icon: Option<String>,
+) -> Result<(), Error> {
+    let (sender, receiver) = oneshot::channel();
+
+    let _ = std::thread::spawn(move || {
+        let mut notification = notify_rust::Notification::new();
+
+        notification.summary(&title).body(&body);
+
+        if let Some(icon) = &icon {
+            notification.icon(icon);
+        }
+
+        let _ = sender.send(notification.show().map(|_| ()));
+    });
+
+    receiver.await.map_err(|_| Error::Interrupted)??;
+
+    Ok(())
+}
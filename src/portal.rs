@@ -0,0 +1,190 @@
+//! Access the desktop through the XDG desktop portals.
+//!
+//! Portals let sandboxed applications, like Flatpaks, pick files, open URIs,
+//! take screenshots, pick colors from the screen, and read the desktop
+//! settings. The requests are plain
+//! futures that can be turned into a [`Command`] with [`Command::perform`].
+//!
+//! [`Command`]: crate::Command
+//! [`Command::perform`]: crate::Command::perform
+use crate::futures::{self, SinkExt, StreamExt};
+use crate::subscription::{self, Subscription};
+use crate::window;
+use crate::Color;
+
+use ashpd::desktop::file_chooser::SelectedFiles;
+use ashpd::desktop::open_uri::OpenFileRequest;
+use ashpd::desktop::screenshot::Screenshot;
+use ashpd::desktop::settings::{self, Settings};
+use ashpd::desktop::{Color as PickedColor, ResponseError};
+
+use std::sync::Arc;
+
+pub use ashpd::url::Url;
+
+/// An error produced by a portal request.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("the portal request failed: {0}")]
+pub struct Error(Arc<ashpd::Error>);
+
+impl From<ashpd::Error> for Error {
+    fn from(error: ashpd::Error) -> Self {
+        Self(Arc::new(error))
+    }
+}
+
+/// The color scheme preferred by the user.
+///
+/// It can be converted into a [`window::ColorScheme`], which is light when
+/// the user has no preference, and then into a built-in `Theme`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorScheme {
+    /// The user has no preference.
+    #[default]
+    NoPreference,
+    /// The user prefers a dark appearance.
+    Dark,
+    /// The user prefers a light appearance.
+    Light,
+}
+
+impl From<settings::ColorScheme> for ColorScheme {
+    fn from(color_scheme: settings::ColorScheme) -> Self {
+        match color_scheme {
+            settings::ColorScheme::NoPreference => Self::NoPreference,
+            settings::ColorScheme::PreferDark => Self::Dark,
+            settings::ColorScheme::PreferLight => Self::Light,
+        }
+    }
+}
+
+impl From<ColorScheme> for window::ColorScheme {
+    fn from(color_scheme: ColorScheme) -> Self {
+        match color_scheme {
+            ColorScheme::Dark => Self::Dark,
+            ColorScheme::Light | ColorScheme::NoPreference => Self::Light,
+        }
+    }
+}
+
+/// Asks the user to pick one or more files to open.
+///
+/// Returns the URIs of the picked files, which is empty if the user
+/// cancelled the dialog.
+pub async fn open_files(
+    title: String,
+    multiple: bool,
+) -> Result<Vec<Url>, Error> {
+    let request = SelectedFiles::open_file()
+        .title(title.as_str())
+        .multiple(multiple)
+        .send()
+        .await?;
+
+    match request.response() {
+        Ok(files) => Ok(files.uris().to_vec()),
+        Err(error) if is_cancelled(&error) => Ok(Vec::new()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Asks the user to pick where to save a file, suggesting the given name.
+///
+/// Returns `None` if the user cancelled the dialog.
+pub async fn save_file(
+    title: String,
+    current_name: Option<String>,
+) -> Result<Option<Url>, Error> {
+    let request = SelectedFiles::save_file()
+        .title(title.as_str())
+        .current_name(current_name.as_deref())
+        .send()
+        .await?;
+
+    match request.response() {
+        Ok(files) => Ok(files.uris().first().cloned()),
+        Err(error) if is_cancelled(&error) => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Opens the given URI with the default application of the desktop.
+pub async fn open_uri(uri: Url) -> Result<(), Error> {
+    let _ = OpenFileRequest::default().send_uri(&uri).await?;
+
+    Ok(())
+}
+
+/// Takes a screenshot of the desktop and returns the URI of the image.
+///
+/// If `interactive` is true, the user can choose what to capture first.
+/// Returns `None` if the user cancelled the screenshot.
+pub async fn screenshot(interactive: bool) -> Result<Option<Url>, Error> {
+    let request = Screenshot::request()
+        .interactive(interactive)
+        .send()
+        .await?;
+
+    match request.response() {
+        Ok(screenshot) => Ok(Some(screenshot.uri().clone())),
+        Err(error) if is_cancelled(&error) => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Asks the user to pick a color from anywhere on the screen.
+///
+/// Returns `None` if the user cancelled the pick.
+pub async fn pick_color() -> Result<Option<Color>, Error> {
+    let request = PickedColor::pick().send().await?;
+
+    match request.response() {
+        Ok(color) => Ok(Some(Color::from_rgb(
+            color.red() as f32,
+            color.green() as f32,
+            color.blue() as f32,
+        ))),
+        Err(error) if is_cancelled(&error) => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Returns the current [`ColorScheme`] of the desktop.
+pub async fn color_scheme() -> Result<ColorScheme, Error> {
+    let settings = Settings::new().await?;
+
+    Ok(settings.color_scheme().await?.into())
+}
+
+/// Listens to changes of the [`ColorScheme`] of the desktop.
+///
+/// The current [`ColorScheme`] is produced first.
+pub fn color_scheme_changes() -> Subscription<ColorScheme> {
+    struct ColorSchemeChanges;
+
+    subscription::channel(
+        std::any::TypeId::of::<ColorSchemeChanges>(),
+        10,
+        |mut output| async move {
+            if let Ok(settings) = Settings::new().await {
+                if let Ok(color_scheme) = settings.color_scheme().await {
+                    let _ = output.send(color_scheme.into()).await;
+                }
+
+                if let Ok(mut changes) =
+                    settings.receive_color_scheme_changed().await
+                {
+                    while let Some(color_scheme) = changes.next().await {
+                        let _ = output.send(color_scheme.into()).await;
+                    }
+                }
+            }
+
+            futures::future::pending().await
+        },
+    )
+}
+
+fn is_cancelled(error: &ashpd::Error) -> bool {
+    matches!(error, ashpd::Error::Response(ResponseError::Cancelled))
+}
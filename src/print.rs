@@ -0,0 +1,120 @@
+//! Print the contents of a window.
+//!
+//! Take a [`Screenshot`] of a window with [`window::screenshot`], split it
+//! into pages with [`Screenshot::paginate`], and submit them with [`print`]
+//! using [`Command::perform`].
+//!
+//! Pages are submitted to CUPS through the `lp` command, which prints to the
+//! default destination.
+//!
+//! [`window::screenshot`]: crate::window::screenshot
+//! [`Command::perform`]: crate::Command::perform
+use crate::futures::channel::oneshot;
+use crate::runtime::window::Screenshot;
+
+use std::io;
+use std::path::PathBuf;
+use std::process;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// An error that occurred while printing.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// There were no pages to print.
+    #[error("there are no pages to print")]
+    Empty,
+
+    /// The pages could not be written or submitted.
+    #[error("the pages could not be submitted: {0}")]
+    Io(Arc<io::Error>),
+
+    /// A page could not be encoded.
+    #[error("a page could not be encoded: {0}")]
+    Image(Arc<::image::ImageError>),
+
+    /// The print system rejected the job.
+    #[error("the print system rejected the job: {0}")]
+    Rejected(String),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+impl From<::image::ImageError> for Error {
+    fn from(error: ::image::ImageError) -> Self {
+        Self::Image(Arc::new(error))
+    }
+}
+
+/// Submits the given pages as a single print job with the given title.
+pub async fn print(title: String, pages: Vec<Screenshot>) -> Result<(), Error> {
+    if pages.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    let (sender, receiver) = oneshot::channel();
+
+    let _ = std::thread::spawn(move || {
+        let _ = sender.send(submit(&title, &pages));
+    });
+
+    receiver.await.unwrap_or_else(|_| {
+        Err(Error::Rejected(String::from(
+            "the print job was interrupted",
+        )))
+    })
+}
+
+fn submit(title: &str, pages: &[Screenshot]) -> Result<(), Error> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    let directory = std::env::temp_dir()
+        .join(format!("iced-print-{}-{timestamp}", process::id()));
+
+    std::fs::create_dir_all(&directory)?;
+
+    let result = (|| {
+        let files = pages
+            .iter()
+            .enumerate()
+            .map(|(i, page)| {
+                let path = directory.join(format!("page-{i:04}.png"));
+
+                ::image::save_buffer(
+                    &path,
+                    &page.bytes,
+                    page.size.width,
+                    page.size.height,
+                    ::image::ColorType::Rgba8,
+                )?;
+
+                Ok(path)
+            })
+            .collect::<Result<Vec<PathBuf>, Error>>()?;
+
+        let output = process::Command::new("lp")
+            .arg("-t")
+            .arg(title)
+            .args(&files)
+            .output()?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(Error::Rejected(
+                String::from_utf8_lossy(&output.stderr).trim().to_owned(),
+            ))
+        }
+    })();
+
+    let _ = std::fs::remove_dir_all(&directory);
+
+    result
+}
@@ -43,6 +43,18 @@ pub struct Settings<Flags> {
     /// The default value is `16.0`.
     pub default_text_size: Pixels,
 
+    /// The factor applied to the spacing and padding of the built-in widgets.
+    ///
+    /// The default value is `1.0`.
+    pub spacing_scale: f32,
+
+    /// The factor applied to the border radius of the built-in widgets.
+    ///
+    /// Radii that already fully round a widget are left untouched.
+    ///
+    /// The default value is `1.0`.
+    pub border_radius_scale: f32,
+
     /// If set to true, the renderer will try to perform antialiasing for some
     /// primitives.
     ///
@@ -66,6 +78,8 @@ impl<Flags> Settings<Flags> {
             fonts: default_settings.fonts,
             default_font: default_settings.default_font,
             default_text_size: default_settings.default_text_size,
+            spacing_scale: default_settings.spacing_scale,
+            border_radius_scale: default_settings.border_radius_scale,
             antialiasing: default_settings.antialiasing,
             exit_on_close_request: default_settings.exit_on_close_request,
         }
@@ -83,6 +97,8 @@ where
             flags: Default::default(),
             default_font: Default::default(),
             default_text_size: iced_core::Pixels(14.0),
+            spacing_scale: 1.0,
+            border_radius_scale: 1.0,
             fonts: Vec::new(),
             antialiasing: false,
             exit_on_close_request: true,
@@ -104,6 +120,8 @@ impl<Flags> Settings<Flags> {
             fonts: default_settings.fonts,
             default_font: default_settings.default_font,
             default_text_size: default_settings.default_text_size,
+            spacing_scale: default_settings.spacing_scale,
+            border_radius_scale: default_settings.border_radius_scale,
             antialiasing: default_settings.antialiasing,
             exit_on_close_request: default_settings.exit_on_close_request,
         }
@@ -123,6 +141,8 @@ where
             fonts: Vec::new(),
             default_font: Font::default(),
             default_text_size: Pixels(14.0),
+            spacing_scale: 1.0,
+            border_radius_scale: 1.0,
             antialiasing: false,
             exit_on_close_request: false,
         }
@@ -155,6 +175,8 @@ impl<Flags> Settings<Flags> {
             initial_surface: default_settings.initial_surface,
             default_font: default_settings.default_font,
             default_text_size: default_settings.default_text_size,
+            spacing_scale: default_settings.spacing_scale,
+            border_radius_scale: default_settings.border_radius_scale,
             antialiasing: default_settings.antialiasing,
             exit_on_close_request: default_settings.exit_on_close_request,
             fonts: default_settings.fonts,
@@ -174,6 +196,8 @@ where
             flags: Default::default(),
             default_font: Default::default(),
             default_text_size: Pixels(14.0),
+            spacing_scale: 1.0,
+            border_radius_scale: 1.0,
             antialiasing: false,
             fonts: Vec::new(),
             exit_on_close_request: true,
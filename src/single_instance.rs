@@ -0,0 +1,282 @@
+//! Keep a single instance of an application running.
+//!
+//! The first launch of an application becomes the primary [`Instance`] and
+//! listens on a Unix socket named after the application. Any later launch
+//! forwards its command line arguments to the primary [`Instance`] and should
+//! exit right away.
+//!
+//! ```no_run
+//! fn main() -> std::io::Result<()> {
+//!     let Some(instance) = iced::single_instance::acquire("com.example.App")?
+//!     else {
+//!         // The arguments were forwarded to the running instance
+//!         return Ok(());
+//!     };
+//!
+//!     // Run the application, and listen to `instance.subscription()`
+//!     // to receive the arguments of later launches.
+//!     Ok(())
+//! }
+//! ```
+use crate::futures::channel::mpsc;
+use crate::futures::{SinkExt, StreamExt};
+use crate::subscription::{self, Subscription};
+
+use std::io::{self, Read, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// The primary instance of an application.
+///
+/// It is released when dropped, letting the next launch become the primary
+/// instance.
+#[derive(Debug, Clone)]
+pub struct Instance {
+    id: String,
+    listener: Arc<Listener>,
+}
+
+#[derive(Debug)]
+struct Listener {
+    socket: UnixListener,
+    path: PathBuf,
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Tries to become the primary [`Instance`] of the application with the
+/// given id.
+///
+/// If another instance is already running, the command line arguments of the
+/// current process are forwarded to it and `None` is returned. The caller is
+/// then expected to exit.
+///
+/// Relative file paths are resolved against the current directory before
+/// being forwarded, since the running instance may have a different one.
+pub fn acquire(id: &str) -> io::Result<Option<Instance>> {
+    let path = socket_path(id);
+
+    let socket = match UnixListener::bind(&path) {
+        Ok(socket) => socket,
+        Err(error) if error.kind() == io::ErrorKind::AddrInUse => {
+            match UnixStream::connect(&path) {
+                Ok(stream) => {
+                    forward(stream, arguments())?;
+
+                    return Ok(None);
+                }
+                Err(error)
+                    if matches!(
+                        error.kind(),
+                        io::ErrorKind::ConnectionRefused
+                            | io::ErrorKind::NotFound
+                    ) =>
+                {
+                    // The previous primary instance did not clean up, or
+                    // it has just been released
+                    if let Err(error) = std::fs::remove_file(&path) {
+                        if error.kind() != io::ErrorKind::NotFound {
+                            return Err(error);
+                        }
+                    }
+
+                    UnixListener::bind(&path)?
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        Err(error) => return Err(error),
+    };
+
+    Ok(Some(Instance {
+        id: id.to_owned(),
+        listener: Arc::new(Listener { socket, path }),
+    }))
+}
+
+impl Instance {
+    /// Listens to the command line arguments forwarded by later launches of
+    /// the application.
+    pub fn subscription(&self) -> Subscription<Vec<String>> {
+        let socket = self.listener.socket.try_clone();
+
+        subscription::channel(
+            (std::any::TypeId::of::<Instance>(), self.id.clone()),
+            100,
+            |mut output| async move {
+                let (sender, mut receiver) = mpsc::unbounded();
+
+                if let Ok(socket) = socket {
+                    let _ = std::thread::spawn(move || {
+                        for stream in socket.incoming() {
+                            let Ok(arguments) = stream.and_then(receive) else {
+                                continue;
+                            };
+
+                            if sender.unbounded_send(arguments).is_err() {
+                                break;
+                            }
+                        }
+                    });
+                }
+
+                loop {
+                    match receiver.next().await {
+                        Some(arguments) => {
+                            let _ = output.send(arguments).await;
+                        }
+                        None => crate::futures::future::pending().await,
+                    }
+                }
+            },
+        )
+    }
+}
+
+/// A file or URI that the application was asked to open.
+///
+/// Desktop entries with an `Exec` line like `myapp %U` receive the files and
+/// links to open as command line arguments. Combined with an [`Instance`],
+/// these reach the running application even when it is launched again.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Target {
+    /// A local file.
+    File(PathBuf),
+    /// Any other URI, like `myapp://settings`.
+    Uri(String),
+}
+
+impl Target {
+    /// Parses a command line argument into a [`Target`].
+    ///
+    /// Flags starting with `-` are not targets and produce `None`.
+    pub fn parse(argument: &str) -> Option<Self> {
+        if argument.is_empty() || argument.starts_with('-') {
+            return None;
+        }
+
+        if let Some(path) = argument.strip_prefix("file://") {
+            return Some(Self::File(PathBuf::from(percent_decode(path))));
+        }
+
+        let is_uri = argument
+            .split_once(':')
+            .is_some_and(|(scheme, _)| is_scheme(scheme));
+
+        if is_uri {
+            Some(Self::Uri(argument.to_owned()))
+        } else {
+            Some(Self::File(PathBuf::from(argument)))
+        }
+    }
+}
+
+/// Returns the [`Target`]s passed to the current process on launch.
+///
+/// Relative file paths are resolved against the current directory.
+pub fn launch_targets() -> Vec<Target> {
+    arguments()
+        .filter_map(|argument| Target::parse(&argument))
+        .collect()
+}
+
+/// Returns the command line arguments of the current process, with relative
+/// file paths resolved against the current directory.
+fn arguments() -> impl Iterator<Item = String> {
+    let current_dir = std::env::current_dir().ok();
+
+    std::env::args()
+        .skip(1)
+        .map(move |argument| absolute(argument, current_dir.as_deref()))
+}
+
+fn absolute(argument: String, current_dir: Option<&Path>) -> String {
+    match (Target::parse(&argument), current_dir) {
+        (Some(Target::File(path)), Some(current_dir)) if path.is_relative() => {
+            current_dir.join(path).to_string_lossy().into_owned()
+        }
+        _ => argument,
+    }
+}
+
+impl Instance {
+    /// Listens to the [`Target`]s that later launches of the application
+    /// were asked to open.
+    pub fn open_requests(&self) -> Subscription<Vec<Target>> {
+        self.subscription().map(|arguments| {
+            arguments
+                .iter()
+                .filter_map(|argument| Target::parse(argument))
+                .collect()
+        })
+    }
+}
+
+fn is_scheme(scheme: &str) -> bool {
+    // Single letters are Windows drive letters, not schemes
+    scheme.len() > 1
+        && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+fn percent_decode(input: &str) -> String {
+    let input = input.as_bytes();
+    let mut bytes = Vec::with_capacity(input.len());
+    let mut i = 0;
+
+    while i < input.len() {
+        let escaped = (input[i] == b'%')
+            .then(|| input.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u8::from_str_radix(digits, 16).ok());
+
+        if let Some(byte) = escaped {
+            bytes.push(byte);
+            i += 3;
+        } else {
+            bytes.push(input[i]);
+            i += 1;
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn socket_path(id: &str) -> PathBuf {
+    let directory = std::env::var_os("XDG_RUNTIME_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(std::env::temp_dir);
+
+    directory.join(format!("{id}.sock"))
+}
+
+fn forward(
+    mut stream: UnixStream,
+    arguments: impl Iterator<Item = String>,
+) -> io::Result<()> {
+    for argument in arguments {
+        stream.write_all(argument.as_bytes())?;
+        stream.write_all(&[0])?;
+    }
+
+    stream.flush()
+}
+
+fn receive(mut stream: UnixStream) -> io::Result<Vec<String>> {
+    let mut bytes = Vec::new();
+    let _ = stream.read_to_end(&mut bytes)?;
+
+    Ok(bytes
+        .split(|byte| *byte == 0)
+        .filter(|argument| !argument.is_empty())
+        .map(|argument| String::from_utf8_lossy(argument).into_owned())
+        .collect())
+}
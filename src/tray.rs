@@ -0,0 +1,187 @@
+//! Show an icon with a menu in the system tray.
+//!
+//! The icon is registered as a `StatusNotifierItem`, which is supported by
+//! most Linux desktops. A [`Tray`] is shown for as long as its
+//! [`Subscription`] is active, and the items of its menu produce messages
+//! when selected.
+//!
+//! An application can hide its main window to the tray by changing its
+//! [`Mode`] to [`Mode::Hidden`], and show it again from the menu.
+//!
+//! [`Mode`]: crate::window::Mode
+//! [`Mode::Hidden`]: crate::window::Mode::Hidden
+use crate::futures::{self, SinkExt};
+use crate::subscription::{self, Subscription};
+
+/// An icon in the system tray with a menu.
+#[derive(Debug, Clone)]
+pub struct Tray<Message> {
+    id: String,
+    title: String,
+    icon_name: String,
+    on_activate: Option<Message>,
+    items: Vec<Item<Message>>,
+}
+
+/// An entry of the menu of a [`Tray`].
+#[derive(Debug, Clone)]
+pub enum Item<Message> {
+    /// A label that produces a message when selected.
+    Action {
+        /// The label of the entry.
+        label: String,
+        /// The message produced when the entry is selected.
+        message: Message,
+    },
+    /// A line between groups of entries.
+    Separator,
+}
+
+impl<Message> Tray<Message> {
+    /// Creates a [`Tray`] with the given unique identifier, usually the
+    /// name of the application.
+    pub fn new(id: impl Into<String>) -> Self {
+        let id = id.into();
+
+        Self {
+            title: id.clone(),
+            icon_name: id.clone(),
+            id,
+            on_activate: None,
+            items: Vec::new(),
+        }
+    }
+
+    /// Sets the title of the [`Tray`], shown as its tooltip.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the name of the icon of the [`Tray`] in the icon theme.
+    ///
+    /// By default, it is the identifier of the [`Tray`].
+    pub fn icon(mut self, icon_name: impl Into<String>) -> Self {
+        self.icon_name = icon_name.into();
+        self
+    }
+
+    /// Sets the message produced when the icon itself is activated, usually
+    /// with a left click.
+    pub fn on_activate(mut self, message: Message) -> Self {
+        self.on_activate = Some(message);
+        self
+    }
+
+    /// Adds an entry with the given label to the menu of the [`Tray`].
+    pub fn item(mut self, label: impl Into<String>, message: Message) -> Self {
+        self.items.push(Item::Action {
+            label: label.into(),
+            message,
+        });
+        self
+    }
+
+    /// Adds a separator to the menu of the [`Tray`].
+    pub fn separator(mut self) -> Self {
+        self.items.push(Item::Separator);
+        self
+    }
+}
+
+/// Shows the given [`Tray`] while the [`Subscription`] is active, producing
+/// the messages of the entries selected by the user.
+///
+/// The [`Tray`] is identified by its identifier, so changing its entries
+/// keeps the icon registered with the first ones.
+pub fn show<Message>(tray: Tray<Message>) -> Subscription<Message>
+where
+    Message: Clone + Send + 'static,
+{
+    struct Show;
+
+    subscription::channel(
+        (std::any::TypeId::of::<Show>(), tray.id.clone()),
+        10,
+        |output| async move {
+            let service = ksni::TrayService::new(Service { tray, output });
+
+            // The icon is removed once the subscription is dropped
+            let _handle = Handle(service.handle());
+
+            service.spawn();
+
+            futures::future::pending().await
+        },
+    )
+}
+
+struct Service<Message> {
+    tray: Tray<Message>,
+    output: futures::channel::mpsc::Sender<Message>,
+}
+
+impl<Message> Service<Message>
+where
+    Message: Clone + Send + 'static,
+{
+    fn publish(&mut self, message: Message) {
+        let mut output = self.output.clone();
+
+        let _ = futures::executor::block_on(output.send(message));
+    }
+}
+
+impl<Message> ksni::Tray for Service<Message>
+where
+    Message: Clone + Send + 'static,
+{
+    fn id(&self) -> String {
+        self.tray.id.clone()
+    }
+
+    fn title(&self) -> String {
+        self.tray.title.clone()
+    }
+
+    fn icon_name(&self) -> String {
+        self.tray.icon_name.clone()
+    }
+
+    fn activate(&mut self, _x: i32, _y: i32) {
+        if let Some(message) = self.tray.on_activate.clone() {
+            self.publish(message);
+        }
+    }
+
+    fn menu(&self) -> Vec<ksni::MenuItem<Self>> {
+        self.tray
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| match item {
+                Item::Action { label, .. } => ksni::menu::StandardItem {
+                    label: label.clone(),
+                    activate: Box::new(move |service: &mut Self| {
+                        if let Some(Item::Action { message, .. }) =
+                            service.tray.items.get(index).cloned()
+                        {
+                            service.publish(message);
+                        }
+                    }),
+                    ..Default::default()
+                }
+                .into(),
+                Item::Separator => ksni::MenuItem::Separator,
+            })
+            .collect()
+    }
+}
+
+struct Handle<Message>(ksni::Handle<Service<Message>>);
+
+impl<Message> Drop for Handle<Message> {
+    fn drop(&mut self) {
+        self.0.shutdown();
+    }
+}
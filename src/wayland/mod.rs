@@ -102,6 +102,40 @@ pub trait Application: Sized {
         1.0
     }
 
+    /// Returns the [`Audio`] backend used to play the sounds requested with
+    /// [`audio::play`].
+    ///
+    /// By default, it returns `None` and no sounds are played.
+    ///
+    /// [`Audio`]: crate::audio::Audio
+    /// [`audio::play`]: crate::audio::play
+    fn audio(&self) -> Option<&dyn crate::audio::Audio> {
+        None
+    }
+
+    /// Returns the [`Haptics`] backend used to trigger the impulses requested
+    /// with [`haptics::impulse`].
+    ///
+    /// By default, it returns `None` and no impulses are triggered.
+    ///
+    /// [`Haptics`]: crate::haptics::Haptics
+    /// [`haptics::impulse`]: crate::haptics::impulse
+    fn haptics(&self) -> Option<&dyn crate::haptics::Haptics> {
+        None
+    }
+
+    /// Returns the keyboard [`Shortcuts`] of the [`Application`].
+    ///
+    /// Key presses that trigger a shortcut produce its message before any
+    /// widget sees them.
+    ///
+    /// By default, it returns no shortcuts.
+    ///
+    /// [`Shortcuts`]: crate::keyboard::Shortcuts
+    fn shortcuts(&self) -> crate::keyboard::Shortcuts<Self::Message> {
+        crate::keyboard::Shortcuts::new()
+    }
+
     /// Runs the [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -119,6 +153,8 @@ pub trait Application: Sized {
         let renderer_settings = crate::renderer::Settings {
             default_font: settings.default_font,
             default_text_size: settings.default_text_size,
+            spacing_scale: settings.spacing_scale,
+            border_radius_scale: settings.border_radius_scale,
             antialiasing: if settings.antialiasing {
                 Some(Antialiasing::MSAAx4)
             } else {
@@ -155,6 +191,10 @@ where
     ) -> Element<'_, Self::Message, Self::Theme, Self::Renderer> {
         self.0.view(id)
     }
+
+    fn shortcuts(&self) -> crate::keyboard::Shortcuts<Self::Message> {
+        self.0.shortcuts()
+    }
 }
 
 impl<A> crate::shell::Application for Instance<A>
@@ -187,4 +227,12 @@ where
     fn scale_factor(&self, window: Id) -> f64 {
         self.0.scale_factor(window)
     }
+
+    fn audio(&self) -> Option<&dyn crate::audio::Audio> {
+        self.0.audio()
+    }
+
+    fn haptics(&self) -> Option<&dyn crate::haptics::Haptics> {
+        self.0.haptics()
+    }
 }
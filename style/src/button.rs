@@ -66,6 +66,11 @@ pub trait StyleSheet {
         }
     }
 
+    /// Produces the [`Appearance`] of a button focused with the keyboard.
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
     /// Produces the disabled [`Appearance`] of a button.
     fn disabled(&self, style: &Self::Style) -> Appearance {
         let active = self.active(style);
@@ -0,0 +1,36 @@
+//! Change the appearance of the caret of text inputs and editors.
+use crate::core::time::Duration;
+
+/// The appearance of a caret.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Caret {
+    /// The [`Shape`] of the caret.
+    pub shape: Shape,
+    /// The width of a [`Shape::Bar`] caret.
+    pub width: f32,
+    /// The time the caret stays visible, and then hidden, while blinking.
+    ///
+    /// The caret does not blink if `None`, which is useful to honor
+    /// reduced-motion preferences. Blinking pauses while the user types.
+    pub blink_interval: Option<Duration>,
+}
+
+impl Default for Caret {
+    fn default() -> Self {
+        Self {
+            shape: Shape::Bar,
+            width: 1.0,
+            blink_interval: Some(Duration::from_millis(500)),
+        }
+    }
+}
+
+/// The shape of a [`Caret`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Shape {
+    /// A thin line between characters.
+    #[default]
+    Bar,
+    /// A box covering the character after the caret.
+    Block,
+}
@@ -0,0 +1,24 @@
+//! Change the appearance of an image crop.
+use crate::core::{Border, Color};
+
+/// The appearance of the crop rectangle of an image.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] drawn over the parts of the image outside the crop.
+    pub shade: Color,
+    /// The [`Border`] of the crop rectangle.
+    pub border: Border,
+    /// The [`Color`] of the rule of thirds guides.
+    pub guide_color: Color,
+    /// The [`Color`] of the handles at the corners of the crop rectangle.
+    pub handle_color: Color,
+}
+
+/// A set of rules that dictate the style of an image crop.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the crop rectangle.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
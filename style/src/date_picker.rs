@@ -0,0 +1,32 @@
+//! Change the appearance of a calendar and a date picker.
+use crate::core::{Background, Border, Color};
+
+/// The appearance of a calendar.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the calendar.
+    pub background: Background,
+    /// The [`Border`] of the calendar.
+    pub border: Border,
+    /// The text [`Color`] of the days and the title of the calendar.
+    pub text_color: Color,
+    /// The text [`Color`] of the weekday labels of the calendar.
+    pub weekday_text_color: Color,
+    /// The [`Color`] of the days that cannot be selected.
+    pub disabled_text_color: Color,
+    /// The [`Background`] of a hovered day or navigation button.
+    pub hovered_background: Background,
+    /// The [`Background`] of the selected day.
+    pub selected_background: Background,
+    /// The text [`Color`] of the selected day.
+    pub selected_text_color: Color,
+}
+
+/// A set of rules that dictate the style of a calendar.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the calendar.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
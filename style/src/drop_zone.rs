@@ -0,0 +1,20 @@
+//! Change the appearance of a drop zone.
+use crate::core::{Background, Border};
+
+/// The appearance of a drop zone while files hover it.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] drawn over the content of the drop zone.
+    pub background: Background,
+    /// The [`Border`] of the drop zone.
+    pub border: Border,
+}
+
+/// A set of rules that dictate the style of a drop zone.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the drop zone while files hover it.
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+}
@@ -0,0 +1,28 @@
+//! Change the appearance of a hex view.
+use crate::core::{Background, Color};
+
+/// The appearance of a hex view.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the hex view, if any.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the bytes.
+    pub text_color: Color,
+    /// The text [`Color`] of the offset column.
+    pub offset_color: Color,
+    /// The [`Color`] of the bytes that are not printable in the ASCII pane.
+    pub placeholder_color: Color,
+    /// The [`Background`] of the selected bytes.
+    pub selection_background: Background,
+    /// The text [`Color`] of the selected bytes.
+    pub selection_text_color: Color,
+}
+
+/// A set of rules that dictate the style of a hex view.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the hex view.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
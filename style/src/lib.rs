@@ -18,13 +18,22 @@ pub use iced_core as core;
 
 pub mod application;
 pub mod button;
+pub mod caret;
 pub mod checkbox;
 pub mod container;
+pub mod crop;
+pub mod date_picker;
+pub mod drop_zone;
+pub mod hex_view;
+pub mod log_view;
 pub mod menu;
+pub mod minimap;
+pub mod node_editor;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod rubber_band;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
@@ -32,6 +41,7 @@ pub mod svg;
 pub mod text_editor;
 pub mod text_input;
 pub mod theme;
+pub mod timeline;
 pub mod toggler;
 
 pub use theme::Theme;
@@ -0,0 +1,38 @@
+//! Change the appearance of a log view.
+use crate::core::{Background, Border, Color};
+
+/// The appearance of a log view.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the log view, if any.
+    pub background: Option<Background>,
+    /// The [`Border`] of the log view.
+    pub border: Border,
+    /// The text [`Color`] of trace lines.
+    pub trace_color: Color,
+    /// The text [`Color`] of debug lines.
+    pub debug_color: Color,
+    /// The text [`Color`] of info lines.
+    pub info_color: Color,
+    /// The text [`Color`] of warning lines.
+    pub warn_color: Color,
+    /// The text [`Color`] of error lines.
+    pub error_color: Color,
+    /// The [`Background`] of the selected lines.
+    pub selection_background: Background,
+    /// The [`Background`] of the matches of a search.
+    pub match_background: Background,
+    /// The [`Background`] of the current match of a search.
+    pub current_match_background: Background,
+    /// The [`Color`] of the scroller.
+    pub scroller_color: Color,
+}
+
+/// A set of rules that dictate the style of a log view.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the log view.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -1,5 +1,5 @@
 //! Change the appearance of menus.
-use iced_core::{Background, Border, Color};
+use iced_core::{Background, Border, Color, Shadow};
 
 /// The appearance of a menu.
 #[derive(Debug, Clone, Copy)]
@@ -14,6 +14,8 @@ pub struct Appearance {
     pub selected_text_color: Color,
     /// The background [`Color`] of a selected option in the menu.
     pub selected_background: Background,
+    /// The [`Shadow`] of the menu.
+    pub shadow: Shadow,
 }
 
 /// The style sheet of a menu.
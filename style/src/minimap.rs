@@ -0,0 +1,22 @@
+//! Change the appearance of a minimap.
+use crate::core::{Background, Border};
+
+/// The appearance of a minimap.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the minimap, if any.
+    pub background: Option<Background>,
+    /// The [`Background`] of the viewport rectangle.
+    pub viewport_background: Background,
+    /// The [`Border`] of the viewport rectangle.
+    pub viewport_border: Border,
+}
+
+/// A set of rules that dictate the style of a minimap.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the minimap.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
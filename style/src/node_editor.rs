@@ -0,0 +1,36 @@
+//! Change the appearance of a node editor.
+use crate::core::{Background, Border, Color};
+
+/// The appearance of a node editor.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the node editor, if any.
+    pub background: Option<Background>,
+    /// The [`Background`] of a node.
+    pub node_background: Background,
+    /// The [`Border`] of a node.
+    pub node_border: Border,
+    /// The [`Border`] of a selected node.
+    pub selected_node_border: Border,
+    /// The [`Color`] of a port.
+    pub port_color: Color,
+    /// The radius of a port.
+    pub port_radius: f32,
+    /// The [`Color`] of a connection.
+    pub connection_color: Color,
+    /// The width of a connection.
+    pub connection_width: f32,
+    /// The [`Background`] of the box selection.
+    pub selection_background: Background,
+    /// The [`Border`] of the box selection.
+    pub selection_border: Border,
+}
+
+/// A set of rules that dictate the style of a node editor.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the node editor.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -0,0 +1,20 @@
+//! Change the appearance of a rubber band selection.
+use crate::core::{Background, Border};
+
+/// The appearance of the box of a rubber band selection.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the box.
+    pub background: Background,
+    /// The [`Border`] of the box.
+    pub border: Border,
+}
+
+/// A set of rules that dictate the style of a rubber band selection.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the box of the selection.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -1,6 +1,8 @@
 //! Change the appearance of a text editor.
 use crate::core::{Background, Border, Color};
 
+pub use crate::caret::Caret;
+
 /// The appearance of a text input.
 #[derive(Debug, Clone, Copy)]
 pub struct Appearance {
@@ -40,4 +42,9 @@ pub trait StyleSheet {
 
     /// Produces the style of a disabled text input.
     fn disabled(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Caret`] of a text editor.
+    fn caret(&self, _style: &Self::Style) -> Caret {
+        Caret::default()
+    }
 }
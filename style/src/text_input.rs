@@ -1,6 +1,8 @@
 //! Change the appearance of a text input.
 use iced_core::{Background, Border, Color};
 
+pub use crate::caret::Caret;
+
 /// The appearance of a text input.
 #[derive(Debug, Clone, Copy)]
 pub struct Appearance {
@@ -35,6 +37,12 @@ pub trait StyleSheet {
     /// Produces the [`Color`] of the selection of a text input.
     fn selection_color(&self, style: &Self::Style) -> Color;
 
+    /// Produces the [`Color`] of the underline of misspelled words in a text
+    /// input.
+    fn misspelled_color(&self, _style: &Self::Style) -> Color {
+        Color::from_rgb(0.9, 0.2, 0.2)
+    }
+
     /// Produces the style of an hovered text input.
     fn hovered(&self, style: &Self::Style) -> Appearance {
         self.focused(style)
@@ -42,4 +50,9 @@ pub trait StyleSheet {
 
     /// Produces the style of a disabled text input.
     fn disabled(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Caret`] of a text input.
+    fn caret(&self, _style: &Self::Style) -> Caret {
+        Caret::default()
+    }
 }
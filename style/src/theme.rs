@@ -1,18 +1,32 @@
 //! Use the built-in theme and styles.
+pub mod elevation;
 pub mod palette;
+pub mod platform;
 
+mod overrides;
+
+pub use self::elevation::Elevation;
 pub use self::palette::Palette;
+pub use self::platform::{Metrics, Platform};
 
 use crate::application;
 use crate::button;
 use crate::checkbox;
 use crate::container;
 use crate::core::widget::text;
+use crate::crop;
+use crate::date_picker;
+use crate::drop_zone;
+use crate::hex_view;
+use crate::log_view;
 use crate::menu;
+use crate::minimap;
+use crate::node_editor;
 use crate::pane_grid;
 use crate::pick_list;
 use crate::progress_bar;
 use crate::radio;
+use crate::rubber_band;
 use crate::rule;
 use crate::scrollable;
 use crate::slider;
@@ -20,8 +34,10 @@ use crate::slider::RailBackground;
 use crate::svg;
 use crate::text_editor;
 use crate::text_input;
+use crate::timeline;
 use crate::toggler;
 
+use iced_core::window;
 use iced_core::{Background, Border, Color, Shadow, Vector};
 
 use std::fmt;
@@ -67,6 +83,29 @@ impl Theme {
         }
     }
 
+    /// Creates a new [`Theme`] flavored after the given [`Platform`], in the
+    /// given color scheme.
+    pub fn platform(
+        platform: Platform,
+        color_scheme: window::ColorScheme,
+    ) -> Self {
+        let name = match color_scheme {
+            window::ColorScheme::Light => format!("{platform} Light"),
+            window::ColorScheme::Dark => format!("{platform} Dark"),
+        };
+
+        Self::Custom(Box::new(
+            Custom::new(name, platform.palette(color_scheme))
+                .metrics(platform.metrics()),
+        ))
+    }
+
+    /// Creates a new [`Theme`] flavored after the current operating system,
+    /// in the given color scheme.
+    pub fn native(color_scheme: window::ColorScheme) -> Self {
+        Self::platform(Platform::current(), color_scheme)
+    }
+
     /// Returns the [`palette::Extended`] of the [`Theme`].
     pub fn extended_palette(&self) -> &palette::Extended {
         match self {
@@ -75,6 +114,28 @@ impl Theme {
             Self::Custom(custom) => &custom.extended,
         }
     }
+
+    /// Returns the [`Metrics`] of the controls of the [`Theme`].
+    pub fn metrics(&self) -> Metrics {
+        match self {
+            Self::Light | Self::Dark => Metrics::default(),
+            Self::Custom(custom) => custom.metrics,
+        }
+    }
+
+    /// Returns the [`Shadow`] of a surface at the given [`Elevation`].
+    pub fn shadow(&self, elevation: Elevation) -> Shadow {
+        elevation.shadow(self.extended_palette().is_dark)
+    }
+}
+
+impl From<window::ColorScheme> for Theme {
+    fn from(color_scheme: window::ColorScheme) -> Self {
+        match color_scheme {
+            window::ColorScheme::Light => Self::Light,
+            window::ColorScheme::Dark => Self::Dark,
+        }
+    }
 }
 
 impl fmt::Display for Theme {
@@ -93,6 +154,7 @@ pub struct Custom {
     name: String,
     palette: Palette,
     extended: palette::Extended,
+    metrics: Metrics,
 }
 
 impl Custom {
@@ -112,8 +174,15 @@ impl Custom {
             name,
             palette,
             extended: generate(palette),
+            metrics: Metrics::default(),
         }
     }
+
+    /// Sets the [`Metrics`] of the controls of the [`Custom`] theme.
+    pub fn metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
 }
 
 impl fmt::Display for Custom {
@@ -201,7 +270,7 @@ impl button::StyleSheet for Theme {
         let palette = self.extended_palette();
 
         let appearance = button::Appearance {
-            border: Border::with_radius(2),
+            border: Border::with_radius(self.metrics().control_radius),
             ..button::Appearance::default()
         };
 
@@ -258,6 +327,24 @@ impl button::StyleSheet for Theme {
         }
     }
 
+    fn focused(&self, style: &Self::Style) -> button::Appearance {
+        if let Button::Custom(custom) = style {
+            return custom.focused(self);
+        }
+
+        let palette = self.extended_palette();
+        let hovered = self.hovered(style);
+
+        button::Appearance {
+            border: Border {
+                width: self.metrics().focus_ring_width,
+                color: palette.background.base.text,
+                ..hovered.border
+            },
+            ..hovered
+        }
+    }
+
     fn disabled(&self, style: &Self::Style) -> button::Appearance {
         if let Button::Custom(custom) = style {
             return custom.disabled(self);
@@ -407,6 +494,8 @@ pub enum Container {
     Transparent,
     /// A simple box.
     Box,
+    /// A box floating at the given [`Elevation`].
+    Elevated(Elevation),
     /// A custom style.
     Custom(Box<dyn container::StyleSheet<Style = Theme>>),
 }
@@ -440,6 +529,25 @@ impl container::StyleSheet for Theme {
                     shadow: Shadow::default(),
                 }
             }
+            Container::Elevated(elevation) => {
+                let palette = self.extended_palette();
+
+                container::Appearance {
+                    icon_color: None,
+                    text_color: None,
+                    background: Some(palette.background.base.color.into()),
+                    border: Border {
+                        color: palette.background.strong.color,
+                        width: if *elevation > Elevation::Level0 {
+                            0.0
+                        } else {
+                            1.0
+                        },
+                        radius: self.metrics().control_radius.into(),
+                    },
+                    shadow: self.shadow(*elevation),
+                }
+            }
             Container::Custom(custom) => custom.appearance(self),
         }
     }
@@ -569,6 +677,7 @@ impl menu::StyleSheet for Theme {
                     },
                     selected_text_color: palette.primary.strong.text,
                     selected_background: palette.primary.strong.color.into(),
+                    shadow: self.shadow(Elevation::Level2),
                 }
             }
             Menu::Custom(custom) => custom.appearance(self),
@@ -605,6 +714,7 @@ impl pick_list::StyleSheet for Theme {
         match style {
             PickList::Default => {
                 let palette = self.extended_palette();
+                let metrics = self.metrics();
 
                 pick_list::Appearance {
                     text_color: palette.background.weak.text,
@@ -612,8 +722,8 @@ impl pick_list::StyleSheet for Theme {
                     placeholder_color: palette.background.strong.color,
                     handle_color: palette.background.weak.text,
                     border: Border {
-                        radius: 2.0.into(),
-                        width: 1.0,
+                        radius: metrics.control_radius.into(),
+                        width: metrics.border_width,
                         color: palette.background.strong.color,
                     },
                 }
@@ -626,6 +736,7 @@ impl pick_list::StyleSheet for Theme {
         match style {
             PickList::Default => {
                 let palette = self.extended_palette();
+                let metrics = self.metrics();
 
                 pick_list::Appearance {
                     text_color: palette.background.weak.text,
@@ -633,8 +744,8 @@ impl pick_list::StyleSheet for Theme {
                     placeholder_color: palette.background.strong.color,
                     handle_color: palette.background.weak.text,
                     border: Border {
-                        radius: 2.0.into(),
-                        width: 1.0,
+                        radius: metrics.control_radius.into(),
+                        width: metrics.border_width,
                         color: palette.primary.strong.color,
                     },
                 }
@@ -829,6 +940,505 @@ impl pane_grid::StyleSheet for Theme {
     }
 }
 
+/// The style of a calendar.
+#[derive(Default)]
+pub enum DatePicker {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn date_picker::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> date_picker::Appearance + 'static> From<T>
+    for DatePicker
+{
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl date_picker::StyleSheet for Theme {
+    type Style = DatePicker;
+
+    fn appearance(&self, style: &Self::Style) -> date_picker::Appearance {
+        match style {
+            DatePicker::Default => {
+                let palette = self.extended_palette();
+
+                date_picker::Appearance {
+                    background: palette.background.base.color.into(),
+                    border: Border {
+                        radius: 4.0.into(),
+                        width: 1.0,
+                        color: palette.background.strong.color,
+                    },
+                    text_color: palette.background.base.text,
+                    weekday_text_color: Color {
+                        a: 0.6,
+                        ..palette.background.base.text
+                    },
+                    disabled_text_color: palette.background.strong.color,
+                    hovered_background: palette.background.weak.color.into(),
+                    selected_background: palette.primary.strong.color.into(),
+                    selected_text_color: palette.primary.strong.text,
+                }
+            }
+            DatePicker::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> date_picker::Appearance> date_picker::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> date_picker::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a hex view.
+#[derive(Default)]
+pub enum HexView {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn hex_view::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> hex_view::Appearance + 'static> From<T> for HexView {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl hex_view::StyleSheet for Theme {
+    type Style = HexView;
+
+    fn appearance(&self, style: &Self::Style) -> hex_view::Appearance {
+        match style {
+            HexView::Default => {
+                let palette = self.extended_palette();
+
+                hex_view::Appearance {
+                    background: None,
+                    text_color: palette.background.base.text,
+                    offset_color: Color {
+                        a: 0.6,
+                        ..palette.background.base.text
+                    },
+                    placeholder_color: palette.background.strong.color,
+                    selection_background: palette.primary.weak.color.into(),
+                    selection_text_color: palette.primary.weak.text,
+                }
+            }
+            HexView::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> hex_view::Appearance> hex_view::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> hex_view::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a log view.
+#[derive(Default)]
+pub enum LogView {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn log_view::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> log_view::Appearance + 'static> From<T> for LogView {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl log_view::StyleSheet for Theme {
+    type Style = LogView;
+
+    fn appearance(&self, style: &Self::Style) -> log_view::Appearance {
+        match style {
+            LogView::Default => {
+                let palette = self.extended_palette();
+                let text = palette.background.base.text;
+
+                log_view::Appearance {
+                    background: Some(palette.background.base.color.into()),
+                    border: Border {
+                        radius: 2.0.into(),
+                        width: 1.0,
+                        color: palette.background.strong.color,
+                    },
+                    trace_color: Color { a: 0.5, ..text },
+                    debug_color: Color { a: 0.7, ..text },
+                    info_color: text,
+                    warn_color: Color::from_rgb(0.85, 0.6, 0.1),
+                    error_color: palette.danger.base.color,
+                    selection_background: palette.primary.weak.color.into(),
+                    match_background: Color {
+                        a: 0.3,
+                        ..palette.secondary.base.color
+                    }
+                    .into(),
+                    current_match_background: palette
+                        .secondary
+                        .strong
+                        .color
+                        .into(),
+                    scroller_color: palette.background.strong.color,
+                }
+            }
+            LogView::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> log_view::Appearance> log_view::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> log_view::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a minimap.
+#[derive(Default)]
+pub enum Minimap {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn minimap::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> minimap::Appearance + 'static> From<T> for Minimap {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl minimap::StyleSheet for Theme {
+    type Style = Minimap;
+
+    fn appearance(&self, style: &Self::Style) -> minimap::Appearance {
+        match style {
+            Minimap::Default => {
+                let palette = self.extended_palette();
+
+                minimap::Appearance {
+                    background: Some(palette.background.weak.color.into()),
+                    viewport_background: Color {
+                        a: 0.2,
+                        ..palette.primary.base.color
+                    }
+                    .into(),
+                    viewport_border: Border {
+                        color: palette.primary.base.color,
+                        width: 1.0,
+                        radius: 2.0.into(),
+                    },
+                }
+            }
+            Minimap::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> minimap::Appearance> minimap::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> minimap::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a node editor.
+#[derive(Default)]
+pub enum NodeEditor {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn node_editor::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> node_editor::Appearance + 'static> From<T>
+    for NodeEditor
+{
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl node_editor::StyleSheet for Theme {
+    type Style = NodeEditor;
+
+    fn appearance(&self, style: &Self::Style) -> node_editor::Appearance {
+        match style {
+            NodeEditor::Default => {
+                let palette = self.extended_palette();
+
+                node_editor::Appearance {
+                    background: Some(palette.background.weak.color.into()),
+                    node_background: palette.background.base.color.into(),
+                    node_border: Border {
+                        color: palette.background.strong.color,
+                        width: 1.0,
+                        radius: 4.0.into(),
+                    },
+                    selected_node_border: Border {
+                        color: palette.primary.strong.color,
+                        width: 2.0,
+                        radius: 4.0.into(),
+                    },
+                    port_color: palette.primary.base.color,
+                    port_radius: 5.0,
+                    connection_color: palette.background.strong.text,
+                    connection_width: 2.0,
+                    selection_background: Color {
+                        a: 0.2,
+                        ..palette.primary.base.color
+                    }
+                    .into(),
+                    selection_border: Border {
+                        color: palette.primary.base.color,
+                        width: 1.0,
+                        radius: 0.0.into(),
+                    },
+                }
+            }
+            NodeEditor::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> node_editor::Appearance> node_editor::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> node_editor::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a timeline.
+#[derive(Default)]
+pub enum Timeline {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn timeline::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> timeline::Appearance + 'static> From<T> for Timeline {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl timeline::StyleSheet for Theme {
+    type Style = Timeline;
+
+    fn appearance(&self, style: &Self::Style) -> timeline::Appearance {
+        match style {
+            Timeline::Default => {
+                let palette = self.extended_palette();
+
+                timeline::Appearance {
+                    background: Some(palette.background.base.color.into()),
+                    track_separator: palette.background.weak.color,
+                    ruler_background: palette.background.weak.color.into(),
+                    tick_color: palette.background.strong.color,
+                    ruler_text_color: palette.background.weak.text,
+                    clip_background: palette.primary.weak.color.into(),
+                    clip_border: Border {
+                        color: palette.primary.base.color,
+                        width: 1.0,
+                        radius: 4.0.into(),
+                    },
+                    active_clip_border: Border {
+                        color: palette.primary.strong.color,
+                        width: 2.0,
+                        radius: 4.0.into(),
+                    },
+                    clip_text_color: palette.primary.weak.text,
+                    playhead_color: palette.danger.base.color,
+                }
+            }
+            Timeline::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> timeline::Appearance> timeline::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> timeline::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of an image crop.
+#[derive(Default)]
+pub enum Crop {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn crop::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> crop::Appearance + 'static> From<T> for Crop {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl crop::StyleSheet for Theme {
+    type Style = Crop;
+
+    fn appearance(&self, style: &Self::Style) -> crop::Appearance {
+        match style {
+            Crop::Default => crop::Appearance {
+                shade: Color {
+                    a: 0.5,
+                    ..Color::BLACK
+                },
+                border: Border {
+                    color: Color::WHITE,
+                    width: 1.0,
+                    radius: 0.0.into(),
+                },
+                guide_color: Color {
+                    a: 0.5,
+                    ..Color::WHITE
+                },
+                handle_color: Color::WHITE,
+            },
+            Crop::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> crop::Appearance> crop::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> crop::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a drop zone.
+#[derive(Default)]
+pub enum DropZone {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn drop_zone::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> drop_zone::Appearance + 'static> From<T> for DropZone {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl drop_zone::StyleSheet for Theme {
+    type Style = DropZone;
+
+    fn hovered(&self, style: &Self::Style) -> drop_zone::Appearance {
+        match style {
+            DropZone::Default => {
+                let palette = self.extended_palette();
+
+                drop_zone::Appearance {
+                    background: Color {
+                        a: 0.2,
+                        ..palette.primary.base.color
+                    }
+                    .into(),
+                    border: Border {
+                        color: palette.primary.strong.color,
+                        width: 2.0,
+                        radius: 4.0.into(),
+                    },
+                }
+            }
+            DropZone::Custom(custom) => custom.hovered(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> drop_zone::Appearance> drop_zone::StyleSheet for T {
+    type Style = Theme;
+
+    fn hovered(&self, style: &Self::Style) -> drop_zone::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a rubber band selection.
+#[derive(Default)]
+pub enum RubberBand {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn rubber_band::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> rubber_band::Appearance + 'static> From<T>
+    for RubberBand
+{
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl rubber_band::StyleSheet for Theme {
+    type Style = RubberBand;
+
+    fn appearance(&self, style: &Self::Style) -> rubber_band::Appearance {
+        match style {
+            RubberBand::Default => {
+                let palette = self.extended_palette();
+
+                rubber_band::Appearance {
+                    background: Color {
+                        a: 0.2,
+                        ..palette.primary.base.color
+                    }
+                    .into(),
+                    border: Border {
+                        color: palette.primary.base.color,
+                        width: 1.0,
+                        radius: 0.0.into(),
+                    },
+                }
+            }
+            RubberBand::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> rubber_band::Appearance> rubber_band::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> rubber_band::Appearance {
+        (self)(style)
+    }
+}
+
 /// The style of a progress bar.
 #[derive(Default)]
 pub enum ProgressBar {
@@ -1098,9 +1708,17 @@ impl text::StyleSheet for Theme {
     type Style = Text;
 
     fn appearance(&self, style: Self::Style) -> text::Appearance {
+        let selection = Some(self.extended_palette().primary.weak.color);
+
         match style {
-            Text::Default => text::Appearance::default(),
-            Text::Color(c) => text::Appearance { color: Some(c) },
+            Text::Default => text::Appearance {
+                color: None,
+                selection,
+            },
+            Text::Color(c) => text::Appearance {
+                color: Some(c),
+                selection,
+            },
         }
     }
 }
@@ -1124,12 +1742,13 @@ impl text_input::StyleSheet for Theme {
         }
 
         let palette = self.extended_palette();
+        let metrics = self.metrics();
 
         text_input::Appearance {
             background: palette.background.base.color.into(),
             border: Border {
-                radius: 2.0.into(),
-                width: 1.0,
+                radius: metrics.control_radius.into(),
+                width: metrics.border_width,
                 color: palette.background.strong.color,
             },
             icon_color: palette.background.weak.text,
@@ -1142,12 +1761,13 @@ impl text_input::StyleSheet for Theme {
         }
 
         let palette = self.extended_palette();
+        let metrics = self.metrics();
 
         text_input::Appearance {
             background: palette.background.base.color.into(),
             border: Border {
-                radius: 2.0.into(),
-                width: 1.0,
+                radius: metrics.control_radius.into(),
+                width: metrics.border_width,
                 color: palette.background.base.text,
             },
             icon_color: palette.background.weak.text,
@@ -1160,12 +1780,13 @@ impl text_input::StyleSheet for Theme {
         }
 
         let palette = self.extended_palette();
+        let metrics = self.metrics();
 
         text_input::Appearance {
             background: palette.background.base.color.into(),
             border: Border {
-                radius: 2.0.into(),
-                width: 1.0,
+                radius: metrics.control_radius.into(),
+                width: metrics.focus_ring_width,
                 color: palette.primary.strong.color,
             },
             icon_color: palette.background.weak.text,
@@ -1202,18 +1823,29 @@ impl text_input::StyleSheet for Theme {
         palette.primary.weak.color
     }
 
+    fn misspelled_color(&self, style: &Self::Style) -> Color {
+        if let TextInput::Custom(custom) = style {
+            return custom.misspelled_color(self);
+        }
+
+        let palette = self.extended_palette();
+
+        palette.danger.base.color
+    }
+
     fn disabled(&self, style: &Self::Style) -> text_input::Appearance {
         if let TextInput::Custom(custom) = style {
             return custom.disabled(self);
         }
 
         let palette = self.extended_palette();
+        let metrics = self.metrics();
 
         text_input::Appearance {
             background: palette.background.weak.color.into(),
             border: Border {
-                radius: 2.0.into(),
-                width: 1.0,
+                radius: metrics.control_radius.into(),
+                width: metrics.border_width,
                 color: palette.background.strong.color,
             },
             icon_color: palette.background.strong.color,
@@ -1227,6 +1859,13 @@ impl text_input::StyleSheet for Theme {
 
         self.placeholder_color(style)
     }
+    fn caret(&self, style: &Self::Style) -> text_input::Caret {
+        if let TextInput::Custom(custom) = style {
+            return custom.caret(self);
+        }
+
+        text_input::Caret::default()
+    }
 }
 
 /// The style of a text input.
@@ -1248,12 +1887,13 @@ impl text_editor::StyleSheet for Theme {
         }
 
         let palette = self.extended_palette();
+        let metrics = self.metrics();
 
         text_editor::Appearance {
             background: palette.background.base.color.into(),
             border: Border {
-                radius: 2.0.into(),
-                width: 1.0,
+                radius: metrics.control_radius.into(),
+                width: metrics.border_width,
                 color: palette.background.strong.color,
             },
         }
@@ -1265,12 +1905,13 @@ impl text_editor::StyleSheet for Theme {
         }
 
         let palette = self.extended_palette();
+        let metrics = self.metrics();
 
         text_editor::Appearance {
             background: palette.background.base.color.into(),
             border: Border {
-                radius: 2.0.into(),
-                width: 1.0,
+                radius: metrics.control_radius.into(),
+                width: metrics.border_width,
                 color: palette.background.base.text,
             },
         }
@@ -1282,12 +1923,13 @@ impl text_editor::StyleSheet for Theme {
         }
 
         let palette = self.extended_palette();
+        let metrics = self.metrics();
 
         text_editor::Appearance {
             background: palette.background.base.color.into(),
             border: Border {
-                radius: 2.0.into(),
-                width: 1.0,
+                radius: metrics.control_radius.into(),
+                width: metrics.focus_ring_width,
                 color: palette.primary.strong.color,
             },
         }
@@ -1329,12 +1971,13 @@ impl text_editor::StyleSheet for Theme {
         }
 
         let palette = self.extended_palette();
+        let metrics = self.metrics();
 
         text_editor::Appearance {
             background: palette.background.weak.color.into(),
             border: Border {
-                radius: 2.0.into(),
-                width: 1.0,
+                radius: metrics.control_radius.into(),
+                width: metrics.border_width,
                 color: palette.background.strong.color,
             },
         }
@@ -1347,4 +1990,12 @@ impl text_editor::StyleSheet for Theme {
 
         self.placeholder_color(style)
     }
+
+    fn caret(&self, style: &Self::Style) -> text_editor::Caret {
+        if let TextEditor::Custom(custom) = style {
+            return custom.caret(self);
+        }
+
+        text_editor::Caret::default()
+    }
 }
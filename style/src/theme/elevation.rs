@@ -0,0 +1,62 @@
+//! Layer surfaces on top of each other with shadows.
+use iced_core::{Color, Shadow, Vector};
+
+/// How far a surface floats above the window, from [`Elevation::Level0`]
+/// (flat) to [`Elevation::Level5`] (highest).
+///
+/// A [`Theme`] turns an [`Elevation`] into a [`Shadow`] with
+/// [`Theme::shadow`], so surfaces at the same level cast the same shadow.
+///
+/// [`Theme`]: super::Theme
+/// [`Theme::shadow`]: super::Theme::shadow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Elevation {
+    /// A flat surface, without a shadow.
+    #[default]
+    Level0,
+    /// Raised controls, like cards.
+    Level1,
+    /// Menus and tooltips.
+    Level2,
+    /// Popovers.
+    Level3,
+    /// Drawers and surfaces being dragged.
+    Level4,
+    /// Modal dialogs.
+    Level5,
+}
+
+impl Elevation {
+    /// All the levels of [`Elevation`], from lowest to highest.
+    pub const ALL: &'static [Self] = &[
+        Self::Level0,
+        Self::Level1,
+        Self::Level2,
+        Self::Level3,
+        Self::Level4,
+        Self::Level5,
+    ];
+
+    /// Returns the [`Shadow`] cast by a surface at this [`Elevation`].
+    ///
+    /// Shadows are darker on dark backgrounds, so that they stay visible.
+    pub fn shadow(self, is_dark: bool) -> Shadow {
+        let (offset, blur_radius, alpha) = match self {
+            Self::Level0 => return Shadow::default(),
+            Self::Level1 => (1.0, 3.0, 0.15),
+            Self::Level2 => (2.0, 6.0, 0.18),
+            Self::Level3 => (4.0, 10.0, 0.2),
+            Self::Level4 => (6.0, 16.0, 0.22),
+            Self::Level5 => (8.0, 24.0, 0.25),
+        };
+
+        Shadow {
+            color: Color {
+                a: if is_dark { alpha * 2.0 } else { alpha },
+                ..Color::BLACK
+            },
+            offset: Vector::new(0.0, offset),
+            blur_radius,
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! Adjust the appearance of the built-in styles inline.
+//!
+//! Styles of multi-state widgets can be built from a function that receives
+//! the [`Appearance`] the [`Theme`] would use in each state and returns an
+//! adjusted one. The function is applied on top of the default style; use
+//! `overriding` to apply it on top of a different one.
+//!
+//! ```
+//! use iced_style::button;
+//! use iced_style::theme;
+//! use iced_core::Color;
+//!
+//! let red = theme::Button::from(|appearance: button::Appearance| {
+//!     button::Appearance {
+//!         background: Some(Color::from_rgb(1.0, 0.0, 0.0).into()),
+//!         ..appearance
+//!     }
+//! });
+//!
+//! let rounded = theme::Button::Secondary.overriding(|appearance| {
+//!     button::Appearance {
+//!         border: iced_core::Border::with_radius(8),
+//!         ..appearance
+//!     }
+//! });
+//! ```
+//!
+//! [`Appearance`]: crate::button::Appearance
+use super::{Button, Checkbox, Radio, Slider, TextInput, Theme, Toggler};
+
+use crate::button;
+use crate::checkbox;
+use crate::radio;
+use crate::slider;
+use crate::text_input;
+use crate::toggler;
+
+use iced_core::Color;
+
+/// A style of the [`Theme`] with its appearance adjusted by a function.
+struct Override<S, F> {
+    base: S,
+    f: F,
+}
+
+impl<F> From<F> for Button
+where
+    F: Fn(button::Appearance) -> button::Appearance + 'static,
+{
+    fn from(f: F) -> Self {
+        Self::default().overriding(f)
+    }
+}
+
+impl Button {
+    /// Adjusts the appearance of this [`Button`] style in every state with
+    /// the given function.
+    pub fn overriding(
+        self,
+        f: impl Fn(button::Appearance) -> button::Appearance + 'static,
+    ) -> Self {
+        Self::Custom(Box::new(Override { base: self, f }))
+    }
+}
+
+impl<F> button::StyleSheet for Override<Button, F>
+where
+    F: Fn(button::Appearance) -> button::Appearance,
+{
+    type Style = Theme;
+
+    fn active(&self, theme: &Theme) -> button::Appearance {
+        (self.f)(button::StyleSheet::active(theme, &self.base))
+    }
+
+    fn hovered(&self, theme: &Theme) -> button::Appearance {
+        (self.f)(button::StyleSheet::hovered(theme, &self.base))
+    }
+
+    fn pressed(&self, theme: &Theme) -> button::Appearance {
+        (self.f)(button::StyleSheet::pressed(theme, &self.base))
+    }
+
+    fn focused(&self, theme: &Theme) -> button::Appearance {
+        (self.f)(button::StyleSheet::focused(theme, &self.base))
+    }
+
+    fn disabled(&self, theme: &Theme) -> button::Appearance {
+        (self.f)(button::StyleSheet::disabled(theme, &self.base))
+    }
+}
+
+impl<F> From<F> for Checkbox
+where
+    F: Fn(checkbox::Appearance) -> checkbox::Appearance + 'static,
+{
+    fn from(f: F) -> Self {
+        Self::default().overriding(f)
+    }
+}
+
+impl Checkbox {
+    /// Adjusts the appearance of this [`Checkbox`] style in every state with
+    /// the given function.
+    pub fn overriding(
+        self,
+        f: impl Fn(checkbox::Appearance) -> checkbox::Appearance + 'static,
+    ) -> Self {
+        Self::Custom(Box::new(Override { base: self, f }))
+    }
+}
+
+impl<F> checkbox::StyleSheet for Override<Checkbox, F>
+where
+    F: Fn(checkbox::Appearance) -> checkbox::Appearance,
+{
+    type Style = Theme;
+
+    fn active(&self, theme: &Theme, is_checked: bool) -> checkbox::Appearance {
+        (self.f)(checkbox::StyleSheet::active(theme, &self.base, is_checked))
+    }
+
+    fn hovered(&self, theme: &Theme, is_checked: bool) -> checkbox::Appearance {
+        (self.f)(checkbox::StyleSheet::hovered(theme, &self.base, is_checked))
+    }
+}
+
+impl<F> From<F> for Radio
+where
+    F: Fn(radio::Appearance) -> radio::Appearance + 'static,
+{
+    fn from(f: F) -> Self {
+        Self::default().overriding(f)
+    }
+}
+
+impl Radio {
+    /// Adjusts the appearance of this [`Radio`] style in every state with
+    /// the given function.
+    pub fn overriding(
+        self,
+        f: impl Fn(radio::Appearance) -> radio::Appearance + 'static,
+    ) -> Self {
+        Self::Custom(Box::new(Override { base: self, f }))
+    }
+}
+
+impl<F> radio::StyleSheet for Override<Radio, F>
+where
+    F: Fn(radio::Appearance) -> radio::Appearance,
+{
+    type Style = Theme;
+
+    fn active(&self, theme: &Theme, is_selected: bool) -> radio::Appearance {
+        (self.f)(radio::StyleSheet::active(theme, &self.base, is_selected))
+    }
+
+    fn hovered(&self, theme: &Theme, is_selected: bool) -> radio::Appearance {
+        (self.f)(radio::StyleSheet::hovered(theme, &self.base, is_selected))
+    }
+}
+
+impl<F> From<F> for Toggler
+where
+    F: Fn(toggler::Appearance) -> toggler::Appearance + 'static,
+{
+    fn from(f: F) -> Self {
+        Self::default().overriding(f)
+    }
+}
+
+impl Toggler {
+    /// Adjusts the appearance of this [`Toggler`] style in every state with
+    /// the given function.
+    pub fn overriding(
+        self,
+        f: impl Fn(toggler::Appearance) -> toggler::Appearance + 'static,
+    ) -> Self {
+        Self::Custom(Box::new(Override { base: self, f }))
+    }
+}
+
+impl<F> toggler::StyleSheet for Override<Toggler, F>
+where
+    F: Fn(toggler::Appearance) -> toggler::Appearance,
+{
+    type Style = Theme;
+
+    fn active(&self, theme: &Theme, is_active: bool) -> toggler::Appearance {
+        (self.f)(toggler::StyleSheet::active(theme, &self.base, is_active))
+    }
+
+    fn hovered(&self, theme: &Theme, is_active: bool) -> toggler::Appearance {
+        (self.f)(toggler::StyleSheet::hovered(theme, &self.base, is_active))
+    }
+}
+
+impl<F> From<F> for Slider
+where
+    F: Fn(slider::Appearance) -> slider::Appearance + 'static,
+{
+    fn from(f: F) -> Self {
+        Self::default().overriding(f)
+    }
+}
+
+impl Slider {
+    /// Adjusts the appearance of this [`Slider`] style in every state with
+    /// the given function.
+    pub fn overriding(
+        self,
+        f: impl Fn(slider::Appearance) -> slider::Appearance + 'static,
+    ) -> Self {
+        Self::Custom(Box::new(Override { base: self, f }))
+    }
+}
+
+impl<F> slider::StyleSheet for Override<Slider, F>
+where
+    F: Fn(slider::Appearance) -> slider::Appearance,
+{
+    type Style = Theme;
+
+    fn active(&self, theme: &Theme) -> slider::Appearance {
+        (self.f)(slider::StyleSheet::active(theme, &self.base))
+    }
+
+    fn hovered(&self, theme: &Theme) -> slider::Appearance {
+        (self.f)(slider::StyleSheet::hovered(theme, &self.base))
+    }
+
+    fn dragging(&self, theme: &Theme) -> slider::Appearance {
+        (self.f)(slider::StyleSheet::dragging(theme, &self.base))
+    }
+}
+
+impl<F> From<F> for TextInput
+where
+    F: Fn(text_input::Appearance) -> text_input::Appearance + 'static,
+{
+    fn from(f: F) -> Self {
+        Self::default().overriding(f)
+    }
+}
+
+impl TextInput {
+    /// Adjusts the appearance of this [`TextInput`] style in every state with
+    /// the given function.
+    ///
+    /// The colors of the text are kept as they are.
+    pub fn overriding(
+        self,
+        f: impl Fn(text_input::Appearance) -> text_input::Appearance + 'static,
+    ) -> Self {
+        Self::Custom(Box::new(Override { base: self, f }))
+    }
+}
+
+impl<F> text_input::StyleSheet for Override<TextInput, F>
+where
+    F: Fn(text_input::Appearance) -> text_input::Appearance,
+{
+    type Style = Theme;
+
+    fn active(&self, theme: &Theme) -> text_input::Appearance {
+        (self.f)(text_input::StyleSheet::active(theme, &self.base))
+    }
+
+    fn focused(&self, theme: &Theme) -> text_input::Appearance {
+        (self.f)(text_input::StyleSheet::focused(theme, &self.base))
+    }
+
+    fn hovered(&self, theme: &Theme) -> text_input::Appearance {
+        (self.f)(text_input::StyleSheet::hovered(theme, &self.base))
+    }
+
+    fn disabled(&self, theme: &Theme) -> text_input::Appearance {
+        (self.f)(text_input::StyleSheet::disabled(theme, &self.base))
+    }
+
+    fn placeholder_color(&self, theme: &Theme) -> Color {
+        text_input::StyleSheet::placeholder_color(theme, &self.base)
+    }
+
+    fn value_color(&self, theme: &Theme) -> Color {
+        text_input::StyleSheet::value_color(theme, &self.base)
+    }
+
+    fn disabled_color(&self, theme: &Theme) -> Color {
+        text_input::StyleSheet::disabled_color(theme, &self.base)
+    }
+
+    fn selection_color(&self, theme: &Theme) -> Color {
+        text_input::StyleSheet::selection_color(theme, &self.base)
+    }
+
+    fn misspelled_color(&self, theme: &Theme) -> Color {
+        text_input::StyleSheet::misspelled_color(theme, &self.base)
+    }
+
+    fn caret(&self, theme: &Theme) -> text_input::Caret {
+        text_input::StyleSheet::caret(theme, &self.base)
+    }
+}
@@ -0,0 +1,157 @@
+//! Match the look of the operating system.
+use super::Palette;
+
+use iced_core::window;
+use iced_core::{Color, Padding};
+
+use std::fmt;
+
+/// The design language of an operating system.
+///
+/// Create a [`Theme`] flavored after it with [`Theme::platform`], or after
+/// the one of the current operating system with [`Theme::native`].
+///
+/// [`Theme`]: super::Theme
+/// [`Theme::platform`]: super::Theme::platform
+/// [`Theme::native`]: super::Theme::native
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    /// Rounded, roomy controls, like Pop!_OS and GNOME.
+    Pop,
+    /// Compact controls with subtle corners, like Windows.
+    Fluent,
+    /// Small controls with thick focus rings, like macOS.
+    Aqua,
+}
+
+impl Platform {
+    /// All the flavors of [`Platform`].
+    pub const ALL: &'static [Self] = &[Self::Pop, Self::Fluent, Self::Aqua];
+
+    /// Returns the [`Platform`] of the current operating system.
+    ///
+    /// Any operating system other than Windows and macOS is considered
+    /// [`Platform::Pop`].
+    pub fn current() -> Self {
+        if cfg!(target_os = "windows") {
+            Self::Fluent
+        } else if cfg!(any(target_os = "macos", target_os = "ios")) {
+            Self::Aqua
+        } else {
+            Self::Pop
+        }
+    }
+
+    /// Returns the [`Metrics`] of the controls of the [`Platform`].
+    pub fn metrics(self) -> Metrics {
+        match self {
+            Self::Pop => Metrics {
+                control_height: 36.0,
+                control_padding: Padding::from([8, 16]),
+                control_radius: 8.0,
+                border_width: 1.0,
+                focus_ring_width: 2.0,
+            },
+            Self::Fluent => Metrics {
+                control_height: 32.0,
+                control_padding: Padding::from([5, 12]),
+                control_radius: 4.0,
+                border_width: 1.0,
+                focus_ring_width: 2.0,
+            },
+            Self::Aqua => Metrics {
+                control_height: 28.0,
+                control_padding: Padding::from([4, 12]),
+                control_radius: 6.0,
+                border_width: 1.0,
+                focus_ring_width: 3.0,
+            },
+        }
+    }
+
+    /// Returns the [`Palette`] of the [`Platform`] for the given color
+    /// scheme.
+    pub fn palette(self, color_scheme: window::ColorScheme) -> Palette {
+        let is_dark = color_scheme == window::ColorScheme::Dark;
+
+        let (background, text) = if is_dark {
+            match self {
+                Self::Pop => (rgb(0x2B, 0x2B, 0x2B), rgb(0xE4, 0xE4, 0xE4)),
+                Self::Fluent => (rgb(0x20, 0x20, 0x20), Color::WHITE),
+                Self::Aqua => (rgb(0x1E, 0x1E, 0x1E), rgb(0xDF, 0xDF, 0xDF)),
+            }
+        } else {
+            match self {
+                Self::Pop => (rgb(0xF6, 0xF6, 0xF6), rgb(0x33, 0x33, 0x33)),
+                Self::Fluent => (rgb(0xF3, 0xF3, 0xF3), rgb(0x1A, 0x1A, 0x1A)),
+                Self::Aqua => (rgb(0xEC, 0xEC, 0xEC), rgb(0x26, 0x26, 0x26)),
+            }
+        };
+
+        let primary = match (self, is_dark) {
+            (Self::Pop, _) => rgb(0x48, 0xB9, 0xC7),
+            (Self::Fluent, false) => rgb(0x00, 0x67, 0xC0),
+            (Self::Fluent, true) => rgb(0x4C, 0xC2, 0xFF),
+            (Self::Aqua, false) => rgb(0x00, 0x7A, 0xFF),
+            (Self::Aqua, true) => rgb(0x0A, 0x84, 0xFF),
+        };
+
+        Palette {
+            background,
+            text,
+            primary,
+            ..if is_dark {
+                Palette::DARK
+            } else {
+                Palette::LIGHT
+            }
+        }
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pop => write!(f, "Pop"),
+            Self::Fluent => write!(f, "Fluent"),
+            Self::Aqua => write!(f, "Aqua"),
+        }
+    }
+}
+
+/// The sizes of the controls of a [`Theme`].
+///
+/// The built-in styles use the radii and border widths to draw controls.
+/// The sizes are not known by the widgets, since they are laid out before
+/// being styled; pass them to your controls to match the [`Platform`].
+///
+/// [`Theme`]: super::Theme
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Metrics {
+    /// The preferred height of buttons, text inputs, and pick lists.
+    pub control_height: f32,
+    /// The preferred padding of buttons, text inputs, and pick lists.
+    pub control_padding: Padding,
+    /// The border radius of controls.
+    pub control_radius: f32,
+    /// The border width of controls.
+    pub border_width: f32,
+    /// The border width of focused controls.
+    pub focus_ring_width: f32,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self {
+            control_height: 30.0,
+            control_padding: Padding::from([5, 10]),
+            control_radius: 2.0,
+            border_width: 1.0,
+            focus_ring_width: 1.0,
+        }
+    }
+}
+
+fn rgb(r: u8, g: u8, b: u8) -> Color {
+    Color::from_rgb8(r, g, b)
+}
@@ -0,0 +1,36 @@
+//! Change the appearance of a timeline.
+use crate::core::{Background, Border, Color};
+
+/// The appearance of a timeline.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the tracks, if any.
+    pub background: Option<Background>,
+    /// The [`Color`] of the lines between tracks.
+    pub track_separator: Color,
+    /// The [`Background`] of the ruler.
+    pub ruler_background: Background,
+    /// The [`Color`] of the ticks of the ruler.
+    pub tick_color: Color,
+    /// The text [`Color`] of the ruler.
+    pub ruler_text_color: Color,
+    /// The [`Background`] of a clip.
+    pub clip_background: Background,
+    /// The [`Border`] of a clip.
+    pub clip_border: Border,
+    /// The [`Border`] of a clip being dragged or resized.
+    pub active_clip_border: Border,
+    /// The text [`Color`] of a clip.
+    pub clip_text_color: Color,
+    /// The [`Color`] of the playhead.
+    pub playhead_color: Color,
+}
+
+/// A set of rules that dictate the style of a timeline.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the timeline.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -508,6 +508,8 @@ impl Backend {
                 horizontal_alignment,
                 vertical_alignment,
                 shaping,
+                wrap,
+                truncate,
                 clip_bounds: text_clip_bounds,
             } => {
                 let physical_bounds =
@@ -531,6 +533,8 @@ impl Backend {
                     *horizontal_alignment,
                     *vertical_alignment,
                     *shaping,
+                    *wrap,
+                    *truncate,
                     scale_factor,
                     pixels,
                     clip_mask,
@@ -565,14 +569,82 @@ impl Backend {
                     clip_mask,
                 );
             }
+            Primitive::Grid {
+                grid,
+                position,
+                cell_size,
+                rows,
+                color,
+                clip_bounds: grid_clip_bounds,
+            } => {
+                let Some(grid) = grid.upgrade() else {
+                    return;
+                };
+
+                let physical_bounds =
+                    (*grid_clip_bounds + translation) * scale_factor;
+
+                if !clip_bounds.intersects(&physical_bounds) {
+                    return;
+                }
+
+                let clip_mask = (!physical_bounds.is_within(&clip_bounds))
+                    .then_some(clip_mask as &_);
+
+                let position = *position + translation;
+
+                for (column, row, cell) in grid.visible_cells(rows.clone()) {
+                    let Some(background) = cell.background else {
+                        continue;
+                    };
+
+                    let Some(rect) = tiny_skia::Rect::from_xywh(
+                        (position.x + column as f32 * cell_size.width)
+                            * scale_factor,
+                        (position.y + row as f32 * cell_size.height)
+                            * scale_factor,
+                        cell_size.width * scale_factor,
+                        cell_size.height * scale_factor,
+                    ) else {
+                        continue;
+                    };
+
+                    pixels.fill_rect(
+                        rect,
+                        &tiny_skia::Paint {
+                            shader: tiny_skia::Shader::SolidColor(into_color(
+                                background,
+                            )),
+                            anti_alias: false,
+                            ..tiny_skia::Paint::default()
+                        },
+                        tiny_skia::Transform::identity(),
+                        clip_mask,
+                    );
+                }
+
+                self.text_pipeline.draw_grid(
+                    &grid,
+                    position,
+                    *cell_size,
+                    rows.clone(),
+                    *color,
+                    scale_factor,
+                    pixels,
+                    clip_mask,
+                );
+            }
             #[cfg(feature = "image")]
             Primitive::Image {
                 handle,
                 filter_method,
                 bounds,
+                rotation,
+                opacity,
                 border_radius,
             } => {
-                let physical_bounds = (*bounds + translation) * scale_factor;
+                let physical_bounds =
+                    (bounds.rotate(*rotation) + translation) * scale_factor;
 
                 if !clip_bounds.intersects(&physical_bounds) {
                     return;
@@ -582,16 +654,26 @@ impl Backend {
                     .is_within_strict(&clip_bounds))
                 .then_some(clip_mask as &_);
 
+                let center = bounds.center();
+
                 let transform = tiny_skia::Transform::from_translate(
                     translation.x,
                     translation.y,
                 )
-                .post_scale(scale_factor, scale_factor);
+                .post_scale(scale_factor, scale_factor)
+                .pre_concat(
+                    tiny_skia::Transform::from_rotate_at(
+                        rotation.0.to_degrees(),
+                        center.x,
+                        center.y,
+                    ),
+                );
 
                 self.raster_pipeline.draw(
                     handle,
                     *filter_method,
                     *bounds,
+                    *opacity,
                     pixels,
                     transform,
                     clip_mask,
@@ -609,8 +691,11 @@ impl Backend {
                 handle,
                 bounds,
                 color,
+                rotation,
+                opacity,
             } => {
-                let physical_bounds = (*bounds + translation) * scale_factor;
+                let physical_bounds =
+                    (bounds.rotate(*rotation) + translation) * scale_factor;
 
                 if !clip_bounds.intersects(&physical_bounds) {
                     return;
@@ -624,6 +709,8 @@ impl Backend {
                     handle,
                     *color,
                     (*bounds + translation) * scale_factor,
+                    *rotation,
+                    *opacity,
                     pixels,
                     clip_mask,
                 );
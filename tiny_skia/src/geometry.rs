@@ -1,4 +1,4 @@
-use crate::core::text::LineHeight;
+use crate::core::text::{LineHeight, Truncate, Wrap};
 use crate::core::{Pixels, Point, Rectangle, Size, Vector};
 use crate::graphics::geometry::fill::{self, Fill};
 use crate::graphics::geometry::stroke::{self, Stroke};
@@ -165,6 +165,8 @@ impl Frame {
                 horizontal_alignment: text.horizontal_alignment,
                 vertical_alignment: text.vertical_alignment,
                 shaping: text.shaping,
+                wrap: Wrap::default(),
+                truncate: Truncate::default(),
                 clip_bounds: Rectangle::with_size(Size::INFINITY),
             });
         } else {
@@ -12,6 +12,17 @@ pub struct Settings {
     ///
     /// By default, it will be set to `16.0`.
     pub default_text_size: Pixels,
+
+    /// The factor applied to the spacing and padding of the built-in widgets.
+    ///
+    /// By default, it is `1.0`.
+    pub spacing_scale: f32,
+
+    /// The factor applied to the border radius of the quads drawn by the
+    /// built-in widgets.
+    ///
+    /// By default, it is `1.0`.
+    pub border_radius_scale: f32,
 }
 
 impl Default for Settings {
@@ -19,6 +30,8 @@ impl Default for Settings {
         Settings {
             default_font: Font::default(),
             default_text_size: Pixels(14.0),
+            spacing_scale: 1.0,
+            border_radius_scale: 1.0,
         }
     }
 }
@@ -1,19 +1,22 @@
 use crate::core::alignment;
-use crate::core::text::{LineHeight, Shaping};
+use crate::core::text::{LineHeight, Shaping, Truncate, Wrap};
 use crate::core::{Color, Font, Pixels, Point, Rectangle, Size};
 use crate::graphics::text::cache::{self, Cache};
 use crate::graphics::text::editor;
 use crate::graphics::text::font_system;
+use crate::graphics::text::grid::{self, Grid};
 use crate::graphics::text::paragraph;
 
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::borrow::Cow;
 use std::cell::RefCell;
 use std::collections::hash_map;
+use std::ops::Range;
 
 #[allow(missing_debug_implementations)]
 pub struct Pipeline {
     glyph_cache: GlyphCache,
+    grid_glyphs: grid::Glyphs,
     cache: RefCell<Cache>,
 }
 
@@ -21,6 +24,7 @@ impl Pipeline {
     pub fn new() -> Self {
         Pipeline {
             glyph_cache: GlyphCache::new(),
+            grid_glyphs: grid::Glyphs::new(),
             cache: RefCell::new(Cache::new()),
         }
     }
@@ -32,6 +36,7 @@ impl Pipeline {
             .load_font(bytes);
 
         self.cache = RefCell::new(Cache::new());
+        self.grid_glyphs = grid::Glyphs::new();
     }
 
     pub fn draw_paragraph(
@@ -109,6 +114,8 @@ impl Pipeline {
         horizontal_alignment: alignment::Horizontal,
         vertical_alignment: alignment::Vertical,
         shaping: Shaping,
+        wrap: Wrap,
+        truncate: Truncate,
         scale_factor: f32,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         clip_mask: Option<&tiny_skia::Mask>,
@@ -125,6 +132,8 @@ impl Pipeline {
             size: size.into(),
             line_height,
             shaping,
+            wrap,
+            truncate,
         };
 
         let (_, entry) = self.cache.get_mut().allocate(font_system, key);
@@ -177,9 +186,67 @@ impl Pipeline {
         );
     }
 
+    pub fn draw_grid(
+        &mut self,
+        grid: &Grid,
+        position: Point,
+        cell_size: Size,
+        rows: Range<usize>,
+        color: Color,
+        scale_factor: f32,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        clip_mask: Option<&tiny_skia::Mask>,
+    ) {
+        let mut font_system = font_system().write().expect("Write font system");
+        let font_system = font_system.raw();
+
+        let mut swash = cosmic_text::SwashCache::new();
+
+        for (column, row, cell) in grid.visible_cells(rows) {
+            let Some(glyph) = self.grid_glyphs.get(
+                font_system,
+                grid,
+                cell.character,
+                scale_factor,
+            ) else {
+                continue;
+            };
+
+            let Some((buffer, placement)) = self.glyph_cache.allocate(
+                glyph.cache_key,
+                cell.foreground.unwrap_or(color),
+                font_system,
+                &mut swash,
+            ) else {
+                continue;
+            };
+
+            let pixmap = tiny_skia::PixmapRef::from_bytes(
+                buffer,
+                placement.width,
+                placement.height,
+            )
+            .expect("Create glyph pixel map");
+
+            let x =
+                (position.x + column as f32 * cell_size.width) * scale_factor;
+            let y = (position.y + row as f32 * cell_size.height) * scale_factor;
+
+            pixels.draw_pixmap(
+                x.round() as i32 + glyph.x + placement.left,
+                y.round() as i32 + glyph.baseline - placement.top,
+                pixmap,
+                &tiny_skia::PixmapPaint::default(),
+                tiny_skia::Transform::identity(),
+                clip_mask,
+            );
+        }
+    }
+
     pub fn trim_cache(&mut self) {
         self.cache.get_mut().trim();
         self.glyph_cache.trim();
+        self.grid_glyphs.trim();
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::core::svg::{Data, Handle};
-use crate::core::{Color, Rectangle, Size};
+use crate::core::{Color, Radians, Rectangle, Size};
 use crate::graphics::text;
 
 use resvg::usvg::{self, TreeTextToPath};
@@ -32,6 +32,8 @@ impl Pipeline {
         handle: &Handle,
         color: Option<Color>,
         bounds: Rectangle,
+        rotation: Radians,
+        opacity: f32,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         clip_mask: Option<&tiny_skia::Mask>,
     ) {
@@ -40,12 +42,21 @@ impl Pipeline {
             color,
             Size::new(bounds.width as u32, bounds.height as u32),
         ) {
+            let center = bounds.center();
+
             pixels.draw_pixmap(
                 bounds.x as i32,
                 bounds.y as i32,
                 image,
-                &tiny_skia::PixmapPaint::default(),
-                tiny_skia::Transform::identity(),
+                &tiny_skia::PixmapPaint {
+                    opacity,
+                    ..Default::default()
+                },
+                tiny_skia::Transform::from_rotate_at(
+                    rotation.0.to_degrees(),
+                    center.x,
+                    center.y,
+                ),
                 clip_mask,
             );
         }
@@ -41,6 +41,8 @@ impl crate::graphics::Compositor for Compositor {
             self.settings.default_font,
             self.settings.default_text_size,
         )
+        .with_spacing_scale(self.settings.spacing_scale)
+        .with_border_radius_scale(self.settings.border_radius_scale)
     }
 
     fn create_surface<W: compositor::Window + Clone>(
@@ -219,6 +221,49 @@ pub fn screenshot<T: AsRef<str>>(
     viewport: &Viewport,
     background_color: Color,
     overlay: &[T],
+) -> Vec<u8> {
+    render(
+        backend,
+        &mut surface.clip_mask,
+        primitives,
+        viewport,
+        background_color,
+        overlay,
+    )
+}
+
+/// Renders the given primitives to an offscreen buffer, without a window.
+///
+/// Returns the RGBA bytes of the rendered image.
+pub fn offscreen<T: AsRef<str>>(
+    backend: &mut Backend,
+    primitives: &[Primitive],
+    viewport: &Viewport,
+    background_color: Color,
+    overlay: &[T],
+) -> Vec<u8> {
+    let size = viewport.physical_size();
+
+    let mut clip_mask = tiny_skia::Mask::new(size.width, size.height)
+        .expect("Create clip mask");
+
+    render(
+        backend,
+        &mut clip_mask,
+        primitives,
+        viewport,
+        background_color,
+        overlay,
+    )
+}
+
+fn render<T: AsRef<str>>(
+    backend: &mut Backend,
+    clip_mask: &mut tiny_skia::Mask,
+    primitives: &[Primitive],
+    viewport: &Viewport,
+    background_color: Color,
+    overlay: &[T],
 ) -> Vec<u8> {
     let size = viewport.physical_size();
 
@@ -232,7 +277,7 @@ pub fn screenshot<T: AsRef<str>>(
             size.height,
         )
         .expect("Create offscreen pixel map"),
-        &mut surface.clip_mask,
+        clip_mask,
         primitives,
         viewport,
         &[Rectangle::with_size(Size::new(
@@ -1,10 +1,11 @@
-use crate::core::{Color, Size};
+use crate::core::{Background, Color, Rectangle, Size};
 use crate::graphics::backend;
 use crate::graphics::color;
 use crate::graphics::{Transformation, Viewport};
+use crate::grid;
 use crate::primitive::pipeline;
 use crate::primitive::{self, Primitive};
-use crate::quad;
+use crate::quad::{self, Quad};
 use crate::text;
 use crate::triangle;
 use crate::{Layer, Settings};
@@ -25,6 +26,7 @@ use std::borrow::Cow;
 pub struct Backend {
     quad_pipeline: quad::Pipeline,
     text_pipeline: text::Pipeline,
+    grid_pipeline: grid::Pipeline,
     triangle_pipeline: triangle::Pipeline,
     pipeline_storage: pipeline::Storage,
 
@@ -42,6 +44,7 @@ impl Backend {
     ) -> Self {
         let text_pipeline = text::Pipeline::new(device, queue, format);
         let quad_pipeline = quad::Pipeline::new(device, format);
+        let grid_pipeline = grid::Pipeline::new(device, format);
         let triangle_pipeline =
             triangle::Pipeline::new(device, format, settings.antialiasing);
 
@@ -51,6 +54,7 @@ impl Backend {
         Self {
             quad_pipeline,
             text_pipeline,
+            grid_pipeline,
             triangle_pipeline,
             pipeline_storage: pipeline::Storage::default(),
 
@@ -79,16 +83,109 @@ impl Backend {
         #[cfg(feature = "tracing")]
         let _ = info_span!("Wgpu::Backend", "PRESENT").entered();
 
-        let target_size = viewport.physical_size();
-        let scale_factor = viewport.scale_factor() as f32;
-        let transformation = viewport.projection();
-
         let mut layers = Layer::generate(primitives, viewport);
 
         if !overlay_text.is_empty() {
             layers.push(Layer::overlay(overlay_text, viewport));
         }
 
+        self.draw(
+            device,
+            queue,
+            encoder,
+            clear_color,
+            format,
+            frame,
+            viewport,
+            &layers,
+        );
+    }
+
+    /// Draws the provided primitives in the given `TextureView`, only inside
+    /// of the given damaged regions, in physical pixels.
+    ///
+    /// The rest of the `TextureView` is kept as it is. The damaged regions are
+    /// filled with the given background color before drawing, which must be
+    /// opaque to cover what was drawn there before.
+    pub fn present_damage(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        background_color: Color,
+        format: wgpu::TextureFormat,
+        frame: &wgpu::TextureView,
+        primitives: &[Primitive],
+        viewport: &Viewport,
+        damage: &[Rectangle],
+    ) {
+        log::debug!("Drawing damage");
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Wgpu::Backend", "PRESENT_DAMAGE").entered();
+
+        let scale_factor = viewport.scale_factor() as f32;
+        let viewport_bounds = Rectangle::with_size(viewport.logical_size());
+
+        let mut layers = Vec::new();
+
+        for region in damage {
+            // Layer bounds are snapped down to whole pixels, so the region is
+            // grown by a pixel to be fully covered
+            let x = region.x.floor() - 1.0;
+            let y = region.y.floor() - 1.0;
+
+            let bounds = Rectangle {
+                x,
+                y,
+                width: (region.x + region.width).ceil() + 1.0 - x,
+                height: (region.y + region.height).ceil() + 1.0 - y,
+            } * (1.0 / scale_factor);
+
+            let Some(bounds) = bounds.intersection(&viewport_bounds) else {
+                continue;
+            };
+
+            // The region is cleared before anything else is drawn on it
+            let mut background = Layer::new(bounds);
+
+            background.quads.add(
+                Quad {
+                    position: [bounds.x, bounds.y],
+                    size: [bounds.width, bounds.height],
+                    border_color: color::pack(Color::TRANSPARENT),
+                    border_radius: [0.0; 4],
+                    border_width: 0.0,
+                    shadow_color: [0.0; 4],
+                    shadow_offset: [0.0; 2],
+                    shadow_blur_radius: 0.0,
+                },
+                &Background::Color(background_color),
+            );
+
+            layers.push(background);
+            layers.extend(Layer::generate_clipped(primitives, bounds));
+        }
+
+        self.draw(
+            device, queue, encoder, None, format, frame, viewport, &layers,
+        );
+    }
+
+    fn draw(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        clear_color: Option<Color>,
+        format: wgpu::TextureFormat,
+        frame: &wgpu::TextureView,
+        viewport: &Viewport,
+        layers: &[Layer<'_>],
+    ) {
+        let target_size = viewport.physical_size();
+        let scale_factor = viewport.scale_factor() as f32;
+        let transformation = viewport.projection();
+
         self.prepare(
             device,
             queue,
@@ -97,7 +194,7 @@ impl Backend {
             scale_factor,
             target_size,
             transformation,
-            &layers,
+            layers,
         );
 
         self.render(
@@ -107,11 +204,12 @@ impl Backend {
             clear_color,
             scale_factor,
             target_size,
-            &layers,
+            layers,
         );
 
         self.quad_pipeline.end_frame();
         self.text_pipeline.end_frame();
+        self.grid_pipeline.end_frame();
         self.triangle_pipeline.end_frame();
 
         #[cfg(any(feature = "image", feature = "svg"))]
@@ -123,7 +221,7 @@ impl Backend {
         device: &wgpu::Device,
         queue: &wgpu::Queue,
         format: wgpu::TextureFormat,
-        _encoder: &mut wgpu::CommandEncoder,
+        encoder: &mut wgpu::CommandEncoder,
         scale_factor: f32,
         target_size: Size<u32>,
         transformation: Transformation,
@@ -167,7 +265,7 @@ impl Backend {
                     self.image_pipeline.prepare(
                         device,
                         queue,
-                        _encoder,
+                        encoder,
                         &layer.images,
                         scaled,
                         scale_factor,
@@ -175,6 +273,18 @@ impl Backend {
                 }
             }
 
+            if !layer.grids.is_empty() {
+                self.grid_pipeline.prepare(
+                    device,
+                    queue,
+                    encoder,
+                    &layer.grids,
+                    layer.bounds,
+                    transformation,
+                    scale_factor,
+                );
+            }
+
             if !layer.text.is_empty() {
                 self.text_pipeline.prepare(
                     device,
@@ -218,6 +328,7 @@ impl Backend {
         let mut triangle_layer = 0;
         #[cfg(any(feature = "image", feature = "svg"))]
         let mut image_layer = 0;
+        let mut grid_layer = 0;
         let mut text_layer = 0;
 
         let mut render_pass = ManuallyDrop::new(encoder.begin_render_pass(
@@ -316,6 +427,12 @@ impl Backend {
                 }
             }
 
+            if !layer.grids.is_empty() {
+                self.grid_pipeline.render(grid_layer, &mut render_pass);
+
+                grid_layer += 1;
+            }
+
             if !layer.text.is_empty() {
                 self.text_pipeline
                     .render(text_layer, bounds, &mut render_pass);
@@ -1,5 +1,5 @@
 //! Build and draw geometry.
-use crate::core::text::LineHeight;
+use crate::core::text::{LineHeight, Truncate, Wrap};
 use crate::core::{Pixels, Point, Rectangle, Size, Vector};
 use crate::graphics::color;
 use crate::graphics::geometry::fill::{self, Fill};
@@ -375,6 +375,8 @@ impl Frame {
                 horizontal_alignment: text.horizontal_alignment,
                 vertical_alignment: text.vertical_alignment,
                 shaping: text.shaping,
+                wrap: Wrap::default(),
+                truncate: Truncate::default(),
                 clip_bounds: Rectangle::with_size(Size::INFINITY),
             });
         } else {
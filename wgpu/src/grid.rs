@@ -0,0 +1,713 @@
+use crate::core::{Color, Rectangle};
+use crate::graphics::color;
+use crate::graphics::text::cosmic_text;
+use crate::graphics::text::font_system;
+use crate::graphics::text::grid::{self, Glyphs};
+use crate::graphics::Transformation;
+use crate::layer;
+use crate::Buffer;
+
+use bytemuck::{Pod, Zeroable};
+use std::collections::HashMap;
+use std::mem;
+use std::ops::Range;
+
+#[cfg(feature = "tracing")]
+use tracing::info_span;
+
+const INITIAL_INSTANCES: usize = 10_000;
+const INITIAL_ATLAS_SIZE: u32 = 512;
+
+/// Draws every visible cell of a grid of monospace characters as an instance
+/// of a single draw call, with its background and the glyph of its character
+/// looked up in an atlas.
+#[allow(missing_debug_implementations)]
+pub struct Pipeline {
+    pipeline: wgpu::RenderPipeline,
+    constant_layout: wgpu::BindGroupLayout,
+    atlas: Atlas,
+    glyphs: Glyphs,
+    layers: Vec<Layer>,
+    prepare_layer: usize,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let constant_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::grid constants layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: wgpu::BufferSize::new(
+                                mem::size_of::<Uniforms>()
+                                    as wgpu::BufferAddress,
+                            ),
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float {
+                                filterable: false,
+                            },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::grid pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&constant_layout],
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu::grid shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    concat!(
+                        include_str!("shader/vertex.wgsl"),
+                        "\n",
+                        include_str!("shader/grid.wgsl"),
+                    ),
+                )),
+            });
+
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu::grid pipeline"),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[wgpu::VertexBufferLayout {
+                        array_stride: mem::size_of::<Instance>() as u64,
+                        step_mode: wgpu::VertexStepMode::Instance,
+                        attributes: &wgpu::vertex_attr_array!(
+                            // Position
+                            0 => Float32x2,
+                            // Size
+                            1 => Float32x2,
+                            // Foreground color
+                            2 => Float32x4,
+                            // Background color
+                            3 => Float32x4,
+                            // Glyph offset
+                            4 => Float32x2,
+                            // Glyph size
+                            5 => Float32x2,
+                            // Atlas position
+                            6 => Float32x2,
+                            // Is color
+                            7 => Uint32,
+                        ),
+                    }],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState {
+                            color: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::SrcAlpha,
+                                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                                operation: wgpu::BlendOperation::Add,
+                            },
+                            alpha: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::One,
+                                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                                operation: wgpu::BlendOperation::Add,
+                            },
+                        }),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+            });
+
+        Self {
+            pipeline,
+            constant_layout,
+            atlas: Atlas::new(device),
+            glyphs: Glyphs::new(),
+            layers: Vec::new(),
+            prepare_layer: 0,
+        }
+    }
+
+    pub fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        grids: &[layer::Grid],
+        layer_bounds: Rectangle,
+        transformation: Transformation,
+        scale_factor: f32,
+    ) {
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Wgpu::Grid", "PREPARE").entered();
+
+        if self.layers.len() <= self.prepare_layer {
+            self.layers.push(Layer::new(device));
+        }
+
+        let mut font_system = font_system().write().expect("Write font system");
+        let font_system = font_system.raw();
+
+        let mut swash = cosmic_text::SwashCache::new();
+        let mut instances = Vec::new();
+        let mut draws = Vec::new();
+
+        let layer_bounds = layer_bounds * scale_factor;
+
+        for section in grids {
+            let Some(grid) = section.grid.upgrade() else {
+                continue;
+            };
+
+            let Some(clip_bounds) = layer_bounds
+                .intersection(&(section.clip_bounds * scale_factor))
+            else {
+                continue;
+            };
+
+            let clip_bounds = clip_bounds.snap();
+
+            if clip_bounds.width < 1 || clip_bounds.height < 1 {
+                continue;
+            }
+
+            let start = instances.len();
+
+            for (column, row, cell) in grid.visible_cells(section.rows.clone())
+            {
+                let glyph = match self.glyphs.get(
+                    font_system,
+                    &grid,
+                    cell.character,
+                    scale_factor,
+                ) {
+                    Some(glyph) => self
+                        .atlas
+                        .upload(device, encoder, font_system, &mut swash, glyph)
+                        .map(|entry| (glyph, entry)),
+                    None => None,
+                };
+
+                if glyph.is_none() && cell.background.is_none() {
+                    continue;
+                }
+
+                let (glyph_offset, glyph_size, atlas_position, is_color) =
+                    match glyph {
+                        Some((glyph, entry)) => (
+                            [
+                                (glyph.x + entry.left) as f32,
+                                (glyph.baseline - entry.top) as f32,
+                            ],
+                            [entry.width as f32, entry.height as f32],
+                            [entry.x as f32, entry.y as f32],
+                            u32::from(entry.is_color),
+                        ),
+                        None => ([0.0; 2], [0.0; 2], [0.0; 2], 0),
+                    };
+
+                instances.push(Instance {
+                    position: [
+                        section.position.x
+                            + column as f32 * section.cell_size.width,
+                        section.position.y
+                            + row as f32 * section.cell_size.height,
+                    ],
+                    size: [section.cell_size.width, section.cell_size.height],
+                    foreground: color::pack(
+                        cell.foreground.unwrap_or(section.color),
+                    ),
+                    background: color::pack(
+                        cell.background.unwrap_or(Color::TRANSPARENT),
+                    ),
+                    glyph_offset,
+                    glyph_size,
+                    atlas_position,
+                    is_color,
+                });
+            }
+
+            if instances.len() > start {
+                draws.push(Draw {
+                    clip_bounds,
+                    instances: start as u32..instances.len() as u32,
+                });
+            }
+        }
+
+        let layer = &mut self.layers[self.prepare_layer];
+
+        layer.prepare(
+            device,
+            queue,
+            &self.constant_layout,
+            &self.atlas,
+            &instances,
+            draws,
+            transformation,
+            scale_factor,
+        );
+
+        self.prepare_layer += 1;
+    }
+
+    pub fn render<'a>(
+        &'a self,
+        layer: usize,
+        render_pass: &mut wgpu::RenderPass<'a>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Wgpu::Grid", "DRAW").entered();
+
+        let Some(layer) = self.layers.get(layer) else {
+            return;
+        };
+
+        let Some(constants) = &layer.constants else {
+            return;
+        };
+
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, constants, &[]);
+        render_pass.set_vertex_buffer(0, layer.instances.slice(..));
+
+        for draw in &layer.draws {
+            render_pass.set_scissor_rect(
+                draw.clip_bounds.x,
+                draw.clip_bounds.y,
+                draw.clip_bounds.width,
+                draw.clip_bounds.height,
+            );
+
+            render_pass.draw(0..6, draw.instances.clone());
+        }
+    }
+
+    pub fn end_frame(&mut self) {
+        self.glyphs.trim();
+        self.atlas.end_frame();
+
+        self.prepare_layer = 0;
+    }
+}
+
+struct Layer {
+    constants: Option<wgpu::BindGroup>,
+    constants_buffer: wgpu::Buffer,
+    atlas_version: usize,
+    instances: Buffer<Instance>,
+    draws: Vec<Draw>,
+}
+
+struct Draw {
+    clip_bounds: Rectangle<u32>,
+    instances: Range<u32>,
+}
+
+impl Layer {
+    fn new(device: &wgpu::Device) -> Self {
+        let constants_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::grid uniforms buffer"),
+            size: mem::size_of::<Uniforms>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let instances = Buffer::new(
+            device,
+            "iced_wgpu::grid instance buffer",
+            INITIAL_INSTANCES,
+            wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+        );
+
+        Self {
+            constants: None,
+            constants_buffer,
+            atlas_version: 0,
+            instances,
+            draws: Vec::new(),
+        }
+    }
+
+    fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        constant_layout: &wgpu::BindGroupLayout,
+        atlas: &Atlas,
+        instances: &[Instance],
+        draws: Vec<Draw>,
+        transformation: Transformation,
+        scale_factor: f32,
+    ) {
+        // The atlas texture is replaced every time it grows
+        if self.constants.is_none() || self.atlas_version != atlas.version {
+            self.constants =
+                Some(device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("iced_wgpu::grid constants bind group"),
+                    layout: constant_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: self.constants_buffer.as_entire_binding(),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::TextureView(
+                                &atlas.view,
+                            ),
+                        },
+                    ],
+                }));
+
+            self.atlas_version = atlas.version;
+        }
+
+        queue.write_buffer(
+            &self.constants_buffer,
+            0,
+            bytemuck::bytes_of(&Uniforms {
+                transform: *transformation.as_ref(),
+                scale: scale_factor,
+                _padding: [0.0; 3],
+            }),
+        );
+
+        if !instances.is_empty() {
+            let _ = self.instances.resize(device, instances.len());
+            let _ = self.instances.write(queue, 0, instances);
+        }
+
+        self.draws = draws;
+    }
+}
+
+/// A cell of a grid, with its background and glyph.
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+struct Instance {
+    /// The position of the cell, in logical pixels.
+    position: [f32; 2],
+
+    /// The size of the cell, in logical pixels.
+    size: [f32; 2],
+
+    /// The color of the glyph, unless it has colors of its own.
+    foreground: color::Packed,
+
+    /// The background color of the cell.
+    background: color::Packed,
+
+    /// The offset of the glyph from the start of the cell, in physical
+    /// pixels.
+    glyph_offset: [f32; 2],
+
+    /// The size of the glyph, in physical pixels.
+    glyph_size: [f32; 2],
+
+    /// The position of the glyph in the atlas.
+    atlas_position: [f32; 2],
+
+    /// Whether the glyph has colors of its own, like an emoji.
+    is_color: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Uniforms {
+    transform: [f32; 16],
+    scale: f32,
+    // Uniforms must be aligned to their largest member,
+    // this uses a mat4x4<f32> which aligns to 16, so align to that
+    _padding: [f32; 3],
+}
+
+/// The rasterized glyphs of some grids, packed in a single texture that
+/// grows as needed.
+struct Atlas {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    size: u32,
+    version: usize,
+    allocator: guillotiere::AtlasAllocator,
+    entries: HashMap<cosmic_text::CacheKey, Option<Entry>>,
+    is_full: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    left: i32,
+    top: i32,
+    is_color: bool,
+}
+
+impl Atlas {
+    fn new(device: &wgpu::Device) -> Self {
+        let texture = create_texture(device, INITIAL_ATLAS_SIZE);
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        Self {
+            texture,
+            view,
+            size: INITIAL_ATLAS_SIZE,
+            version: 0,
+            allocator: guillotiere::AtlasAllocator::new(
+                guillotiere::Size::new(
+                    INITIAL_ATLAS_SIZE as i32,
+                    INITIAL_ATLAS_SIZE as i32,
+                ),
+            ),
+            entries: HashMap::new(),
+            is_full: false,
+        }
+    }
+
+    /// Returns the [`Entry`] of the given glyph, rasterizing it and uploading
+    /// it to the atlas if it is not already present.
+    ///
+    /// Returns `None` if the glyph draws nothing.
+    fn upload(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        font_system: &mut cosmic_text::FontSystem,
+        swash: &mut cosmic_text::SwashCache,
+        glyph: grid::Glyph,
+    ) -> Option<Entry> {
+        if let Some(entry) = self.entries.get(&glyph.cache_key) {
+            return *entry;
+        }
+
+        let Some(image) =
+            swash.get_image_uncached(font_system, glyph.cache_key)
+        else {
+            let _ = self.entries.insert(glyph.cache_key, None);
+
+            return None;
+        };
+
+        let (width, height) = (image.placement.width, image.placement.height);
+
+        let data: Vec<u8> = match image.content {
+            cosmic_text::SwashContent::Mask => image
+                .data
+                .iter()
+                .flat_map(|&coverage| [255, 255, 255, coverage])
+                .collect(),
+            cosmic_text::SwashContent::Color => image.data.clone(),
+            cosmic_text::SwashContent::SubpixelMask => Vec::new(),
+        };
+
+        if width == 0 || height == 0 || data.is_empty() {
+            let _ = self.entries.insert(glyph.cache_key, None);
+
+            return None;
+        }
+
+        let (x, y) = self.allocate(device, encoder, width, height)?;
+
+        // It is a webgpu requirement that:
+        //   BufferCopyView.layout.bytes_per_row % wgpu::COPY_BYTES_PER_ROW_ALIGNMENT == 0
+        // So we pad every row up to the next multiple of
+        // wgpu::COPY_BYTES_PER_ROW_ALIGNMENT.
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padding = (align - (4 * width) % align) % align;
+        let padded_width = (4 * width + padding) as usize;
+
+        let mut padded_data = vec![0; padded_width * height as usize];
+
+        for row in 0..height as usize {
+            let offset = row * padded_width;
+
+            padded_data[offset..offset + 4 * width as usize].copy_from_slice(
+                &data[row * 4 * width as usize..(row + 1) * 4 * width as usize],
+            );
+        }
+
+        {
+            use wgpu::util::DeviceExt;
+
+            let buffer =
+                device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("iced_wgpu::grid glyph upload buffer"),
+                    contents: &padded_data,
+                    usage: wgpu::BufferUsages::COPY_SRC,
+                });
+
+            encoder.copy_buffer_to_texture(
+                wgpu::ImageCopyBuffer {
+                    buffer: &buffer,
+                    layout: wgpu::ImageDataLayout {
+                        offset: 0,
+                        bytes_per_row: Some(padded_width as u32),
+                        rows_per_image: Some(height),
+                    },
+                },
+                wgpu::ImageCopyTexture {
+                    texture: &self.texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d { x, y, z: 0 },
+                    aspect: wgpu::TextureAspect::default(),
+                },
+                wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+            );
+        }
+
+        let entry = Entry {
+            x,
+            y,
+            width,
+            height,
+            left: image.placement.left,
+            top: image.placement.top,
+            is_color: matches!(image.content, cosmic_text::SwashContent::Color),
+        };
+
+        let _ = self.entries.insert(glyph.cache_key, Some(entry));
+
+        Some(entry)
+    }
+
+    /// Allocates a region of the given size in the atlas, growing its texture
+    /// if needed.
+    fn allocate(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        width: u32,
+        height: u32,
+    ) -> Option<(u32, u32)> {
+        let size = guillotiere::Size::new(width as i32, height as i32);
+
+        loop {
+            if let Some(allocation) = self.allocator.allocate(size) {
+                let position = allocation.rectangle.min;
+
+                return Some((position.x as u32, position.y as u32));
+            }
+
+            let max_size = device.limits().max_texture_dimension_2d;
+
+            if self.size >= max_size {
+                // Past the maximum texture size of the device, we skip the
+                // glyph for this frame and start over in the next one.
+                log::warn!("Grid atlas is full, skipping glyph");
+
+                self.is_full = true;
+
+                return None;
+            }
+
+            self.grow((self.size * 2).min(max_size), device, encoder);
+        }
+    }
+
+    /// Replaces the texture of the atlas with a bigger one, keeping every
+    /// glyph where it was.
+    fn grow(
+        &mut self,
+        size: u32,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        let texture = create_texture(device, size);
+
+        encoder.copy_texture_to_texture(
+            wgpu::ImageCopyTexture {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::default(),
+            },
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::default(),
+            },
+            wgpu::Extent3d {
+                width: self.size,
+                height: self.size,
+                depth_or_array_layers: 1,
+            },
+        );
+
+        self.allocator
+            .grow(guillotiere::Size::new(size as i32, size as i32));
+
+        self.view =
+            texture.create_view(&wgpu::TextureViewDescriptor::default());
+        self.texture = texture;
+        self.size = size;
+        self.version += 1;
+    }
+
+    fn end_frame(&mut self) {
+        if self.is_full {
+            self.allocator.clear();
+            self.entries.clear();
+            self.is_full = false;
+        }
+    }
+}
+
+fn create_texture(device: &wgpu::Device, size: u32) -> wgpu::Texture {
+    device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("iced_wgpu::grid texture atlas"),
+        size: wgpu::Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: if color::GAMMA_CORRECTION {
+            wgpu::TextureFormat::Rgba8UnormSrgb
+        } else {
+            wgpu::TextureFormat::Rgba8Unorm
+        },
+        usage: wgpu::TextureUsages::COPY_DST
+            | wgpu::TextureUsages::COPY_SRC
+            | wgpu::TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    })
+}
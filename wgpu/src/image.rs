@@ -282,6 +282,12 @@ impl Pipeline {
                             3 => Float32x2,
                             // Layer
                             4 => Sint32,
+                            // Center
+                            5 => Float32x2,
+                            // Rotation
+                            6 => Float32,
+                            // Opacity
+                            7 => Float32,
                         ),
                     }],
                 },
@@ -400,6 +406,8 @@ impl Pipeline {
                     handle,
                     filter_method,
                     bounds,
+                    rotation,
+                    opacity,
                     ..
                 } => {
                     if let Some(atlas_entry) = raster_cache.upload(
@@ -411,6 +419,8 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
+                            rotation.0,
+                            *opacity,
                             atlas_entry,
                             match filter_method {
                                 image::FilterMethod::Nearest => {
@@ -429,6 +439,8 @@ impl Pipeline {
                     handle,
                     color,
                     bounds,
+                    rotation,
+                    opacity,
                 } => {
                     let size = [bounds.width, bounds.height];
 
@@ -444,6 +456,8 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             size,
+                            rotation.0,
+                            *opacity,
                             atlas_entry,
                             nearest_instances,
                         );
@@ -541,6 +555,9 @@ struct Instance {
     _position_in_atlas: [f32; 2],
     _size_in_atlas: [f32; 2],
     _layer: u32,
+    _center: [f32; 2],
+    _rotation: f32,
+    _opacity: f32,
 }
 
 impl Instance {
@@ -556,12 +573,27 @@ struct Uniforms {
 fn add_instances(
     image_position: [f32; 2],
     image_size: [f32; 2],
+    rotation: f32,
+    opacity: f32,
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
+    let center = [
+        image_position[0] + image_size[0] / 2.0,
+        image_position[1] + image_size[1] / 2.0,
+    ];
+
     match entry {
         atlas::Entry::Contiguous(allocation) => {
-            add_instance(image_position, image_size, allocation, instances);
+            add_instance(
+                image_position,
+                image_size,
+                center,
+                rotation,
+                opacity,
+                allocation,
+                instances,
+            );
         }
         atlas::Entry::Fragmented { fragments, size } => {
             let scaling_x = image_size[0] / size.width as f32;
@@ -587,7 +619,10 @@ fn add_instances(
                     fragment_height as f32 * scaling_y,
                 ];
 
-                add_instance(position, size, allocation, instances);
+                add_instance(
+                    position, size, center, rotation, opacity, allocation,
+                    instances,
+                );
             }
         }
     }
@@ -597,6 +632,9 @@ fn add_instances(
 fn add_instance(
     position: [f32; 2],
     size: [f32; 2],
+    center: [f32; 2],
+    rotation: f32,
+    opacity: f32,
     allocation: &atlas::Allocation,
     instances: &mut Vec<Instance>,
 ) {
@@ -616,6 +654,9 @@ fn add_instance(
             height as f32 / atlas::SIZE as f32,
         ],
         _layer: layer as u32,
+        _center: center,
+        _rotation: rotation,
+        _opacity: opacity,
     };
 
     instances.push(instance);
@@ -1,10 +1,12 @@
 //! Organize rendering primitives into a flattened list of layers.
+mod grid;
 mod image;
 mod pipeline;
 mod text;
 
 pub mod mesh;
 
+pub use grid::Grid;
 pub use image::Image;
 pub use mesh::Mesh;
 pub use pipeline::Pipeline;
@@ -19,6 +21,8 @@ use crate::graphics::Viewport;
 use crate::primitive::{self, Primitive};
 use crate::quad::{self, Quad};
 
+use std::sync::Arc;
+
 /// A group of primitives that should be clipped together.
 #[derive(Debug)]
 pub struct Layer<'a> {
@@ -34,6 +38,9 @@ pub struct Layer<'a> {
     /// The text of the [`Layer`].
     pub text: Vec<Text<'a>>,
 
+    /// The grids of monospace characters of the [`Layer`].
+    pub grids: Vec<Grid>,
+
     /// The images of the [`Layer`].
     pub images: Vec<Image>,
 
@@ -49,6 +56,7 @@ impl<'a> Layer<'a> {
             quads: quad::Batch::default(),
             meshes: Vec::new(),
             text: Vec::new(),
+            grids: Vec::new(),
             images: Vec::new(),
             pipelines: Vec::new(),
         }
@@ -76,6 +84,8 @@ impl<'a> Layer<'a> {
                 vertical_alignment: alignment::Vertical::Top,
                 clip_bounds: Rectangle::with_size(Size::INFINITY),
                 shaping: core::text::Shaping::Advanced,
+                wrap: core::text::Wrap::None,
+                truncate: core::text::Truncate::None,
             };
 
             overlay.text.push(Text::Cached(text.clone()));
@@ -96,10 +106,19 @@ impl<'a> Layer<'a> {
         primitives: &'a [Primitive],
         viewport: &Viewport,
     ) -> Vec<Self> {
-        let first_layer =
-            Layer::new(Rectangle::with_size(viewport.logical_size()));
+        Self::generate_clipped(
+            primitives,
+            Rectangle::with_size(viewport.logical_size()),
+        )
+    }
 
-        let mut layers = vec![first_layer];
+    /// Distributes the given [`Primitive`] and generates a list of layers
+    /// based on its contents, all clipped to the given bounds.
+    pub fn generate_clipped(
+        primitives: &'a [Primitive],
+        bounds: Rectangle,
+    ) -> Vec<Self> {
+        let mut layers = vec![Layer::new(bounds)];
 
         for primitive in primitives {
             Self::process_primitive(
@@ -107,6 +126,7 @@ impl<'a> Layer<'a> {
                 Vector::new(0.0, 0.0),
                 primitive,
                 0,
+                None,
             );
         }
 
@@ -118,6 +138,7 @@ impl<'a> Layer<'a> {
         translation: Vector,
         primitive: &'a Primitive,
         current_layer: usize,
+        cache: Option<&'a Arc<Primitive>>,
     ) {
         match primitive {
             Primitive::Paragraph {
@@ -128,6 +149,19 @@ impl<'a> Layer<'a> {
             } => {
                 let layer = &mut layers[current_layer];
 
+                // Only draw visible content
+                if !is_visible(
+                    layer,
+                    text_bounds(
+                        *position + translation,
+                        paragraph.min_bounds,
+                        paragraph.horizontal_alignment,
+                        paragraph.vertical_alignment,
+                    ),
+                ) {
+                    return;
+                }
+
                 layer.text.push(Text::Paragraph {
                     paragraph: paragraph.clone(),
                     position: *position + translation,
@@ -160,10 +194,25 @@ impl<'a> Layer<'a> {
                 horizontal_alignment,
                 vertical_alignment,
                 shaping,
+                wrap,
+                truncate,
                 clip_bounds,
             } => {
                 let layer = &mut layers[current_layer];
 
+                // Only draw visible content
+                if !is_visible(
+                    layer,
+                    text_bounds(
+                        bounds.position() + translation,
+                        bounds.size(),
+                        *horizontal_alignment,
+                        *vertical_alignment,
+                    ),
+                ) {
+                    return;
+                }
+
                 layer.text.push(Text::Cached(text::Cached {
                     content,
                     bounds: *bounds + translation,
@@ -174,6 +223,8 @@ impl<'a> Layer<'a> {
                     horizontal_alignment: *horizontal_alignment,
                     vertical_alignment: *vertical_alignment,
                     shaping: *shaping,
+                    wrap: *wrap,
+                    truncate: *truncate,
                     clip_bounds: *clip_bounds + translation,
                 }));
             }
@@ -185,6 +236,11 @@ impl<'a> Layer<'a> {
             }) => {
                 let layer = &mut layers[current_layer];
 
+                // Only draw visible content
+                if !is_visible(layer, *clip_bounds + translation) {
+                    return;
+                }
+
                 layer.text.push(Text::Raw(graphics::text::Raw {
                     buffer: buffer.clone(),
                     position: *position + translation,
@@ -192,6 +248,30 @@ impl<'a> Layer<'a> {
                     clip_bounds: *clip_bounds + translation,
                 }));
             }
+            Primitive::Grid {
+                grid,
+                position,
+                cell_size,
+                rows,
+                color,
+                clip_bounds,
+            } => {
+                let layer = &mut layers[current_layer];
+
+                // Only draw visible content
+                if !is_visible(layer, *clip_bounds + translation) {
+                    return;
+                }
+
+                layer.grids.push(Grid {
+                    grid: grid.clone(),
+                    position: *position + translation,
+                    cell_size: *cell_size,
+                    rows: rows.clone(),
+                    color: *color,
+                    clip_bounds: *clip_bounds + translation,
+                });
+            }
             Primitive::Quad {
                 bounds,
                 background,
@@ -200,6 +280,16 @@ impl<'a> Layer<'a> {
             } => {
                 let layer = &mut layers[current_layer];
 
+                // Only draw visible content, including the shadow
+                if !is_visible(
+                    layer,
+                    (*bounds + translation + shadow.offset)
+                        .expand(shadow.blur_radius)
+                        .union(&(*bounds + translation)),
+                ) {
+                    return;
+                }
+
                 let quad = Quad {
                     position: [
                         bounds.x + translation.x,
@@ -220,14 +310,23 @@ impl<'a> Layer<'a> {
                 handle,
                 filter_method,
                 bounds,
+                rotation,
+                opacity,
                 border_radius,
             } => {
                 let layer = &mut layers[current_layer];
 
+                // Only draw visible content
+                if !is_visible(layer, bounds.rotate(*rotation) + translation) {
+                    return;
+                }
+
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
                     filter_method: *filter_method,
                     bounds: *bounds + translation,
+                    rotation: *rotation,
+                    opacity: *opacity,
                     border_radius: *border_radius,
                 });
             }
@@ -235,13 +334,22 @@ impl<'a> Layer<'a> {
                 handle,
                 color,
                 bounds,
+                rotation,
+                opacity,
             } => {
                 let layer = &mut layers[current_layer];
 
+                // Only draw visible content
+                if !is_visible(layer, bounds.rotate(*rotation) + translation) {
+                    return;
+                }
+
                 layer.images.push(Image::Vector {
                     handle: handle.clone(),
                     color: *color,
                     bounds: *bounds + translation,
+                    rotation: *rotation,
+                    opacity: *opacity,
                 });
             }
             Primitive::Group { primitives } => {
@@ -252,6 +360,7 @@ impl<'a> Layer<'a> {
                         translation,
                         primitive,
                         current_layer,
+                        cache,
                     );
                 }
             }
@@ -271,6 +380,7 @@ impl<'a> Layer<'a> {
                         translation,
                         content,
                         layers.len() - 1,
+                        cache,
                     );
                 }
             }
@@ -283,6 +393,7 @@ impl<'a> Layer<'a> {
                     translation + *new_translation,
                     content,
                     current_layer,
+                    cache,
                 );
             }
             Primitive::Cache { content } => {
@@ -291,6 +402,7 @@ impl<'a> Layer<'a> {
                     translation,
                     content,
                     current_layer,
+                    Some(content),
                 );
             }
             Primitive::Custom(custom) => match custom {
@@ -314,6 +426,7 @@ impl<'a> Layer<'a> {
                                 ),
                                 buffers,
                                 clip_bounds,
+                                cache,
                             });
                         }
                     }
@@ -336,6 +449,7 @@ impl<'a> Layer<'a> {
                                 ),
                                 buffers,
                                 clip_bounds,
+                                cache,
                             });
                         }
                     }
@@ -358,3 +472,35 @@ impl<'a> Layer<'a> {
         }
     }
 }
+
+/// Returns whether content with the given bounds may be visible in the
+/// [`Layer`].
+///
+/// Content with unbounded dimensions is always considered visible.
+fn is_visible(layer: &Layer<'_>, bounds: Rectangle) -> bool {
+    !(bounds.width.is_finite() && bounds.height.is_finite())
+        || layer.bounds.intersects(&bounds)
+}
+
+/// Returns the bounds of some text of the given size placed at the given
+/// position with the given alignment.
+fn text_bounds(
+    position: Point,
+    size: Size,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+) -> Rectangle {
+    let x = match horizontal_alignment {
+        alignment::Horizontal::Left => position.x,
+        alignment::Horizontal::Center => position.x - size.width / 2.0,
+        alignment::Horizontal::Right => position.x - size.width,
+    };
+
+    let y = match vertical_alignment {
+        alignment::Vertical::Top => position.y,
+        alignment::Vertical::Center => position.y - size.height / 2.0,
+        alignment::Vertical::Bottom => position.y - size.height,
+    };
+
+    Rectangle::new(Point::new(x, y), size)
+}
@@ -0,0 +1,26 @@
+use crate::core::{Color, Point, Rectangle, Size};
+use crate::graphics::text::grid;
+
+use std::ops::Range;
+
+/// A grid of monospace characters.
+#[derive(Debug, Clone)]
+pub struct Grid {
+    /// The [`grid::Weak`] reference to the cells of the [`Grid`].
+    pub grid: grid::Weak,
+
+    /// The position of the [`Grid`].
+    pub position: Point,
+
+    /// The size of a cell of the [`Grid`].
+    pub cell_size: Size,
+
+    /// The rows of the [`Grid`] to draw.
+    pub rows: Range<usize>,
+
+    /// The default color of the characters of the [`Grid`].
+    pub color: Color,
+
+    /// The clip bounds of the [`Grid`].
+    pub clip_bounds: Rectangle,
+}
@@ -1,6 +1,6 @@
 use crate::core::image;
 use crate::core::svg;
-use crate::core::{Color, Rectangle};
+use crate::core::{Color, Radians, Rectangle};
 
 /// A raster or vector image.
 #[derive(Debug, Clone)]
@@ -16,6 +16,12 @@ pub enum Image {
         /// The bounds of the image.
         bounds: Rectangle,
 
+        /// The clockwise rotation of the image around its center.
+        rotation: Radians,
+
+        /// The opacity of the image.
+        opacity: f32,
+
         /// Border radius to apply
         border_radius: [f32; 4],
     },
@@ -29,5 +35,11 @@ pub enum Image {
 
         /// The bounds of the image.
         bounds: Rectangle,
+
+        /// The clockwise rotation of the image around its center.
+        rotation: Radians,
+
+        /// The opacity of the image.
+        opacity: f32,
     },
 }
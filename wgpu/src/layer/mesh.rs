@@ -1,6 +1,9 @@
 //! A collection of triangle primitives.
 use crate::core::{Point, Rectangle};
 use crate::graphics::mesh;
+use crate::Primitive;
+
+use std::sync::Arc;
 
 /// A mesh of triangles.
 #[derive(Debug, Clone, Copy)]
@@ -15,6 +18,9 @@ pub enum Mesh<'a> {
 
         /// The clipping bounds of the [`Mesh`].
         clip_bounds: Rectangle<f32>,
+
+        /// The cached primitive that contains the [`Mesh`], if any.
+        cache: Option<&'a Arc<Primitive>>,
     },
     /// A mesh of triangles with a gradient color.
     Gradient {
@@ -26,10 +32,13 @@ pub enum Mesh<'a> {
 
         /// The clipping bounds of the [`Mesh`].
         clip_bounds: Rectangle<f32>,
+
+        /// The cached primitive that contains the [`Mesh`], if any.
+        cache: Option<&'a Arc<Primitive>>,
     },
 }
 
-impl Mesh<'_> {
+impl<'a> Mesh<'a> {
     /// Returns the origin of the [`Mesh`].
     pub fn origin(&self) -> Point {
         match self {
@@ -47,6 +56,13 @@ impl Mesh<'_> {
         }
     }
 
+    /// Returns the cached primitive that contains the [`Mesh`], if any.
+    pub fn cache(&self) -> Option<&'a Arc<Primitive>> {
+        match self {
+            Self::Solid { cache, .. } | Self::Gradient { cache, .. } => *cache,
+        }
+    }
+
     /// Returns the clip bounds of the [`Mesh`].
     pub fn clip_bounds(&self) -> Rectangle<f32> {
         match self {
@@ -59,6 +59,12 @@ pub struct Cached<'a> {
     /// The shaping strategy of the text.
     pub shaping: text::Shaping,
 
+    /// The wrapping strategy of the text.
+    pub wrap: text::Wrap,
+
+    /// The truncation strategy of the text.
+    pub truncate: text::Truncate,
+
     /// The clip bounds of the text.
     pub clip_bounds: Rectangle,
 }
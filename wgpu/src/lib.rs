@@ -8,6 +8,8 @@
 //!
 //! Currently, `iced_wgpu` supports the following primitives:
 //! - Text, which is rendered using [`glyphon`].
+//! - Grids of monospace characters, with every visible cell drawn in a single
+//!   instanced draw call.
 //! - Quads or rectangles, with rounded borders and a solid background color.
 //! - Clip areas, useful to implement scrollables or hide overflowing content.
 //! - Images and SVG, loaded from memory or the file system.
@@ -40,6 +42,7 @@ pub mod geometry;
 mod backend;
 mod buffer;
 mod color;
+mod grid;
 mod quad;
 mod text;
 mod triangle;
@@ -15,6 +15,18 @@ pub struct Settings {
     /// The internal graphics backend to use.
     pub internal_backend: wgpu::Backends,
 
+    /// The power preference of the graphics adapter to use.
+    ///
+    /// By default, it is `None`, which prefers a high performance adapter
+    /// when antialiasing is enabled and a low power one otherwise.
+    pub power_preference: Option<wgpu::PowerPreference>,
+
+    /// Whether a software adapter can be used when no hardware adapter is
+    /// compatible.
+    ///
+    /// By default, it is `true`.
+    pub allow_fallback_adapter: bool,
+
     /// The default [`Font`] to use.
     pub default_font: Font,
 
@@ -27,6 +39,17 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The factor applied to the spacing and padding of the built-in widgets.
+    ///
+    /// By default, it is `1.0`.
+    pub spacing_scale: f32,
+
+    /// The factor applied to the border radius of the quads drawn by the
+    /// built-in widgets.
+    ///
+    /// By default, it is `1.0`.
+    pub border_radius_scale: f32,
 }
 
 impl Settings {
@@ -44,10 +67,13 @@ impl Settings {
     ///     - `gl`
     ///     - `webgpu`
     ///     - `primary`
+    /// - The `power_preference` can be configured using the
+    /// `WGPU_POWER_PREF` environment variable, with either `low` or `high`.
     pub fn from_env() -> Self {
         Settings {
             internal_backend: wgpu::util::backend_bits_from_env()
                 .unwrap_or(wgpu::Backends::all()),
+            power_preference: wgpu::util::power_preference_from_env(),
             ..Self::default()
         }
     }
@@ -58,9 +84,13 @@ impl Default for Settings {
         Settings {
             present_mode: wgpu::PresentMode::AutoVsync,
             internal_backend: wgpu::Backends::all(),
+            power_preference: None,
+            allow_fallback_adapter: true,
             default_font: Font::default(),
             default_text_size: Pixels(14.0),
             antialiasing: None,
+            spacing_scale: 1.0,
+            border_radius_scale: 1.0,
         }
     }
 }
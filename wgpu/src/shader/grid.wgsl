@@ -0,0 +1,76 @@
+struct Globals {
+    transform: mat4x4<f32>,
+    scale: f32,
+}
+
+@group(0) @binding(0) var<uniform> globals: Globals;
+@group(0) @binding(1) var atlas: texture_2d<f32>;
+
+struct VertexInput {
+    @builtin(vertex_index) vertex_index: u32,
+    @location(0) position: vec2<f32>,
+    @location(1) size: vec2<f32>,
+    @location(2) foreground: vec4<f32>,
+    @location(3) background: vec4<f32>,
+    @location(4) glyph_offset: vec2<f32>,
+    @location(5) glyph_size: vec2<f32>,
+    @location(6) atlas_position: vec2<f32>,
+    @location(7) is_color: u32,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) @interpolate(flat) foreground: vec4<f32>,
+    @location(1) @interpolate(flat) background: vec4<f32>,
+    @location(2) @interpolate(flat) glyph_position: vec2<f32>,
+    @location(3) @interpolate(flat) glyph_size: vec2<f32>,
+    @location(4) @interpolate(flat) atlas_position: vec2<f32>,
+    @location(5) @interpolate(flat) is_color: u32,
+}
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+
+    let position = input.position * globals.scale;
+    let size = input.size * globals.scale;
+
+    out.position = globals.transform * vec4<f32>(position + vertex_position(input.vertex_index) * size, 0.0, 1.0);
+    out.foreground = input.foreground;
+    out.background = input.background;
+    // Glyphs are placed on whole pixels, so they can be copied from the atlas as they are
+    out.glyph_position = round(position) + input.glyph_offset;
+    out.glyph_size = input.glyph_size;
+    out.atlas_position = input.atlas_position;
+    out.is_color = input.is_color;
+
+    return out;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let texel = floor(input.position.xy - input.glyph_position);
+
+    var glyph = vec4<f32>(0.0, 0.0, 0.0, 0.0);
+
+    if all(texel >= vec2<f32>(0.0, 0.0)) && all(texel < input.glyph_size) {
+        let atlas_color = textureLoad(atlas, vec2<i32>(input.atlas_position + texel), 0);
+
+        glyph = select(
+            vec4<f32>(input.foreground.rgb, input.foreground.a * atlas_color.a),
+            atlas_color,
+            input.is_color == 1u
+        );
+    }
+
+    // Blend the glyph over the background of the cell
+    let alpha = glyph.a + input.background.a * (1.0 - glyph.a);
+
+    if alpha <= 0.0 {
+        discard;
+    }
+
+    let color = (glyph.rgb * glyph.a + input.background.rgb * input.background.a * (1.0 - glyph.a)) / alpha;
+
+    return vec4<f32>(color, alpha);
+}
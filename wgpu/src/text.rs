@@ -14,6 +14,7 @@ pub struct Pipeline {
     renderers: Vec<glyphon::TextRenderer>,
     atlas: glyphon::TextAtlas,
     prepare_layer: usize,
+    skipped_layers: Vec<usize>,
     cache: RefCell<Cache>,
 }
 
@@ -36,6 +37,7 @@ impl Pipeline {
                 },
             ),
             prepare_layer: 0,
+            skipped_layers: Vec::new(),
             cache: RefCell::new(Cache::new()),
         }
     }
@@ -104,6 +106,8 @@ impl Pipeline {
                                 height: text.bounds.height,
                             },
                             shaping: text.shaping,
+                            wrap: text.wrap,
+                            truncate: text.truncate,
                         },
                     );
 
@@ -260,15 +264,21 @@ impl Pipeline {
         );
 
         match result {
-            Ok(()) => {
-                self.prepare_layer += 1;
-            }
+            Ok(()) => {}
             Err(glyphon::PrepareError::AtlasFull) => {
-                // If the atlas cannot grow, then all bets are off.
-                // Instead of panicking, we will just pray that the result
-                // will be somewhat readable...
+                // The atlas grows on its own until it reaches the maximum
+                // texture size of the device. Past that point, we skip the
+                // text of the layer instead of panicking.
+                log::warn!(
+                    "Text atlas is full, skipping text of layer {}",
+                    self.prepare_layer
+                );
+
+                self.skipped_layers.push(self.prepare_layer);
             }
         }
+
+        self.prepare_layer += 1;
     }
 
     pub fn render<'a>(
@@ -277,7 +287,13 @@ impl Pipeline {
         bounds: Rectangle<u32>,
         render_pass: &mut wgpu::RenderPass<'a>,
     ) {
-        let renderer = &self.renderers[layer];
+        if self.skipped_layers.contains(&layer) {
+            return;
+        }
+
+        let Some(renderer) = self.renderers.get(layer) else {
+            return;
+        };
 
         render_pass.set_scissor_rect(
             bounds.x,
@@ -286,9 +302,9 @@ impl Pipeline {
             bounds.height,
         );
 
-        renderer
-            .render(&self.atlas, render_pass)
-            .expect("Render text");
+        if let Err(error) = renderer.render(&self.atlas, render_pass) {
+            log::warn!("Failed to render text of layer {layer}: {error:?}");
+        }
     }
 
     pub fn end_frame(&mut self) {
@@ -296,5 +312,6 @@ impl Pipeline {
         self.cache.get_mut().trim();
 
         self.prepare_layer = 0;
+        self.skipped_layers.clear();
     }
 }
@@ -1,10 +1,12 @@
 //! Draw meshes of triangles.
 mod msaa;
 
-use crate::core::Size;
+use crate::core::{Point, Size};
 use crate::graphics::{Antialiasing, Transformation};
 use crate::layer::mesh::{self, Mesh};
-use crate::Buffer;
+use crate::{Buffer, Primitive};
+
+use std::sync::{Arc, Weak};
 
 const INITIAL_INDEX_COUNT: usize = 1_000;
 const INITIAL_VERTEX_COUNT: usize = 1_000;
@@ -24,6 +26,43 @@ struct Layer {
     index_strides: Vec<u32>,
     solid: solid::Layer,
     gradient: gradient::Layer,
+    uploaded: Vec<Uploaded>,
+    transformation: Transformation,
+}
+
+/// A cached [`Mesh`] whose buffers were written to a [`Layer`].
+///
+/// The [`Weak`] reference keeps the allocation of the cache alive, so its
+/// address cannot be taken by a different cache while it is remembered.
+#[derive(Debug)]
+struct Uploaded {
+    cache: Weak<Primitive>,
+    buffers: usize,
+    origin: Point,
+}
+
+impl Uploaded {
+    fn new(mesh: &Mesh<'_>) -> Option<Self> {
+        Some(Self {
+            cache: Arc::downgrade(mesh.cache()?),
+            buffers: buffers_address(mesh),
+            origin: mesh.origin(),
+        })
+    }
+
+    fn matches(&self, mesh: &Mesh<'_>) -> bool {
+        mesh.cache().is_some_and(|cache| {
+            std::ptr::eq(self.cache.as_ptr(), Arc::as_ptr(cache))
+        }) && self.buffers == buffers_address(mesh)
+            && self.origin == mesh.origin()
+    }
+}
+
+fn buffers_address(mesh: &Mesh<'_>) -> usize {
+    match mesh {
+        Mesh::Solid { buffers, .. } => *buffers as *const _ as usize,
+        Mesh::Gradient { buffers, .. } => *buffers as *const _ as usize,
+    }
 }
 
 impl Layer {
@@ -42,6 +81,8 @@ impl Layer {
             index_strides: Vec::new(),
             solid: solid::Layer::new(device, &solid.constants_layout),
             gradient: gradient::Layer::new(device, &gradient.constants_layout),
+            uploaded: Vec::new(),
+            transformation: Transformation::identity(),
         }
     }
 
@@ -54,6 +95,26 @@ impl Layer {
         meshes: &[Mesh<'_>],
         transformation: Transformation,
     ) {
+        // The buffers still hold the same cached meshes, so there is
+        // nothing to write
+        if !meshes.is_empty()
+            && transformation == self.transformation
+            && meshes.len() == self.uploaded.len()
+            && meshes
+                .iter()
+                .zip(&self.uploaded)
+                .all(|(mesh, uploaded)| uploaded.matches(mesh))
+        {
+            return;
+        }
+
+        self.transformation = transformation;
+        self.uploaded = meshes
+            .iter()
+            .map(Uploaded::new)
+            .collect::<Option<_>>()
+            .unwrap_or_default();
+
         // Count the total amount of vertices & indices we need to handle
         let count = mesh::attribute_count_of(meshes);
 
@@ -3,9 +3,14 @@ use crate::core::{Color, Size};
 use crate::graphics;
 use crate::graphics::color;
 use crate::graphics::compositor;
+use crate::graphics::damage;
 use crate::graphics::{Error, Viewport};
+use crate::primitive;
 use crate::{Backend, Primitive, Renderer, Settings};
 
+use std::sync::atomic::{self, AtomicBool};
+use std::sync::Arc;
+
 /// A window graphics backend for iced powered by `wgpu`.
 #[allow(missing_debug_implementations)]
 pub struct Compositor {
@@ -15,16 +20,45 @@ pub struct Compositor {
     device: wgpu::Device,
     queue: wgpu::Queue,
     format: wgpu::TextureFormat,
+    is_lost: Arc<AtomicBool>,
+}
+
+/// A window surface of a [`Compositor`].
+///
+/// It remembers the last frame presented to it, so frames without changes
+/// are not rendered again. If the surface can be copied to, the last frame
+/// is also kept in a texture, so only the regions damaged by the next frame
+/// are rendered again before copying it to the surface.
+///
+/// The primitives of the last frame are not copied; they are compared
+/// against the ones the [`Renderer`] recorded before it was last cleared.
+#[allow(missing_debug_implementations)]
+pub struct Surface {
+    raw: wgpu::Surface<'static>,
+    last_frame: Option<Frame>,
+    supports_copy: bool,
+}
+
+struct Frame {
+    generation: u64,
+    physical_size: Size<u32>,
+    scale_factor: f64,
+    background_color: Color,
+    texture: Option<wgpu::Texture>,
 }
 
 impl Compositor {
     /// Requests a new [`Compositor`] with the given [`Settings`].
     ///
-    /// Returns `None` if no compatible graphics adapter could be found.
+    /// If no hardware adapter is compatible, a software adapter is used
+    /// instead, unless [`Settings::allow_fallback_adapter`] is disabled.
+    ///
+    /// Without a compatible window, the [`Compositor`] can only render
+    /// offscreen with [`screenshot`].
     pub async fn request<W: compositor::Window>(
         settings: Settings,
         compatible_window: Option<W>,
-    ) -> Option<Self> {
+    ) -> Result<Self, Error> {
         let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
             backends: settings.internal_backend,
             ..Default::default()
@@ -46,22 +80,41 @@ impl Compositor {
         let compatible_surface = compatible_window
             .and_then(|window| instance.create_surface(window).ok());
 
-        let adapter = instance
-            .request_adapter(&wgpu::RequestAdapterOptions {
-                power_preference: wgpu::util::power_preference_from_env()
-                    .unwrap_or(if settings.antialiasing.is_none() {
-                        wgpu::PowerPreference::LowPower
-                    } else {
-                        wgpu::PowerPreference::HighPerformance
-                    }),
-                compatible_surface: compatible_surface.as_ref(),
-                force_fallback_adapter: false,
-            })
-            .await?;
+        let power_preference = settings.power_preference.unwrap_or(
+            if settings.antialiasing.is_none() {
+                wgpu::PowerPreference::LowPower
+            } else {
+                wgpu::PowerPreference::HighPerformance
+            },
+        );
+
+        let mut options = wgpu::RequestAdapterOptions {
+            power_preference,
+            compatible_surface: compatible_surface.as_ref(),
+            force_fallback_adapter: false,
+        };
+
+        let adapter = match instance.request_adapter(&options).await {
+            Some(adapter) => adapter,
+            None if settings.allow_fallback_adapter => {
+                log::warn!(
+                    "No compatible hardware adapter found, \
+                    falling back to a software adapter"
+                );
+
+                options.force_fallback_adapter = true;
+
+                instance
+                    .request_adapter(&options)
+                    .await
+                    .ok_or(Error::GraphicsAdapterNotFound)?
+            }
+            None => return Err(Error::GraphicsAdapterNotFound),
+        };
 
         log::info!("Selected: {:#?}", adapter.get_info());
 
-        let format = compatible_surface.as_ref().and_then(|surface| {
+        let format = if let Some(surface) = &compatible_surface {
             let capabilities = surface.get_capabilities(&adapter);
 
             let mut formats = capabilities.formats.iter().copied();
@@ -72,12 +125,18 @@ impl Compositor {
                 formats.find(|format| !wgpu::TextureFormat::is_srgb(format))
             };
 
-            format.or_else(|| {
-                log::warn!("No format found!");
+            format
+                .or_else(|| {
+                    log::warn!("No format found!");
 
-                capabilities.formats.first().copied()
-            })
-        })?;
+                    capabilities.formats.first().copied()
+                })
+                .ok_or(Error::NoAvailablePixelFormat)?
+        } else if color::GAMMA_CORRECTION {
+            wgpu::TextureFormat::Rgba8UnormSrgb
+        } else {
+            wgpu::TextureFormat::Rgba8Unorm
+        };
 
         log::info!("Selected format: {format:?}");
 
@@ -94,10 +153,15 @@ impl Compositor {
             ..limits
         });
 
-        let (device, queue) =
-            loop {
-                let required_limits = limits.next()?;
-                let device = adapter.request_device(
+        let mut error = Error::GraphicsAdapterNotFound;
+
+        let (device, queue) = loop {
+            let Some(required_limits) = limits.next() else {
+                return Err(error);
+            };
+
+            let device = adapter
+                .request_device(
                     &wgpu::DeviceDescriptor {
                         label: Some(
                             "iced_wgpu::window::compositor device descriptor",
@@ -106,20 +170,45 @@ impl Compositor {
                         required_limits,
                     },
                     None,
-                ).await.ok();
+                )
+                .await;
 
-                if let Some(device) = device {
-                    break Some(device);
+            match device {
+                Ok(device) => break device,
+                Err(request_error) => {
+                    error = Error::BackendError(request_error.to_string());
                 }
-            }?;
+            }
+        };
+
+        // Errors of the device, like a lost device after a GPU reset, are
+        // logged instead of aborting the application
+        device.on_uncaptured_error(Box::new(|error| {
+            log::error!("Uncaptured wgpu error: {error}");
+        }));
+
+        // A lost device cannot be used anymore; the shells notice it and
+        // create a new compositor
+        let is_lost = Arc::new(AtomicBool::new(false));
+
+        device.set_device_lost_callback({
+            let is_lost = is_lost.clone();
 
-        Some(Compositor {
+            move |reason, message| {
+                log::error!("The wgpu device was lost ({reason:?}): {message}");
+
+                is_lost.store(true, atomic::Ordering::Relaxed);
+            }
+        });
+
+        Ok(Compositor {
             instance,
             settings,
             adapter,
             device,
             queue,
             format,
+            is_lost,
         })
     }
 
@@ -135,26 +224,95 @@ pub fn new<W: compositor::Window>(
     settings: Settings,
     compatible_window: W,
 ) -> Result<Compositor, Error> {
-    let compositor = futures::executor::block_on(Compositor::request(
+    futures::executor::block_on(Compositor::request(
         settings,
         Some(compatible_window),
     ))
-    .ok_or(Error::GraphicsAdapterNotFound)?;
+}
 
-    Ok(compositor)
+/// Creates a [`Compositor`] for the given [`Settings`] that is not attached
+/// to any window.
+///
+/// A headless [`Compositor`] renders offscreen with [`screenshot`], so
+/// images can be produced without a display server.
+pub fn headless(settings: Settings) -> Result<Compositor, Error> {
+    futures::executor::block_on(Compositor::request(
+        settings,
+        None::<Box<dyn compositor::Window>>,
+    ))
 }
 
-/// Presents the given primitives with the given [`Compositor`] and [`Backend`].
+/// Presents the primitives of the given [`Renderer`] with the given
+/// [`Compositor`].
 pub fn present<T: AsRef<str>>(
+    compositor: &mut Compositor,
+    renderer: &mut Renderer,
+    surface: &mut Surface,
+    viewport: &Viewport,
+    background_color: Color,
+    overlay: &[T],
+) -> Result<(), compositor::SurfaceError> {
+    let generation = renderer.generation();
+
+    renderer.with_previous_primitives(|backend, primitives, previous| {
+        present_primitives(
+            compositor,
+            backend,
+            surface,
+            primitives,
+            previous,
+            generation,
+            viewport,
+            background_color,
+            overlay,
+        )
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn present_primitives<T: AsRef<str>>(
     compositor: &mut Compositor,
     backend: &mut Backend,
-    surface: &mut wgpu::Surface<'static>,
+    surface: &mut Surface,
     primitives: &[Primitive],
+    previous: &[Primitive],
+    generation: u64,
     viewport: &Viewport,
     background_color: Color,
     overlay: &[T],
 ) -> Result<(), compositor::SurfaceError> {
-    match surface.get_current_texture() {
+    // Pipelines can draw something different every frame, so they are
+    // always rendered again in full, just like the overlay
+    let damage = surface
+        .last_frame
+        .as_ref()
+        .filter(|frame| {
+            overlay.is_empty()
+                && !primitives.iter().any(has_pipeline)
+                && frame.physical_size == viewport.physical_size()
+                && frame.scale_factor == viewport.scale_factor()
+                && frame.background_color == background_color
+        })
+        .and_then(|frame| {
+            if frame.generation == generation {
+                Some(Vec::new())
+            } else if frame.generation + 1 == generation {
+                Some(damage::list(previous, primitives))
+            } else {
+                None
+            }
+        });
+
+    // The surface still shows the last frame
+    if damage.as_ref().is_some_and(Vec::is_empty) {
+        if let Some(frame) = &mut surface.last_frame {
+            frame.generation = generation;
+        }
+
+        return Ok(());
+    }
+
+    match surface.raw.get_current_texture() {
         Ok(frame) => {
             let mut encoder = compositor.device.create_command_encoder(
                 &wgpu::CommandEncoderDescriptor {
@@ -162,26 +320,91 @@ pub fn present<T: AsRef<str>>(
                 },
             );
 
-            let view = &frame
-                .texture
+            let last_texture = surface
+                .last_frame
+                .take()
+                .and_then(|frame| frame.texture)
+                .filter(|texture| texture.size() == frame.texture.size());
+
+            // Damaged regions are filled with the background color, which
+            // must be opaque to cover what was drawn there before
+            let damage = damage.filter(|_| {
+                last_texture.is_some() && background_color.a >= 1.0
+            });
+
+            let texture = last_texture.or_else(|| {
+                surface.supports_copy.then(|| {
+                    compositor.device.create_texture(&wgpu::TextureDescriptor {
+                        label: Some("iced_wgpu.last_frame_texture"),
+                        size: frame.texture.size(),
+                        mip_level_count: 1,
+                        sample_count: 1,
+                        dimension: wgpu::TextureDimension::D2,
+                        format: frame.texture.format(),
+                        usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                            | wgpu::TextureUsages::COPY_SRC,
+                        view_formats: &[],
+                    })
+                })
+            });
+
+            let view = texture
+                .as_ref()
+                .unwrap_or(&frame.texture)
                 .create_view(&wgpu::TextureViewDescriptor::default());
 
-            backend.present(
-                &compositor.device,
-                &compositor.queue,
-                &mut encoder,
-                Some(background_color),
-                frame.texture.format(),
-                view,
-                primitives,
-                viewport,
-                overlay,
-            );
+            if let Some(damage) = damage {
+                let damage = damage::group(
+                    damage,
+                    viewport.scale_factor() as f32,
+                    viewport.physical_size(),
+                );
+
+                backend.present_damage(
+                    &compositor.device,
+                    &compositor.queue,
+                    &mut encoder,
+                    background_color,
+                    frame.texture.format(),
+                    &view,
+                    primitives,
+                    viewport,
+                    &damage,
+                );
+            } else {
+                backend.present(
+                    &compositor.device,
+                    &compositor.queue,
+                    &mut encoder,
+                    Some(background_color),
+                    frame.texture.format(),
+                    &view,
+                    primitives,
+                    viewport,
+                    overlay,
+                );
+            }
+
+            if let Some(texture) = &texture {
+                encoder.copy_texture_to_texture(
+                    texture.as_image_copy(),
+                    frame.texture.as_image_copy(),
+                    texture.size(),
+                );
+            }
 
             // Submit work
             let _submission = compositor.queue.submit(Some(encoder.finish()));
             frame.present();
 
+            surface.last_frame = Some(Frame {
+                generation,
+                physical_size: viewport.physical_size(),
+                scale_factor: viewport.scale_factor(),
+                background_color,
+                texture,
+            });
+
             Ok(())
         }
         Err(error) => match error {
@@ -199,10 +422,25 @@ pub fn present<T: AsRef<str>>(
     }
 }
 
+/// Returns whether the [`Primitive`] is drawn by a custom pipeline.
+///
+/// Pipelines can draw something different every frame, even if their
+/// primitives compare equal.
+fn has_pipeline(primitive: &Primitive) -> bool {
+    match primitive {
+        Primitive::Custom(primitive::Custom::Pipeline(_)) => true,
+        Primitive::Group { primitives } => primitives.iter().any(has_pipeline),
+        Primitive::Clip { content, .. }
+        | Primitive::Translate { content, .. } => has_pipeline(content),
+        Primitive::Cache { content } => has_pipeline(content),
+        _ => false,
+    }
+}
+
 impl graphics::Compositor for Compositor {
     type Settings = Settings;
     type Renderer = Renderer;
-    type Surface = wgpu::Surface<'static>;
+    type Surface = Surface;
 
     fn new<W: compositor::Window>(
         settings: Self::Settings,
@@ -217,6 +455,8 @@ impl graphics::Compositor for Compositor {
             self.settings.default_font,
             self.settings.default_text_size,
         )
+        .with_spacing_scale(self.settings.spacing_scale)
+        .with_border_radius_scale(self.settings.border_radius_scale)
     }
 
     fn create_surface<W: compositor::Window>(
@@ -225,10 +465,14 @@ impl graphics::Compositor for Compositor {
         width: u32,
         height: u32,
     ) -> Self::Surface {
-        let mut surface = self
-            .instance
-            .create_surface(window)
-            .expect("Create surface");
+        let mut surface = Surface {
+            raw: self
+                .instance
+                .create_surface(window)
+                .expect("Create surface"),
+            last_frame: None,
+            supports_copy: false,
+        };
 
         self.configure_surface(&mut surface, width, height);
 
@@ -241,7 +485,10 @@ impl graphics::Compositor for Compositor {
         width: u32,
         height: u32,
     ) {
-        let caps = surface.get_capabilities(&self.adapter);
+        // The surface textures are recreated and must be drawn again
+        surface.last_frame = None;
+
+        let caps = surface.raw.get_capabilities(&self.adapter);
         let alpha_mode = if caps
             .alpha_modes
             .contains(&wgpu::CompositeAlphaMode::PostMultiplied)
@@ -256,10 +503,20 @@ impl graphics::Compositor for Compositor {
             wgpu::CompositeAlphaMode::Auto
         };
 
-        surface.configure(
+        // The last frame is copied to the surface, if possible, to only render
+        // its damaged regions again
+        surface.supports_copy =
+            caps.usages.contains(wgpu::TextureUsages::COPY_DST);
+
+        surface.raw.configure(
             &self.device,
             &wgpu::SurfaceConfiguration {
-                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                usage: if surface.supports_copy {
+                    wgpu::TextureUsages::RENDER_ATTACHMENT
+                        | wgpu::TextureUsages::COPY_DST
+                } else {
+                    wgpu::TextureUsages::RENDER_ATTACHMENT
+                },
                 format: self.format,
                 present_mode: self.settings.present_mode,
                 width,
@@ -280,6 +537,10 @@ impl graphics::Compositor for Compositor {
         }
     }
 
+    fn is_lost(&self) -> bool {
+        self.is_lost.load(atomic::Ordering::Relaxed)
+    }
+
     fn present<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -288,17 +549,7 @@ impl graphics::Compositor for Compositor {
         background_color: Color,
         overlay: &[T],
     ) -> Result<(), compositor::SurfaceError> {
-        renderer.with_primitives(|backend, primitives| {
-            present(
-                self,
-                backend,
-                surface,
-                primitives,
-                viewport,
-                background_color,
-                overlay,
-            )
-        })
+        present(self, renderer, surface, viewport, background_color, overlay)
     }
 
     fn screenshot<T: AsRef<str>>(
@@ -0,0 +1,313 @@
+//! Bind the fields of your state to sliders, text inputs, and checkboxes.
+//!
+//! Keep each value in a [`Field`], which remembers the text being typed and
+//! any validation error. In your `view`, [`bind`] the [`Field`] to a message
+//! carrying an [`Update`], and turn the [`Binding`] into a widget. In your
+//! `update`, pass the [`Update`] back to [`Field::update`].
+//!
+//! ```no_run
+//! # type Element<'a, Message> =
+//! #     iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced_widget::binding::{bind, Field, Update};
+//! use iced_widget::column;
+//!
+//! struct Settings {
+//!     volume: Field<f32>,
+//!     port: Field<u16>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Volume(Update<f32>),
+//!     Port(Update<u16>),
+//! }
+//!
+//! fn view(settings: &Settings) -> Element<'_, Message> {
+//!     column![
+//!         bind(&settings.volume, Message::Volume).slider(0.0..=100.0),
+//!         bind(&settings.port, Message::Port).text_input("Port"),
+//!     ]
+//!     .into()
+//! }
+//!
+//! fn update(settings: &mut Settings, message: Message) {
+//!     match message {
+//!         Message::Volume(update) => settings.volume.update(update),
+//!         Message::Port(update) => settings.port.update(update),
+//!     }
+//! }
+//! ```
+use crate::checkbox::{self, Checkbox};
+use crate::core;
+use crate::core::Element;
+use crate::slider::{self, Slider};
+use crate::style::theme::palette::Palette;
+use crate::text;
+use crate::text_input::{self, TextInput};
+use crate::{Column, Renderer, Text, Theme};
+
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// A value bound to some widget, along with its text while it is being
+/// edited and its latest validation error.
+#[derive(Clone)]
+pub struct Field<T> {
+    value: T,
+    text: String,
+    error: Option<String>,
+    format: fn(&T) -> String,
+    validate: fn(&T) -> Result<(), String>,
+}
+
+impl<T> Field<T>
+where
+    T: fmt::Display,
+{
+    /// Creates a new [`Field`] holding the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            text: value.to_string(),
+            value,
+            error: None,
+            format: T::to_string,
+            validate: |_| Ok(()),
+        }
+    }
+}
+
+impl<T> Field<T> {
+    /// Sets the function used to display the value of the [`Field`] as text.
+    pub fn format(mut self, format: fn(&T) -> String) -> Self {
+        self.format = format;
+        self.text = format(&self.value);
+        self
+    }
+
+    /// Sets the function used to validate new values of the [`Field`].
+    ///
+    /// Values that fail validation are rejected, and their error is kept
+    /// until the next valid update.
+    pub fn validate(mut self, validate: fn(&T) -> Result<(), String>) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Returns the current value of the [`Field`].
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the current text of the [`Field`].
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the latest validation error of the [`Field`], if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns whether the latest update of the [`Field`] was valid.
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Replaces the value of the [`Field`], if it is valid.
+    pub fn set(&mut self, value: T) {
+        match (self.validate)(&value) {
+            Ok(()) => {
+                self.text = (self.format)(&value);
+                self.value = value;
+                self.error = None;
+            }
+            Err(error) => {
+                self.error = Some(error);
+            }
+        }
+    }
+}
+
+impl<T> Field<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    /// Applies an [`Update`] produced by a [`Binding`] to the [`Field`].
+    ///
+    /// Text updates are kept as typed, even when they cannot be parsed, so
+    /// users can go through invalid states while editing.
+    pub fn update(&mut self, update: Update<T>) {
+        match update {
+            Update::Value(value) => self.set(value),
+            Update::Text(text) => {
+                match text.trim().parse::<T>() {
+                    Ok(value) => match (self.validate)(&value) {
+                        Ok(()) => {
+                            self.value = value;
+                            self.error = None;
+                        }
+                        Err(error) => {
+                            self.error = Some(error);
+                        }
+                    },
+                    Err(error) => {
+                        self.error = Some(error.to_string());
+                    }
+                }
+
+                self.text = text;
+            }
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Field<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Field")
+            .field("value", &self.value)
+            .field("text", &self.text)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+/// A change to a [`Field`], produced by a [`Binding`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Update<T> {
+    /// A new value was picked.
+    Value(T),
+    /// New text was typed.
+    Text(String),
+}
+
+/// A [`Field`] bound to a message, ready to be turned into a widget.
+#[allow(missing_debug_implementations)]
+pub struct Binding<'a, T, Message> {
+    field: &'a Field<T>,
+    on_update: Box<dyn Fn(Update<T>) -> Message + 'a>,
+}
+
+impl<'a, T, Message> Binding<'a, T, Message>
+where
+    T: 'a,
+{
+    /// Creates a new [`Binding`] of the given [`Field`], producing messages
+    /// with the given function.
+    pub fn new(
+        field: &'a Field<T>,
+        on_update: impl Fn(Update<T>) -> Message + 'a,
+    ) -> Self {
+        Self {
+            field,
+            on_update: Box::new(on_update),
+        }
+    }
+
+    /// Turns the [`Binding`] into a [`Slider`] over the given range.
+    pub fn slider<Theme>(
+        self,
+        range: RangeInclusive<T>,
+    ) -> Slider<'a, T, Message, Theme>
+    where
+        T: Copy + From<u8> + PartialOrd,
+        Message: Clone,
+        Theme: slider::StyleSheet,
+    {
+        let on_update = self.on_update;
+
+        Slider::new(range, *self.field.value(), move |value| {
+            on_update(Update::Value(value))
+        })
+    }
+
+    /// Turns the [`Binding`] into a [`TextInput`] with the given
+    /// placeholder, showing the text of the [`Field`].
+    pub fn text_input<Theme, Renderer>(
+        self,
+        placeholder: &str,
+    ) -> TextInput<'a, Message, Theme, Renderer>
+    where
+        Message: Clone,
+        Theme: text_input::StyleSheet,
+        Renderer: core::text::Renderer,
+    {
+        let on_update = self.on_update;
+
+        TextInput::new(placeholder, self.field.text())
+            .on_input(move |text| on_update(Update::Text(text)))
+    }
+}
+
+impl<'a, Message> Binding<'a, bool, Message> {
+    /// Turns the [`Binding`] into a [`Checkbox`] with the given label.
+    pub fn checkbox<Theme, Renderer>(
+        self,
+        label: impl Into<String>,
+    ) -> Checkbox<'a, Message, Theme, Renderer>
+    where
+        Theme: checkbox::StyleSheet + text::StyleSheet,
+        Renderer: core::text::Renderer,
+    {
+        let on_update = self.on_update;
+
+        Checkbox::new(label, *self.field.value(), move |is_checked| {
+            on_update(Update::Value(is_checked))
+        })
+    }
+}
+
+/// Binds the given [`Field`] to a message produced with the given function.
+pub fn bind<'a, T, Message>(
+    field: &'a Field<T>,
+    on_update: impl Fn(Update<T>) -> Message + 'a,
+) -> Binding<'a, T, Message>
+where
+    T: 'a,
+{
+    Binding::new(field, on_update)
+}
+
+/// Lays out a widget bound to the given [`Field`] below its label, followed by
+/// the latest validation error of the [`Field`], if any.
+///
+/// An empty label is omitted.
+pub fn labeled<'a, T, Message>(
+    label: &str,
+    field: &Field<T>,
+    widget: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+{
+    let mut column = Column::new().spacing(5);
+
+    if !label.is_empty() {
+        column = column.push(Text::new(label.to_owned()));
+    }
+
+    column = column.push(widget);
+
+    if let Some(error) = field.error() {
+        column = column
+            .push(Text::new(error.to_owned()).style(Palette::LIGHT.danger));
+    }
+
+    column.into()
+}
+
+/// A set of [`Field`]s that can be viewed and updated as a whole.
+///
+/// Enable the `form` feature of `iced` to derive it for a struct of
+/// [`Field`]s with `#[derive(iced::Form)]`.
+pub trait Form {
+    /// The message produced by the view of the [`Form`].
+    type Message;
+
+    /// Produces a view of every [`Field`] of the [`Form`].
+    fn view(&self) -> Element<'_, Self::Message, Theme, Renderer>;
+
+    /// Applies a message produced by the view of the [`Form`].
+    fn update(&mut self, message: Self::Message);
+}
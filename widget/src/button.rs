@@ -225,7 +225,9 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout(limits, self.width, self.height, self.padding, |limits| {
+        let padding = self.padding.scale(renderer.spacing_scale());
+
+        layout(limits, self.width, self.height, padding, |limits| {
             self.content.as_widget().layout(
                 &mut tree.children[0],
                 renderer,
@@ -241,6 +243,12 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
     ) {
+        if self.on_press.is_some() {
+            let state = tree.state.downcast_mut::<State>();
+
+            operation.focusable(state, Some(&self.id));
+        }
+
         operation.container(None, layout.bounds(), &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
@@ -498,14 +506,15 @@ pub fn update<'a, Message: Clone>(
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
             if on_press.is_some() {
                 let bounds = layout.bounds();
+                let state = state();
 
                 if cursor.is_over(bounds) {
-                    let state = state();
-
                     state.is_pressed = true;
 
                     return event::Status::Captured;
                 }
+
+                state.is_focused = false;
             }
         }
         Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
@@ -550,7 +559,10 @@ pub fn update<'a, Message: Clone>(
                 if state.is_focused
                     && matches!(
                         key,
-                        keyboard::Key::Named(keyboard::key::Named::Enter)
+                        keyboard::Key::Named(
+                            keyboard::key::Named::Enter
+                                | keyboard::key::Named::Space
+                        )
                     )
                 {
                     state.is_pressed = true;
@@ -585,17 +597,16 @@ where
     Theme: StyleSheet,
 {
     let is_mouse_over = cursor.is_over(bounds);
+    let state = state();
 
     let styling = if !is_enabled {
         theme.disabled(style)
+    } else if is_mouse_over && state.is_pressed {
+        theme.pressed(style)
+    } else if state.is_focused {
+        theme.focused(style)
     } else if is_mouse_over {
-        let state = state();
-
-        if state.is_pressed {
-            theme.pressed(style)
-        } else {
-            theme.hovered(style)
-        }
+        theme.hovered(style)
     } else {
         theme.active(style)
     };
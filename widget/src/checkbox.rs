@@ -258,6 +258,8 @@ where
                     alignment::Horizontal::Left,
                     alignment::Vertical::Top,
                     self.text_shaping,
+                    text::Wrap::default(),
+                    text::Truncate::default(),
                 )
             },
         )
@@ -359,6 +361,8 @@ where
                         horizontal_alignment: alignment::Horizontal::Center,
                         vertical_alignment: alignment::Vertical::Center,
                         shaping: *shaping,
+                        wrap: text::Wrap::default(),
+                        truncate: text::Truncate::default(),
                     },
                     bounds.center(),
                     custom_style.icon_color,
@@ -377,6 +381,7 @@ where
                 tree.state.downcast_ref(),
                 crate::text::Appearance {
                     color: custom_style.text_color,
+                    selection: None,
                 },
                 viewport,
             );
@@ -444,9 +449,23 @@ where
         node.add_action(Action::Default);
         let mut label_node = NodeBuilder::new(Role::StaticText);
 
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = layout
+            .children()
+            .nth(1)
+            .map_or(layout.bounds(), |label| label.bounds());
+
         label_node.set_name(self.label.clone());
-        // TODO proper label bounds
-        label_node.set_bounds(bounds);
+        label_node.set_bounds(Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        ));
 
         A11yTree::node_with_child_tree(
             A11yNode::new(node, self.id.clone()),
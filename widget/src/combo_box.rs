@@ -388,6 +388,10 @@ where
             viewport,
         );
 
+        if let Some(cursor) = local_shell.ime_request() {
+            shell.request_ime(cursor);
+        }
+
         // Then finally react to them here
         for message in local_messages {
             let TextInputEvent::TextChanged(new_value) = message;
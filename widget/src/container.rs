@@ -168,7 +168,7 @@ where
             self.height,
             self.max_width,
             self.max_height,
-            self.padding,
+            self.padding.scale(renderer.spacing_scale()),
             self.horizontal_alignment,
             self.vertical_alignment,
             |limits| self.content.as_widget().layout(tree, renderer, limits),
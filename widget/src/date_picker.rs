@@ -0,0 +1,1037 @@
+//! Pick a date from a month calendar.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Shaping};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, OperationOutputWrapper};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Length, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub use iced_style::date_picker::{Appearance, StyleSheet};
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// A day of the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Creates a new [`Date`] from the given year, month (1-12) and day
+    /// (1-31).
+    ///
+    /// Returns `None` if the date does not exist.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month)
+            || day == 0
+            || day > days_in_month(year, month)
+        {
+            return None;
+        }
+
+        Some(Self { year, month, day })
+    }
+
+    /// Returns the current [`Date`] in UTC.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+
+        Self::from_days(seconds as i64 / 86_400)
+    }
+
+    /// Returns the year of the [`Date`].
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the [`Date`], starting at 1.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month of the [`Date`], starting at 1.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the day of the week of the [`Date`], where Monday is 0 and
+    /// Sunday is 6.
+    pub fn weekday(&self) -> u32 {
+        // 1970-01-01 was a Thursday
+        (self.to_days() + 3).rem_euclid(7) as u32
+    }
+
+    /// Returns the [`Date`] the given amount of days after this one.
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    fn first_of_month(year: i32, month: u32) -> Self {
+        Self {
+            year,
+            month,
+            day: 1,
+        }
+    }
+
+    /// Days since 1970-01-01.
+    fn to_days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2)
+            / 5
+            + i64::from(self.day)
+            - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4
+            - year_of_era / 100
+            + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460
+            + day_of_era / 36_524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+        let month = (if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        }) as u32;
+        let year = (year_of_era + era * 400) as i32 + i32::from(month <= 2);
+
+        Self { year, month, day }
+    }
+}
+
+/// Returns the amount of days in the given month of the given year.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn previous_month(year: i32, month: u32) -> (i32, u32) {
+    if month == 1 {
+        (year - 1, 12)
+    } else {
+        (year, month - 1)
+    }
+}
+
+fn next_month(year: i32, month: u32) -> (i32, u32) {
+    if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    }
+}
+
+/// A month grid that allows the user to pick a [`Date`].
+///
+/// # Example
+/// ```no_run
+/// # type Calendar<'a, Message> = iced_widget::date_picker::Calendar<'a, Message>;
+/// # use iced_widget::date_picker::Date;
+/// #
+/// #[derive(Clone)]
+/// enum Message {
+///     DateSelected(Date),
+/// }
+///
+/// let selected = Date::from_ymd(2024, 3, 14);
+///
+/// let calendar = Calendar::new(selected, Message::DateSelected);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Calendar<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    selected: Option<Date>,
+    on_select: Box<dyn Fn(Date) -> Message + 'a>,
+    min: Option<Date>,
+    max: Option<Date>,
+    cell_size: f32,
+    padding: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: Theme::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Calendar<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    /// The default size of a cell of the [`Calendar`].
+    pub const DEFAULT_CELL_SIZE: f32 = 32.0;
+
+    /// The default padding of the [`Calendar`].
+    pub const DEFAULT_PADDING: f32 = 8.0;
+
+    /// Creates a new [`Calendar`].
+    ///
+    /// It expects:
+    ///   * the currently selected [`Date`], if any
+    ///   * a function that produces a message when a [`Date`] is selected
+    pub fn new(
+        selected: Option<Date>,
+        on_select: impl Fn(Date) -> Message + 'a,
+    ) -> Self {
+        Self {
+            selected,
+            on_select: Box::new(on_select),
+            min: None,
+            max: None,
+            cell_size: Self::DEFAULT_CELL_SIZE,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the earliest [`Date`] that can be selected.
+    pub fn min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest [`Date`] that can be selected.
+    pub fn max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the size of a day cell of the [`Calendar`].
+    pub fn cell_size(mut self, cell_size: impl Into<Pixels>) -> Self {
+        self.cell_size = cell_size.into().0;
+        self
+    }
+
+    /// Sets the padding of the [`Calendar`].
+    pub fn padding(mut self, padding: impl Into<Pixels>) -> Self {
+        self.padding = padding.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`Calendar`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the [`Calendar`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Calendar`].
+    pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn shown_month(&self, state: &State) -> (i32, u32) {
+        state.shown_month.unwrap_or_else(|| {
+            let date = self.selected.unwrap_or_else(Date::today);
+
+            (date.year, date.month)
+        })
+    }
+
+    fn is_enabled(&self, date: Date) -> bool {
+        self.min.map_or(true, |min| date >= min)
+            && self.max.map_or(true, |max| date <= max)
+    }
+
+    fn can_go_back(&self, (year, month): (i32, u32)) -> bool {
+        self.min
+            .map_or(true, |min| Date::first_of_month(year, month) > min)
+    }
+
+    fn can_go_forward(&self, (year, month): (i32, u32)) -> bool {
+        let (year, month) = next_month(year, month);
+
+        self.max
+            .map_or(true, |max| Date::first_of_month(year, month) <= max)
+    }
+
+    fn cell_bounds(
+        &self,
+        bounds: Rectangle,
+        column: usize,
+        row: usize,
+    ) -> Rectangle {
+        Rectangle {
+            x: bounds.x + self.padding + column as f32 * self.cell_size,
+            y: bounds.y + self.padding + row as f32 * self.cell_size,
+            width: self.cell_size,
+            height: self.cell_size,
+        }
+    }
+
+    fn target_at(
+        &self,
+        bounds: Rectangle,
+        position: Point,
+        shown_month: (i32, u32),
+    ) -> Option<Target> {
+        let x = position.x - bounds.x - self.padding;
+        let y = position.y - bounds.y - self.padding;
+
+        if x < 0.0 || y < 0.0 || self.cell_size <= 0.0 {
+            return None;
+        }
+
+        let column = (x / self.cell_size) as usize;
+        let row = (y / self.cell_size) as usize;
+
+        if column >= 7 {
+            return None;
+        }
+
+        match row {
+            0 if column == 0 => Some(Target::Previous),
+            0 if column == 6 => Some(Target::Next),
+            2..=7 => Some(Target::Day(
+                first_visible_day(shown_month)
+                    .add_days(((row - 2) * 7 + column) as i64),
+            )),
+            _ => None,
+        }
+    }
+
+    fn is_target_enabled(
+        &self,
+        target: Target,
+        shown_month: (i32, u32),
+    ) -> bool {
+        match target {
+            Target::Previous => self.can_go_back(shown_month),
+            Target::Next => self.can_go_forward(shown_month),
+            Target::Day(date) => self.is_enabled(date),
+        }
+    }
+}
+
+fn first_visible_day((year, month): (i32, u32)) -> Date {
+    let first = Date::first_of_month(year, month);
+
+    first.add_days(-i64::from(first.weekday()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Previous,
+    Next,
+    Day(Date),
+}
+
+/// The local state of a [`Calendar`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    shown_month: Option<(i32, u32)>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Calendar<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        // A header, the weekday labels, and six weeks
+        let size = Size::new(
+            7.0 * self.cell_size + 2.0 * self.padding,
+            8.0 * self.cell_size + 2.0 * self.padding,
+        );
+
+        layout::Node::new(limits.resolve(Length::Shrink, Length::Shrink, size))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let bounds = layout.bounds();
+
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let state = tree.state.downcast_mut::<State>();
+                let shown_month = self.shown_month(state);
+
+                match self.target_at(bounds, position, shown_month) {
+                    Some(target)
+                        if self.is_target_enabled(target, shown_month) =>
+                    {
+                        let (year, month) = shown_month;
+
+                        state.shown_month = Some(match target {
+                            Target::Previous => previous_month(year, month),
+                            Target::Next => next_month(year, month),
+                            Target::Day(date) => {
+                                shell.publish((self.on_select)(date));
+
+                                (date.year, date.month)
+                            }
+                        });
+                    }
+                    _ => {}
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let shown_month = self.shown_month(state);
+
+        match cursor
+            .position_over(bounds)
+            .and_then(|position| self.target_at(bounds, position, shown_month))
+        {
+            Some(target) if self.is_target_enabled(target, shown_month) => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let shown_month = self.shown_month(state);
+        let (year, month) = shown_month;
+
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let hovered = cursor
+            .position_over(bounds)
+            .and_then(|position| self.target_at(bounds, position, shown_month))
+            .filter(|target| self.is_target_enabled(*target, shown_month));
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.border,
+                ..renderer::Quad::default()
+            },
+            appearance.background,
+        );
+
+        let label = |renderer: &mut Renderer,
+                     content: &str,
+                     cell: Rectangle,
+                     color: Color| {
+            renderer.fill_text(
+                text::Text {
+                    content,
+                    bounds: cell.size(),
+                    size,
+                    line_height: LineHeight::default(),
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: Shaping::Basic,
+                    wrap: text::Wrap::default(),
+                    truncate: text::Truncate::default(),
+                },
+                cell.center(),
+                color,
+                bounds,
+            );
+        };
+
+        let highlight = |renderer: &mut Renderer,
+                         cell: Rectangle,
+                         background: Background| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: cell,
+                    border: Border::with_radius(cell.width / 2.0),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        };
+
+        // Header
+        for (target, column, content) in
+            [(Target::Previous, 0, "<"), (Target::Next, 6, ">")]
+        {
+            let cell = self.cell_bounds(bounds, column, 0);
+
+            if hovered == Some(target) {
+                highlight(renderer, cell, appearance.hovered_background);
+            }
+
+            let color = if self.is_target_enabled(target, shown_month) {
+                appearance.text_color
+            } else {
+                appearance.disabled_text_color
+            };
+
+            label(renderer, content, cell, color);
+        }
+
+        let title = format!("{} {year}", MONTHS[month as usize - 1]);
+        let title_bounds = Rectangle {
+            width: 5.0 * self.cell_size,
+            ..self.cell_bounds(bounds, 1, 0)
+        };
+
+        label(renderer, &title, title_bounds, appearance.text_color);
+
+        // Weekdays
+        for (column, weekday) in WEEKDAYS.iter().enumerate() {
+            label(
+                renderer,
+                weekday,
+                self.cell_bounds(bounds, column, 1),
+                appearance.weekday_text_color,
+            );
+        }
+
+        // Days
+        let first = first_visible_day(shown_month);
+
+        for index in 0..42 {
+            let date = first.add_days(index as i64);
+            let cell = self.cell_bounds(bounds, index % 7, 2 + index / 7);
+
+            let is_selected = self.selected == Some(date);
+
+            if is_selected {
+                highlight(renderer, cell, appearance.selected_background);
+            } else if hovered == Some(Target::Day(date)) {
+                highlight(renderer, cell, appearance.hovered_background);
+            }
+
+            let color = if is_selected {
+                appearance.selected_text_color
+            } else if date.month != month || !self.is_enabled(date) {
+                appearance.disabled_text_color
+            } else {
+                appearance.text_color
+            };
+
+            label(renderer, &date.day.to_string(), cell, color);
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Calendar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        calendar: Calendar<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(calendar)
+    }
+}
+
+/// A widget that displays a [`Calendar`] below its content while open.
+#[allow(missing_debug_implementations)]
+pub struct DatePicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    calendar: Calendar<'a, Message, Theme, Renderer>,
+    is_open: bool,
+    on_dismiss: Option<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`DatePicker`].
+    ///
+    /// It expects:
+    ///   * whether the [`Calendar`] is open
+    ///   * the content displayed in place of the [`DatePicker`], usually a
+    ///     button that opens it
+    ///   * the [`Calendar`] displayed while open
+    pub fn new(
+        is_open: bool,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        calendar: Calendar<'a, Message, Theme, Renderer>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            calendar,
+            is_open,
+            on_dismiss: None,
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Calendar`] is open
+    /// and the user clicks outside of it or presses Escape.
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DatePicker<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.content),
+            Tree::new(&self.calendar as &dyn Widget<Message, _, _>),
+        ]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut [
+            self.content.as_widget_mut(),
+            &mut self.calendar,
+        ]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = tree.children.iter_mut();
+
+        let content = self.content.as_widget_mut().overlay(
+            children.next().unwrap(),
+            layout,
+            renderer,
+        );
+
+        let calendar = if self.is_open {
+            Some(overlay::Element::new(
+                layout.position(),
+                Box::new(Overlay {
+                    calendar: &mut self.calendar,
+                    tree: children.next().unwrap(),
+                    target_height: layout.bounds().height,
+                    on_dismiss: &self.on_dismiss,
+                }),
+            ))
+        } else {
+            None
+        };
+
+        if content.is_some() || calendar.is_some() {
+            Some(
+                overlay::Group::with_children(
+                    content.into_iter().chain(calendar).collect(),
+                )
+                .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<DatePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        date_picker: DatePicker<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(date_picker)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    calendar: &'b mut Calendar<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    target_height: f32,
+    on_dismiss: &'b Option<Message>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+        _translation: Vector,
+    ) -> layout::Node {
+        let node = Widget::<Message, Theme, Renderer>::layout(
+            self.calendar,
+            self.tree,
+            renderer,
+            &layout::Limits::new(Size::ZERO, bounds),
+        );
+        let size = node.size();
+
+        let space_below = bounds.height - (position.y + self.target_height);
+        let space_above = position.y;
+
+        let y = if space_below >= size.height || space_below > space_above {
+            position.y + self.target_height
+        } else {
+            position.y - size.height
+        };
+
+        let x = position.x.min(bounds.width - size.width).max(0.0);
+
+        node.move_to(Point::new(x, y))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !cursor.is_over(bounds) =>
+            {
+                if let Some(on_dismiss) = self.on_dismiss {
+                    shell.publish(on_dismiss.clone());
+                }
+
+                return event::Status::Ignored;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => {
+                if let Some(on_dismiss) = self.on_dismiss {
+                    shell.publish(on_dismiss.clone());
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        Widget::<Message, Theme, Renderer>::on_event(
+            self.calendar,
+            self.tree,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &bounds,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        Widget::<Message, Theme, Renderer>::mouse_interaction(
+            self.calendar,
+            self.tree,
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        Widget::<Message, Theme, Renderer>::draw(
+            self.calendar,
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            &layout.bounds(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::from_ymd(year, month, day).expect("Valid date")
+    }
+
+    #[test]
+    fn leap_years_have_29_days_in_february() {
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(2023, 2), 28);
+        assert_eq!(days_in_month(1900, 2), 28);
+
+        assert!(Date::from_ymd(2024, 2, 29).is_some());
+        assert!(Date::from_ymd(2023, 2, 29).is_none());
+    }
+
+    #[test]
+    fn first_of_march_follows_the_end_of_february() {
+        assert_eq!(date(2024, 2, 28).add_days(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 2, 28).add_days(2), date(2024, 3, 1));
+        assert_eq!(date(2023, 2, 28).add_days(1), date(2023, 3, 1));
+        assert_eq!(date(2100, 2, 28).add_days(1), date(2100, 3, 1));
+        assert_eq!(date(2024, 3, 1).add_days(-1), date(2024, 2, 29));
+
+        for day in [date(2000, 3, 1), date(2024, 3, 1), date(1900, 3, 1)] {
+            assert_eq!(Date::from_days(day.to_days()), day);
+        }
+    }
+
+    #[test]
+    fn month_starting_on_monday_has_no_leading_days() {
+        // 2024-04-01 was a Monday
+        assert_eq!(date(2024, 4, 1).weekday(), 0);
+        assert_eq!(first_visible_day((2024, 4)), date(2024, 4, 1));
+
+        // 2024-09-01 was a Sunday
+        assert_eq!(date(2024, 9, 1).weekday(), 6);
+        assert_eq!(first_visible_day((2024, 9)), date(2024, 8, 26));
+    }
+}
@@ -0,0 +1,296 @@
+//! Accept files dropped from the operating system.
+use iced_renderer::core::widget::OperationOutputWrapper;
+
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+
+pub use iced_style::drop_zone::{Appearance, StyleSheet};
+
+use std::path::PathBuf;
+
+/// A container that highlights while files are dragged over it, and emits a
+/// message with the paths of the files dropped on it.
+///
+/// Some platforms do not report the position of the cursor while files are
+/// being dragged into a window. When the position is unknown, every
+/// [`DropZone`] in the window accepts the files.
+#[allow(missing_debug_implementations)]
+pub struct DropZone<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: StyleSheet,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drop: Option<Box<dyn Fn(Vec<PathBuf>) -> Message + 'a>>,
+    style: Theme::Style,
+}
+
+impl<'a, Message, Theme, Renderer> DropZone<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a [`DropZone`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        DropZone {
+            content: content.into(),
+            on_drop: None,
+            style: Theme::Style::default(),
+        }
+    }
+
+    /// Sets the message to emit when files are dropped on the [`DropZone`].
+    ///
+    /// Files dropped together are emitted in a single message.
+    #[must_use]
+    pub fn on_drop(mut self, f: impl Fn(Vec<PathBuf>) -> Message + 'a) -> Self {
+        self.on_drop = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`DropZone`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// Local state of the [`DropZone`].
+#[derive(Default)]
+struct State {
+    is_hovered: bool,
+    dropped: Vec<PathBuf>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropZone<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let Some(on_drop) = &self.on_drop else {
+            return status;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        let is_targeted = cursor
+            .position()
+            .map_or(true, |position| layout.bounds().contains(position));
+
+        match event {
+            Event::Window(_, window::Event::FileHovered(_)) => {
+                if state.is_hovered != is_targeted {
+                    state.is_hovered = is_targeted;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if state.is_hovered && !is_targeted =>
+            {
+                state.is_hovered = false;
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+            Event::Window(_, window::Event::FilesHoveredLeft) => {
+                if state.is_hovered {
+                    state.is_hovered = false;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            Event::Window(_, window::Event::FileDropped(path)) => {
+                state.is_hovered = false;
+
+                // Each file is dropped in its own event; gather them until
+                // the next frame
+                if is_targeted {
+                    state.dropped.push(path);
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            Event::Window(_, window::Event::RedrawRequested(_))
+                if !state.dropped.is_empty() =>
+            {
+                shell.publish(on_drop(std::mem::take(&mut state.dropped)));
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        if tree.state.downcast_ref::<State>().is_hovered {
+            let appearance = theme.hovered(&self.style);
+
+            renderer.with_layer(layout.bounds(), |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: layout.bounds(),
+                        border: appearance.border,
+                        ..renderer::Quad::default()
+                    },
+                    appearance.background,
+                );
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_style::core::clipboard::DndDestinationRectangles,
+    ) {
+        if let Some(state) = state.children.iter().next() {
+            self.content.as_widget().drag_destinations(
+                state,
+                layout,
+                dnd_rectangles,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DropZone<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        drop_zone: DropZone<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(drop_zone)
+    }
+}
@@ -0,0 +1,594 @@
+//! Search the contents of a widget with a find bar.
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, highlighter};
+use crate::core::widget::Tree;
+use crate::core::widget::{Operation, OperationOutputWrapper};
+use crate::core::{
+    Clipboard, Element, Font, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+use crate::text_input::{self, TextInput};
+
+use std::ops::Range;
+
+/// An occurrence of a query in some [`Searchable`] content.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Match {
+    /// The index of the line containing the [`Match`].
+    pub line: usize,
+    /// The byte range of the [`Match`] in its line.
+    pub range: Range<usize>,
+}
+
+/// Returns the [`Match`]es of the query in the given line.
+pub fn find_in_line<'a>(
+    line: usize,
+    content: &'a str,
+    query: &'a str,
+) -> impl Iterator<Item = Match> + 'a {
+    content
+        .match_indices(query)
+        .filter(move |_| !query.is_empty())
+        .map(move |(start, text)| Match {
+            line,
+            range: start..start + text.len(),
+        })
+}
+
+/// Some content that can be searched with a [`FindBar`].
+pub trait Searchable {
+    /// Returns all the [`Match`]es of the query, in order.
+    fn find(&self, query: &str) -> Vec<Match>;
+}
+
+impl Searchable for str {
+    fn find(&self, query: &str) -> Vec<Match> {
+        self.lines()
+            .enumerate()
+            .flat_map(|(index, line)| find_in_line(index, line, query))
+            .collect()
+    }
+}
+
+/// The query of a [`FindBar`], used to highlight its [`Match`]es.
+///
+/// It can be used as the settings of the find [`Highlighter`] of a
+/// [`TextEditor`].
+///
+/// [`TextEditor`]: crate::TextEditor
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Query {
+    /// The text being searched.
+    pub text: String,
+    /// The current [`Match`], if any.
+    pub current: Option<Match>,
+}
+
+/// A kind of [`Match`] produced by the find [`Highlighter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    /// A [`Match`] of the query.
+    Match,
+    /// The current [`Match`] of the query.
+    Current,
+}
+
+impl Highlight {
+    /// Returns the [`highlighter::Format`] of the [`Highlight`] with the
+    /// given [`Theme`].
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn to_format(&self, theme: &crate::Theme) -> highlighter::Format<Font> {
+        let palette = theme.extended_palette();
+
+        highlighter::Format {
+            color: Some(match self {
+                Highlight::Match => palette.primary.base.color,
+                Highlight::Current => palette.danger.base.color,
+            }),
+            font: None,
+        }
+    }
+}
+
+/// A [`text::Highlighter`] that highlights the [`Match`]es of a [`Query`].
+#[derive(Debug, Clone)]
+pub struct Highlighter {
+    query: Query,
+    current_line: usize,
+}
+
+impl text::Highlighter for Highlighter {
+    type Settings = Query;
+    type Highlight = Highlight;
+
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Highlight)>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        Self {
+            query: settings.clone(),
+            current_line: 0,
+        }
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        self.query = new_settings.clone();
+
+        // Restart the highlighter
+        self.change_line(0);
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.current_line = line;
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        let index = self.current_line;
+        self.current_line += 1;
+
+        find_in_line(index, line, &self.query.text)
+            .map(|found| {
+                let highlight = if self.query.current.as_ref() == Some(&found) {
+                    Highlight::Current
+                } else {
+                    Highlight::Match
+                };
+
+                (found.range, highlight)
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+}
+
+/// A widget that shows a find bar on top of some content.
+///
+/// Pressing Ctrl+F (or Cmd+F) while hovering the content produces the
+/// open message. While the find bar is focused, Enter and Shift+Enter
+/// produce the next and previous messages, and Escape produces the close
+/// message.
+///
+/// The [`FindBar`] does not search by itself. The application keeps the
+/// query and the current [`Match`], searches its [`Searchable`] content,
+/// and highlights the [`Match`]es in the content widget.
+///
+/// The text input of the [`FindBar`] can be focused with
+/// [`text_input::focus`] and the [`text_input::Id`] given to
+/// [`FindBar::id`].
+#[allow(missing_debug_implementations)]
+pub struct FindBar<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: text_input::StyleSheet,
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    input: TextInput<'a, Message, Theme, Renderer>,
+    is_open: bool,
+    on_open: Option<Message>,
+    on_close: Option<Message>,
+    on_next: Option<Message>,
+    on_previous: Option<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> FindBar<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: text_input::StyleSheet,
+    Renderer: text::Renderer,
+{
+    /// The default width of the text input of a [`FindBar`].
+    pub const DEFAULT_WIDTH: f32 = 250.0;
+
+    /// Creates a new [`FindBar`].
+    ///
+    /// It expects:
+    ///   * whether the find bar is open
+    ///   * the content to search
+    ///   * the current query
+    ///   * a function that produces a message when the query changes
+    pub fn new(
+        is_open: bool,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        query: &str,
+        on_query: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            input: TextInput::new("Find", query)
+                .on_input(on_query)
+                .width(Length::Fixed(Self::DEFAULT_WIDTH)),
+            is_open,
+            on_open: None,
+            on_close: None,
+            on_next: None,
+            on_previous: None,
+        }
+    }
+
+    /// Sets the [`text_input::Id`] of the text input of the [`FindBar`].
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.input = self.input.id(id);
+        self
+    }
+
+    /// Sets the message produced when Ctrl+F is pressed.
+    pub fn on_open(mut self, message: Message) -> Self {
+        self.on_open = Some(message);
+        self
+    }
+
+    /// Sets the message produced when Escape is pressed in the find bar.
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    /// Sets the message produced when Enter is pressed in the find bar.
+    pub fn on_next(mut self, message: Message) -> Self {
+        self.on_next = Some(message);
+        self
+    }
+
+    /// Sets the message produced when Shift+Enter is pressed in the find bar.
+    pub fn on_previous(mut self, message: Message) -> Self {
+        self.on_previous = Some(message);
+        self
+    }
+
+    /// Sets the style of the text input of the [`FindBar`].
+    pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
+        self.input = self.input.style(style);
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for FindBar<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: text_input::StyleSheet,
+    Renderer: text::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.content),
+            Tree::new(&self.input as &dyn Widget<Message, _, _>),
+        ]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut [
+            self.content.as_widget_mut(),
+            &mut self.input,
+        ]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) {
+            return event::Status::Captured;
+        }
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(c),
+                modifiers,
+                ..
+            }) if modifiers.command()
+                && c == "f"
+                && cursor.is_over(layout.bounds()) =>
+            {
+                if let Some(on_open) = &self.on_open {
+                    shell.publish(on_open.clone());
+
+                    return event::Status::Captured;
+                }
+
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = tree.children.iter_mut();
+
+        let content = self.content.as_widget_mut().overlay(
+            children.next().unwrap(),
+            layout,
+            renderer,
+        );
+
+        let input = if self.is_open {
+            Some(overlay::Element::new(
+                layout.position(),
+                Box::new(Overlay {
+                    input: &mut self.input,
+                    tree: children.next().unwrap(),
+                    target_width: layout.bounds().width,
+                    on_close: &self.on_close,
+                    on_next: &self.on_next,
+                    on_previous: &self.on_previous,
+                }),
+            ))
+        } else {
+            None
+        };
+
+        if content.is_some() || input.is_some() {
+            Some(
+                overlay::Group::with_children(
+                    content.into_iter().chain(input).collect(),
+                )
+                .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FindBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: text_input::StyleSheet + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        find_bar: FindBar<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(find_bar)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: text_input::StyleSheet,
+    Renderer: text::Renderer,
+{
+    input: &'b mut TextInput<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    target_width: f32,
+    on_close: &'b Option<Message>,
+    on_next: &'b Option<Message>,
+    on_previous: &'b Option<Message>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: text_input::StyleSheet,
+    Renderer: text::Renderer,
+{
+    fn is_focused(&self) -> bool {
+        self.tree
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused()
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: text_input::StyleSheet,
+    Renderer: text::Renderer,
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+        _translation: Vector,
+    ) -> layout::Node {
+        let node = Widget::<Message, Theme, Renderer>::layout(
+            self.input,
+            self.tree,
+            renderer,
+            &layout::Limits::new(Size::ZERO, bounds),
+        );
+        let size = node.size();
+
+        let x = (position.x + self.target_width - size.width)
+            .min(bounds.width - size.width)
+            .max(0.0);
+
+        node.move_to(Point::new(x, position.y))
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        Widget::<Message, Theme, Renderer>::operate(
+            self.input, self.tree, layout, renderer, operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(named),
+            modifiers,
+            ..
+        }) = &event
+        {
+            let message = match named {
+                key::Named::Enter if modifiers.shift() => {
+                    self.on_previous.as_ref()
+                }
+                key::Named::Enter => self.on_next.as_ref(),
+                key::Named::Escape => self.on_close.as_ref(),
+                _ => None,
+            };
+
+            if let Some(message) = message {
+                if self.is_focused() {
+                    shell.publish(message.clone());
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        Widget::<Message, Theme, Renderer>::on_event(
+            self.input,
+            self.tree,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        Widget::<Message, Theme, Renderer>::mouse_interaction(
+            self.input, self.tree, layout, cursor, viewport, renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        Widget::<Message, Theme, Renderer>::draw(
+            self.input,
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            &layout.bounds(),
+        );
+    }
+}
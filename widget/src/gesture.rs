@@ -0,0 +1,340 @@
+//! Attach multi-touch gestures to any widget.
+use iced_renderer::core::widget::OperationOutputWrapper;
+
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch::{self, gesture::Recognizer};
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Radians, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+/// Emit messages on two-finger pan, pinch, and rotation gestures performed
+/// over its content.
+///
+/// Single-finger touches are passed through to the content untouched. Once a
+/// second finger touches down, the gesture takes over: the content loses the
+/// first finger and receives no touch events until the gesture ends.
+#[allow(missing_debug_implementations)]
+pub struct Gesture<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_pan: Option<Box<dyn Fn(Vector, Vector) -> Message + 'a>>,
+    on_pinch: Option<Box<dyn Fn(f32, Point) -> Message + 'a>>,
+    on_rotate: Option<Box<dyn Fn(Radians, Point) -> Message + 'a>>,
+    on_end: Option<Box<dyn Fn(Vector) -> Message + 'a>>,
+}
+
+impl<'a, Message, Theme, Renderer> Gesture<'a, Message, Theme, Renderer> {
+    /// Creates a [`Gesture`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Gesture {
+            content: content.into(),
+            on_pan: None,
+            on_pinch: None,
+            on_rotate: None,
+            on_end: None,
+        }
+    }
+
+    /// Sets the message to emit when both fingers move together.
+    ///
+    /// The closure receives the movement since the last message and the
+    /// current velocity, in pixels per second.
+    #[must_use]
+    pub fn on_pan(
+        mut self,
+        f: impl Fn(Vector, Vector) -> Message + 'a,
+    ) -> Self {
+        self.on_pan = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message to emit when the fingers move apart or closer
+    /// together.
+    ///
+    /// The closure receives the scale factor since the last message and the
+    /// center point between both fingers.
+    #[must_use]
+    pub fn on_pinch(mut self, f: impl Fn(f32, Point) -> Message + 'a) -> Self {
+        self.on_pinch = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message to emit when the fingers rotate around each other.
+    ///
+    /// The closure receives the clockwise angle since the last message and
+    /// the center point between both fingers.
+    #[must_use]
+    pub fn on_rotate(
+        mut self,
+        f: impl Fn(Radians, Point) -> Message + 'a,
+    ) -> Self {
+        self.on_rotate = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message to emit when a gesture ends.
+    ///
+    /// The closure receives the velocity of the pan when the fingers were
+    /// lifted, which can be used to keep the content moving.
+    #[must_use]
+    pub fn on_end(mut self, f: impl Fn(Vector) -> Message + 'a) -> Self {
+        self.on_end = Some(Box::new(f));
+        self
+    }
+}
+
+/// Local state of the [`Gesture`].
+#[derive(Default)]
+struct State {
+    recognizer: Recognizer,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Gesture<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Touch(touch_event) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            let is_outside = matches!(
+                touch_event,
+                touch::Event::FingerPressed { position, .. }
+                    if !layout.bounds().contains(position)
+            );
+
+            if !is_outside {
+                let was_active = state.recognizer.is_active();
+                let gestures = state.recognizer.update(touch_event);
+
+                if was_active || state.recognizer.is_active() {
+                    // The content has only seen the fingers that came
+                    // before the gesture; take them away before it captures
+                    // every touch
+                    let lost: Vec<_> = if was_active {
+                        Vec::new()
+                    } else {
+                        state
+                            .recognizer
+                            .fingers()
+                            .filter(|(id, _)| *id != finger(touch_event))
+                            .collect()
+                    };
+
+                    for (id, position) in lost {
+                        let _ = self.content.as_widget_mut().on_event(
+                            &mut tree.children[0],
+                            Event::Touch(touch::Event::FingerLost {
+                                id,
+                                position,
+                            }),
+                            layout,
+                            cursor,
+                            renderer,
+                            clipboard,
+                            shell,
+                            viewport,
+                        );
+                    }
+
+                    for gesture in gestures {
+                        if let Some(message) = self.message(gesture) {
+                            shell.publish(message);
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_style::core::clipboard::DndDestinationRectangles,
+    ) {
+        if let Some(state) = state.children.iter().next() {
+            self.content.as_widget().drag_destinations(
+                state,
+                layout,
+                dnd_rectangles,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Gesture<'a, Message, Theme, Renderer> {
+    fn message(&self, gesture: touch::Gesture) -> Option<Message> {
+        match gesture {
+            touch::Gesture::Started { .. } => None,
+            touch::Gesture::Pan { delta, velocity } => {
+                self.on_pan.as_ref().map(|f| f(delta, velocity))
+            }
+            touch::Gesture::Pinch { scale, center } => {
+                self.on_pinch.as_ref().map(|f| f(scale, center))
+            }
+            touch::Gesture::Rotate { angle, center } => {
+                self.on_rotate.as_ref().map(|f| f(angle, center))
+            }
+            touch::Gesture::Ended { velocity } => {
+                self.on_end.as_ref().map(|f| f(velocity))
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Gesture<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        gesture: Gesture<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(gesture)
+    }
+}
+
+fn finger(event: touch::Event) -> touch::Finger {
+    match event {
+        touch::Event::FingerPressed { id, .. }
+        | touch::Event::FingerMoved { id, .. }
+        | touch::Event::FingerLifted { id, .. }
+        | touch::Event::FingerLost { id, .. } => id,
+    }
+}
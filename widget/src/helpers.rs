@@ -6,21 +6,32 @@ use crate::container::{self, Container};
 use crate::core;
 use crate::core::widget::operation;
 use crate::core::{Element, Length, Pixels};
+use crate::date_picker::{self, Calendar, Date, DatePicker};
+use crate::drop_zone::{self, DropZone};
+use crate::find_bar::FindBar;
+use crate::hex_view::{self, HexView};
+use crate::infinite_canvas::InfiniteCanvas;
 use crate::keyed;
+use crate::log_view::{self, LogView};
+use crate::number_input::NumberInput;
 use crate::overlay;
 use crate::pick_list::{self, PickList};
 use crate::progress_bar::{self, ProgressBar};
 use crate::radio::{self, Radio};
+use crate::range_slider::RangeSlider;
+use crate::rubber_band::{self, RubberBand};
 use crate::rule::{self, Rule};
 use crate::runtime::Command;
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
 use crate::text::{self, Text};
 use crate::text_editor::{self, TextEditor};
+use crate::text_grid::{self, TextGrid};
 use crate::text_input::{self, TextInput};
+use crate::timeline::{self, Timeline};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
-use crate::{Column, MouseArea, Row, Space, Themer, VerticalSlider};
+use crate::{Column, Gesture, MouseArea, Row, Space, Themer, VerticalSlider};
 
 #[cfg(feature = "wayland")]
 use crate::dnd_listener::DndListener;
@@ -157,6 +168,21 @@ where
     Text::new(text.to_string())
 }
 
+/// Creates a new [`Rich`] text widget with the provided [`Span`]s.
+///
+/// [`Rich`]: text::Rich
+/// [`Span`]: text::Span
+pub fn rich_text<'a, Link, Message, Theme, Renderer>(
+    spans: impl IntoIterator<Item = text::Span<'a, Link, Renderer::Font>>,
+) -> text::Rich<'a, Link, Message, Theme, Renderer>
+where
+    Link: Clone,
+    Theme: text::StyleSheet,
+    Renderer: core::text::Renderer,
+{
+    text::Rich::new(spans)
+}
+
 /// Creates a new [`Checkbox`].
 ///
 /// [`Checkbox`]: crate::Checkbox
@@ -220,6 +246,128 @@ where
     TextInput::new(placeholder, value)
 }
 
+/// Creates a new [`NumberInput`].
+///
+/// [`NumberInput`]: crate::NumberInput
+pub fn number_input<'a, T, Message, Theme, Renderer>(
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy
+        + PartialOrd
+        + std::fmt::Display
+        + std::str::FromStr
+        + num_traits::Num
+        + num_traits::Bounded,
+    Theme: text_input::StyleSheet,
+    Renderer: core::text::Renderer,
+{
+    NumberInput::new(value, on_change)
+}
+
+/// Creates a new [`Calendar`].
+///
+/// [`Calendar`]: crate::date_picker::Calendar
+pub fn calendar<'a, Message, Theme, Renderer>(
+    selected: Option<Date>,
+    on_select: impl Fn(Date) -> Message + 'a,
+) -> Calendar<'a, Message, Theme, Renderer>
+where
+    Theme: date_picker::StyleSheet,
+    Renderer: core::text::Renderer,
+{
+    Calendar::new(selected, on_select)
+}
+
+/// Creates a new [`DatePicker`] that displays the given [`Calendar`] below
+/// its content while open.
+///
+/// [`DatePicker`]: crate::DatePicker
+/// [`Calendar`]: crate::date_picker::Calendar
+pub fn date_picker<'a, Message, Theme, Renderer>(
+    is_open: bool,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    calendar: Calendar<'a, Message, Theme, Renderer>,
+) -> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: date_picker::StyleSheet,
+    Renderer: core::text::Renderer,
+{
+    DatePicker::new(is_open, content, calendar)
+}
+
+/// Creates a new [`FindBar`] on top of the given content.
+///
+/// [`FindBar`]: crate::FindBar
+pub fn find_bar<'a, Message, Theme, Renderer>(
+    is_open: bool,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    query: &str,
+    on_query: impl Fn(String) -> Message + 'a,
+) -> FindBar<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: text_input::StyleSheet,
+    Renderer: core::text::Renderer,
+{
+    FindBar::new(is_open, content, query, on_query)
+}
+
+/// Creates a new [`HexView`] displaying the given data.
+///
+/// [`HexView`]: crate::HexView
+pub fn hex_view<Message, Theme>(data: &[u8]) -> HexView<'_, Message, Theme>
+where
+    Theme: hex_view::StyleSheet,
+{
+    HexView::new(data)
+}
+
+/// Creates a new empty [`InfiniteCanvas`].
+///
+/// [`InfiniteCanvas`]: crate::InfiniteCanvas
+pub fn infinite_canvas<'a, Message, Theme, Renderer>(
+) -> InfiniteCanvas<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    InfiniteCanvas::new()
+}
+
+/// Creates a new [`LogView`] displaying the given lines.
+///
+/// [`LogView`]: crate::LogView
+pub fn log_view<Theme>(lines: &[log_view::Line]) -> LogView<'_, Theme>
+where
+    Theme: log_view::StyleSheet,
+{
+    LogView::new(lines)
+}
+
+/// Creates a new [`TextGrid`] with the given amount of columns and cells.
+///
+/// [`TextGrid`]: crate::TextGrid
+pub fn text_grid(columns: usize, cells: &[text_grid::Cell]) -> TextGrid<'_> {
+    TextGrid::new(columns, cells)
+}
+
+/// Creates a new [`Timeline`] with the given amount of tracks, holding the
+/// given clips, with its playhead at the given time.
+///
+/// [`Timeline`]: crate::Timeline
+pub fn timeline<'a, Message, Theme, Renderer>(
+    tracks: usize,
+    clips: &'a [timeline::Clip],
+    playhead: f32,
+) -> Timeline<'a, Message, Theme, Renderer>
+where
+    Theme: timeline::StyleSheet,
+    Renderer: core::text::Renderer,
+{
+    Timeline::new(tracks, clips, playhead)
+}
+
 /// Creates a new [`TextEditor`].
 ///
 /// [`TextEditor`]: crate::TextEditor
@@ -250,6 +398,22 @@ where
     Slider::new(range, value, on_change)
 }
 
+/// Creates a new [`RangeSlider`].
+///
+/// [`RangeSlider`]: crate::RangeSlider
+pub fn range_slider<'a, T, Message, Theme>(
+    range: std::ops::RangeInclusive<T>,
+    value: (T, T),
+    on_change: impl Fn((T, T)) -> Message + 'a,
+) -> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+    Theme: slider::StyleSheet,
+{
+    RangeSlider::new(range, value, on_change)
+}
+
 /// Creates a new [`VerticalSlider`].
 ///
 /// [`VerticalSlider`]: crate::VerticalSlider
@@ -367,6 +531,22 @@ pub fn image<'a, Handle>(
     crate::Image::new(handle.into())
 }
 
+/// Creates a new [`Minimap`] of a region of the given size, where the given
+/// rectangle is visible.
+///
+/// [`Minimap`]: crate::Minimap
+#[cfg(feature = "image")]
+#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+pub fn minimap<'a, Message, Theme>(
+    content: core::Size,
+    visible: core::Rectangle,
+) -> crate::Minimap<'a, Message, Theme>
+where
+    Theme: crate::minimap::StyleSheet,
+{
+    crate::Minimap::new(content, visible)
+}
+
 /// Creates a new [`Svg`] widget from the given [`Handle`].
 ///
 /// [`Svg`]: crate::Svg
@@ -395,6 +575,19 @@ where
     crate::Canvas::new(program)
 }
 
+/// Creates a new [`NodeEditor`] drawing the given connections.
+///
+/// [`NodeEditor`]: crate::NodeEditor
+#[cfg(feature = "canvas")]
+pub fn node_editor<'a, Message, Theme>(
+    connections: &'a [crate::node_editor::Connection],
+) -> crate::NodeEditor<'a, Message, Theme>
+where
+    Theme: crate::node_editor::StyleSheet,
+{
+    crate::NodeEditor::new(connections)
+}
+
 /// Creates a new [`Shader`].
 ///
 /// [`Shader`]: crate::Shader
@@ -432,6 +625,38 @@ where
     MouseArea::new(widget)
 }
 
+/// A container accepting files dropped from the operating system.
+pub fn drop_zone<'a, Message, Theme, Renderer>(
+    widget: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DropZone<'a, Message, Theme, Renderer>
+where
+    Theme: drop_zone::StyleSheet,
+    Renderer: core::Renderer,
+{
+    DropZone::new(widget)
+}
+
+/// A container selecting its children with a box dragged over them.
+pub fn rubber_band<'a, Message, Theme, Renderer>(
+    widget: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> RubberBand<'a, Message, Theme, Renderer>
+where
+    Theme: rubber_band::StyleSheet,
+    Renderer: core::Renderer,
+{
+    RubberBand::new(widget)
+}
+
+/// A container recognizing multi-touch gestures.
+pub fn gesture<'a, Message, Theme, Renderer>(
+    widget: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Gesture<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Gesture::new(widget)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, Theme, Renderer>(
     theme: Theme,
@@ -0,0 +1,546 @@
+//! Inspect binary data as hexadecimal bytes and ASCII characters.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Paragraph};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Element, Font, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Vector, Widget,
+};
+use crate::graphics::text::grid::{self, Grid};
+use crate::text_grid::{self, Metrics};
+
+use std::cell::RefCell;
+use std::ops::Range;
+
+pub use iced_style::hex_view::{Appearance, StyleSheet};
+
+/// The amount of columns of the offset pane.
+const OFFSET_COLUMNS: usize = 8;
+
+/// The amount of columns between the offset pane and the hex pane.
+const GAP_COLUMNS: usize = 2;
+
+/// A widget that displays binary data in three synchronized panes: the offset
+/// of every row, the bytes in hexadecimal, and the bytes as ASCII characters.
+///
+/// Only the rows inside of the viewport are drawn, as a [`Grid`] of monospace
+/// characters, so it can be placed inside of a [`Scrollable`] to inspect
+/// large amounts of data.
+///
+/// [`Scrollable`]: crate::Scrollable
+#[allow(missing_debug_implementations)]
+pub struct HexView<'a, Message, Theme = crate::Theme>
+where
+    Theme: StyleSheet,
+{
+    data: &'a [u8],
+    bytes_per_row: usize,
+    font: Font,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    on_select: Option<Box<dyn Fn(Range<usize>) -> Message + 'a>>,
+    style: Theme::Style,
+}
+
+impl<'a, Message, Theme> HexView<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The default amount of bytes displayed in a row of a [`HexView`].
+    pub const DEFAULT_BYTES_PER_ROW: usize = 16;
+
+    /// Creates a new [`HexView`] displaying the given data.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            bytes_per_row: Self::DEFAULT_BYTES_PER_ROW,
+            font: Font::MONOSPACE,
+            size: None,
+            line_height: LineHeight::default(),
+            on_select: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the amount of bytes displayed in a row of the [`HexView`].
+    pub fn bytes_per_row(mut self, bytes_per_row: usize) -> Self {
+        self.bytes_per_row = bytes_per_row.max(1);
+        self
+    }
+
+    /// Sets the message that should be produced when the selected bytes of
+    /// the [`HexView`] change.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(Range<usize>) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the monospace [`Font`] of the [`HexView`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the text size of the [`HexView`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`HexView`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the style of the [`HexView`].
+    pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn rows(&self) -> usize {
+        self.data.len().div_ceil(self.bytes_per_row)
+    }
+
+    fn hex_column(&self) -> usize {
+        OFFSET_COLUMNS + GAP_COLUMNS
+    }
+
+    fn ascii_column(&self) -> usize {
+        self.hex_column() + 3 * self.bytes_per_row + 1
+    }
+
+    fn columns(&self) -> usize {
+        self.ascii_column() + self.bytes_per_row
+    }
+
+    /// Returns the index of the byte at the given position, relative to the
+    /// origin of the [`HexView`].
+    ///
+    /// If `clamp` is true, positions outside of the panes resolve to the
+    /// nearest byte.
+    fn byte_at(
+        &self,
+        metrics: Metrics,
+        position: Point,
+        clamp: bool,
+    ) -> Option<usize> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let (column, row) = match metrics.cell_at(position) {
+            Some(cell) => cell,
+            None if clamp => (
+                metrics
+                    .cell_at(Point::new(position.x.max(0.0), 0.0))
+                    .map_or(0, |(column, _)| column),
+                if position.y < 0.0 {
+                    0
+                } else {
+                    metrics
+                        .cell_at(Point::new(0.0, position.y))
+                        .map_or(0, |(_, row)| row)
+                },
+            ),
+            None => return None,
+        };
+
+        if !clamp && row >= self.rows() {
+            return None;
+        }
+
+        let hex_column = self.hex_column();
+        let ascii_column = self.ascii_column();
+
+        let byte = if (hex_column..ascii_column - 1).contains(&column) {
+            (column - hex_column) / 3
+        } else if (ascii_column..self.columns()).contains(&column) {
+            column - ascii_column
+        } else if clamp {
+            if column < hex_column {
+                0
+            } else {
+                self.bytes_per_row - 1
+            }
+        } else {
+            return None;
+        };
+
+        Some((row * self.bytes_per_row + byte).min(self.data.len() - 1))
+    }
+}
+
+/// The local state of a [`HexView`].
+#[derive(Debug, Default)]
+pub struct State<P: Paragraph> {
+    paragraph: P,
+    /// The visible rows, which are only known when drawing
+    grid: RefCell<Grid>,
+    selection: Option<(usize, usize)>,
+    is_selecting: bool,
+}
+
+impl<P: Paragraph> State<P> {
+    fn selection(&self) -> Option<Range<usize>> {
+        self.selection
+            .map(|(anchor, head)| anchor.min(head)..anchor.max(head) + 1)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for HexView<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer<Font = Font> + grid::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        state.paragraph.update(text_grid::cell_text(
+            self.font,
+            size,
+            self.line_height,
+        ));
+
+        let metrics =
+            Metrics::from_paragraph(&state.paragraph, size, self.line_height);
+
+        layout::Node::new(limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            metrics.grid_size(self.columns(), self.rows()),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let metrics =
+            Metrics::from_paragraph(&state.paragraph, size, self.line_height);
+
+        let previous_selection = state.selection();
+
+        let status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let Some(byte) = self.byte_at(
+                    metrics,
+                    Point::ORIGIN + (position - bounds.position()),
+                    false,
+                ) else {
+                    return event::Status::Ignored;
+                };
+
+                state.selection = Some((byte, byte));
+                state.is_selecting = true;
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if state.is_selecting =>
+            {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                if let (Some((anchor, _)), Some(byte)) = (
+                    state.selection,
+                    self.byte_at(
+                        metrics,
+                        Point::ORIGIN + (position - bounds.position()),
+                        true,
+                    ),
+                ) {
+                    state.selection = Some((anchor, byte));
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.is_selecting =>
+            {
+                state.is_selecting = false;
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        };
+
+        let selection = state.selection();
+
+        if selection != previous_selection {
+            if let (Some(on_select), Some(selection)) =
+                (&self.on_select, selection)
+            {
+                shell.publish(on_select(selection));
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let metrics =
+            Metrics::from_paragraph(&state.paragraph, size, self.line_height);
+
+        let is_over_bytes =
+            cursor.position_over(bounds).is_some_and(|position| {
+                self.byte_at(
+                    metrics,
+                    Point::ORIGIN + (position - bounds.position()),
+                    false,
+                )
+                .is_some()
+            });
+
+        if is_over_bytes || state.is_selecting {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let appearance = theme.appearance(&self.style);
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let metrics =
+            Metrics::from_paragraph(&state.paragraph, size, self.line_height);
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let Some(visible) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let rows = metrics.visible_rows(bounds, visible, self.rows());
+
+        if rows.is_empty() {
+            return;
+        }
+
+        let selection = state.selection();
+        let is_selected = |index: usize| {
+            selection
+                .as_ref()
+                .is_some_and(|selection| selection.contains(&index))
+        };
+
+        let cell = |column: usize, row: usize, columns: usize| {
+            let bounds = metrics.cell_bounds(column, row)
+                + Vector::new(bounds.x, bounds.y);
+
+            Rectangle {
+                width: columns as f32 * metrics.cell_width,
+                ..bounds
+            }
+        };
+
+        let hex_column = self.hex_column();
+        let ascii_column = self.ascii_column();
+        let columns = self.columns();
+
+        let blank = grid::Cell {
+            character: ' ',
+            foreground: None,
+            background: None,
+        };
+
+        let mut cells = Vec::with_capacity(rows.len() * columns);
+
+        for row in rows.clone() {
+            let offset = row * self.bytes_per_row;
+            let bytes = &self.data
+                [offset..(offset + self.bytes_per_row).min(self.data.len())];
+
+            if let Some(selection) = &selection {
+                let start = selection.start.max(offset);
+                let end = selection.end.min(offset + bytes.len());
+
+                if start < end {
+                    let hex = cell(
+                        hex_column + 3 * (start - offset),
+                        row,
+                        3 * (end - start) - 1,
+                    );
+                    let ascii =
+                        cell(ascii_column + (start - offset), row, end - start);
+
+                    for bounds in [hex, ascii] {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds,
+                                ..renderer::Quad::default()
+                            },
+                            appearance.selection_background,
+                        );
+                    }
+                }
+            }
+
+            let start = cells.len();
+
+            cells.extend(format!("{offset:08x}").chars().map(|character| {
+                grid::Cell {
+                    character,
+                    foreground: Some(appearance.offset_color),
+                    background: None,
+                }
+            }));
+            cells.resize(start + columns, blank);
+
+            for (index, &byte) in bytes.iter().enumerate() {
+                let is_selected = is_selected(offset + index);
+                let is_printable = byte.is_ascii_graphic() || byte == b' ';
+
+                let hex_color = if is_selected {
+                    appearance.selection_text_color
+                } else {
+                    appearance.text_color
+                };
+
+                let digits = [byte >> 4, byte & 0xf].map(|digit| {
+                    char::from_digit(u32::from(digit), 16).unwrap_or('0')
+                });
+
+                for (column, character) in digits.into_iter().enumerate() {
+                    cells[start + hex_column + 3 * index + column] =
+                        grid::Cell {
+                            character,
+                            foreground: Some(hex_color),
+                            background: None,
+                        };
+                }
+
+                cells[start + ascii_column + index] = grid::Cell {
+                    character: if is_printable {
+                        char::from(byte)
+                    } else {
+                        '.'
+                    },
+                    foreground: Some(if is_selected {
+                        appearance.selection_text_color
+                    } else if is_printable {
+                        appearance.text_color
+                    } else {
+                        appearance.placeholder_color
+                    }),
+                    background: None,
+                };
+            }
+        }
+
+        // The grid only holds the visible rows and is only replaced when
+        // they change, so renderers can keep what they uploaded for it
+        let mut grid = state.grid.borrow_mut();
+
+        grid.update(
+            &cells,
+            columns,
+            |cell| *cell,
+            self.font,
+            size,
+            Pixels(metrics.cell_height),
+        );
+
+        renderer.fill_grid(
+            &grid,
+            bounds.position()
+                + Vector::new(0.0, rows.start as f32 * metrics.cell_height),
+            Size::new(metrics.cell_width, metrics.cell_height),
+            0..rows.len(),
+            appearance.text_color,
+            visible,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<HexView<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: text::Renderer<Font = Font> + grid::Renderer + 'a,
+{
+    fn from(
+        hex_view: HexView<'a, Message, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(hex_view)
+    }
+}
@@ -1,5 +1,9 @@
 //! Display images in your user interface.
+pub mod animated;
+pub mod crop;
 pub mod viewer;
+pub use animated::Animated;
+pub use crop::Crop;
 use iced_runtime::core::widget::Id;
 pub use viewer::Viewer;
 
@@ -9,7 +13,8 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget::Tree;
 use crate::core::{
-    ContentFit, Element, Layout, Length, Rectangle, Size, Vector, Widget,
+    ContentFit, Element, Layout, Length, Radians, Rectangle, Size, Vector,
+    Widget,
 };
 
 use std::hash::Hash;
@@ -24,6 +29,20 @@ pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
     Viewer::new(handle)
 }
 
+/// Creates a new [`Crop`] of the image with the given `Handle`, showing the
+/// given crop rectangle.
+pub fn crop<'a, Handle, Message>(
+    handle: impl Into<Handle>,
+    crop: Rectangle,
+) -> Crop<'a, Handle, Message> {
+    Crop::new(handle, crop)
+}
+
+/// Creates a new [`Animated`] image playing the given [`animated::Frames`].
+pub fn animated(frames: &animated::Frames) -> Animated<'_> {
+    Animated::new(frames)
+}
+
 /// A frame that displays an image while keeping aspect ratio.
 ///
 /// # Example
@@ -49,6 +68,8 @@ pub struct Image<'a, Handle> {
     height: Length,
     content_fit: ContentFit,
     filter_method: FilterMethod,
+    rotation: Radians,
+    opacity: f32,
     border_radius: [f32; 4],
     phantom_data: std::marker::PhantomData<&'a ()>,
 }
@@ -69,6 +90,8 @@ impl<'a, Handle> Image<'a, Handle> {
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
             filter_method: FilterMethod::default(),
+            rotation: Radians(0.0),
+            opacity: 1.0,
             border_radius: [0.0; 4],
             phantom_data: std::marker::PhantomData,
         }
@@ -106,6 +129,21 @@ impl<'a, Handle> Image<'a, Handle> {
         self
     }
 
+    /// Sets the clockwise rotation of the [`Image`] around its center.
+    ///
+    /// The rotation does not affect the layout of the [`Image`].
+    pub fn rotation(mut self, rotation: impl Into<Radians>) -> Self {
+        self.rotation = rotation.into();
+        self
+    }
+
+    /// Sets the opacity of the [`Image`], from `0.0` (transparent) to `1.0`
+    /// (opaque).
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
     #[cfg(feature = "a11y")]
     /// Sets the name of the [`Button`].
     pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
@@ -190,6 +228,8 @@ pub fn draw<Renderer, Handle>(
     handle: &Handle,
     content_fit: ContentFit,
     filter_method: FilterMethod,
+    rotation: Radians,
+    opacity: f32,
     border_radius: [f32; 4],
 ) where
     Renderer: image::Renderer<Handle = Handle>,
@@ -217,6 +257,8 @@ pub fn draw<Renderer, Handle>(
             handle.clone(),
             filter_method,
             drawing_bounds + offset,
+            rotation,
+            opacity,
             border_radius,
         );
     };
@@ -275,6 +317,8 @@ where
             &self.handle,
             self.content_fit,
             self.filter_method,
+            self.rotation,
+            self.opacity,
             self.border_radius,
         );
     }
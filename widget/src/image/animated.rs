@@ -0,0 +1,338 @@
+//! Play animated images, like GIFs and APNGs.
+use crate::core::event::{self, Event};
+use crate::core::image::{self, FilterMethod, Handle};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, ContentFit, Element, Layout, Length, Radians, Rectangle, Shell,
+    Size, Widget,
+};
+use crate::graphics::image::image_rs;
+
+use image_rs::codecs::gif::GifDecoder;
+use image_rs::codecs::png::PngDecoder;
+use image_rs::AnimationDecoder;
+
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+/// The decoded frames of an animated image.
+#[derive(Debug, Clone)]
+pub struct Frames {
+    frames: Vec<Frame>,
+}
+
+#[derive(Debug, Clone)]
+struct Frame {
+    handle: Handle,
+    delay: Duration,
+}
+
+/// An error that occurred while decoding [`Frames`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The image file could not be read.
+    #[error("the image file could not be read: {0}")]
+    Io(Arc<io::Error>),
+
+    /// The image data could not be decoded.
+    #[error("the image could not be decoded: {0}")]
+    Image(Arc<image_rs::ImageError>),
+
+    /// The image does not contain any frames.
+    #[error("the image does not contain any frames")]
+    Empty,
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+impl From<image_rs::ImageError> for Error {
+    fn from(error: image_rs::ImageError) -> Self {
+        Self::Image(Arc::new(error))
+    }
+}
+
+impl Frames {
+    /// Reads and decodes the [`Frames`] of the image at the given path.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let bytes = std::fs::read(path)?;
+
+        Self::from_memory(&bytes)
+    }
+
+    /// Decodes the [`Frames`] of the given encoded image data.
+    ///
+    /// GIFs and APNGs are decoded frame by frame. Any other format supported
+    /// by [`Handle::from_memory`] is decoded as a single still frame.
+    pub fn from_memory(bytes: &[u8]) -> Result<Self, Error> {
+        let format = image_rs::guess_format(bytes)?;
+
+        let frames = match format {
+            image_rs::ImageFormat::Gif => {
+                decode(GifDecoder::new(io::Cursor::new(bytes))?)?
+            }
+            image_rs::ImageFormat::Png => {
+                let decoder = PngDecoder::new(io::Cursor::new(bytes))?;
+
+                if decoder.is_apng() {
+                    decode(decoder.apng()?)?
+                } else {
+                    still(bytes)?
+                }
+            }
+            _ => still(bytes)?,
+        };
+
+        if frames.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        Ok(Self { frames })
+    }
+
+    /// Returns the amount of frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns true if there are no frames.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+fn decode<'a>(decoder: impl AnimationDecoder<'a>) -> Result<Vec<Frame>, Error> {
+    decoder
+        .into_frames()
+        .map(|frame| {
+            let frame = frame?;
+            let (numerator, denominator) = frame.delay().numer_denom_ms();
+            let buffer = frame.into_buffer();
+
+            Ok(Frame {
+                handle: Handle::from_pixels(
+                    buffer.width(),
+                    buffer.height(),
+                    buffer.into_raw(),
+                ),
+                delay: Duration::from_micros(
+                    u64::from(numerator) * 1000 / u64::from(denominator.max(1)),
+                ),
+            })
+        })
+        .collect()
+}
+
+fn still(bytes: &[u8]) -> Result<Vec<Frame>, Error> {
+    let image = image_rs::load_from_memory(bytes)?.into_rgba8();
+
+    Ok(vec![Frame {
+        handle: Handle::from_pixels(
+            image.width(),
+            image.height(),
+            image.into_raw(),
+        ),
+        delay: Duration::ZERO,
+    }])
+}
+
+/// A frame that plays the [`Frames`] of an animated image in a loop while
+/// keeping aspect ratio.
+///
+/// Every frame is a separate image [`Handle`], so only the frame being
+/// displayed is uploaded to the GPU.
+#[allow(missing_debug_implementations)]
+pub struct Animated<'a> {
+    frames: &'a Frames,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: FilterMethod,
+    border_radius: [f32; 4],
+}
+
+impl<'a> Animated<'a> {
+    /// Creates a new [`Animated`] image with the given [`Frames`].
+    pub fn new(frames: &'a Frames) -> Self {
+        Self {
+            frames,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            filter_method: FilterMethod::default(),
+            border_radius: [0.0; 4],
+        }
+    }
+
+    /// Sets the border radius of the [`Animated`] image.
+    pub fn border_radius(mut self, border_radius: [f32; 4]) -> Self {
+        self.border_radius = border_radius;
+        self
+    }
+
+    /// Sets the width of the [`Animated`] image boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Animated`] image boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Animated`] image.
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`Animated`] image.
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+}
+
+/// The local state of an [`Animated`] image.
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    index: usize,
+    next_frame_at: Option<Instant>,
+}
+
+impl<'a> Animated<'a> {
+    fn current(&self, state: &State) -> &Frame {
+        &self.frames.frames[state.index % self.frames.frames.len()]
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Animated<'a>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State>();
+
+        super::layout(
+            renderer,
+            limits,
+            &self.current(state).handle,
+            self.width,
+            self.height,
+            self.content_fit,
+            self.border_radius,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let Event::Window(_, window::Event::RedrawRequested(now)) = event
+        else {
+            return event::Status::Ignored;
+        };
+
+        if self.frames.len() < 2 {
+            return event::Status::Ignored;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let mut next_frame_at = state
+            .next_frame_at
+            .unwrap_or_else(|| now + self.current(state).delay);
+
+        while next_frame_at <= now {
+            state.index = (state.index + 1) % self.frames.len();
+
+            let delay = self.current(state).delay;
+
+            // Browsers show frames without a delay for 100ms
+            if delay.is_zero() {
+                next_frame_at = now + Duration::from_millis(100);
+            } else {
+                next_frame_at += delay;
+            }
+        }
+
+        state.next_frame_at = Some(next_frame_at);
+        shell.request_redraw(window::RedrawRequest::At(next_frame_at));
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        super::draw(
+            renderer,
+            layout,
+            &self.current(state).handle,
+            self.content_fit,
+            self.filter_method,
+            Radians(0.0),
+            1.0,
+            self.border_radius,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Animated<'a>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+{
+    fn from(animated: Animated<'a>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(animated)
+    }
+}
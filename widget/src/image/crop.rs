@@ -0,0 +1,687 @@
+//! Pick a region of an image with a movable, resizable rectangle.
+use crate::core::event::{self, Event};
+use crate::core::image;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Border, Clipboard, Color, ContentFit, Element, Layout, Length, Point,
+    Radians, Rectangle, Shell, Size, Vector, Widget,
+};
+
+pub use iced_style::crop::{Appearance, StyleSheet};
+
+use std::hash::Hash;
+
+const GRIP_SIZE: f32 = 8.0;
+const HANDLE_SIZE: f32 = 6.0;
+const MIN_SIZE: f32 = 1.0;
+
+/// A frame that displays an image with a crop rectangle on top of it.
+///
+/// The crop rectangle can be moved by dragging its inside and resized by
+/// dragging its edges and corners. Its geometry is given and emitted in the
+/// pixels of the image, regardless of the size the image is displayed at.
+///
+/// The image is fit within the bounds of the [`Crop`] like
+/// [`ContentFit::Contain`].
+#[allow(missing_debug_implementations)]
+pub struct Crop<'a, Handle, Message, Theme = crate::Theme>
+where
+    Theme: StyleSheet,
+{
+    handle: Handle,
+    crop: Rectangle,
+    width: Length,
+    height: Length,
+    aspect_ratio: Option<f32>,
+    guides: bool,
+    filter_method: image::FilterMethod,
+    on_change: Option<Box<dyn Fn(Rectangle) -> Message + 'a>>,
+    style: Theme::Style,
+}
+
+impl<'a, Handle, Message, Theme> Crop<'a, Handle, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Crop`] of the image with the given `Handle`, showing
+    /// the given crop rectangle, in pixels of the image.
+    pub fn new(handle: impl Into<Handle>, crop: Rectangle) -> Self {
+        Crop {
+            handle: handle.into(),
+            crop,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            aspect_ratio: None,
+            guides: true,
+            filter_method: image::FilterMethod::default(),
+            on_change: None,
+            style: Theme::Style::default(),
+        }
+    }
+
+    /// Sets the message to emit when the crop rectangle is moved or
+    /// resized.
+    ///
+    /// The closure receives the new crop rectangle, in pixels of the image.
+    /// The crop rectangle cannot be changed without it.
+    pub fn on_change(mut self, f: impl Fn(Rectangle) -> Message + 'a) -> Self {
+        self.on_change = Some(Box::new(f));
+        self
+    }
+
+    /// Locks the ratio between the width and the height of the crop
+    /// rectangle while it is resized.
+    ///
+    /// `None` lets the crop rectangle be resized freely, which is the
+    /// default.
+    pub fn aspect_ratio(
+        mut self,
+        aspect_ratio: impl Into<Option<f32>>,
+    ) -> Self {
+        self.aspect_ratio = aspect_ratio
+            .into()
+            .filter(|ratio| ratio.is_finite() && *ratio > 0.0);
+        self
+    }
+
+    /// Sets whether the rule of thirds guides are drawn inside the crop
+    /// rectangle.
+    ///
+    /// Defaults to `true`.
+    pub fn guides(mut self, guides: bool) -> Self {
+        self.guides = guides;
+        self
+    }
+
+    /// Sets the width of the [`Crop`] boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Crop`] boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`FilterMethod`](image::FilterMethod) of the image.
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the style of the [`Crop`].
+    pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// Local state of the [`Crop`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    drag: Option<Drag>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    grip: Grip,
+    origin: Point,
+    initial: Rectangle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Grip {
+    Move,
+    Resize { x: Option<Edge>, y: Option<Edge> },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edge {
+    Start,
+    End,
+}
+
+/// The placement of the image inside the bounds of a [`Crop`].
+#[derive(Debug, Clone, Copy)]
+struct Frame {
+    bounds: Rectangle,
+    image: Size,
+}
+
+impl Frame {
+    fn new<Renderer>(
+        renderer: &Renderer,
+        handle: &Renderer::Handle,
+        bounds: Rectangle,
+    ) -> Self
+    where
+        Renderer: image::Renderer,
+    {
+        let Size { width, height } = renderer.dimensions(handle);
+        let image = Size::new(width as f32, height as f32);
+        let fit = ContentFit::Contain.fit(image, bounds.size());
+
+        Frame {
+            bounds: Rectangle {
+                x: bounds.x + (bounds.width - fit.width) / 2.0,
+                y: bounds.y + (bounds.height - fit.height) / 2.0,
+                width: fit.width,
+                height: fit.height,
+            },
+            image,
+        }
+    }
+
+    fn scale(&self) -> f32 {
+        if self.image.width > 0.0 {
+            self.bounds.width / self.image.width
+        } else {
+            1.0
+        }
+    }
+
+    fn to_image(&self, point: Point) -> Point {
+        let scale = self.scale();
+
+        Point::new(
+            (point.x - self.bounds.x) / scale,
+            (point.y - self.bounds.y) / scale,
+        )
+    }
+
+    fn to_screen(&self, crop: Rectangle) -> Rectangle {
+        let scale = self.scale();
+
+        Rectangle {
+            x: self.bounds.x + crop.x * scale,
+            y: self.bounds.y + crop.y * scale,
+            width: crop.width * scale,
+            height: crop.height * scale,
+        }
+    }
+}
+
+impl<'a, Handle, Message, Theme> Crop<'a, Handle, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn grip(&self, frame: &Frame, cursor: Point) -> Option<Grip> {
+        let crop = frame.to_screen(self.crop);
+
+        let expanded = Rectangle {
+            x: crop.x - GRIP_SIZE,
+            y: crop.y - GRIP_SIZE,
+            width: crop.width + GRIP_SIZE * 2.0,
+            height: crop.height + GRIP_SIZE * 2.0,
+        };
+
+        if !expanded.contains(cursor) {
+            return None;
+        }
+
+        let edge = |position: f32, start: f32, length: f32| {
+            if (position - start).abs() <= GRIP_SIZE {
+                Some(Edge::Start)
+            } else if (position - (start + length)).abs() <= GRIP_SIZE {
+                Some(Edge::End)
+            } else {
+                None
+            }
+        };
+
+        let x = edge(cursor.x, crop.x, crop.width);
+        let y = edge(cursor.y, crop.y, crop.height);
+
+        if x.is_some() || y.is_some() {
+            Some(Grip::Resize { x, y })
+        } else {
+            crop.contains(cursor).then_some(Grip::Move)
+        }
+    }
+}
+
+/// Returns the crop rectangle resulting from dragging the given [`Grip`] of
+/// the initial crop rectangle to the given position, all in pixels of the
+/// image.
+fn drag(
+    grip: Grip,
+    initial: Rectangle,
+    origin: Point,
+    position: Point,
+    image: Size,
+    aspect_ratio: Option<f32>,
+) -> Rectangle {
+    let (x, y) = match grip {
+        Grip::Move => {
+            let delta = position - origin;
+
+            return Rectangle {
+                x: (initial.x + delta.x)
+                    .clamp(0.0, (image.width - initial.width).max(0.0)),
+                y: (initial.y + delta.y)
+                    .clamp(0.0, (image.height - initial.height).max(0.0)),
+                ..initial
+            };
+        }
+        Grip::Resize { x, y } => (x, y),
+    };
+
+    let mut left = initial.x;
+    let mut top = initial.y;
+    let mut right = initial.x + initial.width;
+    let mut bottom = initial.y + initial.height;
+
+    match x {
+        Some(Edge::Start) => left = position.x.min(right - MIN_SIZE).max(0.0),
+        Some(Edge::End) => {
+            right = position.x.max(left + MIN_SIZE).min(image.width);
+        }
+        None => {}
+    }
+
+    match y {
+        Some(Edge::Start) => top = position.y.min(bottom - MIN_SIZE).max(0.0),
+        Some(Edge::End) => {
+            bottom = position.y.max(top + MIN_SIZE).min(image.height);
+        }
+        None => {}
+    }
+
+    let Some(ratio) = aspect_ratio else {
+        return Rectangle {
+            x: left,
+            y: top,
+            width: right - left,
+            height: bottom - top,
+        };
+    };
+
+    let mut width = right - left;
+    let mut height = bottom - top;
+
+    match (x, y) {
+        (Some(_), None) => height = width / ratio,
+        (None, Some(_)) => width = height * ratio,
+        _ => {
+            if width / height > ratio {
+                height = width / ratio;
+            } else {
+                width = height * ratio;
+            }
+        }
+    }
+
+    // The edges opposite to the grip stay in place, while the crop
+    // rectangle is centered on the axis without a dragged edge
+    let anchor = |edge: Option<Edge>, start: f32, end: f32| match edge {
+        Some(Edge::Start) => (end, -1.0),
+        Some(Edge::End) => (start, 1.0),
+        None => ((start + end) / 2.0, 0.0),
+    };
+
+    let (anchor_x, direction_x) =
+        anchor(x, initial.x, initial.x + initial.width);
+    let (anchor_y, direction_y) =
+        anchor(y, initial.y, initial.y + initial.height);
+
+    let available = |anchor: f32, direction: f32, max: f32| {
+        if direction > 0.0 {
+            max - anchor
+        } else if direction < 0.0 {
+            anchor
+        } else {
+            2.0 * anchor.min(max - anchor)
+        }
+    };
+
+    let scale = 1.0_f32
+        .min(available(anchor_x, direction_x, image.width) / width)
+        .min(available(anchor_y, direction_y, image.height) / height);
+
+    width *= scale;
+    height *= scale;
+
+    let start = |anchor: f32, direction: f32, length: f32| {
+        if direction > 0.0 {
+            anchor
+        } else if direction < 0.0 {
+            anchor - length
+        } else {
+            anchor - length / 2.0
+        }
+    };
+
+    Rectangle {
+        x: start(anchor_x, direction_x, width),
+        y: start(anchor_y, direction_y, height),
+        width,
+        height,
+    }
+}
+
+impl<'a, Handle, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Crop<'a, Handle, Message, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + Hash,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        super::layout(
+            renderer,
+            limits,
+            &self.handle,
+            self.width,
+            self.height,
+            ContentFit::Contain,
+            [0.0; 4],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let Some(on_change) = &self.on_change else {
+            return event::Status::Ignored;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let frame = Frame::new(renderer, &self.handle, layout.bounds());
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(layout.bounds())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(grip) = self.grip(&frame, position) {
+                    state.drag = Some(Drag {
+                        grip,
+                        origin: frame.to_image(position),
+                        initial: self.crop,
+                    });
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(Drag {
+                    grip,
+                    origin,
+                    initial,
+                }) = state.drag
+                {
+                    let crop = drag(
+                        grip,
+                        initial,
+                        origin,
+                        frame.to_image(position),
+                        frame.image,
+                        self.aspect_ratio,
+                    );
+
+                    if crop != self.crop {
+                        shell.publish(on_change(crop));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.take().is_some() {
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_change.is_none() {
+            return mouse::Interaction::default();
+        }
+
+        let grip = match tree.state.downcast_ref::<State>().drag {
+            Some(drag) => Some(drag.grip),
+            None => {
+                cursor.position_over(layout.bounds()).and_then(|position| {
+                    let frame =
+                        Frame::new(renderer, &self.handle, layout.bounds());
+
+                    self.grip(&frame, position)
+                })
+            }
+        };
+
+        match grip {
+            Some(Grip::Move) => mouse::Interaction::Move,
+            Some(Grip::Resize {
+                x: Some(_),
+                y: None,
+            }) => mouse::Interaction::ResizingHorizontally,
+            Some(Grip::Resize {
+                x: None,
+                y: Some(_),
+            }) => mouse::Interaction::ResizingVertically,
+            Some(Grip::Resize {
+                x: Some(x),
+                y: Some(y),
+            }) => {
+                if x == y {
+                    mouse::Interaction::ResizingDiagonallyDown
+                } else {
+                    mouse::Interaction::ResizingDiagonallyUp
+                }
+            }
+            Some(Grip::Resize { x: None, y: None }) | None => {
+                mouse::Interaction::default()
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        super::draw(
+            renderer,
+            layout,
+            &self.handle,
+            ContentFit::Contain,
+            self.filter_method,
+            Radians(0.0),
+            1.0,
+            [0.0; 4],
+        );
+
+        let appearance = theme.appearance(&self.style);
+        let frame = Frame::new(renderer, &self.handle, layout.bounds());
+        let image = frame.bounds;
+        let crop = frame.to_screen(self.crop).intersection(&image);
+
+        let Some(crop) = crop else {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: image,
+                    ..renderer::Quad::default()
+                },
+                appearance.shade,
+            );
+
+            return;
+        };
+
+        renderer.with_layer(image, |renderer| {
+            // The parts of the image around the crop rectangle
+            let shades = [
+                Rectangle {
+                    height: crop.y - image.y,
+                    ..image
+                },
+                Rectangle {
+                    y: crop.y + crop.height,
+                    height: image.y + image.height - crop.y - crop.height,
+                    ..image
+                },
+                Rectangle {
+                    y: crop.y,
+                    width: crop.x - image.x,
+                    height: crop.height,
+                    ..image
+                },
+                Rectangle {
+                    x: crop.x + crop.width,
+                    y: crop.y,
+                    width: image.x + image.width - crop.x - crop.width,
+                    height: crop.height,
+                },
+            ];
+
+            for bounds in shades {
+                if bounds.width > 0.0 && bounds.height > 0.0 {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            ..renderer::Quad::default()
+                        },
+                        appearance.shade,
+                    );
+                }
+            }
+
+            if self.guides {
+                for i in 1..3 {
+                    let offset = i as f32 / 3.0;
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: crop.x + crop.width * offset,
+                                width: 1.0,
+                                ..crop
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        appearance.guide_color,
+                    );
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                y: crop.y + crop.height * offset,
+                                height: 1.0,
+                                ..crop
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        appearance.guide_color,
+                    );
+                }
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: crop,
+                    border: appearance.border,
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+
+            if self.on_change.is_some() {
+                let corners = [
+                    crop.position(),
+                    crop.position() + Vector::new(crop.width, 0.0),
+                    crop.position() + Vector::new(0.0, crop.height),
+                    crop.position() + Vector::new(crop.width, crop.height),
+                ];
+
+                for corner in corners {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: corner.x - HANDLE_SIZE / 2.0,
+                                y: corner.y - HANDLE_SIZE / 2.0,
+                                width: HANDLE_SIZE,
+                                height: HANDLE_SIZE,
+                            },
+                            border: Border::with_radius(HANDLE_SIZE / 2.0),
+                            ..renderer::Quad::default()
+                        },
+                        appearance.handle_color,
+                    );
+                }
+            }
+        });
+    }
+}
+
+impl<'a, Handle, Message, Theme, Renderer>
+    From<Crop<'a, Handle, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + Hash + 'a,
+{
+    fn from(
+        crop: Crop<'a, Handle, Message, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(crop)
+    }
+}
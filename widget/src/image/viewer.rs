@@ -1,18 +1,25 @@
 //! Zoom and pan on an image.
 use crate::core::event::{self, Event};
 use crate::core::image;
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    Clipboard, Element, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
-    Vector, Widget,
+    Clipboard, Element, Layout, Length, Pixels, Point, Radians, Rectangle,
+    Shell, Size, Vector, Widget,
 };
 
 use std::hash::Hash;
 
 /// A frame that displays an image with the ability to zoom in/out and pan.
+///
+/// The image is zoomed around the cursor by scrolling while holding Ctrl,
+/// and panned by dragging it. Scrolling without Ctrl is left to any
+/// enclosing [`Scrollable`].
+///
+/// [`Scrollable`]: crate::Scrollable
 #[allow(missing_debug_implementations)]
 pub struct Viewer<Handle> {
     padding: f32,
@@ -157,11 +164,23 @@ where
         let bounds = layout.bounds();
 
         match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.keyboard_modifiers = modifiers;
+
+                event::Status::Ignored
+            }
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
                 let Some(cursor_position) = cursor.position_over(bounds) else {
                     return event::Status::Ignored;
                 };
 
+                if !tree.state.downcast_ref::<State>().keyboard_modifiers.zoom()
+                {
+                    return event::Status::Ignored;
+                }
+
                 match delta {
                     mouse::ScrollDelta::Lines { y, .. }
                     | mouse::ScrollDelta::Pixels { y, .. } => {
@@ -338,6 +357,8 @@ where
                         y: bounds.y,
                         ..Rectangle::with_size(image_size)
                     },
+                    Radians(0.0),
+                    1.0,
                     [0.0; 4],
                 );
             });
@@ -352,6 +373,7 @@ pub struct State {
     starting_offset: Vector,
     current_offset: Vector,
     cursor_grabbed_at: Option<Point>,
+    keyboard_modifiers: keyboard::Modifiers,
 }
 
 impl Default for State {
@@ -361,6 +383,7 @@ impl Default for State {
             starting_offset: Vector::default(),
             current_offset: Vector::default(),
             cursor_grabbed_at: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
         }
     }
 }
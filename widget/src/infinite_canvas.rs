@@ -0,0 +1,528 @@
+//! Place widgets on an infinite plane that can be panned and zoomed.
+use iced_renderer::core::widget::OperationOutputWrapper;
+
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// A container that places its children at arbitrary world coordinates of
+/// an infinite plane.
+///
+/// The user pans the [`Viewport`] by dragging the background with the left or
+/// middle mouse button or by scrolling, and zooms by scrolling while holding
+/// Ctrl. Children that fall outside of
+/// the visible bounds are not drawn.
+///
+/// Zooming scales the distances between children, while the children keep
+/// their own size. Children should have a [`Length::Shrink`] or
+/// [`Length::Fixed`] size, since the plane has no boundaries to fill.
+#[allow(missing_debug_implementations)]
+pub struct InfiniteCanvas<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    width: Length,
+    height: Length,
+    min_zoom: f32,
+    max_zoom: f32,
+    positions: Vec<Point>,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    on_viewport_change: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+}
+
+/// The visible region of an [`InfiniteCanvas`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// The world coordinates shown at the top-left corner.
+    pub offset: Vector,
+    /// The zoom factor applied to world coordinates.
+    pub zoom: f32,
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self {
+            offset: Vector::ZERO,
+            zoom: 1.0,
+        }
+    }
+}
+
+impl Viewport {
+    /// Converts a point in world coordinates into a point relative to the
+    /// top-left corner of the [`InfiniteCanvas`].
+    pub fn to_screen(&self, point: Point) -> Point {
+        Point::ORIGIN
+            + (Vector::new(point.x, point.y) - self.offset) * self.zoom
+    }
+
+    /// Converts a point relative to the top-left corner of the
+    /// [`InfiniteCanvas`] into a point in world coordinates.
+    pub fn to_world(&self, point: Point) -> Point {
+        Point::ORIGIN
+            + Vector::new(point.x, point.y) * (1.0 / self.zoom)
+            + self.offset
+    }
+
+    /// Returns the translation that moves a child laid out at the given
+    /// world position to its place on screen.
+    pub(crate) fn translation(&self, position: Point) -> Vector {
+        self.to_screen(position) - position
+    }
+
+    /// Moves the [`Viewport`] by the given amount of screen pixels.
+    pub(crate) fn pan(&mut self, delta: Vector) {
+        self.offset = self.offset - delta * (1.0 / self.zoom);
+    }
+
+    /// Moves the [`Viewport`] by the given scroll delta.
+    pub(crate) fn scroll(&mut self, delta: mouse::ScrollDelta) {
+        let delta = match delta {
+            mouse::ScrollDelta::Lines { x, y } => Vector::new(x, y) * 60.0,
+            mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
+        };
+
+        self.pan(delta);
+    }
+
+    /// Zooms the [`Viewport`] by the given scroll delta, keeping the world
+    /// point under the given screen position in place.
+    pub(crate) fn zoom_at(
+        &mut self,
+        position: Point,
+        delta: mouse::ScrollDelta,
+        min_zoom: f32,
+        max_zoom: f32,
+    ) {
+        let lines = match delta {
+            mouse::ScrollDelta::Lines { y, .. } => y,
+            mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+        };
+
+        let anchor = self.to_world(position);
+
+        self.zoom = (self.zoom * 1.1f32.powf(lines)).clamp(min_zoom, max_zoom);
+        self.offset = self.offset + (anchor - self.to_world(position));
+    }
+}
+
+impl<'a, Message, Theme, Renderer> InfiniteCanvas<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates an empty [`InfiniteCanvas`].
+    pub fn new() -> Self {
+        Self {
+            width: Length::Fill,
+            height: Length::Fill,
+            min_zoom: 0.1,
+            max_zoom: 10.0,
+            positions: Vec::new(),
+            children: Vec::new(),
+            on_viewport_change: None,
+        }
+    }
+
+    /// Sets the width of the [`InfiniteCanvas`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`InfiniteCanvas`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the range of zoom factors the user can reach.
+    ///
+    /// Defaults to `0.1..=10.0`.
+    pub fn zoom_range(mut self, min_zoom: f32, max_zoom: f32) -> Self {
+        self.min_zoom = min_zoom;
+        self.max_zoom = max_zoom.max(min_zoom);
+        self
+    }
+
+    /// Sets the message that should be produced when the user pans or zooms
+    /// the [`Viewport`].
+    pub fn on_viewport_change(
+        mut self,
+        on_viewport_change: impl Fn(Viewport) -> Message + 'a,
+    ) -> Self {
+        self.on_viewport_change = Some(Box::new(on_viewport_change));
+        self
+    }
+
+    /// Adds an element to the [`InfiniteCanvas`] at the given world
+    /// coordinates.
+    pub fn push(
+        mut self,
+        position: impl Into<Point>,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.positions.push(position.into());
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Default
+    for InfiniteCanvas<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The local state of an [`InfiniteCanvas`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    viewport: Viewport,
+    panning_from: Option<Point>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for InfiniteCanvas<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(self.children.as_mut_slice());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits.resolve(self.width, self.height, Size::ZERO);
+
+        let children = self
+            .children
+            .iter()
+            .zip(&self.positions)
+            .zip(&mut tree.children)
+            .map(|((child, position), tree)| {
+                child
+                    .as_widget()
+                    .layout(tree, renderer, &layout::Limits::NONE)
+                    .move_to(*position)
+            })
+            .collect();
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let canvas_viewport = tree.state.downcast_ref::<State>().viewport;
+
+        let status = self
+            .children
+            .iter_mut()
+            .zip(&self.positions)
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|(((child, position), state), layout)| {
+                let translation = canvas_viewport.translation(*position);
+
+                let cursor = match cursor.position_over(bounds) {
+                    Some(position) => {
+                        mouse::Cursor::Available(position - translation)
+                    }
+                    None => mouse::Cursor::Unavailable,
+                };
+
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &(*viewport - translation),
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let previous = state.viewport;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Left | mouse::Button::Middle,
+            )) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.panning_from = Some(position);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Left | mouse::Button::Middle,
+            )) => {
+                if state.panning_from.take().is_some() {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(from) = state.panning_from {
+                    state.viewport.pan(position - from);
+                    state.panning_from = Some(position);
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if let Some(position) = cursor.position_in(bounds) {
+                    if state.modifiers.zoom() {
+                        state.viewport.zoom_at(
+                            position,
+                            delta,
+                            self.min_zoom,
+                            self.max_zoom,
+                        );
+                    } else {
+                        state.viewport.scroll(delta);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if state.viewport == previous {
+            return event::Status::Ignored;
+        }
+
+        if let Some(on_viewport_change) = &self.on_viewport_change {
+            shell.publish(on_viewport_change(state.viewport));
+        }
+
+        event::Status::Captured
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.panning_from.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let Some(cursor_position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        self.children
+            .iter()
+            .zip(&self.positions)
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|(((child, position), tree), layout)| {
+                let translation = state.viewport.translation(*position);
+
+                child.as_widget().mouse_interaction(
+                    tree,
+                    layout,
+                    mouse::Cursor::Available(cursor_position - translation),
+                    &(*viewport - translation),
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+
+        let Some(visible_bounds) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(visible_bounds, |renderer| {
+            for (((child, position), tree), layout) in self
+                .children
+                .iter()
+                .zip(&self.positions)
+                .zip(&tree.children)
+                .zip(layout.children())
+            {
+                let translation = state.viewport.translation(*position);
+
+                // Cull children outside of the visible bounds
+                if !(layout.bounds() + translation).intersects(&visible_bounds)
+                {
+                    continue;
+                }
+
+                let cursor = match cursor.position_over(visible_bounds) {
+                    Some(position) => {
+                        mouse::Cursor::Available(position - translation)
+                    }
+                    None => mouse::Cursor::Unavailable,
+                };
+
+                renderer.with_translation(translation, |renderer| {
+                    child.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        style,
+                        layout,
+                        cursor,
+                        &(visible_bounds - translation),
+                    );
+                });
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let bounds = layout.bounds();
+        let viewport = tree.state.downcast_ref::<State>().viewport;
+
+        let children = self
+            .children
+            .iter_mut()
+            .zip(&self.positions)
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|(((child, position), tree), layout)| {
+                let translation = viewport.translation(*position);
+
+                child
+                    .as_widget_mut()
+                    .overlay(tree, layout, renderer)
+                    .map(|overlay| overlay.translate(translation))
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut crate::core::clipboard::DndDestinationRectangles,
+    ) {
+        for ((child, layout), state) in self
+            .children
+            .iter()
+            .zip(layout.children())
+            .zip(state.children.iter())
+        {
+            child
+                .as_widget()
+                .drag_destinations(state, layout, dnd_rectangles);
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<InfiniteCanvas<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(canvas: InfiniteCanvas<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(canvas)
+    }
+}
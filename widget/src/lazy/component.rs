@@ -314,6 +314,10 @@ where
             shell.request_redraw(redraw_request);
         }
 
+        if let Some(cursor) = local_shell.ime_request() {
+            shell.request_ime(cursor);
+        }
+
         if !local_messages.is_empty() {
             let mut heads = self.state.take().unwrap().into_heads();
 
@@ -697,6 +701,10 @@ where
             shell.request_redraw(redraw_request);
         }
 
+        if let Some(cursor) = local_shell.ime_request() {
+            shell.request_ime(cursor);
+        }
+
         if !local_messages.is_empty() {
             let mut inner =
                 self.overlay.take().unwrap().0.take().unwrap().into_heads();
@@ -21,23 +21,36 @@ mod mouse_area;
 mod row;
 mod themer;
 
+pub mod binding;
 pub mod button;
 pub mod checkbox;
 pub mod combo_box;
 pub mod container;
+pub mod date_picker;
+pub mod drop_zone;
+pub mod find_bar;
+pub mod gesture;
+pub mod hex_view;
+pub mod infinite_canvas;
 pub mod keyed;
+pub mod log_view;
+pub mod number_input;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod range_slider;
+pub mod rubber_band;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod space;
 pub mod text;
 pub mod text_editor;
+pub mod text_grid;
 pub mod text_input;
+pub mod timeline;
 pub mod toggler;
 pub mod tooltip;
 pub mod vertical_slider;
@@ -66,8 +79,24 @@ pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
+pub use drop_zone::DropZone;
+#[doc(no_inline)]
+pub use find_bar::FindBar;
+#[doc(no_inline)]
+pub use gesture::Gesture;
+#[doc(no_inline)]
+pub use hex_view::HexView;
+#[doc(no_inline)]
+pub use infinite_canvas::InfiniteCanvas;
+#[doc(no_inline)]
+pub use log_view::LogView;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -76,8 +105,12 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
+pub use rubber_band::RubberBand;
+#[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
@@ -90,10 +123,14 @@ pub use text::Text;
 #[doc(no_inline)]
 pub use text_editor::TextEditor;
 #[doc(no_inline)]
+pub use text_grid::TextGrid;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use themer::Themer;
 #[doc(no_inline)]
+pub use timeline::Timeline;
+#[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
@@ -121,6 +158,13 @@ pub mod image;
 #[doc(no_inline)]
 pub use image::Image;
 
+#[cfg(feature = "image")]
+pub mod minimap;
+
+#[cfg(feature = "image")]
+#[doc(no_inline)]
+pub use minimap::Minimap;
+
 #[cfg(feature = "canvas")]
 pub mod canvas;
 
@@ -128,6 +172,13 @@ pub mod canvas;
 #[doc(no_inline)]
 pub use canvas::Canvas;
 
+#[cfg(feature = "canvas")]
+pub mod node_editor;
+
+#[cfg(feature = "canvas")]
+#[doc(no_inline)]
+pub use node_editor::NodeEditor;
+
 #[cfg(feature = "qr_code")]
 pub mod qr_code;
 
@@ -0,0 +1,645 @@
+//! Display a stream of log lines with follow mode and filtering.
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Paragraph, Shaping};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    alignment, Border, Clipboard, Element, Font, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Widget,
+};
+use crate::find_bar::{find_in_line, Match, Searchable};
+use crate::text_grid::{self, Metrics};
+
+use std::ops::RangeInclusive;
+
+pub use iced_style::log_view::{Appearance, StyleSheet};
+
+/// The severity of a [`Line`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Level {
+    /// Very verbose information.
+    Trace,
+    /// Information useful while debugging.
+    Debug,
+    /// Regular information.
+    #[default]
+    Info,
+    /// Something unexpected happened.
+    Warn,
+    /// Something failed.
+    Error,
+}
+
+/// A line of a [`LogView`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    /// The [`Level`] of the [`Line`].
+    pub level: Level,
+    /// The text of the [`Line`].
+    pub content: String,
+}
+
+impl Line {
+    /// Creates a new [`Line`] with the given [`Level`] and content.
+    pub fn new(level: Level, content: impl Into<String>) -> Self {
+        Self {
+            level,
+            content: content.into(),
+        }
+    }
+}
+
+impl Searchable for [Line] {
+    fn find(&self, query: &str) -> Vec<Match> {
+        self.iter()
+            .enumerate()
+            .flat_map(|(index, line)| find_in_line(index, &line.content, query))
+            .collect()
+    }
+}
+
+/// A widget that displays log [`Line`]s colored by their [`Level`].
+///
+/// The [`LogView`] follows new lines as they are appended, until the user
+/// scrolls up. Scrolling back to the bottom resumes following. Lines can be
+/// filtered by a substring and a minimum [`Level`], selected with the mouse,
+/// and copied to the clipboard. The [`Match`]es of a [`FindBar`] can be
+/// highlighted, and the current one is scrolled into view.
+///
+/// [`FindBar`]: crate::FindBar
+///
+/// Only the visible lines are drawn, so it can display long logs.
+#[allow(missing_debug_implementations)]
+pub struct LogView<'a, Theme = crate::Theme>
+where
+    Theme: StyleSheet,
+{
+    lines: &'a [Line],
+    filter: &'a str,
+    min_level: Level,
+    matches: &'a [Match],
+    current_match: Option<&'a Match>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    font: Font,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    style: Theme::Style,
+}
+
+impl<'a, Theme> LogView<'a, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The default padding of a [`LogView`].
+    pub const DEFAULT_PADDING: f32 = 5.0;
+
+    /// Creates a new [`LogView`] displaying the given [`Line`]s.
+    pub fn new(lines: &'a [Line]) -> Self {
+        Self {
+            lines,
+            filter: "",
+            min_level: Level::Trace,
+            matches: &[],
+            current_match: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Padding::new(Self::DEFAULT_PADDING),
+            font: Font::MONOSPACE,
+            size: None,
+            line_height: LineHeight::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Only displays the lines containing the given substring.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Only displays the lines with at least the given [`Level`].
+    pub fn min_level(mut self, min_level: Level) -> Self {
+        self.min_level = min_level;
+        self
+    }
+
+    /// Highlights the given [`Match`]es, sorted by line, and scrolls the
+    /// current one into view.
+    pub fn matches(
+        mut self,
+        matches: &'a [Match],
+        current: Option<&'a Match>,
+    ) -> Self {
+        self.matches = matches;
+        self.current_match = current;
+        self
+    }
+
+    /// Sets the width of the [`LogView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`LogView`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`LogView`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the [`Font`] of the [`LogView`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the text size of the [`LogView`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`LogView`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the style of the [`LogView`].
+    pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn inner_bounds(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + self.padding.left,
+            y: bounds.y + self.padding.top,
+            width: (bounds.width - self.padding.horizontal()).max(0.0),
+            height: (bounds.height - self.padding.vertical()).max(0.0),
+        }
+    }
+
+    fn line_height<Renderer: text::Renderer>(
+        &self,
+        renderer: &Renderer,
+    ) -> f32 {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        self.line_height.to_absolute(size).0
+    }
+}
+
+/// The local state of a [`LogView`].
+#[allow(missing_debug_implementations)]
+pub struct State<P: Paragraph> {
+    paragraph: P,
+    offset: f32,
+    is_following: bool,
+    visible: Vec<usize>,
+    selection: Option<(usize, usize)>,
+    is_focused: bool,
+    is_selecting: bool,
+    modifiers: keyboard::Modifiers,
+    revealed: Option<Match>,
+}
+
+impl<P: Paragraph> Default for State<P> {
+    fn default() -> Self {
+        Self {
+            paragraph: P::default(),
+            offset: 0.0,
+            is_following: true,
+            visible: Vec::new(),
+            selection: None,
+            is_focused: false,
+            is_selecting: false,
+            modifiers: keyboard::Modifiers::default(),
+            revealed: None,
+        }
+    }
+}
+
+impl<P: Paragraph> State<P> {
+    /// Returns whether the [`LogView`] is following new lines.
+    pub fn is_following(&self) -> bool {
+        self.is_following
+    }
+
+    fn max_offset(&self, line_height: f32, viewport_height: f32) -> f32 {
+        (self.visible.len() as f32 * line_height - viewport_height).max(0.0)
+    }
+
+    fn scroll_to(&mut self, offset: f32, max_offset: f32) {
+        self.offset = offset.clamp(0.0, max_offset);
+        self.is_following = self.offset >= max_offset - 0.5;
+    }
+
+    /// Returns the position in the visible lines of the given line, or of the
+    /// closest visible line if it is filtered out.
+    fn position_of(&self, line: usize) -> Option<usize> {
+        if self.visible.is_empty() {
+            return None;
+        }
+
+        Some(
+            self.visible
+                .binary_search(&line)
+                .unwrap_or_else(|index| index.min(self.visible.len() - 1)),
+        )
+    }
+
+    fn selected_positions(&self) -> Option<RangeInclusive<usize>> {
+        let (anchor, head) = self.selection?;
+        let anchor = self.position_of(anchor)?;
+        let head = self.position_of(head)?;
+
+        Some(anchor.min(head)..=anchor.max(head))
+    }
+
+    fn position_at(
+        &self,
+        inner: Rectangle,
+        line_height: f32,
+        y: f32,
+    ) -> Option<usize> {
+        if self.visible.is_empty() || line_height <= 0.0 {
+            return None;
+        }
+
+        let position = ((y - inner.y + self.offset) / line_height).max(0.0);
+
+        Some((position as usize).min(self.visible.len() - 1))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for LogView<'a, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let node = layout::atomic(limits, self.width, self.height);
+
+        state.visible.clear();
+        state.visible.extend(
+            self.lines
+                .iter()
+                .enumerate()
+                .filter(|(_, line)| {
+                    line.level >= self.min_level
+                        && (self.filter.is_empty()
+                            || line.content.contains(self.filter))
+                })
+                .map(|(index, _)| index),
+        );
+
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        state.paragraph.update(text_grid::cell_text(
+            self.font,
+            size,
+            self.line_height,
+        ));
+
+        let line_height = self.line_height(renderer);
+        let viewport_height = node.size().height - self.padding.vertical();
+        let max_offset = state.max_offset(line_height, viewport_height);
+
+        if state.is_following {
+            state.offset = max_offset;
+        } else {
+            state.scroll_to(state.offset, max_offset);
+        }
+
+        if self.current_match != state.revealed.as_ref() {
+            state.revealed = self.current_match.cloned();
+
+            if let Some(position) = self.current_match.and_then(|current| {
+                state.visible.binary_search(&current.line).ok()
+            }) {
+                let top = position as f32 * line_height;
+
+                if top < state.offset {
+                    state.scroll_to(top, max_offset);
+                } else if top + line_height > state.offset + viewport_height {
+                    state.scroll_to(
+                        top + line_height - viewport_height,
+                        max_offset,
+                    );
+                }
+            }
+        }
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let inner = self.inner_bounds(bounds);
+        let line_height = self.line_height(renderer);
+        let max_offset = state.max_offset(line_height, inner.height);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !cursor.is_over(bounds) {
+                    return event::Status::Ignored;
+                }
+
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => {
+                        y * line_height * 3.0
+                    }
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                state.scroll_to(state.offset - delta, max_offset);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.is_focused = false;
+
+                    return event::Status::Ignored;
+                };
+
+                state.is_focused = true;
+
+                if let Some(line) = state
+                    .position_at(inner, line_height, position.y)
+                    .map(|position| state.visible[position])
+                {
+                    state.selection = match state.selection {
+                        Some((anchor, _)) if state.modifiers.shift() => {
+                            Some((anchor, line))
+                        }
+                        _ => Some((line, line)),
+                    };
+                    state.is_selecting = true;
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+                if state.is_selecting =>
+            {
+                if let (Some((anchor, _)), Some(line)) = (
+                    state.selection,
+                    state
+                        .position_at(inner, line_height, position.y)
+                        .map(|position| state.visible[position]),
+                ) {
+                    state.selection = Some((anchor, line));
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.is_selecting =>
+            {
+                state.is_selecting = false;
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(c),
+                modifiers,
+                ..
+            }) if state.is_focused && modifiers.command() && c == "c" => {
+                let Some(positions) = state.selected_positions() else {
+                    return event::Status::Ignored;
+                };
+
+                let contents = state.visible[positions]
+                    .iter()
+                    .map(|&line| self.lines[line].content.as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                clipboard.write(contents);
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let inner = self.inner_bounds(bounds);
+        let appearance = theme.appearance(&self.style);
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let line_height = self.line_height(renderer);
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.border,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        if line_height <= 0.0 || state.visible.is_empty() {
+            return;
+        }
+
+        let first = (state.offset / line_height) as usize;
+        let last = (((state.offset + inner.height) / line_height).ceil()
+            as usize)
+            .min(state.visible.len());
+
+        let selected = state.selected_positions();
+        let metrics =
+            Metrics::from_paragraph(&state.paragraph, size, self.line_height);
+
+        renderer.with_layer(inner, |renderer| {
+            for position in first..last {
+                let line = &self.lines[state.visible[position]];
+                let y = inner.y + position as f32 * line_height - state.offset;
+
+                if selected
+                    .as_ref()
+                    .is_some_and(|selected| selected.contains(&position))
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: inner.x,
+                                y,
+                                width: inner.width,
+                                height: line_height,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        appearance.selection_background,
+                    );
+                }
+
+                let index = state.visible[position];
+                let start =
+                    self.matches.partition_point(|found| found.line < index);
+
+                for found in self.matches[start..]
+                    .iter()
+                    .take_while(|found| found.line == index)
+                {
+                    let Some(prefix) = line.content.get(..found.range.start)
+                    else {
+                        continue;
+                    };
+                    let Some(text) = line.content.get(found.range.clone())
+                    else {
+                        continue;
+                    };
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: inner.x
+                                    + prefix.chars().count() as f32
+                                        * metrics.cell_width,
+                                y,
+                                width: text.chars().count() as f32
+                                    * metrics.cell_width,
+                                height: line_height,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        if self.current_match == Some(found) {
+                            appearance.current_match_background
+                        } else {
+                            appearance.match_background
+                        },
+                    );
+                }
+
+                let color = match line.level {
+                    Level::Trace => appearance.trace_color,
+                    Level::Debug => appearance.debug_color,
+                    Level::Info => appearance.info_color,
+                    Level::Warn => appearance.warn_color,
+                    Level::Error => appearance.error_color,
+                };
+
+                renderer.fill_text(
+                    text::Text {
+                        content: &line.content,
+                        bounds: Size::new(f32::INFINITY, line_height),
+                        size,
+                        line_height: self.line_height,
+                        font: self.font,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Top,
+                        shaping: Shaping::Basic,
+                        wrap: text::Wrap::default(),
+                        truncate: text::Truncate::default(),
+                    },
+                    Point::new(inner.x, y),
+                    color,
+                    inner,
+                );
+            }
+        });
+
+        let content_height = state.visible.len() as f32 * line_height;
+
+        if content_height > inner.height {
+            let max_offset = state.max_offset(line_height, inner.height);
+            let height =
+                (inner.height * inner.height / content_height).max(10.0);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + bounds.width - 6.0,
+                        y: inner.y
+                            + state.offset / max_offset
+                                * (inner.height - height),
+                        width: 4.0,
+                        height,
+                    },
+                    border: Border::with_radius(2.0),
+                    ..renderer::Quad::default()
+                },
+                appearance.scroller_color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<LogView<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(
+        log_view: LogView<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(log_view)
+    }
+}
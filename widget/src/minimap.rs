@@ -0,0 +1,367 @@
+//! Overview a scrollable region and move around it.
+use crate::core::event::{self, Event};
+use crate::core::image;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Radians, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+use crate::scrollable::{self, AbsoluteOffset};
+
+pub use iced_style::minimap::{Appearance, StyleSheet};
+
+/// A scaled-down overview of a region, with a rectangle showing the part of
+/// it that is currently visible.
+///
+/// Dragging the rectangle, or clicking anywhere else, produces the offset
+/// the target should scroll to; for a [`Scrollable`], feed it to
+/// [`scrollable::scroll_to`].
+///
+/// The renderers cannot draw widgets offscreen, so the overview is an image
+/// snapshot of the whole region provided by the application, such as a
+/// cropped [`Screenshot`] of a window.
+///
+/// [`Scrollable`]: crate::Scrollable
+/// [`Screenshot`]: crate::runtime::window::Screenshot
+#[allow(missing_debug_implementations)]
+pub struct Minimap<'a, Message, Theme = crate::Theme>
+where
+    Theme: StyleSheet,
+{
+    content: Size,
+    visible: Rectangle,
+    snapshot: Option<image::Handle>,
+    width: Length,
+    height: Length,
+    on_scroll: Option<Box<dyn Fn(AbsoluteOffset) -> Message + 'a>>,
+    style: Theme::Style,
+}
+
+impl<'a, Message, Theme> Minimap<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The default width of a [`Minimap`].
+    pub const DEFAULT_WIDTH: f32 = 120.0;
+
+    /// Creates a new [`Minimap`] of a region of the given size, where the
+    /// given rectangle is visible.
+    pub fn new(content: Size, visible: Rectangle) -> Self {
+        Self {
+            content,
+            visible,
+            snapshot: None,
+            width: Length::Fixed(Self::DEFAULT_WIDTH),
+            height: Length::Shrink,
+            on_scroll: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Creates a new [`Minimap`] of the contents of a [`Scrollable`] with
+    /// the given [`scrollable::Viewport`].
+    ///
+    /// [`Scrollable`]: crate::Scrollable
+    pub fn from_viewport(viewport: &scrollable::Viewport) -> Self {
+        let AbsoluteOffset { x, y } = viewport.absolute_offset();
+
+        Self::new(
+            viewport.content_bounds().size(),
+            Rectangle::new(Point::new(x, y), viewport.bounds().size()),
+        )
+    }
+
+    /// Sets the snapshot of the whole region shown by the [`Minimap`].
+    pub fn snapshot(mut self, snapshot: impl Into<image::Handle>) -> Self {
+        self.snapshot = Some(snapshot.into());
+        self
+    }
+
+    /// Sets the width of the [`Minimap`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Minimap`].
+    ///
+    /// By default, it keeps the aspect ratio of the region.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the message that should be produced with the offset the target
+    /// should scroll to.
+    pub fn on_scroll(
+        mut self,
+        on_scroll: impl Fn(AbsoluteOffset) -> Message + 'a,
+    ) -> Self {
+        self.on_scroll = Some(Box::new(on_scroll));
+        self
+    }
+
+    /// Sets the style of the [`Minimap`].
+    pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the bounds of the whole region within the given bounds, and
+    /// the scale it is drawn at.
+    fn map(&self, bounds: Rectangle) -> (Rectangle, f32) {
+        if self.content.width <= 0.0 || self.content.height <= 0.0 {
+            return (bounds, 1.0);
+        }
+
+        let scale = (bounds.width / self.content.width)
+            .min(bounds.height / self.content.height);
+        let size =
+            Size::new(self.content.width * scale, self.content.height * scale);
+
+        (
+            Rectangle {
+                x: bounds.center_x() - size.width / 2.0,
+                y: bounds.center_y() - size.height / 2.0,
+                width: size.width,
+                height: size.height,
+            },
+            scale,
+        )
+    }
+
+    fn viewport_bounds(&self, map: Rectangle, scale: f32) -> Rectangle {
+        Rectangle {
+            x: map.x + self.visible.x * scale,
+            y: map.y + self.visible.y * scale,
+            width: self.visible.width * scale,
+            height: self.visible.height * scale,
+        }
+    }
+
+    /// Publishes the offset that puts the top-left corner of the visible
+    /// rectangle at the given position of the [`Minimap`].
+    fn scroll_to(
+        &self,
+        map: Rectangle,
+        scale: f32,
+        top_left: Point,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(on_scroll) = &self.on_scroll else {
+            return;
+        };
+
+        let max_x = (self.content.width - self.visible.width).max(0.0);
+        let max_y = (self.content.height - self.visible.height).max(0.0);
+
+        shell.publish(on_scroll(AbsoluteOffset {
+            x: ((top_left.x - map.x) / scale).clamp(0.0, max_x),
+            y: ((top_left.y - map.y) / scale).clamp(0.0, max_y),
+        }));
+    }
+}
+
+/// The local state of a [`Minimap`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// The position of the cursor relative to the viewport rectangle, while
+    /// dragging it.
+    grab: Option<Vector>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Minimap<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let max = limits.width(self.width).height(self.height).max();
+
+        let intrinsic = if self.content.width <= 0.0
+            || self.content.height <= 0.0
+        {
+            Size::ZERO
+        } else {
+            let scale = (max.width / self.content.width)
+                .min(max.height / self.content.height);
+
+            Size::new(self.content.width * scale, self.content.height * scale)
+        };
+
+        layout::Node::new(limits.resolve(self.width, self.height, intrinsic))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let (map, scale) = self.map(layout.bounds());
+        let viewport = self.viewport_bounds(map, scale);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(layout.bounds())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let grab = if viewport.contains(position) {
+                    position - viewport.position()
+                } else {
+                    // Center the viewport on the cursor and keep dragging
+                    let grab = Vector::new(
+                        viewport.width / 2.0,
+                        viewport.height / 2.0,
+                    );
+
+                    self.scroll_to(map, scale, position - grab, shell);
+
+                    grab
+                };
+
+                state.grab = Some(grab);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(grab) = state.grab {
+                    self.scroll_to(map, scale, position - grab, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if state.grab.take().is_some() {
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.grab.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let (map, scale) = self.map(layout.bounds());
+
+        if cursor.is_over(self.viewport_bounds(map, scale)) {
+            mouse::Interaction::Grab
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.appearance(&self.style);
+
+        let Some(visible_bounds) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let (map, scale) = self.map(bounds);
+
+        renderer.with_layer(visible_bounds, |renderer| {
+            if let Some(background) = appearance.background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            if let Some(snapshot) = &self.snapshot {
+                renderer.draw(
+                    snapshot.clone(),
+                    image::FilterMethod::Linear,
+                    map,
+                    Radians(0.0),
+                    1.0,
+                    [0.0; 4],
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: self.viewport_bounds(map, scale),
+                    border: appearance.viewport_border,
+                    ..renderer::Quad::default()
+                },
+                appearance.viewport_background,
+            );
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Minimap<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: image::Renderer<Handle = image::Handle> + 'a,
+{
+    fn from(
+        minimap: Minimap<'a, Message, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(minimap)
+    }
+}
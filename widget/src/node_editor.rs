@@ -0,0 +1,904 @@
+//! Edit graphs of nodes connected through their ports.
+use iced_renderer::core::widget::OperationOutputWrapper;
+
+use crate::canvas;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer::{self, Renderer as _};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Padding, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+use crate::graphics::geometry::Renderer as _;
+use crate::infinite_canvas::Viewport;
+use crate::rubber_band;
+use crate::Renderer;
+
+pub use iced_style::node_editor::{Appearance, StyleSheet};
+
+const PADDING: f32 = 8.0;
+const PORT_HIT_RADIUS: f32 = 8.0;
+const MIN_ZOOM: f32 = 0.1;
+const MAX_ZOOM: f32 = 10.0;
+
+/// A port of a [`Node`], identified by the index of its node in the
+/// [`NodeEditor`] and its own index among the inputs or outputs of the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Port {
+    /// The index of the [`Node`].
+    pub node: usize,
+    /// The index of the port.
+    pub index: usize,
+}
+
+/// A connection from an output [`Port`] to an input [`Port`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Connection {
+    /// The output [`Port`].
+    pub from: Port,
+    /// The input [`Port`].
+    pub to: Port,
+}
+
+/// A node of a [`NodeEditor`], placed at world coordinates.
+///
+/// Inputs are laid out along its left edge, and outputs along its right edge.
+#[allow(missing_debug_implementations)]
+pub struct Node<'a, Message, Theme = crate::Theme> {
+    position: Point,
+    inputs: usize,
+    outputs: usize,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme> Node<'a, Message, Theme> {
+    /// Creates a new [`Node`] at the given world coordinates with the given
+    /// content.
+    pub fn new(
+        position: impl Into<Point>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            position: position.into(),
+            inputs: 0,
+            outputs: 0,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the amount of input ports of the [`Node`].
+    pub fn inputs(mut self, inputs: usize) -> Self {
+        self.inputs = inputs;
+        self
+    }
+
+    /// Sets the amount of output ports of the [`Node`].
+    pub fn outputs(mut self, outputs: usize) -> Self {
+        self.outputs = outputs;
+        self
+    }
+}
+
+/// A graph editor where the user drags [`Node`]s around, connects their
+/// ports, and selects them with a box.
+///
+/// Like an [`InfiniteCanvas`], the graph can be panned by dragging the
+/// background or by scrolling, and zoomed by scrolling while holding Ctrl.
+/// Holding Shift while dragging the background selects the nodes within a
+/// box instead.
+///
+/// Dragging from an output [`Port`] to an input [`Port`] creates a
+/// [`Connection`], while dragging a connected input [`Port`] away removes its
+/// [`Connection`].
+///
+/// [`InfiniteCanvas`]: crate::InfiniteCanvas
+#[allow(missing_debug_implementations)]
+pub struct NodeEditor<'a, Message, Theme = crate::Theme>
+where
+    Theme: StyleSheet,
+{
+    nodes: Vec<Node<'a, Message, Theme>>,
+    connections: &'a [Connection],
+    selection: &'a [usize],
+    width: Length,
+    height: Length,
+    on_move: Option<Box<dyn Fn(usize, Point) -> Message + 'a>>,
+    on_connect: Option<Box<dyn Fn(Connection) -> Message + 'a>>,
+    on_disconnect: Option<Box<dyn Fn(Connection) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    style: Theme::Style,
+}
+
+impl<'a, Message, Theme> NodeEditor<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`NodeEditor`] without nodes, drawing the given
+    /// [`Connection`]s.
+    pub fn new(connections: &'a [Connection]) -> Self {
+        Self {
+            nodes: Vec::new(),
+            connections,
+            selection: &[],
+            width: Length::Fill,
+            height: Length::Fill,
+            on_move: None,
+            on_connect: None,
+            on_disconnect: None,
+            on_select: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a [`Node`] to the [`NodeEditor`].
+    pub fn push(mut self, node: Node<'a, Message, Theme>) -> Self {
+        self.nodes.push(node);
+        self
+    }
+
+    /// Sets the indices of the selected [`Node`]s.
+    pub fn selection(mut self, selection: &'a [usize]) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Sets the width of the [`NodeEditor`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`NodeEditor`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Node`] is dragged
+    /// to new world coordinates.
+    pub fn on_move(
+        mut self,
+        on_move: impl Fn(usize, Point) -> Message + 'a,
+    ) -> Self {
+        self.on_move = Some(Box::new(on_move));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Connection`] is
+    /// created.
+    pub fn on_connect(
+        mut self,
+        on_connect: impl Fn(Connection) -> Message + 'a,
+    ) -> Self {
+        self.on_connect = Some(Box::new(on_connect));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Connection`] is
+    /// removed.
+    pub fn on_disconnect(
+        mut self,
+        on_disconnect: impl Fn(Connection) -> Message + 'a,
+    ) -> Self {
+        self.on_disconnect = Some(Box::new(on_disconnect));
+        self
+    }
+
+    /// Sets the message that should be produced when the selection changes.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(Vec<usize>) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the style of the [`NodeEditor`].
+    pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`NodeEditor`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    viewport: Viewport,
+    interaction: Interaction,
+    modifiers: keyboard::Modifiers,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+enum Interaction {
+    #[default]
+    None,
+    Panning(Point),
+    Dragging {
+        node: usize,
+        grab: Vector,
+    },
+    Connecting {
+        from: Port,
+        to: Point,
+    },
+    Selecting(rubber_band::Selection),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Hit {
+    Input(Port),
+    Output(Port),
+    Node(usize),
+}
+
+impl<'a, Message, Theme> NodeEditor<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Returns the screen bounds of every [`Node`].
+    fn node_bounds(
+        &self,
+        layout: Layout<'_>,
+        viewport: Viewport,
+    ) -> Vec<Rectangle> {
+        self.nodes
+            .iter()
+            .zip(layout.children())
+            .map(|(node, layout)| {
+                layout.bounds() + viewport.translation(node.position)
+            })
+            .collect()
+    }
+
+    fn input_position(
+        &self,
+        layout: Layout<'_>,
+        viewport: Viewport,
+        port: Port,
+    ) -> Option<Point> {
+        let node = self.nodes.get(port.node)?;
+        let bounds = *self.node_bounds(layout, viewport).get(port.node)?;
+
+        Some(port_position(bounds, port.index, node.inputs, 0.0))
+    }
+
+    fn output_position(
+        &self,
+        layout: Layout<'_>,
+        viewport: Viewport,
+        port: Port,
+    ) -> Option<Point> {
+        let node = self.nodes.get(port.node)?;
+        let bounds = *self.node_bounds(layout, viewport).get(port.node)?;
+
+        Some(port_position(bounds, port.index, node.outputs, 1.0))
+    }
+
+    fn hit_test(
+        &self,
+        layout: Layout<'_>,
+        viewport: Viewport,
+        position: Point,
+    ) -> Option<Hit> {
+        let is_near = |port: Point| port.distance(position) <= PORT_HIT_RADIUS;
+
+        for (index, (node, bounds)) in self
+            .nodes
+            .iter()
+            .zip(self.node_bounds(layout, viewport))
+            .enumerate()
+            .rev()
+        {
+            if let Some(port) = (0..node.inputs).find(|port| {
+                is_near(port_position(bounds, *port, node.inputs, 0.0))
+            }) {
+                return Some(Hit::Input(Port {
+                    node: index,
+                    index: port,
+                }));
+            }
+
+            if let Some(port) = (0..node.outputs).find(|port| {
+                is_near(port_position(bounds, *port, node.outputs, 1.0))
+            }) {
+                return Some(Hit::Output(Port {
+                    node: index,
+                    index: port,
+                }));
+            }
+        }
+
+        self.node_bounds(layout, viewport)
+            .into_iter()
+            .enumerate()
+            .rev()
+            .find(|(_, bounds)| bounds.contains(position))
+            .map(|(index, _)| Hit::Node(index))
+    }
+}
+
+/// Returns the position of a port along the left (`0.0`) or right (`1.0`)
+/// edge of the given node bounds.
+fn port_position(
+    bounds: Rectangle,
+    index: usize,
+    count: usize,
+    side: f32,
+) -> Point {
+    Point::new(
+        bounds.x + bounds.width * side,
+        bounds.y + bounds.height * (index + 1) as f32 / (count + 1) as f32,
+    )
+}
+
+fn connection_path(from: Point, to: Point) -> canvas::Path {
+    let bend = ((to.x - from.x).abs() / 2.0).max(50.0);
+
+    canvas::Path::new(|builder| {
+        builder.move_to(from);
+        builder.bezier_curve_to(
+            Point::new(from.x + bend, from.y),
+            Point::new(to.x - bend, to.y),
+            to,
+        );
+    })
+}
+
+impl<'a, Message, Theme> Widget<Message, Theme, Renderer>
+    for NodeEditor<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.nodes
+            .iter()
+            .map(|node| Tree::new(&node.content))
+            .collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let ids = self
+            .nodes
+            .iter()
+            .map(|node| node.content.as_widget().id())
+            .collect();
+
+        tree.diff_children_custom(
+            &mut self.nodes,
+            ids,
+            |state, node| state.diff(node.content.as_widget_mut()),
+            |node| Tree::new(&node.content),
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits.resolve(self.width, self.height, Size::ZERO);
+
+        let nodes = self
+            .nodes
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(node, tree)| {
+                let content = node.content.as_widget().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::NONE,
+                );
+
+                layout::Node::container(content, Padding::new(PADDING))
+                    .move_to(node.position)
+            })
+            .collect();
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.nodes
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((node, state), layout)| {
+                    node.content.as_widget().operate(
+                        state,
+                        layout.children().next().unwrap(),
+                        renderer,
+                        operation,
+                    );
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = *tree.state.downcast_ref::<State>();
+
+        if matches!(state.interaction, Interaction::None) {
+            let status = self
+                .nodes
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .map(|((node, tree), layout)| {
+                    let translation = state.viewport.translation(node.position);
+
+                    let cursor = match cursor.position_over(bounds) {
+                        Some(position) => {
+                            mouse::Cursor::Available(position - translation)
+                        }
+                        None => mouse::Cursor::Unavailable,
+                    };
+
+                    node.content.as_widget_mut().on_event(
+                        tree,
+                        event.clone(),
+                        layout.children().next().unwrap(),
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        &(*viewport - translation),
+                    )
+                })
+                .fold(event::Status::Ignored, event::Status::merge);
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        let origin = Vector::new(bounds.x, bounds.y);
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                state.interaction =
+                    match self.hit_test(layout, state.viewport, position) {
+                        Some(Hit::Output(port)) => Interaction::Connecting {
+                            from: port,
+                            to: position,
+                        },
+                        Some(Hit::Input(port)) => {
+                            let Some(connection) = self
+                                .connections
+                                .iter()
+                                .find(|connection| connection.to == port)
+                            else {
+                                return event::Status::Captured;
+                            };
+
+                            if let Some(on_disconnect) = &self.on_disconnect {
+                                shell.publish(on_disconnect(*connection));
+                            }
+
+                            Interaction::Connecting {
+                                from: connection.from,
+                                to: position,
+                            }
+                        }
+                        Some(Hit::Node(node)) => {
+                            let node_bounds = self
+                                .node_bounds(layout, state.viewport)
+                                .get(node)
+                                .copied()
+                                .unwrap_or(bounds);
+
+                            if !self.selection.contains(&node) {
+                                if let Some(on_select) = &self.on_select {
+                                    shell.publish(on_select(vec![node]));
+                                }
+                            }
+
+                            Interaction::Dragging {
+                                node,
+                                grab: position - node_bounds.position(),
+                            }
+                        }
+                        None if state.modifiers.shift() => {
+                            Interaction::Selecting(rubber_band::Selection::new(
+                                position,
+                            ))
+                        }
+                        None => {
+                            if !self.selection.is_empty() {
+                                if let Some(on_select) = &self.on_select {
+                                    shell.publish(on_select(Vec::new()));
+                                }
+                            }
+
+                            Interaction::Panning(position)
+                        }
+                    };
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.interaction = Interaction::Panning(position);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                match &mut state.interaction {
+                    Interaction::None => {}
+                    Interaction::Panning(from) => {
+                        state.viewport.pan(position - *from);
+                        *from = position;
+                    }
+                    Interaction::Dragging { node, grab } => {
+                        if let Some(on_move) = &self.on_move {
+                            let top_left = position - *grab - origin;
+
+                            shell.publish(on_move(
+                                *node,
+                                state.viewport.to_world(top_left),
+                            ));
+                        }
+                    }
+                    Interaction::Connecting { to, .. } => {
+                        *to = position;
+                    }
+                    Interaction::Selecting(selection) => {
+                        selection.update(position);
+                    }
+                }
+
+                if !matches!(state.interaction, Interaction::None) {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Left | mouse::Button::Middle,
+            )) => {
+                match std::mem::take(&mut state.interaction) {
+                    Interaction::None => return event::Status::Ignored,
+                    Interaction::Connecting { from, to } => {
+                        if let Some(Hit::Input(port)) =
+                            self.hit_test(layout, state.viewport, to)
+                        {
+                            if port.node != from.node {
+                                if let Some(on_connect) = &self.on_connect {
+                                    shell.publish(on_connect(Connection {
+                                        from,
+                                        to: port,
+                                    }));
+                                }
+                            }
+                        }
+                    }
+                    Interaction::Selecting(selection) => {
+                        if let Some(on_select) = &self.on_select {
+                            shell.publish(on_select(selection.intersecting(
+                                self.node_bounds(layout, state.viewport),
+                            )));
+                        }
+                    }
+                    Interaction::Panning(_) | Interaction::Dragging { .. } => {}
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if let Some(position) = cursor.position_in(bounds) {
+                    if state.modifiers.zoom() {
+                        state
+                            .viewport
+                            .zoom_at(position, delta, MIN_ZOOM, MAX_ZOOM);
+                    } else {
+                        state.viewport.scroll(delta);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+
+        match state.interaction {
+            Interaction::Panning(_) | Interaction::Dragging { .. } => {
+                return mouse::Interaction::Grabbing;
+            }
+            Interaction::Connecting { .. } | Interaction::Selecting(_) => {
+                return mouse::Interaction::Crosshair;
+            }
+            Interaction::None => {}
+        }
+
+        let Some(cursor_position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        let content = self
+            .nodes
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((node, tree), layout)| {
+                let translation = state.viewport.translation(node.position);
+
+                node.content.as_widget().mouse_interaction(
+                    tree,
+                    layout.children().next().unwrap(),
+                    mouse::Cursor::Available(cursor_position - translation),
+                    &(*viewport - translation),
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if content != mouse::Interaction::default() {
+            return content;
+        }
+
+        match self.hit_test(layout, state.viewport, cursor_position) {
+            Some(Hit::Input(_) | Hit::Output(_)) => mouse::Interaction::Pointer,
+            Some(Hit::Node(_)) => mouse::Interaction::Grab,
+            None => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+
+        let Some(visible_bounds) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        // Connections are drawn below the nodes
+        let origin = Vector::new(bounds.x, bounds.y);
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let stroke = canvas::Stroke::default()
+            .with_color(appearance.connection_color)
+            .with_width(appearance.connection_width);
+
+        for connection in self.connections {
+            let (Some(from), Some(to)) = (
+                self.output_position(layout, state.viewport, connection.from),
+                self.input_position(layout, state.viewport, connection.to),
+            ) else {
+                continue;
+            };
+
+            frame.stroke(
+                &connection_path(from - origin, to - origin),
+                stroke.clone(),
+            );
+        }
+
+        if let Interaction::Connecting { from, to } = state.interaction {
+            if let Some(from) =
+                self.output_position(layout, state.viewport, from)
+            {
+                frame.stroke(
+                    &connection_path(from - origin, to - origin),
+                    stroke,
+                );
+            }
+        }
+
+        renderer.with_layer(visible_bounds, |renderer| {
+            renderer.with_translation(origin, |renderer| {
+                renderer.draw(vec![frame.into_geometry()]);
+            });
+        });
+
+        renderer.with_layer(visible_bounds, |renderer| {
+            for (index, (((node, tree), layout), node_bounds)) in self
+                .nodes
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .zip(self.node_bounds(layout, state.viewport))
+                .enumerate()
+            {
+                // Cull nodes outside of the visible bounds
+                if !node_bounds
+                    .expand(PORT_HIT_RADIUS)
+                    .intersects(&visible_bounds)
+                {
+                    continue;
+                }
+
+                let border = if self.selection.contains(&index) {
+                    appearance.selected_node_border
+                } else {
+                    appearance.node_border
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: node_bounds,
+                        border,
+                        ..renderer::Quad::default()
+                    },
+                    appearance.node_background,
+                );
+
+                let translation = state.viewport.translation(node.position);
+
+                let cursor = match cursor.position_over(visible_bounds) {
+                    Some(position) => {
+                        mouse::Cursor::Available(position - translation)
+                    }
+                    None => mouse::Cursor::Unavailable,
+                };
+
+                renderer.with_translation(translation, |renderer| {
+                    node.content.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        style,
+                        layout.children().next().unwrap(),
+                        cursor,
+                        &(visible_bounds - translation),
+                    );
+                });
+
+                let ports = (0..node.inputs)
+                    .map(|port| {
+                        port_position(node_bounds, port, node.inputs, 0.0)
+                    })
+                    .chain((0..node.outputs).map(|port| {
+                        port_position(node_bounds, port, node.outputs, 1.0)
+                    }));
+
+                for port in ports {
+                    let radius = appearance.port_radius;
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: port.x - radius,
+                                y: port.y - radius,
+                                width: radius * 2.0,
+                                height: radius * 2.0,
+                            },
+                            border: crate::core::Border::with_radius(radius),
+                            ..renderer::Quad::default()
+                        },
+                        appearance.port_color,
+                    );
+                }
+            }
+        });
+
+        if let Interaction::Selecting(selection) = state.interaction {
+            renderer.with_layer(visible_bounds, |renderer| {
+                selection.draw(
+                    renderer,
+                    rubber_band::Appearance {
+                        background: appearance.selection_background,
+                        border: appearance.selection_border,
+                    },
+                );
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let viewport = tree.state.downcast_ref::<State>().viewport;
+
+        let children = self
+            .nodes
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((node, tree), layout)| {
+                let translation = viewport.translation(node.position);
+
+                node.content
+                    .as_widget_mut()
+                    .overlay(tree, layout.children().next().unwrap(), renderer)
+                    .map(|overlay| overlay.translate(translation))
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme> From<NodeEditor<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+{
+    fn from(node_editor: NodeEditor<'a, Message, Theme>) -> Self {
+        Self::new(node_editor)
+    }
+}
@@ -0,0 +1,670 @@
+//! Display fields that can be filled with numbers.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::widget::{self, Widget};
+use crate::core::{
+    Border, Clipboard, Color, Element, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size,
+};
+use crate::text_input::{self, TextInput};
+
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// A [`TextInput`] that only accepts numbers, with a pair of buttons to
+/// increment and decrement its value.
+///
+/// The value can also be adjusted with the arrow keys while focused and with
+/// the mouse wheel while hovered. Values are always clamped to the range of
+/// the [`NumberInput`].
+///
+/// # Example
+/// ```no_run
+/// # pub type NumberInput<'a, T, Message> =
+/// #     iced_widget::NumberInput<'a, T, Message, iced_widget::style::Theme, iced_widget::renderer::Renderer>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     VolumeChanged(u8),
+/// }
+///
+/// let volume = 50;
+///
+/// let input = NumberInput::new(volume, Message::VolumeChanged)
+///     .range(0..=100)
+///     .step(5);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: text_input::StyleSheet,
+    Renderer: text::Renderer,
+{
+    value: T,
+    range: RangeInclusive<T>,
+    step: T,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    text_input: TextInput<'a, InputEvent, Theme, Renderer>,
+    padding: Padding,
+    size: Option<Pixels>,
+    style: <Theme as text_input::StyleSheet>::Style,
+}
+
+/// The width of the increment and decrement buttons of a [`NumberInput`].
+const BUTTONS_WIDTH: f32 = 20.0;
+
+impl<'a, T, Message, Theme, Renderer>
+    NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy
+        + PartialOrd
+        + Display
+        + FromStr
+        + num_traits::Num
+        + num_traits::Bounded,
+    Theme: text_input::StyleSheet,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`NumberInput`].
+    ///
+    /// It expects:
+    /// - the current value
+    /// - a function that will be called when the value changes. It receives
+    ///   the new value and must produce a `Message`.
+    pub fn new(value: T, on_change: impl Fn(T) -> Message + 'a) -> Self {
+        let padding = text_input::DEFAULT_PADDING;
+
+        Self {
+            value,
+            range: T::min_value()..=T::max_value(),
+            step: T::one(),
+            on_change: Box::new(on_change),
+            text_input: TextInput::new("", &value.to_string())
+                .on_input(InputEvent::Changed)
+                .on_submit(InputEvent::Submitted)
+                .padding(with_buttons(padding)),
+            padding,
+            size: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the inclusive range of accepted values of the [`NumberInput`].
+    ///
+    /// By default, every value of `T` is accepted.
+    pub fn range(mut self, range: RangeInclusive<T>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Sets the step size of the [`NumberInput`].
+    ///
+    /// The step must be positive. It defaults to 1 unit.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`NumberInput`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self.text_input = self.text_input.padding(with_buttons(self.padding));
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        self.text_input = self.text_input.size(size);
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`NumberInput`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the style of the [`NumberInput`].
+    pub fn style<S>(mut self, style: S) -> Self
+    where
+        S: Into<<Theme as text_input::StyleSheet>::Style> + Clone,
+    {
+        self.style = style.clone().into();
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    fn clamp(&self, value: T) -> T {
+        let (start, end) = (*self.range.start(), *self.range.end());
+
+        // Unordered values, like NaN, fall outside of any range
+        if value.partial_cmp(&value).is_none() {
+            return start;
+        }
+
+        if value < start {
+            start
+        } else if value > end {
+            end
+        } else {
+            value
+        }
+    }
+
+    fn increment(&self) -> T {
+        let end = *self.range.end();
+        let value = self.clamp(self.value);
+
+        // The step is checked against the bounds of `T` first, so it never
+        // overflows when the range sits next to them
+        if value >= end || value > T::max_value() - self.step {
+            return end;
+        }
+
+        let next = value + self.step;
+
+        if next > end {
+            end
+        } else {
+            next
+        }
+    }
+
+    fn decrement(&self) -> T {
+        let start = *self.range.start();
+        let value = self.clamp(self.value);
+
+        if value <= start || value < T::min_value() + self.step {
+            return start;
+        }
+
+        let previous = value - self.step;
+
+        if previous < start {
+            start
+        } else {
+            previous
+        }
+    }
+
+    fn change(
+        &mut self,
+        new_value: T,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        state.buffer = None;
+        self.text_input.set_value(&new_value.to_string());
+
+        if new_value != self.value {
+            self.value = new_value;
+            shell.publish((self.on_change)(new_value));
+        }
+    }
+
+    fn parse(&self, buffer: &str) -> Option<T> {
+        buffer
+            .trim()
+            .parse::<T>()
+            .ok()
+            // Unordered values, like NaN, are rejected instead of clamped
+            .filter(|value| value.partial_cmp(value).is_some())
+            .map(|value| self.clamp(value))
+    }
+
+    fn commit(&mut self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let new_value = state
+            .buffer
+            .as_deref()
+            .and_then(|buffer| self.parse(buffer))
+            .unwrap_or(self.value);
+
+        self.change(new_value, state, shell);
+    }
+}
+
+/// The local state of a [`NumberInput`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    buffer: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+enum InputEvent {
+    Changed(String),
+    Submitted,
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy
+        + PartialOrd
+        + Display
+        + FromStr
+        + num_traits::Num
+        + num_traits::Bounded,
+    Theme: text_input::StyleSheet,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.text_input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&mut self, tree: &mut widget::Tree) {
+        let is_focused = is_focused::<Renderer>(&tree.children[0]);
+        let state = tree.state.downcast_mut::<State>();
+
+        if !is_focused {
+            state.buffer = None;
+        }
+
+        if let Some(buffer) = &state.buffer {
+            self.text_input.set_value(buffer);
+        }
+
+        Widget::<InputEvent, Theme, Renderer>::diff(
+            &mut self.text_input,
+            &mut tree.children[0],
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::<InputEvent, Theme, Renderer>::size(&self.text_input)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.text_input
+            .layout(&mut tree.children[0], renderer, limits, None)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let buttons = buttons_bounds(bounds);
+        let was_focused = is_focused::<Renderer>(&tree.children[0]);
+
+        match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(buttons) {
+                    let new_value = if position.y < buttons.center_y() {
+                        self.increment()
+                    } else {
+                        self.decrement()
+                    };
+
+                    self.change(
+                        new_value,
+                        tree.state.downcast_mut::<State>(),
+                        shell,
+                    );
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if cursor.is_over(bounds) {
+                    let y = match delta {
+                        mouse::ScrollDelta::Lines { y, .. }
+                        | mouse::ScrollDelta::Pixels { y, .. } => *y,
+                    };
+
+                    if y != 0.0 {
+                        let new_value = if y > 0.0 {
+                            self.increment()
+                        } else {
+                            self.decrement()
+                        };
+
+                        self.change(
+                            new_value,
+                            tree.state.downcast_mut::<State>(),
+                            shell,
+                        );
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) if was_focused
+                && matches!(
+                    named,
+                    key::Named::ArrowUp | key::Named::ArrowDown
+                ) =>
+            {
+                let new_value = if *named == key::Named::ArrowUp {
+                    self.increment()
+                } else {
+                    self.decrement()
+                };
+
+                self.change(
+                    new_value,
+                    tree.state.downcast_mut::<State>(),
+                    shell,
+                );
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let mut local_messages = Vec::new();
+        let mut local_shell = Shell::new(&mut local_messages);
+
+        let status = self.text_input.on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        if let Some(cursor) = local_shell.ime_request() {
+            shell.request_ime(cursor);
+        }
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        for message in local_messages {
+            match message {
+                InputEvent::Changed(buffer) => {
+                    self.text_input.set_value(&buffer);
+
+                    if let Ok(value) = buffer.trim().parse::<T>() {
+                        if self.range.contains(&value) && value != self.value {
+                            self.value = value;
+                            shell.publish((self.on_change)(value));
+                        }
+                    }
+
+                    state.buffer = Some(buffer);
+                }
+                InputEvent::Submitted => {
+                    self.commit(state, shell);
+                }
+            }
+        }
+
+        if was_focused && !is_focused::<Renderer>(&tree.children[0]) {
+            self.commit(state, shell);
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(buttons_bounds(layout.bounds())) {
+            mouse::Interaction::Pointer
+        } else {
+            self.text_input.mouse_interaction(
+                &tree.children[0],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.text_input.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            layout,
+            cursor,
+            None,
+            viewport,
+        );
+
+        let bounds = layout.bounds();
+        let buttons = buttons_bounds(bounds);
+
+        let appearance = if is_focused::<Renderer>(&tree.children[0]) {
+            theme.focused(&self.style)
+        } else if cursor.is_over(bounds) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        let separator = appearance.border.width.max(1.0);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: buttons.x,
+                    y: buttons.y,
+                    width: separator,
+                    height: buttons.height,
+                },
+                border: Border::default(),
+                ..renderer::Quad::default()
+            },
+            appearance.border.color,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: buttons.x,
+                    y: buttons.center_y() - separator / 2.0,
+                    width: buttons.width,
+                    height: separator,
+                },
+                border: Border::default(),
+                ..renderer::Quad::default()
+            },
+            appearance.border.color,
+        );
+
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let half_height = buttons.height / 2.0;
+
+        for (content, center_y) in [
+            ("+", buttons.y + half_height / 2.0),
+            ("-", buttons.y + half_height * 1.5),
+        ] {
+            let is_hovered =
+                cursor.position_over(buttons).is_some_and(|position| {
+                    (position.y < buttons.center_y())
+                        == (center_y < buttons.center_y())
+                });
+
+            renderer.fill_text(
+                Text {
+                    content,
+                    bounds: Size::new(buttons.width, half_height),
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                    wrap: text::Wrap::default(),
+                    truncate: text::Truncate::default(),
+                },
+                Point::new(buttons.center_x(), center_y),
+                if is_hovered {
+                    appearance.icon_color
+                } else {
+                    Color {
+                        a: appearance.icon_color.a * 0.7,
+                        ..appearance.icon_color
+                    }
+                },
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<NumberInput<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy
+        + PartialOrd
+        + Display
+        + FromStr
+        + num_traits::Num
+        + num_traits::Bounded
+        + 'a,
+    Message: 'a,
+    Theme: text_input::StyleSheet + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Theme, Renderer>,
+    ) -> Self {
+        Self::new(number_input)
+    }
+}
+
+fn with_buttons(padding: Padding) -> Padding {
+    Padding {
+        right: padding.right + BUTTONS_WIDTH,
+        ..padding
+    }
+}
+
+fn buttons_bounds(bounds: Rectangle) -> Rectangle {
+    Rectangle {
+        x: bounds.x + bounds.width - BUTTONS_WIDTH,
+        width: BUTTONS_WIDTH,
+        ..bounds
+    }
+}
+
+fn is_focused<Renderer>(tree: &widget::Tree) -> bool
+where
+    Renderer: text::Renderer,
+{
+    tree.state
+        .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+        .is_focused()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::renderer::Null;
+
+    fn input(
+        value: u8,
+        range: RangeInclusive<u8>,
+        step: u8,
+    ) -> NumberInput<'static, u8, (), crate::Theme, Null> {
+        NumberInput::new(value, |_| ()).range(range).step(step)
+    }
+
+    #[test]
+    fn step_larger_than_range_reaches_bounds() {
+        assert_eq!(input(3, 0..=5, 10).increment(), 5);
+        assert_eq!(input(3, 0..=5, 10).decrement(), 0);
+    }
+
+    #[test]
+    fn step_next_to_type_bounds_does_not_overflow() {
+        assert_eq!(input(252, 250..=255, 10).increment(), 255);
+        assert_eq!(input(252, 250..=255, 10).decrement(), 250);
+        assert_eq!(input(250, 0..=u8::MAX, 10).increment(), u8::MAX);
+        assert_eq!(input(5, 0..=u8::MAX, 10).decrement(), 0);
+    }
+
+    #[test]
+    fn step_within_range_moves_value() {
+        assert_eq!(input(20, 0..=100, 5).increment(), 25);
+        assert_eq!(input(20, 0..=100, 5).decrement(), 15);
+    }
+
+    #[test]
+    fn unordered_values_are_rejected() {
+        let input: NumberInput<'static, f32, (), crate::Theme, Null> =
+            NumberInput::new(1.5, |_| ()).range(0.0..=10.0);
+
+        assert_eq!(input.parse("NaN"), None);
+        assert_eq!(input.parse(" 2.5 "), Some(2.5));
+        assert_eq!(input.parse("inf"), Some(10.0));
+        assert_eq!(input.clamp(f32::NAN), 0.0);
+    }
+}
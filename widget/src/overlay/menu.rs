@@ -310,7 +310,7 @@ where
             renderer::Quad {
                 bounds,
                 border: appearance.border,
-                ..renderer::Quad::default()
+                shadow: appearance.shadow,
             },
             appearance.background,
         );
@@ -535,6 +535,8 @@ where
                     horizontal_alignment: alignment::Horizontal::Left,
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
+                    wrap: text::Wrap::default(),
+                    truncate: text::Truncate::default(),
                 },
                 Point::new(bounds.x + self.padding.left, bounds.center_y()),
                 if is_selected {
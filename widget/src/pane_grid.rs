@@ -7,6 +7,7 @@
 //! drag and drop, and hotkey support.
 //!
 //! [`pane_grid` example]: https://github.com/iced-rs/iced/tree/0.10/examples/pane_grid
+mod animation;
 mod axis;
 mod configuration;
 mod content;
@@ -14,6 +15,8 @@ mod direction;
 mod draggable;
 mod node;
 mod pane;
+mod side;
+mod snapshot;
 mod split;
 mod title_bar;
 
@@ -27,25 +30,33 @@ pub use draggable::Draggable;
 use iced_renderer::core::widget::{Operation, OperationOutputWrapper};
 pub use node::Node;
 pub use pane::Pane;
+pub use side::Side;
+pub use snapshot::Snapshot;
 pub use split::Split;
 pub use state::State;
 pub use title_bar::TitleBar;
 
 pub use crate::style::pane_grid::{Appearance, Line, StyleSheet};
 
+use animation::Animation;
+
 use crate::container;
 use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay::{self, Group};
 use crate::core::renderer;
+use crate::core::time::Instant;
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     Clipboard, Element, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
     Vector, Widget,
 };
 
+use std::collections::BTreeMap;
+
 /// A collection of panes distributed using either vertical or horizontal splits
 /// to completely fill the space available.
 ///
@@ -61,10 +72,15 @@ use crate::core::{
 /// * Tracking of the last active pane
 /// * Mouse-based resizing
 /// * Drag and drop to reorganize panes
+/// * Animated transitions when panes are split, closed, swapped, or maximized
 /// * Hotkey support
 /// * Configurable modifier keys
 /// * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
 ///
+/// Only the left mouse button and touches resize and drag panes. Any other
+/// button, like a right click opening a context menu, always reaches the
+/// content of the panes.
+///
 /// ## Example
 ///
 /// ```no_run
@@ -241,11 +257,14 @@ where
     Theme: StyleSheet + container::StyleSheet,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<state::Action>()
+        tree::Tag::of::<Memory>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(state::Action::Idle)
+        tree::State::new(Memory {
+            action: state::Action::Idle,
+            animation: Animation::default(),
+        })
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -288,14 +307,22 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let size = limits.resolve(self.width, self.height, Size::ZERO);
+
+        let regions = tree.state.downcast_mut::<Memory>().animation.regions(
+            self.contents.layout(),
+            self.spacing,
+            size,
+            Instant::now(),
+        );
+
         layout(
             tree,
             renderer,
             limits,
-            self.contents.layout(),
+            &regions,
             self.width,
             self.height,
-            self.spacing,
             self.contents.iter(),
             |content, tree, renderer, limits| {
                 content.layout(tree, renderer, limits)
@@ -332,7 +359,16 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        let action = tree.state.downcast_mut::<state::Action>();
+        let memory = tree.state.downcast_mut::<Memory>();
+
+        if let Event::Window(_, window::Event::RedrawRequested(now)) = event {
+            if memory.animation.is_animating(now) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                shell.invalidate_layout();
+            }
+        }
+
+        let action = &mut memory.action;
 
         let on_drag = if self.drag_enabled() {
             &self.on_drag
@@ -356,6 +392,15 @@ where
 
         let picked_pane = action.picked_pane().map(|(pane, _)| pane);
 
+        // Panes still receive the events of a resize or a drag, so they can
+        // reset their state, but the cursor is not over them anymore
+        let cursor = if picked_pane.is_some() || action.picked_split().is_some()
+        {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
         self.contents
             .iter_mut()
             .zip(&mut tree.children)
@@ -387,7 +432,7 @@ where
         renderer: &Renderer,
     ) -> mouse::Interaction {
         mouse_interaction(
-            tree.state.downcast_ref(),
+            &tree.state.downcast_ref::<Memory>().action,
             self.contents.layout(),
             layout,
             cursor,
@@ -425,7 +470,7 @@ where
         viewport: &Rectangle,
     ) {
         draw(
-            tree.state.downcast_ref(),
+            &tree.state.downcast_ref::<Memory>().action,
             self.contents.layout(),
             layout,
             cursor,
@@ -482,15 +527,15 @@ where
     }
 }
 
-/// Calculates the [`Layout`] of a [`PaneGrid`].
+/// Calculates the [`Layout`] of a [`PaneGrid`], placing each pane in its
+/// region.
 pub fn layout<Renderer, T>(
     tree: &mut Tree,
     renderer: &Renderer,
     limits: &layout::Limits,
-    node: &Node,
+    regions: &BTreeMap<Pane, Rectangle>,
     width: Length,
     height: Length,
-    spacing: f32,
     contents: impl Iterator<Item = (Pane, T)>,
     layout_content: impl Fn(
         T,
@@ -501,7 +546,6 @@ pub fn layout<Renderer, T>(
 ) -> layout::Node {
     let size = limits.resolve(width, height, Size::ZERO);
 
-    let regions = node.pane_regions(spacing, size);
     let children = contents
         .zip(tree.children.iter_mut())
         .filter_map(|((pane, content), tree)| {
@@ -522,6 +566,12 @@ pub fn layout<Renderer, T>(
     layout::Node::with_children(size, children)
 }
 
+/// The local state of a [`PaneGrid`].
+struct Memory {
+    action: state::Action,
+    animation: Animation,
+}
+
 /// Processes an [`Event`] and updates the [`state`] of a [`PaneGrid`]
 /// accordingly.
 pub fn update<'a, Message, T: Draggable>(
@@ -697,7 +747,9 @@ pub fn update<'a, Message, T: Draggable>(
                         Size::new(bounds.width, bounds.height),
                     );
 
-                    if let Some((axis, rectangle, _)) = splits.get(&split) {
+                    if let Some((axis, rectangle, current_ratio)) =
+                        splits.get(&split)
+                    {
                         if let Some(cursor_position) = cursor.position() {
                             let ratio = match axis {
                                 Axis::Horizontal => {
@@ -717,10 +769,14 @@ pub fn update<'a, Message, T: Draggable>(
                                 }
                             };
 
-                            shell.publish(on_resize(ResizeEvent {
-                                split,
-                                ratio,
-                            }));
+                            // Don't publish redundant ratios, like when
+                            // moving along the split or past its limits
+                            if (ratio - current_ratio).abs() > f32::EPSILON {
+                                shell.publish(on_resize(ResizeEvent {
+                                    split,
+                                    ratio,
+                                }));
+                            }
 
                             event_status = event::Status::Captured;
                         }
@@ -791,6 +847,10 @@ pub fn mouse_interaction(
     spacing: f32,
     resize_leeway: Option<f32>,
 ) -> Option<mouse::Interaction> {
+    if action.picked_pane().is_some() && !cursor.is_over(layout.bounds()) {
+        return Some(mouse::Interaction::NotAllowed);
+    }
+
     if action.clicked_pane().is_some() || action.picked_pane().is_some() {
         return Some(mouse::Interaction::Grabbing);
     }
@@ -1247,3 +1307,249 @@ impl<'a, T> Contents<'a, T> {
         matches!(self, Self::Maximized(..))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPACING: f32 = 10.0;
+    const SIZE: Size = Size::new(200.0, 100.0);
+
+    enum Message {
+        Clicked(Pane),
+        Dragged(DragEvent),
+        Resized(ResizeEvent),
+    }
+
+    struct Item;
+
+    impl Draggable for Item {
+        fn can_be_dragged_at(
+            &self,
+            _layout: Layout<'_>,
+            _cursor: Point,
+        ) -> bool {
+            true
+        }
+    }
+
+    /// Two panes side by side, with a split line at x = 100.
+    struct Grid {
+        panes: State<()>,
+        regions: BTreeMap<Pane, Rectangle>,
+        action: state::Action,
+        resizable: bool,
+    }
+
+    impl Grid {
+        fn new(resizable: bool) -> Self {
+            let (mut panes, pane) = State::new(());
+            let _ = panes.split(Axis::Vertical, pane, ());
+
+            let regions = panes.layout().pane_regions(SPACING, SIZE);
+
+            Self {
+                panes,
+                regions,
+                action: state::Action::Idle,
+                resizable,
+            }
+        }
+
+        fn send(
+            &mut self,
+            event: Event,
+            position: Point,
+        ) -> (event::Status, Vec<Message>) {
+            let node = layout::Node::with_children(
+                SIZE,
+                self.regions
+                    .values()
+                    .map(|region| {
+                        layout::Node::new(region.size())
+                            .move_to(region.position())
+                    })
+                    .collect(),
+            );
+
+            let on_click: Option<Box<dyn Fn(Pane) -> Message>> =
+                Some(Box::new(Message::Clicked));
+            let on_drag: Option<Box<dyn Fn(DragEvent) -> Message>> =
+                Some(Box::new(Message::Dragged));
+            let on_resize = self.resizable.then(|| {
+                let on_resize: Box<dyn Fn(ResizeEvent) -> Message> =
+                    Box::new(Message::Resized);
+
+                (0.0, on_resize)
+            });
+
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            let status = update(
+                &mut self.action,
+                self.panes.layout(),
+                &event,
+                Layout::new(&node),
+                mouse::Cursor::Available(position),
+                &mut shell,
+                SPACING,
+                self.regions.keys().map(|pane| (*pane, Item)),
+                &on_click,
+                &on_drag,
+                &on_resize,
+            );
+
+            (status, messages)
+        }
+
+        fn press(
+            &mut self,
+            button: mouse::Button,
+            position: Point,
+        ) -> (event::Status, Vec<Message>) {
+            self.send(
+                Event::Mouse(mouse::Event::ButtonPressed(button)),
+                position,
+            )
+        }
+
+        fn release(
+            &mut self,
+            button: mouse::Button,
+            position: Point,
+        ) -> (event::Status, Vec<Message>) {
+            self.send(
+                Event::Mouse(mouse::Event::ButtonReleased(button)),
+                position,
+            )
+        }
+
+        fn move_to(
+            &mut self,
+            position: Point,
+        ) -> (event::Status, Vec<Message>) {
+            self.send(
+                Event::Mouse(mouse::Event::CursorMoved { position }),
+                position,
+            )
+        }
+    }
+
+    const ON_SPLIT: Point = Point::new(100.0, 50.0);
+    const ON_FIRST_PANE: Point = Point::new(40.0, 50.0);
+    const ON_SECOND_PANE: Point = Point::new(160.0, 50.0);
+
+    #[test]
+    fn right_click_reaches_content() {
+        for resizable in [false, true] {
+            for position in [ON_SPLIT, ON_FIRST_PANE, ON_SECOND_PANE] {
+                let mut grid = Grid::new(resizable);
+
+                let (status, messages) =
+                    grid.press(mouse::Button::Right, position);
+
+                assert_eq!(status, event::Status::Ignored);
+                assert!(messages.is_empty());
+                assert_eq!(grid.action, state::Action::Idle);
+
+                let (status, messages) =
+                    grid.release(mouse::Button::Right, position);
+
+                assert_eq!(status, event::Status::Ignored);
+                assert!(messages.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn left_click_on_split_resizes() {
+        let mut grid = Grid::new(true);
+
+        let (status, messages) = grid.press(mouse::Button::Left, ON_SPLIT);
+
+        assert_eq!(status, event::Status::Captured);
+        assert!(messages.is_empty());
+        assert!(grid.action.picked_split().is_some());
+
+        let (status, messages) = grid.move_to(Point::new(120.0, 50.0));
+
+        assert_eq!(status, event::Status::Captured);
+        assert!(matches!(
+            messages[..],
+            [Message::Resized(ResizeEvent { ratio, .. })] if ratio == 0.6
+        ));
+
+        // A right click during a resize does not interrupt it
+        let (status, messages) = grid.press(mouse::Button::Right, ON_SPLIT);
+
+        assert_eq!(status, event::Status::Ignored);
+        assert!(messages.is_empty());
+        assert!(grid.action.picked_split().is_some());
+
+        let (status, messages) =
+            grid.release(mouse::Button::Left, Point::new(120.0, 50.0));
+
+        assert_eq!(status, event::Status::Captured);
+        assert!(messages.is_empty());
+        assert_eq!(grid.action, state::Action::Idle);
+    }
+
+    #[test]
+    fn left_click_on_split_without_resize_does_not_resize() {
+        let mut grid = Grid::new(false);
+
+        let _ = grid.press(mouse::Button::Left, ON_SPLIT);
+
+        assert!(grid.action.picked_split().is_none());
+
+        let (_, messages) = grid.move_to(Point::new(120.0, 50.0));
+
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn left_drag_on_pane_moves_it() {
+        let mut grid = Grid::new(true);
+
+        let first = *grid.regions.keys().next().unwrap();
+        let second = *grid.regions.keys().nth(1).unwrap();
+
+        let (status, messages) = grid.press(mouse::Button::Left, ON_FIRST_PANE);
+
+        assert_eq!(status, event::Status::Captured);
+        assert!(
+            matches!(messages[..], [Message::Clicked(pane)] if pane == first)
+        );
+        assert!(grid.action.clicked_pane().is_some());
+
+        let (_, messages) = grid.move_to(Point::new(70.0, 50.0));
+
+        assert!(matches!(
+            messages[..],
+            [Message::Dragged(DragEvent::Picked { pane })] if pane == first
+        ));
+        assert!(grid.action.picked_pane().is_some());
+
+        // A right click during a drag does not drop the pane
+        let (status, messages) =
+            grid.press(mouse::Button::Right, ON_SECOND_PANE);
+
+        assert_eq!(status, event::Status::Ignored);
+        assert!(messages.is_empty());
+        assert!(grid.action.picked_pane().is_some());
+
+        let (status, messages) =
+            grid.release(mouse::Button::Left, ON_SECOND_PANE);
+
+        assert_eq!(status, event::Status::Captured);
+        assert!(matches!(
+            messages[..],
+            [Message::Dragged(DragEvent::Dropped {
+                pane,
+                target: Target::Pane(target, _),
+            })] if pane == first && target == second
+        ));
+        assert_eq!(grid.action, state::Action::Idle);
+    }
+}
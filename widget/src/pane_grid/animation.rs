@@ -0,0 +1,135 @@
+use crate::core::time::{Duration, Instant};
+use crate::core::{Rectangle, Size};
+use crate::pane_grid::{Node, Pane};
+
+use std::collections::BTreeMap;
+
+/// The transition of the regions of a [`PaneGrid`] between layouts.
+///
+/// Only changes to the arrangement of the panes are animated; moving a split
+/// or resizing the [`PaneGrid`] is applied at once.
+///
+/// [`PaneGrid`]: super::PaneGrid
+#[derive(Debug, Clone, Default)]
+pub(super) struct Animation {
+    layout: Option<Node>,
+    regions: BTreeMap<Pane, Rectangle>,
+    from: BTreeMap<Pane, Rectangle>,
+    started_at: Option<Instant>,
+}
+
+impl Animation {
+    const DURATION: Duration = Duration::from_millis(150);
+
+    /// Returns the regions of the panes of the given layout [`Node`] at the
+    /// given time, starting a transition if the panes were rearranged.
+    pub fn regions(
+        &mut self,
+        layout: &Node,
+        spacing: f32,
+        size: Size,
+        now: Instant,
+    ) -> BTreeMap<Pane, Rectangle> {
+        let target = layout.pane_regions(spacing, size);
+
+        if self
+            .layout
+            .as_ref()
+            .is_some_and(|last| !is_same_arrangement(last, layout))
+        {
+            self.from = std::mem::take(&mut self.regions);
+            self.started_at = Some(now);
+        }
+
+        self.layout = Some(layout.clone());
+
+        let progress = self
+            .started_at
+            .map(|started_at| {
+                (now.saturating_duration_since(started_at).as_secs_f32()
+                    / Self::DURATION.as_secs_f32())
+                .min(1.0)
+            })
+            .unwrap_or(1.0);
+
+        self.regions = if progress < 1.0 {
+            let t = ease_out(progress);
+
+            target
+                .into_iter()
+                .map(|(pane, to)| {
+                    // New panes grow from their center
+                    let from =
+                        self.from.get(&pane).copied().unwrap_or(Rectangle {
+                            x: to.center_x(),
+                            y: to.center_y(),
+                            width: 0.0,
+                            height: 0.0,
+                        });
+
+                    (pane, interpolate(from, to, t))
+                })
+                .collect()
+        } else {
+            self.started_at = None;
+            self.from.clear();
+
+            target
+        };
+
+        self.regions.clone()
+    }
+
+    /// Returns whether the regions are still transitioning at the given
+    /// time.
+    pub fn is_animating(&self, now: Instant) -> bool {
+        self.started_at.is_some_and(|started_at| {
+            now.saturating_duration_since(started_at) < Self::DURATION
+        })
+    }
+}
+
+/// Returns whether both layouts place the same panes in the same positions,
+/// regardless of the ratio of their splits.
+fn is_same_arrangement(a: &Node, b: &Node) -> bool {
+    match (a, b) {
+        (Node::Pane(a), Node::Pane(b)) => a == b,
+        (
+            Node::Split {
+                id: id_a,
+                axis: axis_a,
+                a: a_a,
+                b: b_a,
+                ..
+            },
+            Node::Split {
+                id: id_b,
+                axis: axis_b,
+                a: a_b,
+                b: b_b,
+                ..
+            },
+        ) => {
+            id_a == id_b
+                && axis_a == axis_b
+                && is_same_arrangement(a_a, a_b)
+                && is_same_arrangement(b_a, b_b)
+        }
+        _ => false,
+    }
+}
+
+fn ease_out(t: f32) -> f32 {
+    1.0 - (1.0 - t).powi(3)
+}
+
+fn interpolate(from: Rectangle, to: Rectangle, t: f32) -> Rectangle {
+    let lerp = |a: f32, b: f32| a + (b - a) * t;
+
+    Rectangle {
+        x: lerp(from.x, to.x),
+        y: lerp(from.y, to.y),
+        width: lerp(from.width, to.width),
+        height: lerp(from.height, to.height),
+    }
+}
@@ -6,7 +6,9 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{Clipboard, Element, Layout, Point, Rectangle, Shell, Size};
 use crate::pane_grid::{Draggable, TitleBar};
 
@@ -26,6 +28,7 @@ pub struct Content<
     title_bar: Option<TitleBar<'a, Message, Theme, Renderer>>,
     body: Element<'a, Message, Theme, Renderer>,
     style: Theme::Style,
+    throttle: Option<Duration>,
 }
 
 impl<'a, Message, Theme, Renderer> Content<'a, Message, Theme, Renderer>
@@ -39,6 +42,7 @@ where
             title_bar: None,
             body: body.into(),
             style: Default::default(),
+            throttle: None,
         }
     }
 
@@ -56,6 +60,32 @@ where
         self.style = style.into();
         self
     }
+
+    /// Limits the body of the [`Content`] to at most one redraw per
+    /// `interval`.
+    ///
+    /// Redraw requests of the body are postponed until the interval has
+    /// elapsed. This is useful to keep expensive content of unfocused panes,
+    /// like animated charts, from updating every frame.
+    pub fn throttle(mut self, interval: Duration) -> Self {
+        self.throttle = Some(interval);
+        self
+    }
+}
+
+/// The redraw bookkeeping of a throttled [`Content`].
+#[derive(Debug, Default)]
+struct Throttle {
+    last_redraw: Option<Instant>,
+}
+
+impl Throttle {
+    fn next_redraw(&self, interval: Duration) -> window::RedrawRequest {
+        self.last_redraw
+            .map_or(window::RedrawRequest::NextFrame, |last_redraw| {
+                window::RedrawRequest::At(last_redraw + interval)
+            })
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Content<'a, Message, Theme, Renderer>
@@ -71,13 +101,15 @@ where
         };
 
         Tree {
+            tag: tree::Tag::of::<Throttle>(),
+            state: tree::State::new(Throttle::default()),
             children,
             ..Tree::empty()
         }
     }
 
     pub(super) fn diff(&mut self, tree: &mut Tree) {
-        if tree.children.len() == 2 {
+        if tree.children.len() == 2 && tree.tag == tree::Tag::of::<Throttle>() {
             if let Some(title_bar) = self.title_bar.as_mut() {
                 title_bar.diff(&mut tree.children[1]);
             }
@@ -259,6 +291,66 @@ where
 
         let body_status = if is_picked {
             event::Status::Ignored
+        } else if let Some(interval) = self.throttle {
+            let throttle = tree.state.downcast_mut::<Throttle>();
+
+            if let Event::Window(_, window::Event::RedrawRequested(now)) =
+                &event
+            {
+                match throttle.last_redraw {
+                    Some(last_redraw) if *now < last_redraw + interval => {
+                        shell.request_redraw(throttle.next_redraw(interval));
+
+                        return event_status;
+                    }
+                    _ => {
+                        throttle.last_redraw = Some(*now);
+                    }
+                }
+            }
+
+            let mut messages = Vec::new();
+            let mut local_shell = Shell::new(&mut messages);
+
+            let status = self.body.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                body_layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut local_shell,
+                viewport,
+            );
+
+            let redraw_request = local_shell.redraw_request();
+            let ime_request = local_shell.ime_request();
+            let is_layout_invalid = local_shell.is_layout_invalid();
+            let are_widgets_invalid = local_shell.are_widgets_invalid();
+
+            for message in messages {
+                shell.publish(message);
+            }
+
+            if is_layout_invalid {
+                shell.invalidate_layout();
+            }
+
+            if are_widgets_invalid {
+                shell.invalidate_widgets();
+            }
+
+            if let Some(cursor) = ime_request {
+                shell.request_ime(cursor);
+            }
+
+            if let Some(redraw_request) = redraw_request {
+                shell.request_redraw(
+                    redraw_request.max(throttle.next_redraw(interval)),
+                );
+            }
+
+            status
         } else {
             self.body.as_widget_mut().on_event(
                 &mut tree.children[0],
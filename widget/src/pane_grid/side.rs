@@ -0,0 +1,11 @@
+/// The side of a [`Split`] where a new [`Pane`] is placed.
+///
+/// [`Split`]: super::Split
+/// [`Pane`]: super::Pane
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// The left of a vertical split, or the top of a horizontal split.
+    First,
+    /// The right of a vertical split, or the bottom of a horizontal split.
+    Second,
+}
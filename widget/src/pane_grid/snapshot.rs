@@ -0,0 +1,92 @@
+use crate::pane_grid::{Configuration, State};
+
+/// A versioned [`Configuration`] of a [`PaneGrid`], meant to be saved and
+/// restored across runs of an application.
+///
+/// With the `serde` feature enabled, a [`Snapshot`] can be serialized along
+/// with the version of its format. When a saved [`Snapshot`] is loaded by a
+/// newer version of the crate, [`Snapshot::migrate`] gives the application a
+/// chance to upgrade it.
+///
+/// [`PaneGrid`]: super::PaneGrid
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot<T> {
+    version: u32,
+    configuration: Configuration<T>,
+}
+
+impl<T> Snapshot<T> {
+    /// The current version of the format of a [`Snapshot`].
+    pub const VERSION: u32 = 1;
+
+    /// Creates a [`Snapshot`] of the given [`Configuration`] with the
+    /// current [`VERSION`](Self::VERSION).
+    pub fn new(configuration: Configuration<T>) -> Self {
+        Self {
+            version: Self::VERSION,
+            configuration,
+        }
+    }
+
+    /// Returns the version of the format the [`Snapshot`] was saved with.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the [`Configuration`] of the [`Snapshot`], upgrading it with
+    /// the given function if it was saved with an older version.
+    ///
+    /// The function receives the version of the [`Snapshot`] and its
+    /// [`Configuration`] as it was loaded. Split ratios are clamped to
+    /// [0.0, 1.0] afterwards.
+    pub fn migrate(
+        self,
+        f: impl FnOnce(u32, Configuration<T>) -> Configuration<T>,
+    ) -> Configuration<T> {
+        let configuration = if self.version < Self::VERSION {
+            f(self.version, self.configuration)
+        } else {
+            self.configuration
+        };
+
+        sanitize(configuration)
+    }
+
+    /// Returns the [`Configuration`] of the [`Snapshot`], without any
+    /// migration.
+    pub fn into_configuration(self) -> Configuration<T> {
+        self.migrate(|_, configuration| configuration)
+    }
+}
+
+impl<T> From<&State<T>> for Snapshot<T>
+where
+    T: Clone,
+{
+    fn from(state: &State<T>) -> Self {
+        Self::new(state.configuration())
+    }
+}
+
+impl<T> From<Snapshot<T>> for Configuration<T> {
+    fn from(snapshot: Snapshot<T>) -> Self {
+        snapshot.into_configuration()
+    }
+}
+
+fn sanitize<T>(configuration: Configuration<T>) -> Configuration<T> {
+    match configuration {
+        Configuration::Split { axis, ratio, a, b } => Configuration::Split {
+            axis,
+            ratio: if ratio.is_nan() {
+                0.5
+            } else {
+                ratio.clamp(0.0, 1.0)
+            },
+            a: Box::new(sanitize(*a)),
+            b: Box::new(sanitize(*b)),
+        },
+        Configuration::Pane(pane) => Configuration::Pane(pane),
+    }
+}
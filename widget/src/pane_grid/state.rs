@@ -3,7 +3,8 @@
 //! [`PaneGrid`]: super::PaneGrid
 use crate::core::{Point, Size};
 use crate::pane_grid::{
-    Axis, Configuration, Direction, Edge, Node, Pane, Region, Split, Target,
+    Axis, Configuration, Direction, Edge, Node, Pane, Region, Side, Split,
+    Target,
 };
 
 use std::collections::HashMap;
@@ -102,6 +103,32 @@ impl<T> State<T> {
         &self.internal.layout
     }
 
+    /// Returns the [`Configuration`] of the [`State`], which can be used to
+    /// recreate it with [`State::with_configuration`].
+    pub fn configuration(&self) -> Configuration<T>
+    where
+        T: Clone,
+    {
+        fn configuration<T: Clone>(
+            node: &Node,
+            panes: &HashMap<Pane, T>,
+        ) -> Configuration<T> {
+            match node {
+                Node::Split {
+                    axis, ratio, a, b, ..
+                } => Configuration::Split {
+                    axis: *axis,
+                    ratio: *ratio,
+                    a: Box::new(configuration(a, panes)),
+                    b: Box::new(configuration(b, panes)),
+                },
+                Node::Pane(pane) => Configuration::Pane(panes[pane].clone()),
+            }
+        }
+
+        configuration(&self.internal.layout, &self.panes)
+    }
+
     /// Returns the adjacent [`Pane`] of another [`Pane`] in the given
     /// direction, if there is one.
     pub fn adjacent(&self, pane: Pane, direction: Direction) -> Option<Pane> {
@@ -148,6 +175,44 @@ impl<T> State<T> {
         self.split_node(axis, Some(pane), state, false)
     }
 
+    /// Splits the given [`Pane`] into two in the given [`Axis`], placing
+    /// the new [`Pane`] on the given [`Side`] and initializing it with the
+    /// provided internal state.
+    ///
+    /// The new [`Pane`] takes the given `ratio` of the space of the split,
+    /// from `0.0` to `1.0`. For instance, a terminal can be opened below
+    /// the given [`Pane`] at a quarter of its height with a ratio of `0.25`
+    /// on the [`Side::Second`] of an [`Axis::Horizontal`] split.
+    ///
+    /// A ratio that is not finite, like `NaN`, splits the space in half.
+    pub fn split_with_ratio(
+        &mut self,
+        axis: Axis,
+        pane: Pane,
+        state: T,
+        ratio: f32,
+        side: Side,
+    ) -> Option<(Pane, Split)> {
+        let (new_pane, split) =
+            self.split_node(axis, Some(pane), state, side == Side::First)?;
+
+        let ratio = if ratio.is_finite() {
+            ratio.clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+
+        self.resize(
+            split,
+            match side {
+                Side::First => ratio,
+                Side::Second => 1.0 - ratio,
+            },
+        );
+
+        Some((new_pane, split))
+    }
+
     /// Split a target [`Pane`] with a given [`Pane`] on a given [`Region`].
     ///
     /// Panes will be swapped by default for [`Region::Center`].
@@ -220,7 +220,8 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let limits = limits.shrink(self.padding);
+        let padding = self.padding.scale(renderer.spacing_scale());
+        let limits = limits.shrink(padding);
         let max_size = limits.max();
 
         let title_layout = self.content.as_widget().layout(
@@ -258,7 +259,7 @@ where
             )
         };
 
-        layout::Node::container(node, self.padding)
+        layout::Node::container(node, padding)
     }
 
     pub(crate) fn operate(
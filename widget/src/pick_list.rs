@@ -188,7 +188,7 @@ where
             renderer,
             limits,
             self.width,
-            self.padding,
+            self.padding.scale(renderer.spacing_scale()),
             self.text_size,
             self.text_line_height,
             self.text_shaping,
@@ -243,12 +243,14 @@ where
         viewport: &Rectangle,
     ) {
         let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let padding = self.padding.scale(renderer.spacing_scale());
+
         draw(
             renderer,
             theme,
             layout,
             cursor,
-            self.padding,
+            padding,
             self.text_size,
             self.text_line_height,
             self.text_shaping,
@@ -273,7 +275,7 @@ where
         overlay(
             layout,
             state,
-            self.padding,
+            self.padding.scale(renderer.spacing_scale()),
             self.text_size,
             self.text_shaping,
             self.font.unwrap_or_else(|| renderer.default_font()),
@@ -416,6 +418,8 @@ where
         horizontal_alignment: alignment::Horizontal::Left,
         vertical_alignment: alignment::Vertical::Center,
         shaping: text_shaping,
+        wrap: text::Wrap::default(),
+        truncate: text::Truncate::default(),
     };
 
     for (option, paragraph) in options.iter().zip(state.options.iter_mut()) {
@@ -747,6 +751,8 @@ pub fn draw<'a, T, Theme, Renderer>(
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Center,
                 shaping: text_shaping,
+                wrap: text::Wrap::default(),
+                truncate: text::Truncate::default(),
             },
             Point::new(bounds.x + padding.left, bounds.center_y()),
             if is_selected {
@@ -1,4 +1,6 @@
 //! Create choices using radio buttons.
+use iced_runtime::core::widget::Id;
+
 use crate::core::alignment;
 use crate::core::event::{self, Event};
 use crate::core::layout;
@@ -9,8 +11,8 @@ use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    Border, Clipboard, Element, Layout, Length, Pixels, Rectangle, Shell, Size,
-    Widget,
+    id::Internal, Border, Clipboard, Element, Layout, Length, Pixels,
+    Rectangle, Shell, Size, Widget,
 };
 
 pub use iced_style::radio::{Appearance, StyleSheet};
@@ -74,6 +76,8 @@ where
     Theme: StyleSheet,
     Renderer: text::Renderer,
 {
+    id: Id,
+    label_id: Id,
     is_selected: bool,
     on_click: Message,
     label: String,
@@ -118,6 +122,8 @@ where
         F: FnOnce(V) -> Message,
     {
         Radio {
+            id: Id::unique(),
+            label_id: Id::unique(),
             is_selected: Some(value) == selected,
             on_click: f(value),
             label: label.into(),
@@ -234,6 +240,8 @@ where
                     alignment::Horizontal::Left,
                     alignment::Vertical::Top,
                     self.text_shaping,
+                    text::Wrap::default(),
+                    text::Truncate::default(),
                 )
             },
         )
@@ -347,11 +355,83 @@ where
                 tree.state.downcast_ref(),
                 crate::text::Appearance {
                     color: custom_style.text_color,
+                    selection: None,
                 },
                 viewport,
             );
         }
     }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        _state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Action, Checked, NodeBuilder, Rect, Role},
+            A11yNode, A11yTree,
+        };
+
+        let rect = |bounds: Rectangle| {
+            Rect::new(
+                bounds.x as f64,
+                bounds.y as f64,
+                (bounds.x + bounds.width) as f64,
+                (bounds.y + bounds.height) as f64,
+            )
+        };
+
+        // The label is clickable too, so the button covers both
+        let bounds = layout.bounds();
+
+        let mut node = NodeBuilder::new(Role::RadioButton);
+        node.add_action(Action::Focus);
+        node.add_action(Action::Default);
+        node.set_bounds(rect(bounds));
+        node.set_name(self.label.clone());
+        node.set_checked(if self.is_selected {
+            Checked::True
+        } else {
+            Checked::False
+        });
+
+        if cursor.is_over(bounds) {
+            node.set_hovered();
+        }
+
+        let mut label_node = NodeBuilder::new(Role::StaticText);
+        label_node.set_name(self.label.clone());
+        label_node.set_bounds(rect(
+            layout
+                .children()
+                .nth(1)
+                .map_or(bounds, |label| label.bounds()),
+        ));
+
+        A11yTree::node_with_child_tree(
+            A11yNode::new(node, self.id.clone()),
+            A11yTree::leaf(label_node, self.label_id.clone()),
+        )
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(Id(Internal::Set(vec![
+            self.id.0.clone(),
+            self.label_id.0.clone(),
+        ])))
+    }
+
+    fn set_id(&mut self, id: Id) {
+        if let Id(Internal::Set(list)) = id {
+            if list.len() == 2 {
+                self.id.0 = list[0].clone();
+                self.label_id.0 = list[1].clone();
+            }
+        }
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Radio<Message, Theme, Renderer>>
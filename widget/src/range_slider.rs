@@ -0,0 +1,548 @@
+//! Display an interactive selector of a range of values.
+//!
+//! A [`RangeSlider`] has some local [`State`].
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Border, Clipboard, Element, Layout, Length, Pixels, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+use std::ops::RangeInclusive;
+
+use iced_renderer::core::border::Radius;
+pub use iced_style::slider::{
+    Appearance, Handle, HandleShape, Rail, RailBackground, StyleSheet,
+};
+
+/// An horizontal bar and two handles that select an inclusive range of values
+/// from a range of values.
+///
+/// A [`RangeSlider`] will try to fill the horizontal space of its container.
+///
+/// The [`RangeSlider`] range of numeric values is generic and its step size
+/// defaults to 1 unit.
+///
+/// # Example
+/// ```no_run
+/// # type RangeSlider<'a, T, Message> =
+/// #     iced_widget::RangeSlider<'a, T, Message, iced_widget::style::Theme>;
+/// #
+/// #[derive(Clone)]
+/// pub enum Message {
+///     PriceRangeChanged((f32, f32)),
+/// }
+///
+/// let price_range = (20.0, 80.0);
+///
+/// RangeSlider::new(0.0..=100.0, price_range, Message::PriceRangeChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+#[must_use]
+pub struct RangeSlider<'a, T, Message, Theme = crate::Theme>
+where
+    Theme: StyleSheet,
+{
+    range: RangeInclusive<T>,
+    step: T,
+    value: (T, T),
+    on_change: Box<dyn Fn((T, T)) -> Message + 'a>,
+    on_release: Option<Message>,
+    width: Length,
+    height: f32,
+    style: Theme::Style,
+}
+
+impl<'a, T, Message, Theme> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// The default height of a [`RangeSlider`].
+    pub const DEFAULT_HEIGHT: f32 = 22.0;
+
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current selected range of the [`RangeSlider`], as a pair of
+    ///   start and end values
+    ///   * a function that will be called when any of the handles of the
+    ///   [`RangeSlider`] is dragged. It receives the new selected range and
+    ///   must produce a `Message`.
+    pub fn new<F>(range: RangeInclusive<T>, value: (T, T), on_change: F) -> Self
+    where
+        F: 'a + Fn((T, T)) -> Message,
+    {
+        let clamp = |value: T| {
+            if value < *range.start() {
+                *range.start()
+            } else if value > *range.end() {
+                *range.end()
+            } else {
+                value
+            }
+        };
+
+        let (start, end) = (clamp(value.0), clamp(value.1));
+
+        let value = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+
+        RangeSlider {
+            value,
+            range,
+            step: T::from(1),
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fill,
+            height: Self::DEFAULT_HEIGHT,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the release message of the [`RangeSlider`].
+    /// This is called when the mouse is released from the slider.
+    ///
+    /// Typically, the user's interaction with the slider is finished when this message is produced.
+    /// This is useful if you need to spawn a long-running task from the slider's result, where
+    /// the default `on_change` message could create too many events.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`].
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into().0;
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the step size of the [`RangeSlider`].
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Theme: StyleSheet,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        update(
+            event,
+            layout,
+            cursor,
+            shell,
+            tree.state.downcast_mut::<State>(),
+            &mut self.value,
+            &self.range,
+            self.step,
+            self.on_change.as_ref(),
+            &self.on_release,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        draw(
+            renderer,
+            layout,
+            cursor,
+            tree.state.downcast_ref::<State>(),
+            self.value,
+            &self.range,
+            theme,
+            &self.style,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse_interaction(layout, cursor, tree.state.downcast_ref::<State>())
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<RangeSlider<'a, T, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
+    Message: Clone + 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(
+        slider: RangeSlider<'a, T, Message, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(slider)
+    }
+}
+
+/// Processes an [`Event`] and updates the [`State`] of a [`RangeSlider`]
+/// accordingly.
+pub fn update<Message, T>(
+    event: Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    shell: &mut Shell<'_, Message>,
+    state: &mut State,
+    value: &mut (T, T),
+    range: &RangeInclusive<T>,
+    step: T,
+    on_change: &dyn Fn((T, T)) -> Message,
+    on_release: &Option<Message>,
+) -> event::Status
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+{
+    let bounds = layout.bounds();
+
+    let value_at = |cursor_position: Point| {
+        if cursor_position.x <= bounds.x {
+            Some(*range.start())
+        } else if cursor_position.x >= bounds.x + bounds.width {
+            Some(*range.end())
+        } else {
+            let step = step.into();
+            let start = (*range.start()).into();
+            let end = (*range.end()).into();
+
+            let percent = f64::from(cursor_position.x - bounds.x)
+                / f64::from(bounds.width);
+
+            let steps = (percent * (end - start) / step).round();
+
+            T::from_f64(steps * step + start)
+        }
+    };
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if let Some(cursor_position) = cursor.position_over(bounds) {
+                if let Some(new_value) = value_at(cursor_position) {
+                    let (start, end) = (value.0.into(), value.1.into());
+                    let new = new_value.into();
+
+                    // Grab the closest handle, preferring the one that can
+                    // move towards the cursor when both overlap
+                    let dragging = if (new - start).abs() < (new - end).abs()
+                        || ((end - start).abs() <= f64::EPSILON && new < start)
+                    {
+                        Dragging::Start
+                    } else {
+                        Dragging::End
+                    };
+
+                    change(dragging, new_value, value, shell, on_change);
+                    state.dragging = Some(dragging);
+                }
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+        | Event::Touch(touch::Event::FingerLost { .. }) => {
+            if state.dragging.take().is_some() {
+                if let Some(on_release) = on_release.clone() {
+                    shell.publish(on_release);
+                }
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Touch(touch::Event::FingerMoved { .. }) => {
+            if let Some(dragging) = state.dragging {
+                if let Some(new_value) = cursor.position().and_then(value_at) {
+                    change(dragging, new_value, value, shell, on_change);
+                }
+
+                return event::Status::Captured;
+            }
+        }
+        _ => {}
+    }
+
+    event::Status::Ignored
+}
+
+fn change<Message, T>(
+    dragging: Dragging,
+    new_value: T,
+    value: &mut (T, T),
+    shell: &mut Shell<'_, Message>,
+    on_change: &dyn Fn((T, T)) -> Message,
+) where
+    T: Copy + Into<f64>,
+{
+    let (start, end) = (value.0.into(), value.1.into());
+
+    // Handles cannot cross each other
+    let new_value = match dragging {
+        Dragging::Start if new_value.into() > end => value.1,
+        Dragging::End if new_value.into() < start => value.0,
+        _ => new_value,
+    };
+
+    let new_range = match dragging {
+        Dragging::Start => (new_value, value.1),
+        Dragging::End => (value.0, new_value),
+    };
+
+    if (start - new_range.0.into()).abs() > f64::EPSILON
+        || (end - new_range.1.into()).abs() > f64::EPSILON
+    {
+        shell.publish((on_change)(new_range));
+
+        *value = new_range;
+    }
+}
+
+/// Draws a [`RangeSlider`].
+pub fn draw<T, Theme, Renderer>(
+    renderer: &mut Renderer,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    state: &State,
+    value: (T, T),
+    range: &RangeInclusive<T>,
+    theme: &Theme,
+    style: &Theme::Style,
+) where
+    T: Into<f64> + Copy,
+    Theme: StyleSheet,
+    Renderer: crate::core::Renderer,
+{
+    let bounds = layout.bounds();
+    let is_mouse_over = cursor.is_over(bounds);
+
+    let style = if state.dragging.is_some() {
+        theme.dragging(style)
+    } else if is_mouse_over {
+        theme.hovered(style)
+    } else {
+        theme.active(style)
+    };
+
+    let border_width = style
+        .handle
+        .border_width
+        .min(bounds.height / 2.0)
+        .min(bounds.width / 2.0);
+
+    let (handle_width, handle_height, handle_border_radius) =
+        match style.handle.shape {
+            HandleShape::Circle { radius } => {
+                let radius = (radius)
+                    .max(2.0 * border_width)
+                    .min(bounds.height / 2.0)
+                    .min(bounds.width / 2.0);
+                (radius * 2.0, radius * 2.0, Radius::from(radius))
+            }
+            HandleShape::Rectangle {
+                width,
+                border_radius,
+            } => {
+                let width = (f32::from(width))
+                    .max(2.0 * border_width)
+                    .min(bounds.width);
+                let height = bounds.height;
+                let mut border_radius: [f32; 4] = border_radius.into();
+                for r in &mut border_radius {
+                    *r = (*r).min(height / 2.0).min(width / 2.0).max(0.0);
+                }
+                (width, height, border_radius.into())
+            }
+        };
+
+    let (range_start, range_end) = {
+        let (start, end) = range.clone().into_inner();
+
+        (start.into() as f32, end.into() as f32)
+    };
+
+    let offset = |value: T| {
+        if range_start >= range_end {
+            0.0
+        } else {
+            (bounds.width - handle_width) * (value.into() as f32 - range_start)
+                / (range_end - range_start)
+        }
+    };
+
+    let (start_offset, end_offset) = (offset(value.0), offset(value.1));
+
+    let rail_y = bounds.y + bounds.height / 2.0;
+
+    let rail = |x: f32, width: f32| renderer::Quad {
+        bounds: Rectangle {
+            x,
+            y: rail_y - style.rail.width / 2.0,
+            width,
+            height: style.rail.width,
+        },
+        border: Border::with_radius(style.rail.border_radius),
+        ..renderer::Quad::default()
+    };
+
+    match style.rail.colors {
+        RailBackground::Pair(selected, unselected) => {
+            renderer.fill_quad(
+                rail(bounds.x, start_offset + handle_width / 2.0),
+                unselected,
+            );
+
+            renderer.fill_quad(
+                rail(
+                    bounds.x + start_offset + handle_width / 2.0,
+                    end_offset - start_offset,
+                ),
+                selected,
+            );
+
+            renderer.fill_quad(
+                rail(
+                    bounds.x + end_offset + handle_width / 2.0,
+                    bounds.width - end_offset - handle_width / 2.0,
+                ),
+                unselected,
+            );
+        }
+        RailBackground::Gradient { gradient, .. } => {
+            renderer.fill_quad(rail(bounds.x, bounds.width), gradient);
+        }
+    }
+
+    for offset in [start_offset, end_offset] {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + offset,
+                    y: rail_y - (handle_height / 2.0),
+                    width: handle_width,
+                    height: handle_height,
+                },
+                border: Border {
+                    radius: handle_border_radius,
+                    width: style.handle.border_width,
+                    color: style.handle.border_color,
+                },
+                ..renderer::Quad::default()
+            },
+            style.handle.color,
+        );
+    }
+}
+
+/// Computes the current [`mouse::Interaction`] of a [`RangeSlider`].
+pub fn mouse_interaction(
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    state: &State,
+) -> mouse::Interaction {
+    let bounds = layout.bounds();
+    let is_mouse_over = cursor.is_over(bounds);
+
+    if state.dragging.is_some() {
+        mouse::Interaction::Grabbing
+    } else if is_mouse_over {
+        mouse::Interaction::Grab
+    } else {
+        mouse::Interaction::default()
+    }
+}
+
+/// The local state of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    dragging: Option<Dragging>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dragging {
+    Start,
+    End,
+}
@@ -0,0 +1,387 @@
+//! Select items by dragging a box over them.
+use iced_renderer::core::widget::OperationOutputWrapper;
+
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+pub use iced_style::rubber_band::{Appearance, StyleSheet};
+
+/// The box of a rubber band selection, dragged from one corner to another.
+///
+/// A [`Selection`] only tracks the interaction. Widgets with their own
+/// notion of items, like a canvas tool, can keep one in their state and use
+/// it to find and draw the selected items. Use a [`RubberBand`] to select
+/// the children of any other widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Selection {
+    from: Point,
+    to: Point,
+}
+
+impl Selection {
+    /// Starts a [`Selection`] at the given position.
+    pub fn new(from: Point) -> Self {
+        Self { from, to: from }
+    }
+
+    /// Moves the dragged corner of the [`Selection`] to the given position.
+    pub fn update(&mut self, to: Point) {
+        self.to = to;
+    }
+
+    /// Returns the bounds of the box of the [`Selection`].
+    pub fn bounds(&self) -> Rectangle {
+        Rectangle {
+            x: self.from.x.min(self.to.x),
+            y: self.from.y.min(self.to.y),
+            width: (self.to.x - self.from.x).abs(),
+            height: (self.to.y - self.from.y).abs(),
+        }
+    }
+
+    /// Returns the indices of the given bounds that intersect the box of
+    /// the [`Selection`].
+    pub fn intersecting(
+        &self,
+        bounds: impl IntoIterator<Item = Rectangle>,
+    ) -> Vec<usize> {
+        let selection = self.bounds();
+
+        bounds
+            .into_iter()
+            .enumerate()
+            .filter(|(_, bounds)| bounds.intersects(&selection))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Draws the box of the [`Selection`] with the given [`Appearance`].
+    pub fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        appearance: Appearance,
+    ) where
+        Renderer: renderer::Renderer,
+    {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: self.bounds(),
+                border: appearance.border,
+                ..renderer::Quad::default()
+            },
+            appearance.background,
+        );
+    }
+}
+
+/// A container that lets the user select the children of its content by
+/// dragging a box over them.
+///
+/// A drag starts when a press on the content is not captured by any of its
+/// widgets. While dragging, the indices of the children of the content whose
+/// bounds intersect the box are emitted every time they change. Place a
+/// [`Row`], a [`Column`], or any other widget with a child per item inside.
+///
+/// [`Row`]: crate::Row
+/// [`Column`]: crate::Column
+#[allow(missing_debug_implementations)]
+pub struct RubberBand<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: StyleSheet,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_select: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    style: Theme::Style,
+}
+
+impl<'a, Message, Theme, Renderer> RubberBand<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a [`RubberBand`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        RubberBand {
+            content: content.into(),
+            on_select: None,
+            style: Theme::Style::default(),
+        }
+    }
+
+    /// Sets the message to emit when the selected children change.
+    ///
+    /// Starting a drag selects nothing, so a click on the content outside
+    /// of its widgets clears the selection.
+    #[must_use]
+    pub fn on_select(mut self, f: impl Fn(Vec<usize>) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`RubberBand`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// Local state of the [`RubberBand`].
+#[derive(Default)]
+struct State {
+    selection: Option<Selection>,
+    selected: Vec<usize>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RubberBand<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        // The content does not see the cursor while a box is dragged
+        let content_cursor = if state.selection.is_some() {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            content_cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let Some(on_select) = &self.on_select else {
+            return status;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if status == event::Status::Ignored =>
+            {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.selection = Some(Selection::new(position));
+                    state.selected.clear();
+
+                    shell.publish(on_select(Vec::new()));
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(selection) = &mut state.selection {
+                    selection.update(Point::new(
+                        position.x.clamp(bounds.x, bounds.x + bounds.width),
+                        position.y.clamp(bounds.y, bounds.y + bounds.height),
+                    ));
+
+                    let selected = selection.intersecting(
+                        layout.children().map(|layout| layout.bounds()),
+                    );
+
+                    if selected != state.selected {
+                        shell.publish(on_select(selected.clone()));
+                        state.selected = selected;
+                    }
+
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.selection.take().is_some() {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State>().selection.is_some() {
+            return mouse::Interaction::Crosshair;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            if state.selection.is_some() {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            viewport,
+        );
+
+        if let Some(selection) = &state.selection {
+            let appearance = theme.appearance(&self.style);
+
+            renderer.with_layer(layout.bounds(), |renderer| {
+                selection.draw(renderer, appearance);
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        dnd_rectangles: &mut iced_style::core::clipboard::DndDestinationRectangles,
+    ) {
+        if let Some(state) = state.children.iter().next() {
+            self.content.as_widget().drag_destinations(
+                state,
+                layout,
+                dnd_rectangles,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<RubberBand<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        rubber_band: RubberBand<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(rubber_band)
+    }
+}
@@ -186,6 +186,26 @@ impl Direction {
             Self::Horizontal(_) => None,
         }
     }
+
+    /// Scales the margins of the scrollbars by the given factor.
+    fn scale(self, factor: f32) -> Self {
+        let scale = |properties: Properties| Properties {
+            margin: properties.margin * factor,
+            ..properties
+        };
+
+        match self {
+            Self::Vertical(properties) => Self::Vertical(scale(properties)),
+            Self::Horizontal(properties) => Self::Horizontal(scale(properties)),
+            Self::Both {
+                vertical,
+                horizontal,
+            } => Self::Both {
+                vertical: scale(vertical),
+                horizontal: scale(horizontal),
+            },
+        }
+    }
 }
 
 impl Default for Direction {
@@ -351,7 +371,7 @@ where
             cursor,
             clipboard,
             shell,
-            self.direction,
+            self.direction.scale(renderer.spacing_scale()),
             &self.on_scroll,
             |event, layout, cursor, clipboard, shell, viewport| {
                 self.content.as_widget_mut().on_event(
@@ -378,13 +398,15 @@ where
         cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
+        let direction = self.direction.scale(renderer.spacing_scale());
+
         draw(
             tree.state.downcast_ref::<State>(),
             renderer,
             theme,
             layout,
             cursor,
-            self.direction,
+            direction,
             &self.style,
             |renderer, layout, cursor, viewport| {
                 self.content.as_widget().draw(
@@ -412,7 +434,7 @@ where
             tree.state.downcast_ref::<State>(),
             layout,
             cursor,
-            self.direction,
+            self.direction.scale(renderer.spacing_scale()),
             |layout, cursor, viewport| {
                 self.content.as_widget().mouse_interaction(
                     &tree.children[0],
@@ -783,7 +805,9 @@ pub fn update<Message>(
 
     match event {
         Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-            if cursor_over_scrollable.is_none() {
+            if cursor_over_scrollable.is_none()
+                || state.keyboard_modifiers.zoom()
+            {
                 return event::Status::Ignored;
             }
 
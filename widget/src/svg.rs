@@ -7,7 +7,8 @@ use crate::core::renderer;
 use crate::core::svg;
 use crate::core::widget::Tree;
 use crate::core::{
-    ContentFit, Element, Layout, Length, Rectangle, Size, Vector, Widget,
+    ContentFit, Element, Layout, Length, Radians, Rectangle, Size, Vector,
+    Widget,
 };
 
 #[cfg(feature = "a11y")]
@@ -40,6 +41,8 @@ where
     width: Length,
     height: Length,
     content_fit: ContentFit,
+    rotation: Radians,
+    opacity: f32,
     style: <Theme as StyleSheet>::Style,
     symbolic: bool,
     _phantom_data: PhantomData<&'a ()>,
@@ -63,6 +66,8 @@ where
             width: Length::Fill,
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
+            rotation: Radians(0.0),
+            opacity: 1.0,
             symbolic: false,
             style: Default::default(),
             _phantom_data: PhantomData::default(),
@@ -101,6 +106,23 @@ where
         }
     }
 
+    /// Sets the clockwise rotation of the [`Svg`] around its center.
+    ///
+    /// The rotation does not affect the layout of the [`Svg`].
+    #[must_use]
+    pub fn rotation(mut self, rotation: impl Into<Radians>) -> Self {
+        self.rotation = rotation.into();
+        self
+    }
+
+    /// Sets the opacity of the [`Svg`], from `0.0` (transparent) to `1.0`
+    /// (opaque).
+    #[must_use]
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
     /// Symbolic icons inherit their color from the renderer if a color is not defined.
     #[must_use]
     pub fn symbolic(mut self, symbolic: bool) -> Self {
@@ -237,6 +259,8 @@ where
                 self.handle.clone(),
                 appearance.color,
                 drawing_bounds + offset,
+                self.rotation,
+                self.opacity,
             );
         };
 
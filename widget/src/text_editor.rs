@@ -8,17 +8,21 @@ use crate::core::renderer;
 use crate::core::text::editor::{Cursor, Editor as _};
 use crate::core::text::highlighter::{self, Highlighter};
 use crate::core::text::{self, LineHeight};
+use crate::core::time::{Duration, Instant};
 use crate::core::widget::{self, Widget};
+use crate::core::window;
 use crate::core::{
     Clipboard, Element, Length, Padding, Pixels, Rectangle, Shell, Size, Vector,
 };
+use crate::find_bar::{find_in_line, Match, Searchable};
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::fmt;
 use std::ops::DerefMut;
 use std::sync::Arc;
 
-pub use crate::style::text_editor::{Appearance, StyleSheet};
+pub use crate::style::caret;
+pub use crate::style::text_editor::{Appearance, Caret, StyleSheet};
 pub use text::editor::{Action, Edit, Motion};
 
 /// A multi-line text input.
@@ -261,6 +265,20 @@ where
     }
 }
 
+impl<Renderer> Searchable for Content<Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn find(&self, query: &str) -> Vec<Match> {
+        self.lines()
+            .enumerate()
+            .flat_map(|(index, line)| {
+                find_in_line(index, &line, query).collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
 impl<Renderer> Default for Content<Renderer>
 where
     Renderer: text::Renderer,
@@ -287,6 +305,9 @@ where
 
 struct State<Highlighter: text::Highlighter> {
     is_focused: bool,
+    updated_at: Instant,
+    now: Instant,
+    blink_interval: Cell<Option<Duration>>,
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
     highlighter: RefCell<Highlighter>,
@@ -306,8 +327,13 @@ where
     }
 
     fn state(&self) -> widget::tree::State {
+        let now = Instant::now();
+
         widget::tree::State::new(State {
             is_focused: false,
+            updated_at: now,
+            now,
+            blink_interval: Cell::default(),
             last_click: None,
             drag_click: None,
             highlighter: RefCell::new(Highlighter::new(
@@ -351,7 +377,9 @@ where
         }
 
         internal.editor.update(
-            limits.shrink(self.padding).max(),
+            limits
+                .shrink(self.padding.scale(renderer.spacing_scale()))
+                .max(),
             self.font.unwrap_or_else(|| renderer.default_font()),
             self.text_size.unwrap_or_else(|| renderer.default_size()),
             self.line_height,
@@ -378,11 +406,30 @@ where
 
         let state = tree.state.downcast_mut::<State<Highlighter>>();
 
+        if let Event::Window(_, window::Event::RedrawRequested(now)) = event {
+            if state.is_focused {
+                state.now = now;
+
+                if let Some(interval) = state.blink_interval.get() {
+                    let interval = interval.as_millis().max(1);
+
+                    let millis_until_redraw = interval
+                        - (now - state.updated_at).as_millis() % interval;
+
+                    shell.request_redraw(window::RedrawRequest::At(
+                        now + Duration::from_millis(millis_until_redraw as u64),
+                    ));
+                }
+            }
+
+            return event::Status::Ignored;
+        }
+
         let Some(update) = Update::from_event(
             event,
             state,
             layout.bounds(),
-            self.padding,
+            self.padding.scale(renderer.spacing_scale()),
             cursor,
         ) else {
             return event::Status::Ignored;
@@ -399,6 +446,7 @@ where
                 };
 
                 state.is_focused = true;
+                state.updated_at = Instant::now();
                 state.last_click = Some(click);
                 state.drag_click = Some(click.kind());
 
@@ -412,6 +460,9 @@ where
                 state.drag_click = None;
             }
             Update::Action(action) => {
+                // Keep the caret still while typing
+                state.updated_at = Instant::now();
+
                 shell.publish(on_edit(action));
             }
             Update::Copy => {
@@ -421,6 +472,8 @@ where
             }
             Update::Paste => {
                 if let Some(contents) = clipboard.read() {
+                    state.updated_at = Instant::now();
+
                     shell.publish(on_edit(Action::Edit(Edit::Paste(
                         Arc::new(contents),
                     ))));
@@ -442,6 +495,7 @@ where
         viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
+        let padding = self.padding.scale(renderer.spacing_scale());
 
         let mut internal = self.content.0.borrow_mut();
         let state = tree.state.downcast_ref::<State<Highlighter>>();
@@ -476,41 +530,61 @@ where
 
         renderer.fill_editor(
             &internal.editor,
-            bounds.position()
-                + Vector::new(self.padding.left, self.padding.top),
+            bounds.position() + Vector::new(padding.left, padding.top),
             style.text_color,
             *viewport,
         );
 
-        let translation = Vector::new(
-            bounds.x + self.padding.left,
-            bounds.y + self.padding.top,
-        );
+        let translation =
+            Vector::new(bounds.x + padding.left, bounds.y + padding.top);
+
+        let caret = theme.caret(&self.style);
+
+        // Remember the blink interval to schedule the next blink on redraw
+        state.blink_interval.set(caret.blink_interval);
 
         if state.is_focused {
             match internal.editor.cursor() {
                 Cursor::Caret(position) => {
                     let position = position + translation;
 
-                    if bounds.contains(position) {
+                    let is_visible =
+                        caret.blink_interval.map_or(true, |interval| {
+                            ((state.now - state.updated_at).as_millis()
+                                / interval.as_millis().max(1))
+                                % 2
+                                == 0
+                        });
+
+                    if is_visible && bounds.contains(position) {
+                        let text_size = self
+                            .text_size
+                            .unwrap_or_else(|| renderer.default_size());
+
+                        let (width, color) = match caret.shape {
+                            caret::Shape::Bar => {
+                                (caret.width, theme.value_color(&self.style))
+                            }
+                            caret::Shape::Block => (
+                                text_size.0 / 2.0,
+                                theme.selection_color(&self.style),
+                            ),
+                        };
+
                         renderer.fill_quad(
                             renderer::Quad {
                                 bounds: Rectangle {
                                     x: position.x,
                                     y: position.y,
-                                    width: 1.0,
+                                    width,
                                     height: self
                                         .line_height
-                                        .to_absolute(
-                                            self.text_size.unwrap_or_else(
-                                                || renderer.default_size(),
-                                            ),
-                                        )
+                                        .to_absolute(text_size)
                                         .into(),
                                 },
                                 ..renderer::Quad::default()
                             },
-                            theme.value_color(&self.style),
+                            color,
                         );
                     }
                 }
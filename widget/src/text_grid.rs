@@ -0,0 +1,325 @@
+//! Display a grid of monospace characters, like a terminal or a hex viewer.
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Paragraph, Shaping};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Color, Element, Font, Layout, Length, Pixels, Point, Rectangle, Size,
+    Widget,
+};
+use crate::graphics::text::grid::{self, Grid};
+
+use std::ops::Range;
+
+/// The dimensions of a single cell of a monospace character grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Metrics {
+    /// The width of a cell in logical pixels.
+    pub cell_width: f32,
+
+    /// The height of a cell in logical pixels.
+    pub cell_height: f32,
+}
+
+impl Metrics {
+    /// Measures the cell [`Metrics`] of the given monospace font at the given
+    /// size and [`LineHeight`].
+    pub fn measure<P: Paragraph>(
+        font: P::Font,
+        size: Pixels,
+        line_height: LineHeight,
+    ) -> Self {
+        let paragraph = P::with_text(cell_text(font, size, line_height));
+
+        Self::from_paragraph(&paragraph, size, line_height)
+    }
+
+    pub(crate) fn from_paragraph<P: Paragraph>(
+        paragraph: &P,
+        size: Pixels,
+        line_height: LineHeight,
+    ) -> Self {
+        Self {
+            cell_width: paragraph.min_width(),
+            cell_height: line_height.to_absolute(size).0,
+        }
+    }
+
+    /// Returns the [`Size`] of a grid with the given amount of columns and
+    /// rows.
+    pub fn grid_size(&self, columns: usize, rows: usize) -> Size {
+        Size::new(
+            columns as f32 * self.cell_width,
+            rows as f32 * self.cell_height,
+        )
+    }
+
+    /// Returns the bounds of the cell at the given column and row, relative
+    /// to the origin of the grid.
+    pub fn cell_bounds(&self, column: usize, row: usize) -> Rectangle {
+        Rectangle {
+            x: column as f32 * self.cell_width,
+            y: row as f32 * self.cell_height,
+            width: self.cell_width,
+            height: self.cell_height,
+        }
+    }
+
+    /// Returns the rows of a grid with the given amount of rows and bounds
+    /// that are inside of the visible bounds.
+    pub(crate) fn visible_rows(
+        &self,
+        bounds: Rectangle,
+        visible: Rectangle,
+        rows: usize,
+    ) -> Range<usize> {
+        if self.cell_height <= 0.0 {
+            return 0..0;
+        }
+
+        let first_row = ((visible.y - bounds.y) / self.cell_height) as usize;
+        let last_row = (((visible.y + visible.height - bounds.y)
+            / self.cell_height)
+            .ceil() as usize)
+            .min(rows);
+
+        first_row.min(last_row)..last_row
+    }
+
+    /// Returns the column and row of the cell containing the given [`Point`],
+    /// relative to the origin of the grid.
+    ///
+    /// Returns `None` if the [`Point`] lies before the origin.
+    pub fn cell_at(&self, point: Point) -> Option<(usize, usize)> {
+        if point.x < 0.0
+            || point.y < 0.0
+            || self.cell_width <= 0.0
+            || self.cell_height <= 0.0
+        {
+            return None;
+        }
+
+        Some((
+            (point.x / self.cell_width) as usize,
+            (point.y / self.cell_height) as usize,
+        ))
+    }
+}
+
+/// A single character of a [`TextGrid`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    /// The character of the [`Cell`].
+    pub content: char,
+
+    /// The color of the character, if different from the default text color.
+    pub foreground: Option<Color>,
+
+    /// The background color of the [`Cell`], if any.
+    pub background: Option<Color>,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            content: ' ',
+            foreground: None,
+            background: None,
+        }
+    }
+}
+
+impl From<char> for Cell {
+    fn from(content: char) -> Self {
+        Self {
+            content,
+            ..Self::default()
+        }
+    }
+}
+
+/// A widget that displays a 2D grid of styled characters with a monospace
+/// font.
+///
+/// Every glyph is placed at the start of its cell, even when it comes from a
+/// fallback font, and only the rows inside of the viewport are drawn. With
+/// `iced_wgpu`, every visible cell is uploaded as instance data and drawn in a
+/// single draw call.
+#[allow(missing_debug_implementations)]
+pub struct TextGrid<'a> {
+    cells: &'a [Cell],
+    columns: usize,
+    font: Font,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+}
+
+impl<'a> TextGrid<'a> {
+    /// Creates a new [`TextGrid`] with the given amount of columns and
+    /// [`Cell`]s, laid out in row-major order.
+    pub fn new(columns: usize, cells: &'a [Cell]) -> Self {
+        Self {
+            cells,
+            columns: columns.max(1),
+            font: Font::MONOSPACE,
+            size: None,
+            line_height: LineHeight::default(),
+        }
+    }
+
+    /// Sets the monospace [`Font`] of the [`TextGrid`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the text size of the [`TextGrid`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`TextGrid`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    fn rows(&self) -> usize {
+        self.cells.len().div_ceil(self.columns)
+    }
+}
+
+/// The local state of a [`TextGrid`].
+#[allow(missing_debug_implementations)]
+#[derive(Default)]
+pub struct State<P: Paragraph> {
+    paragraph: P,
+    grid: Grid,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TextGrid<'a>
+where
+    Renderer: text::Renderer<Font = Font> + grid::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        state
+            .paragraph
+            .update(cell_text(self.font, size, self.line_height));
+
+        let metrics =
+            Metrics::from_paragraph(&state.paragraph, size, self.line_height);
+
+        state.grid.update(
+            self.cells,
+            self.columns,
+            |cell| grid::Cell {
+                character: cell.content,
+                foreground: cell.foreground,
+                background: cell.background,
+            },
+            self.font,
+            size,
+            Pixels(metrics.cell_height),
+        );
+
+        layout::Node::new(limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            metrics.grid_size(self.columns, self.rows()),
+        ))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let metrics =
+            Metrics::from_paragraph(&state.paragraph, size, self.line_height);
+
+        let Some(visible) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let rows = metrics.visible_rows(bounds, visible, self.rows());
+
+        if rows.is_empty() {
+            return;
+        }
+
+        renderer.fill_grid(
+            &state.grid,
+            bounds.position(),
+            Size::new(metrics.cell_width, metrics.cell_height),
+            rows,
+            style.text_color,
+            visible,
+        );
+    }
+}
+
+pub(crate) fn cell_text(
+    font: Font,
+    size: Pixels,
+    line_height: LineHeight,
+) -> text::Text<'static, Font> {
+    text::Text {
+        content: "M",
+        bounds: Size::INFINITY,
+        size,
+        line_height,
+        font,
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+        shaping: Shaping::Basic,
+        wrap: text::Wrap::default(),
+        truncate: text::Truncate::default(),
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TextGrid<'a>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer<Font = Font> + grid::Renderer + 'a,
+{
+    fn from(text_grid: TextGrid<'a>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(text_grid)
+    }
+}
@@ -25,12 +25,16 @@ use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::Id;
 use crate::core::window;
 use crate::core::{
-    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
-    Shell, Size, Vector, Widget,
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
 };
 use crate::runtime::Command;
 
-pub use iced_style::text_input::{Appearance, StyleSheet};
+use std::cell::Cell;
+use std::ops::Range;
+
+pub use iced_style::caret;
+pub use iced_style::text_input::{Appearance, Caret, StyleSheet};
 
 /// A field that can be filled with text.
 ///
@@ -76,6 +80,8 @@ pub struct TextInput<
     on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
+    misspelled: &'a [Range<usize>],
+    on_spelling_menu: Option<Box<dyn Fn(Range<usize>) -> Message + 'a>>,
     icon: Option<Icon<Renderer::Font>>,
     style: Theme::Style,
 }
@@ -108,6 +114,8 @@ where
             on_input: None,
             on_paste: None,
             on_submit: None,
+            misspelled: &[],
+            on_spelling_menu: None,
             icon: None,
             style: Default::default(),
         }
@@ -154,6 +162,28 @@ where
         self
     }
 
+    /// Sets the byte ranges of the misspelled words of the value, usually
+    /// supplied by an external spell checker.
+    ///
+    /// Misspelled words are drawn with a squiggly underline.
+    pub fn misspelled(mut self, ranges: &'a [Range<usize>]) -> Self {
+        self.misspelled = ranges;
+        self
+    }
+
+    /// Sets the message that should be produced when a misspelled word of the
+    /// [`TextInput`] is right-clicked.
+    ///
+    /// It receives the byte range of the word, so the application can show
+    /// spelling suggestions and replace it.
+    pub fn on_spelling_menu(
+        mut self,
+        on_spelling_menu: impl Fn(Range<usize>) -> Message + 'a,
+    ) -> Self {
+        self.on_spelling_menu = Some(Box::new(on_spelling_menu));
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextInput`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -201,6 +231,11 @@ where
         self
     }
 
+    /// Replaces the [`Value`] of the [`TextInput`].
+    pub(crate) fn set_value(&mut self, value: &str) {
+        self.value = Value::new(value);
+    }
+
     /// Lays out the [`TextInput`], overriding its [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
@@ -215,7 +250,7 @@ where
             renderer,
             limits,
             self.width,
-            self.padding,
+            self.padding.scale(renderer.spacing_scale()),
             self.size,
             self.font,
             self.line_height,
@@ -301,7 +336,7 @@ where
             renderer,
             limits,
             self.width,
-            self.padding,
+            self.padding.scale(renderer.spacing_scale()),
             self.size,
             self.font,
             self.line_height,
@@ -337,6 +372,41 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        if let (
+            Some(on_spelling_menu),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)),
+        ) = (&self.on_spelling_menu, &event)
+        {
+            let text_bounds = layout.children().next().unwrap().bounds();
+
+            if let Some(position) = cursor
+                .position_over(text_bounds)
+                .filter(|_| !self.is_secure)
+            {
+                let state =
+                    tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+                let offset = offset(text_bounds, &self.value, state);
+
+                if let Some(range) = state
+                    .value
+                    .hit_test(Point::new(
+                        position.x - text_bounds.x + offset,
+                        text_bounds.height / 2.0,
+                    ))
+                    .map(text::Hit::cursor)
+                    .and_then(|index| {
+                        self.misspelled
+                            .iter()
+                            .find(|range| range.contains(&index))
+                    })
+                {
+                    shell.publish(on_spelling_menu(range.clone()));
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
         update(
             event,
             layout,
@@ -375,6 +445,7 @@ where
             &self.value,
             self.on_input.is_none(),
             self.is_secure,
+            self.misspelled,
             self.icon.as_ref(),
             &self.style,
             viewport,
@@ -497,6 +568,8 @@ where
         horizontal_alignment: alignment::Horizontal::Left,
         vertical_alignment: alignment::Vertical::Center,
         shaping: text::Shaping::Advanced,
+        wrap: text::Wrap::default(),
+        truncate: text::Truncate::default(),
     };
 
     state.placeholder.update(placeholder_text);
@@ -519,6 +592,8 @@ where
             horizontal_alignment: alignment::Horizontal::Center,
             vertical_alignment: alignment::Vertical::Center,
             shaping: text::Shaping::Advanced,
+            wrap: text::Wrap::default(),
+            truncate: text::Truncate::default(),
         };
 
         state.icon.update(icon_text);
@@ -970,6 +1045,68 @@ where
 
             state.keyboard_modifiers = modifiers;
         }
+        Event::Keyboard(keyboard::Event::Ime(ime)) => {
+            let state = state();
+
+            let Some(focus) = &mut state.is_focused else {
+                return event::Status::Ignored;
+            };
+
+            let Some(on_input) = on_input.filter(|_| !is_secure) else {
+                return event::Status::Ignored;
+            };
+
+            focus.updated_at = Instant::now();
+
+            match ime {
+                keyboard::Ime::Preedit { text: content, .. }
+                    if !content.is_empty() =>
+                {
+                    let text_bounds =
+                        layout.children().next().unwrap().bounds();
+
+                    state.preedit =
+                        Some(Renderer::Paragraph::with_text(Text {
+                            font: font
+                                .unwrap_or_else(|| renderer.default_font()),
+                            line_height,
+                            content: &content,
+                            bounds: Size::new(
+                                f32::INFINITY,
+                                text_bounds.height,
+                            ),
+                            size: size
+                                .unwrap_or_else(|| renderer.default_size()),
+                            horizontal_alignment: alignment::Horizontal::Left,
+                            vertical_alignment: alignment::Vertical::Top,
+                            shaping: text::Shaping::Advanced,
+                            wrap: text::Wrap::default(),
+                            truncate: text::Truncate::default(),
+                        }));
+                }
+                keyboard::Ime::Commit(content) => {
+                    state.preedit = None;
+
+                    if !content.is_empty() {
+                        let mut editor = Editor::new(value, &mut state.cursor);
+
+                        editor.paste(Value::new(&content));
+
+                        let message = (on_input)(editor.contents());
+                        shell.publish(message);
+
+                        update_cache(state, value);
+                    }
+                }
+                keyboard::Ime::Preedit { .. }
+                | keyboard::Ime::Enabled
+                | keyboard::Ime::Disabled => {
+                    state.preedit = None;
+                }
+            }
+
+            return event::Status::Captured;
+        }
         Event::Window(_, window::Event::Unfocused) => {
             let state = state();
 
@@ -994,15 +1131,43 @@ where
                 if focus.is_window_focused {
                     focus.now = now;
 
-                    let millis_until_redraw = CURSOR_BLINK_INTERVAL_MILLIS
-                        - (now - focus.updated_at).as_millis()
-                            % CURSOR_BLINK_INTERVAL_MILLIS;
+                    if let Some(interval) = state.blink_interval.get() {
+                        let interval = interval.as_millis().max(1);
+
+                        let millis_until_redraw = interval
+                            - (now - focus.updated_at).as_millis() % interval;
 
-                    shell.request_redraw(window::RedrawRequest::At(
-                        now + Duration::from_millis(millis_until_redraw as u64),
-                    ));
+                        shell.request_redraw(window::RedrawRequest::At(
+                            now + Duration::from_millis(
+                                millis_until_redraw as u64,
+                            ),
+                        ));
+                    }
                 }
             }
+
+            let is_window_focused = state
+                .is_focused
+                .is_some_and(|focus| focus.is_window_focused);
+
+            // Let the input method editor place its candidates next to the cursor
+            if is_window_focused && on_input.is_some() && !is_secure {
+                let text_bounds = layout.children().next().unwrap().bounds();
+
+                let (position, _) = measure_cursor_and_scroll_offset(
+                    &state.value,
+                    text_bounds,
+                    state.cursor.end(value),
+                );
+
+                shell.request_ime(Rectangle {
+                    x: text_bounds.x + position
+                        - offset(text_bounds, value, state),
+                    y: text_bounds.y,
+                    width: 1.0,
+                    height: text_bounds.height,
+                });
+            }
         }
         _ => {}
     }
@@ -1023,6 +1188,7 @@ pub fn draw<Theme, Renderer>(
     value: &Value,
     is_disabled: bool,
     is_secure: bool,
+    misspelled: &[Range<usize>],
     icon: Option<&Icon<Renderer::Font>>,
     style: &Theme::Style,
     viewport: &Rectangle,
@@ -1072,6 +1238,11 @@ pub fn draw<Theme, Renderer>(
 
     let text = value.to_string();
 
+    let caret = theme.caret(style);
+
+    // Remember the blink interval to schedule the next blink on redraw
+    state.blink_interval.set(caret.blink_interval);
+
     let (cursor, offset) = if let Some(focus) = state
         .is_focused
         .as_ref()
@@ -1086,24 +1257,50 @@ pub fn draw<Theme, Renderer>(
                         position,
                     );
 
-                let is_cursor_visible = ((focus.now - focus.updated_at)
-                    .as_millis()
-                    / CURSOR_BLINK_INTERVAL_MILLIS)
-                    % 2
-                    == 0;
+                let is_cursor_visible =
+                    caret.blink_interval.map_or(true, |interval| {
+                        ((focus.now - focus.updated_at).as_millis()
+                            / interval.as_millis().max(1))
+                            % 2
+                            == 0
+                    });
 
                 let cursor = if is_cursor_visible {
+                    let (width, color) = match caret.shape {
+                        caret::Shape::Bar => {
+                            (caret.width, theme.value_color(style))
+                        }
+                        caret::Shape::Block => {
+                            // Cover the next character, if any
+                            let next = if position < value.len() {
+                                measure_cursor_and_scroll_offset(
+                                    &state.value,
+                                    text_bounds,
+                                    position + 1,
+                                )
+                                .0
+                            } else {
+                                text_value_width + text_bounds.height / 2.0
+                            };
+
+                            (
+                                next - text_value_width,
+                                theme.selection_color(style),
+                            )
+                        }
+                    };
+
                     Some((
                         renderer::Quad {
                             bounds: Rectangle {
                                 x: text_bounds.x + text_value_width,
                                 y: text_bounds.y,
-                                width: 1.0,
+                                width,
                                 height: text_bounds.height,
                             },
                             ..renderer::Quad::default()
                         },
-                        theme.value_color(style),
+                        color,
                     ))
                 } else {
                     None
@@ -1156,6 +1353,46 @@ pub fn draw<Theme, Renderer>(
         (None, 0.0)
     };
 
+    // The text being composed replaces the cursor until it is committed
+    let preedit = state.preedit.as_ref().filter(|_| state.is_focused());
+
+    let cursor = cursor.filter(|_| preedit.is_none());
+
+    let preedit = preedit.map(|paragraph| {
+        let (position, _) = measure_cursor_and_scroll_offset(
+            &state.value,
+            text_bounds,
+            state.cursor.end(value),
+        );
+
+        (paragraph, position)
+    });
+
+    let misspellings: Vec<(f32, f32)> = if is_secure || text.is_empty() {
+        Vec::new()
+    } else {
+        misspelled
+            .iter()
+            .filter_map(|range| {
+                let start = grapheme_index(&text, range.start)?;
+                let end = grapheme_index(&text, range.end)?;
+
+                let (left, _) = measure_cursor_and_scroll_offset(
+                    &state.value,
+                    text_bounds,
+                    start,
+                );
+                let (right, _) = measure_cursor_and_scroll_offset(
+                    &state.value,
+                    text_bounds,
+                    end,
+                );
+
+                Some((left, right))
+            })
+            .collect()
+    };
+
     let draw = |renderer: &mut Renderer, viewport| {
         if let Some((cursor, color)) = cursor {
             renderer.with_translation(Vector::new(-offset, 0.0), |renderer| {
@@ -1182,6 +1419,53 @@ pub fn draw<Theme, Renderer>(
             },
             viewport,
         );
+
+        for &(left, right) in &misspellings {
+            draw_squiggle(
+                renderer,
+                text_bounds.x + left - offset,
+                (text_bounds.x + right - offset)
+                    .min(text_bounds.x + text_bounds.width),
+                text_bounds.y + text_bounds.height - 1.0,
+                theme.misspelled_color(style),
+            );
+        }
+
+        if let Some((paragraph, position)) = preedit {
+            let bounds = Rectangle {
+                x: text_bounds.x + position - offset,
+                y: text_bounds.y,
+                width: paragraph.min_width(),
+                height: text_bounds.height,
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                appearance.background,
+            );
+
+            renderer.fill_paragraph(
+                paragraph,
+                Point::new(bounds.x, text_bounds.center_y()),
+                theme.value_color(style),
+                viewport,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - 1.0,
+                        height: 1.0,
+                        ..bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                theme.value_color(style),
+            );
+        }
     };
 
     if cursor.is_some() {
@@ -1220,6 +1504,8 @@ pub struct State<P: text::Paragraph> {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    preedit: Option<P>,
+    blink_interval: Cell<Option<Duration>>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -1248,6 +1534,8 @@ impl<P: text::Paragraph> State<P> {
             last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            preedit: None,
+            blink_interval: Cell::default(),
         }
     }
 
@@ -1277,6 +1565,7 @@ impl<P: text::Paragraph> State<P> {
     /// Unfocuses the [`TextInput`].
     pub fn unfocus(&mut self) {
         self.is_focused = None;
+        self.preedit = None;
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
@@ -1383,6 +1672,47 @@ fn measure_cursor_and_scroll_offset(
     (grapheme_position.x, offset)
 }
 
+/// Returns the grapheme index of the given byte offset of the text, if it
+/// lies on a character boundary.
+fn grapheme_index(text: &str, offset: usize) -> Option<usize> {
+    text.get(..offset).map(|prefix| {
+        unicode_segmentation::UnicodeSegmentation::graphemes(prefix, true)
+            .count()
+    })
+}
+
+/// Draws a squiggly underline from `left` to `right` at the given height.
+fn draw_squiggle<Renderer: crate::core::Renderer>(
+    renderer: &mut Renderer,
+    left: f32,
+    right: f32,
+    y: f32,
+    color: Color,
+) {
+    const STEP: f32 = 2.0;
+
+    let mut x = left;
+    let mut is_up = false;
+
+    while x < right {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x,
+                    y: if is_up { y - 1.0 } else { y },
+                    width: STEP.min(right - x),
+                    height: 1.0,
+                },
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+
+        x += STEP;
+        is_up = !is_up;
+    }
+}
+
 /// Computes the position of the text cursor at the given X coordinate of
 /// a [`TextInput`].
 fn find_cursor_position<P: text::Paragraph>(
@@ -1434,7 +1764,7 @@ fn replace_paragraph<Renderer>(
         horizontal_alignment: alignment::Horizontal::Left,
         vertical_alignment: alignment::Vertical::Top,
         shaping: text::Shaping::Advanced,
+        wrap: text::Wrap::default(),
+        truncate: text::Truncate::default(),
     });
 }
-
-const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
@@ -0,0 +1,853 @@
+//! Arrange clips along tracks over time.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Border, Clipboard, Element, Layout, Length, Pixels, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+pub use iced_style::timeline::{Appearance, StyleSheet};
+
+const RULER_HEIGHT: f32 = 24.0;
+const HANDLE_WIDTH: f32 = 6.0;
+const SNAP_DISTANCE: f32 = 8.0;
+const MIN_CLIP_WIDTH: f32 = 2.0 * HANDLE_WIDTH;
+const MIN_TICK_SPACING: f32 = 60.0;
+const SCROLL_LINE: f32 = 60.0;
+const DEFAULT_SCALE: f32 = 100.0;
+const MIN_SCALE: f32 = 1.0;
+const MAX_SCALE: f32 = 10_000.0;
+
+/// A clip of a [`Timeline`], placed on a track.
+///
+/// Times are measured in seconds from the start of the [`Timeline`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clip {
+    /// The index of the track of the [`Clip`].
+    pub track: usize,
+    /// The start time of the [`Clip`].
+    pub start: f32,
+    /// The duration of the [`Clip`].
+    pub duration: f32,
+    /// The label of the [`Clip`].
+    pub label: String,
+}
+
+impl Clip {
+    /// Creates a new [`Clip`] on the given track with the given start time
+    /// and duration.
+    pub fn new(track: usize, start: f32, duration: f32) -> Self {
+        Self {
+            track,
+            start,
+            duration,
+            label: String::new(),
+        }
+    }
+
+    /// Sets the label of the [`Clip`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Returns the end time of the [`Clip`].
+    pub fn end(&self) -> f32 {
+        self.start + self.duration
+    }
+}
+
+/// A set of tracks holding [`Clip`]s over time, with a ruler and a playhead.
+///
+/// Clips can be dragged along and across tracks, and resized by dragging
+/// their edges. Clicking or dragging the ruler moves the playhead.
+///
+/// Scrolling moves the timeline horizontally, and scrolling while holding
+/// Ctrl zooms it around the cursor. When snapping is enabled, dragged times
+/// stick to nearby clip edges and the playhead, or else to a grid.
+#[allow(missing_debug_implementations)]
+pub struct Timeline<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    tracks: usize,
+    clips: &'a [Clip],
+    playhead: f32,
+    track_height: f32,
+    snap: Option<f32>,
+    width: Length,
+    height: Length,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    on_move: Option<Box<dyn Fn(usize, usize, f32) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(usize, f32, f32) -> Message + 'a>>,
+    on_seek: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    style: Theme::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Timeline<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    /// The default height of a track.
+    pub const DEFAULT_TRACK_HEIGHT: f32 = 40.0;
+
+    /// Creates a new [`Timeline`] with the given amount of tracks, holding
+    /// the given [`Clip`]s, with its playhead at the given time.
+    pub fn new(tracks: usize, clips: &'a [Clip], playhead: f32) -> Self {
+        Self {
+            tracks,
+            clips,
+            playhead,
+            track_height: Self::DEFAULT_TRACK_HEIGHT,
+            snap: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            text_size: None,
+            font: None,
+            on_move: None,
+            on_resize: None,
+            on_seek: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the height of every track of the [`Timeline`].
+    pub fn track_height(mut self, track_height: impl Into<Pixels>) -> Self {
+        self.track_height = track_height.into().0;
+        self
+    }
+
+    /// Enables snapping, using a grid with the given interval in seconds.
+    pub fn snap(mut self, interval: f32) -> Self {
+        self.snap = (interval > 0.0).then_some(interval);
+        self
+    }
+
+    /// Sets the width of the [`Timeline`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Timeline`].
+    ///
+    /// By default, it is as tall as the ruler and all of its tracks.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the [`Timeline`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the [`Timeline`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Clip`] is dragged
+    /// to a new track and start time.
+    pub fn on_move(
+        mut self,
+        on_move: impl Fn(usize, usize, f32) -> Message + 'a,
+    ) -> Self {
+        self.on_move = Some(Box::new(on_move));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Clip`] is resized
+    /// to a new start time and duration.
+    pub fn on_resize(
+        mut self,
+        on_resize: impl Fn(usize, f32, f32) -> Message + 'a,
+    ) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the message that should be produced when the playhead is moved
+    /// to a new time.
+    pub fn on_seek(mut self, on_seek: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_seek = Some(Box::new(on_seek));
+        self
+    }
+
+    /// Sets the style of the [`Timeline`].
+    pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`Timeline`].
+#[derive(Debug, Clone, Copy)]
+struct State {
+    /// The time at the left edge of the [`Timeline`].
+    offset: f32,
+    /// The amount of pixels per second.
+    scale: f32,
+    interaction: Interaction,
+    modifiers: keyboard::Modifiers,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            offset: 0.0,
+            scale: DEFAULT_SCALE,
+            interaction: Interaction::None,
+            modifiers: keyboard::Modifiers::default(),
+        }
+    }
+}
+
+impl State {
+    fn time_at(&self, bounds: Rectangle, x: f32) -> f32 {
+        self.offset + (x - bounds.x) / self.scale
+    }
+
+    fn x_at(&self, bounds: Rectangle, time: f32) -> f32 {
+        bounds.x + (time - self.offset) * self.scale
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum Interaction {
+    #[default]
+    None,
+    Seeking,
+    Moving {
+        clip: usize,
+        grab: f32,
+    },
+    Resizing {
+        clip: usize,
+        edge: Edge,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Edge {
+    Start,
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Hit {
+    Ruler,
+    Clip(usize),
+    Edge(usize, Edge),
+}
+
+impl<'a, Message, Theme, Renderer> Timeline<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    fn clip_bounds(
+        &self,
+        bounds: Rectangle,
+        state: &State,
+        clip: &Clip,
+    ) -> Rectangle {
+        let x = state.x_at(bounds, clip.start);
+
+        Rectangle {
+            x,
+            y: bounds.y
+                + RULER_HEIGHT
+                + clip.track as f32 * self.track_height
+                + 2.0,
+            width: (clip.duration * state.scale).max(1.0),
+            height: (self.track_height - 4.0).max(1.0),
+        }
+    }
+
+    fn track_at(&self, bounds: Rectangle, y: f32) -> usize {
+        let track = ((y - bounds.y - RULER_HEIGHT) / self.track_height)
+            .floor()
+            .max(0.0) as usize;
+
+        track.min(self.tracks.saturating_sub(1))
+    }
+
+    fn hit_test(
+        &self,
+        bounds: Rectangle,
+        state: &State,
+        position: Point,
+    ) -> Option<Hit> {
+        if !bounds.contains(position) {
+            return None;
+        }
+
+        if position.y < bounds.y + RULER_HEIGHT {
+            return Some(Hit::Ruler);
+        }
+
+        self.clips
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(index, clip)| {
+                let clip_bounds = self.clip_bounds(bounds, state, clip);
+
+                if !clip_bounds.contains(position) {
+                    return None;
+                }
+
+                let handle = HANDLE_WIDTH.min(clip_bounds.width / 3.0);
+
+                Some(if position.x < clip_bounds.x + handle {
+                    Hit::Edge(index, Edge::Start)
+                } else if position.x
+                    > clip_bounds.x + clip_bounds.width - handle
+                {
+                    Hit::Edge(index, Edge::End)
+                } else {
+                    Hit::Clip(index)
+                })
+            })
+    }
+
+    /// Returns the closest clip edge, or the playhead, within reach of the
+    /// given time.
+    fn snap_edge(
+        &self,
+        time: f32,
+        reach: f32,
+        skip: Option<usize>,
+        playhead: bool,
+    ) -> Option<f32> {
+        self.clips
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| Some(*index) != skip)
+            .flat_map(|(_, clip)| [clip.start, clip.end()])
+            .chain(playhead.then_some(self.playhead))
+            .filter(|edge| (edge - time).abs() <= reach)
+            .min_by(|a, b| (a - time).abs().total_cmp(&(b - time).abs()))
+    }
+
+    /// Snaps the given time to a nearby edge or else to the grid, if
+    /// snapping is enabled.
+    fn snap(
+        &self,
+        state: &State,
+        time: f32,
+        skip: Option<usize>,
+        playhead: bool,
+    ) -> f32 {
+        let Some(interval) = self.snap else {
+            return time;
+        };
+
+        self.snap_edge(time, SNAP_DISTANCE / state.scale, skip, playhead)
+            .unwrap_or_else(|| (time / interval).round() * interval)
+    }
+
+    /// Snaps a moving clip by whichever of its edges is closest to a
+    /// snapping target.
+    fn snap_clip(&self, state: &State, clip: usize, start: f32) -> f32 {
+        let Some(interval) = self.snap else {
+            return start;
+        };
+
+        let duration = self.clips[clip].duration;
+        let reach = SNAP_DISTANCE / state.scale;
+
+        let by_start = self
+            .snap_edge(start, reach, Some(clip), true)
+            .map(|edge| edge - start);
+        let by_end = self
+            .snap_edge(start + duration, reach, Some(clip), true)
+            .map(|edge| edge - (start + duration));
+
+        let adjustment = match (by_start, by_end) {
+            (Some(a), Some(b)) => Some(if a.abs() <= b.abs() { a } else { b }),
+            (adjustment, None) | (None, adjustment) => adjustment,
+        };
+
+        adjustment.map_or_else(
+            || (start / interval).round() * interval,
+            |adjustment| start + adjustment,
+        )
+    }
+
+    fn seek(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        x: f32,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if let Some(on_seek) = &self.on_seek {
+            let time = self.snap(state, state.time_at(bounds, x), None, false);
+
+            shell.publish(on_seek(time.max(0.0)));
+        }
+    }
+
+    fn drag(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        position: Point,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let time = state.time_at(bounds, position.x);
+
+        match state.interaction {
+            Interaction::None => {}
+            Interaction::Seeking => {
+                self.seek(state, bounds, position.x, shell);
+            }
+            Interaction::Moving { clip, grab } => {
+                let Some(on_move) = &self.on_move else {
+                    return;
+                };
+
+                let start = self.snap_clip(state, clip, time - grab).max(0.0);
+
+                shell.publish(on_move(
+                    clip,
+                    self.track_at(bounds, position.y),
+                    start,
+                ));
+            }
+            Interaction::Resizing { clip: index, edge } => {
+                let Some(on_resize) = &self.on_resize else {
+                    return;
+                };
+
+                let clip = &self.clips[index];
+                let min_duration = MIN_CLIP_WIDTH / state.scale;
+                let time = self.snap(state, time, Some(index), true);
+
+                let (start, end) = match edge {
+                    Edge::Start => (
+                        time.clamp(0.0, (clip.end() - min_duration).max(0.0)),
+                        clip.end(),
+                    ),
+                    Edge::End => {
+                        (clip.start, time.max(clip.start + min_duration))
+                    }
+                };
+
+                shell.publish(on_resize(index, start, end - start));
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Timeline<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let intrinsic = Size::new(
+            0.0,
+            RULER_HEIGHT + self.tracks as f32 * self.track_height,
+        );
+
+        layout::Node::new(limits.resolve(self.width, self.height, intrinsic))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                state.interaction = match self.hit_test(bounds, state, position)
+                {
+                    Some(Hit::Ruler) => {
+                        self.seek(state, bounds, position.x, shell);
+
+                        Interaction::Seeking
+                    }
+                    Some(Hit::Clip(clip)) => Interaction::Moving {
+                        clip,
+                        grab: state.time_at(bounds, position.x)
+                            - self.clips[clip].start,
+                    },
+                    Some(Hit::Edge(clip, edge)) => {
+                        Interaction::Resizing { clip, edge }
+                    }
+                    None => return event::Status::Ignored,
+                };
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if state.interaction != Interaction::None {
+                    self.drag(state, bounds, position, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if std::mem::take(&mut state.interaction) != Interaction::None {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (x * SCROLL_LINE, y * SCROLL_LINE)
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                };
+
+                if state.modifiers.zoom() {
+                    let anchor = state.time_at(bounds, position.x);
+
+                    state.scale = (state.scale * 1.1f32.powf(y / SCROLL_LINE))
+                        .clamp(MIN_SCALE, MAX_SCALE);
+                    state.offset =
+                        anchor - (position.x - bounds.x) / state.scale;
+                } else {
+                    let amount = if x == 0.0 { y } else { x };
+
+                    state.offset -= amount / state.scale;
+                }
+
+                state.offset = state.offset.max(0.0);
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+
+        match state.interaction {
+            Interaction::Moving { .. } => return mouse::Interaction::Grabbing,
+            Interaction::Resizing { .. } => {
+                return mouse::Interaction::ResizingHorizontally;
+            }
+            Interaction::Seeking => return mouse::Interaction::Pointer,
+            Interaction::None => {}
+        }
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        match self.hit_test(bounds, state, position) {
+            Some(Hit::Ruler) => mouse::Interaction::Pointer,
+            Some(Hit::Clip(_)) => mouse::Interaction::Grab,
+            Some(Hit::Edge(..)) => mouse::Interaction::ResizingHorizontally,
+            None => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+
+        let Some(visible_bounds) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        renderer.with_layer(visible_bounds, |renderer| {
+            if let Some(background) = appearance.background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            for track in 1..=self.tracks {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x,
+                            y: bounds.y
+                                + RULER_HEIGHT
+                                + track as f32 * self.track_height
+                                - 0.5,
+                            width: bounds.width,
+                            height: 1.0,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    appearance.track_separator,
+                );
+            }
+
+            let ruler = Rectangle {
+                height: RULER_HEIGHT,
+                ..bounds
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: ruler,
+                    ..renderer::Quad::default()
+                },
+                appearance.ruler_background,
+            );
+
+            let step = tick_step(state.scale, self.snap);
+            let first = (state.offset / step).floor() as i64;
+            let last = (state.time_at(bounds, bounds.x + bounds.width) / step)
+                .ceil() as i64;
+
+            for tick in first..=last {
+                let time = tick as f32 * step;
+                let x = state.x_at(bounds, time);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: x - 0.5,
+                            y: ruler.y + RULER_HEIGHT / 2.0,
+                            width: 1.0,
+                            height: RULER_HEIGHT / 2.0,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    appearance.tick_color,
+                );
+
+                renderer.fill_text(
+                    Text {
+                        content: &format_time(time, step),
+                        bounds: Size::new(f32::INFINITY, RULER_HEIGHT / 2.0),
+                        size: Pixels(text_size.0 * 0.75),
+                        line_height: text::LineHeight::default(),
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                        wrap: text::Wrap::None,
+                        truncate: text::Truncate::None,
+                    },
+                    Point::new(x + 3.0, ruler.y + RULER_HEIGHT / 4.0),
+                    appearance.ruler_text_color,
+                    ruler,
+                );
+            }
+
+            for (index, clip) in self.clips.iter().enumerate() {
+                let clip_bounds = self.clip_bounds(bounds, state, clip);
+
+                if !clip_bounds.intersects(&visible_bounds) {
+                    continue;
+                }
+
+                let is_active = matches!(
+                    state.interaction,
+                    Interaction::Moving { clip, .. }
+                        | Interaction::Resizing { clip, .. } if clip == index
+                );
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: clip_bounds,
+                        border: if is_active {
+                            appearance.active_clip_border
+                        } else {
+                            appearance.clip_border
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    appearance.clip_background,
+                );
+
+                if clip.label.is_empty() {
+                    continue;
+                }
+
+                renderer.fill_text(
+                    Text {
+                        content: &clip.label,
+                        bounds: Size::new(
+                            (clip_bounds.width - 2.0 * HANDLE_WIDTH).max(0.0),
+                            clip_bounds.height,
+                        ),
+                        size: text_size,
+                        line_height: text::LineHeight::default(),
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: text::Shaping::Advanced,
+                        wrap: text::Wrap::None,
+                        truncate: text::Truncate::Ellipsis,
+                    },
+                    Point::new(
+                        clip_bounds.x + HANDLE_WIDTH,
+                        clip_bounds.center_y(),
+                    ),
+                    appearance.clip_text_color,
+                    clip_bounds,
+                );
+            }
+
+            let playhead = state.x_at(bounds, self.playhead);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: playhead - 1.0,
+                        y: bounds.y,
+                        width: 2.0,
+                        height: bounds.height,
+                    },
+                    ..renderer::Quad::default()
+                },
+                appearance.playhead_color,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: playhead - 5.0,
+                        y: bounds.y,
+                        width: 10.0,
+                        height: RULER_HEIGHT / 2.0,
+                    },
+                    border: Border::with_radius(2.0),
+                    ..renderer::Quad::default()
+                },
+                appearance.playhead_color,
+            );
+        });
+    }
+}
+
+/// Returns the interval between the ticks of the ruler, keeping them at
+/// least [`MIN_TICK_SPACING`] apart.
+fn tick_step(scale: f32, snap: Option<f32>) -> f32 {
+    let min_step = MIN_TICK_SPACING / scale;
+
+    if let Some(interval) = snap {
+        if interval >= min_step {
+            return interval;
+        }
+    }
+
+    let magnitude = 10f32.powf(min_step.log10().floor());
+
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| *step >= min_step)
+        .unwrap_or(10.0 * magnitude)
+}
+
+fn format_time(time: f32, step: f32) -> String {
+    if step >= 1.0 {
+        let seconds = time.round() as u32;
+
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    } else {
+        let tenths = (time * 10.0).round() as u32;
+
+        format!("{}:{:02}.{}", tenths / 600, tenths / 10 % 60, tenths % 10)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Timeline<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        timeline: Timeline<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(timeline)
+    }
+}
@@ -268,6 +268,8 @@ where
                         self.text_alignment,
                         alignment::Vertical::Top,
                         self.text_shaping,
+                        text::Wrap::default(),
+                        text::Truncate::default(),
                     )
                 } else {
                     layout::Node::new(crate::core::Size::ZERO)
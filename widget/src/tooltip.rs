@@ -255,8 +255,8 @@ where
                     content_bounds: layout.bounds(),
                     snap_within_viewport: self.snap_within_viewport,
                     position: self.position,
-                    gap: self.gap,
-                    padding: self.padding,
+                    gap: self.gap * renderer.spacing_scale(),
+                    padding: self.padding * renderer.spacing_scale(),
                     style: &self.style,
                 }),
             ))
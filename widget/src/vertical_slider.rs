@@ -15,8 +15,8 @@ use crate::core::renderer;
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    Border, Clipboard, Element, Length, Pixels, Point, Rectangle, Shell, Size,
-    Widget,
+    Background, Border, Clipboard, Color, Element, Length, Pixels, Point,
+    Rectangle, Shell, Size, Widget,
 };
 
 /// An vertical bar and a handle that selects a single value from a range of
@@ -49,6 +49,7 @@ where
     range: RangeInclusive<T>,
     step: T,
     value: T,
+    breakpoints: &'a [T],
     on_change: Box<dyn Fn(T) -> Message + 'a>,
     on_release: Option<Message>,
     width: f32,
@@ -93,6 +94,7 @@ where
             value,
             range,
             step: T::from(1),
+            breakpoints: &[],
             on_change: Box::new(on_change),
             on_release: None,
             width: Self::DEFAULT_WIDTH,
@@ -101,6 +103,12 @@ where
         }
     }
 
+    /// Defines breakpoints to visibly mark on the [`VerticalSlider`].
+    pub fn breakpoints(mut self, breakpoints: &'a [T]) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+
     /// Sets the release message of the [`VerticalSlider`].
     /// This is called when the mouse is released from the slider.
     ///
@@ -204,13 +212,14 @@ where
         cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
-        draw(
+        draw_with_breakpoints(
             renderer,
             layout,
             cursor,
             tree.state.downcast_ref::<State>(),
             self.value,
             &self.range,
+            self.breakpoints,
             theme,
             &self.style,
         );
@@ -348,6 +357,35 @@ pub fn draw<T, Theme, Renderer>(
     T: Into<f64> + Copy,
     Theme: StyleSheet,
     Renderer: core::Renderer,
+{
+    draw_with_breakpoints(
+        renderer,
+        layout,
+        cursor,
+        state,
+        value,
+        range,
+        &[],
+        style_sheet,
+        style,
+    );
+}
+
+/// Draws a [`VerticalSlider`] with the given breakpoints marked next to it.
+pub fn draw_with_breakpoints<T, Theme, Renderer>(
+    renderer: &mut Renderer,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    state: &State,
+    value: T,
+    range: &RangeInclusive<T>,
+    breakpoints: &[T],
+    style_sheet: &Theme,
+    style: &Theme::Style,
+) where
+    T: Into<f64> + Copy,
+    Theme: StyleSheet,
+    Renderer: core::Renderer,
 {
     let bounds = layout.bounds();
     let is_mouse_over = cursor.is_over(bounds);
@@ -387,6 +425,36 @@ pub fn draw<T, Theme, Renderer>(
 
     let rail_x = bounds.x + bounds.width / 2.0;
 
+    // Draw the breakpoint indicators next to the slider.
+    const BREAKPOINT_HEIGHT: f32 = 2.0;
+    for &value in breakpoints {
+        let value: f64 = value.into();
+        let offset = if range_start >= range_end {
+            0.0
+        } else {
+            (bounds.height - BREAKPOINT_HEIGHT) * (value as f32 - range_end)
+                / (range_start - range_end)
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: rail_x + 6.0,
+                    y: bounds.y + offset,
+                    width: 8.0,
+                    height: BREAKPOINT_HEIGHT,
+                },
+                border: Border {
+                    radius: 0.0.into(),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+                ..renderer::Quad::default()
+            },
+            Background::Color(style.breakpoint.color),
+        );
+    }
+
     match style.rail.colors {
         iced_style::slider::RailBackground::Pair(start, end) => {
             renderer.fill_quad(
@@ -21,14 +21,17 @@ use window_clipboard::mime::ClipboardStoreData;
 
 use crate::conversion;
 use crate::core;
+use crate::core::audio::Audio;
+use crate::core::haptics::Haptics;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::time::Instant;
 use crate::core::widget::operation;
 use crate::core::window;
-use crate::core::{Event, Size};
+use crate::core::{Event, Rectangle, Size, Vector};
 use crate::futures::futures;
 use crate::futures::{Executor, Runtime, Subscription};
+use crate::graphics;
 use crate::graphics::compositor::{self, Compositor};
 use crate::runtime::clipboard;
 use crate::runtime::program::Program;
@@ -180,6 +183,26 @@ where
     fn scale_factor(&self) -> f64 {
         1.0
     }
+
+    /// Returns the [`Audio`] backend used to play the sounds requested with
+    /// [`audio::play`].
+    ///
+    /// By default, it returns `None` and no sounds are played.
+    ///
+    /// [`audio::play`]: crate::runtime::audio::play
+    fn audio(&self) -> Option<&dyn Audio> {
+        None
+    }
+
+    /// Returns the [`Haptics`] backend used to trigger the impulses requested
+    /// with [`haptics::impulse`].
+    ///
+    /// By default, it returns `None` and no impulses are triggered.
+    ///
+    /// [`haptics::impulse`]: crate::runtime::haptics::impulse
+    fn haptics(&self) -> Option<&dyn Haptics> {
+        None
+    }
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -276,7 +299,7 @@ where
         };
     }
 
-    let compositor = C::new(compositor_settings, window.clone())?;
+    let compositor = C::new(compositor_settings.clone(), window.clone())?;
     let mut renderer = compositor.create_renderer();
 
     for font in settings.fonts {
@@ -291,6 +314,7 @@ where
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
         compositor,
+        compositor_settings,
         renderer,
         runtime,
         proxy,
@@ -305,8 +329,9 @@ where
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
+    let mut result = Ok(());
 
-    let _ = event_loop.run(move |event, event_loop| {
+    let _ = event_loop.run(|event, event_loop| {
         if event_loop.exiting() {
             return;
         }
@@ -321,18 +346,21 @@ where
                     event_loop.set_control_flow(flow);
                 }
             }
-            task::Poll::Ready(_) => {
+            task::Poll::Ready(outcome) => {
+                result = outcome;
+
                 event_loop.exit();
             }
         };
     });
 
-    Ok(())
+    result
 }
 
 async fn run_instance<A, E, C>(
     mut application: A,
     mut compositor: C,
+    compositor_settings: C::Settings,
     mut renderer: A::Renderer,
     mut runtime: Runtime<
         E,
@@ -350,7 +378,8 @@ async fn run_instance<A, E, C>(
     should_be_visible: bool,
     exit_on_close_request: bool,
     resize_border: u32,
-) where
+) -> Result<(), Error>
+where
     A: Application + 'static,
     E: Executor + 'static,
     C: Compositor<Renderer = A::Renderer> + 'static,
@@ -417,9 +446,25 @@ async fn run_instance<A, E, C>(
     );
 
     let mut mouse_interaction = mouse::Interaction::default();
-    let mut events = Vec::new();
+    let mut ime_request = None;
+    let mut events: Vec<_> = window
+        .theme()
+        .map(|theme| {
+            Event::Window(
+                window::Id::MAIN,
+                window::Event::ColorSchemeChanged(conversion::color_scheme(
+                    theme,
+                )),
+            )
+        })
+        .into_iter()
+        .collect();
     let mut messages = Vec::new();
+    let mut shortcuts = application.shortcuts();
     let mut redraw_pending = false;
+    let mut out_of_memory_errors = 0;
+    let mut is_device_lost = false;
+    let mut result = Ok(());
     #[cfg(feature = "a11y")]
     let mut commands: Vec<Command<A::Message>> = Vec::new();
 
@@ -619,6 +664,32 @@ async fn run_instance<A, E, C>(
                     continue;
                 }
 
+                if is_device_lost || compositor.is_lost() {
+                    log::warn!("The graphics device was lost, recreating it.");
+
+                    match C::new(compositor_settings.clone(), window.clone()) {
+                        Ok(new_compositor) => {
+                            // The old surface must be released before the
+                            // window gets a new one
+                            drop(surface);
+
+                            compositor = new_compositor;
+                            renderer = compositor.create_renderer();
+                            surface = compositor.create_surface(
+                                window.clone(),
+                                physical_size.width,
+                                physical_size.height,
+                            );
+
+                            is_device_lost = false;
+                        }
+                        Err(error) => {
+                            result = Err(error.into());
+                            break;
+                        }
+                    }
+                }
+
                 let current_viewport_version = state.viewport_version();
 
                 if viewport_version != current_viewport_version {
@@ -658,9 +729,23 @@ async fn run_instance<A, E, C>(
                     &mut messages,
                 );
 
+                if let user_interface::State::Updated {
+                    ime_request: new_ime_request,
+                    ..
+                } = interface_state
+                {
+                    update_ime(
+                        &window,
+                        state.scale_factor(),
+                        &mut ime_request,
+                        new_ime_request,
+                    );
+                }
+
                 let _ = control_sender.start_send(match interface_state {
                     user_interface::State::Updated {
                         redraw_request: Some(redraw_request),
+                        ..
                     } => match redraw_request {
                         window::RedrawRequest::NextFrame => {
                             window.request_redraw();
@@ -690,13 +775,11 @@ async fn run_instance<A, E, C>(
 
                 debug.draw_finished();
 
-                if new_mouse_interaction != mouse_interaction {
-                    window.set_cursor_icon(conversion::mouse_interaction(
-                        new_mouse_interaction,
-                    ));
-
-                    mouse_interaction = new_mouse_interaction;
-                }
+                update_mouse_interaction(
+                    &window,
+                    &mut mouse_interaction,
+                    new_mouse_interaction,
+                );
 
                 redraw_pending = false;
 
@@ -790,13 +873,70 @@ async fn run_instance<A, E, C>(
                     Ok(()) => {
                         debug.render_finished();
 
+                        out_of_memory_errors = 0;
+
                         // TODO: Handle animations!
                         // Maybe we can use `ControlFlow::WaitUntil` for this.
                     }
                     Err(error) => match error {
-                        // This is an unrecoverable error.
                         compositor::SurfaceError::OutOfMemory => {
-                            panic!("{error:?}");
+                            debug.render_finished();
+
+                            out_of_memory_errors += 1;
+
+                            log::error!(
+                                "Error {error:?} when presenting surface \
+                                ({out_of_memory_errors} in a row)."
+                            );
+
+                            // Recreate the surface first, then the whole
+                            // device, before giving up
+                            match out_of_memory_errors {
+                                1 => {
+                                    let physical_size = state.physical_size();
+
+                                    drop(surface);
+
+                                    surface = compositor.create_surface(
+                                        window.clone(),
+                                        physical_size.width,
+                                        physical_size.height,
+                                    );
+                                }
+                                2 => {
+                                    is_device_lost = true;
+                                }
+                                _ => {
+                                    result =
+                                        Err(Error::GraphicsCreationFailed(
+                                            graphics::Error::DeviceLost,
+                                        ));
+                                    break;
+                                }
+                            }
+
+                            window.request_redraw();
+                        }
+                        compositor::SurfaceError::Outdated
+                        | compositor::SurfaceError::Lost => {
+                            debug.render_finished();
+
+                            log::warn!(
+                                "Error {error:?} when presenting surface, \
+                                recreating it."
+                            );
+
+                            // Recreate the swap chain and try rendering
+                            // again next frame.
+                            let physical_size = state.physical_size();
+
+                            compositor.configure_surface(
+                                &mut surface,
+                                physical_size.width,
+                                physical_size.height,
+                            );
+
+                            window.request_redraw();
                         }
                         _ => {
                             debug.render_finished();
@@ -835,6 +975,16 @@ async fn run_instance<A, E, C>(
                     events.push(event);
                 }
             }
+            event::Event::DeviceEvent {
+                event: event::DeviceEvent::MouseMotion { delta: (x, y) },
+                ..
+            } if mouse_interaction == mouse::Interaction::Locked => {
+                center_cursor(&window);
+
+                events.push(Event::Mouse(mouse::Event::Motion {
+                    delta: Vector::new(x as f32, y as f32),
+                }));
+            }
             event::Event::AboutToWait => {
                 if events.is_empty() && messages.is_empty() {
                     continue;
@@ -842,8 +992,27 @@ async fn run_instance<A, E, C>(
 
                 debug.event_processing_started();
 
+                let is_shortcut: Vec<bool> = events
+                    .iter()
+                    .map(|event| match shortcuts.handle(event) {
+                        Some(message) => {
+                            messages.push(message);
+
+                            true
+                        }
+                        None => false,
+                    })
+                    .collect();
+
+                let widget_events: Vec<_> = events
+                    .iter()
+                    .zip(&is_shortcut)
+                    .filter(|(_, is_shortcut)| !**is_shortcut)
+                    .map(|(event, _)| event.clone())
+                    .collect();
+
                 let (interface_state, statuses) = user_interface.update(
-                    &events,
+                    &widget_events,
                     state.cursor(),
                     &mut renderer,
                     &mut clipboard,
@@ -852,9 +1021,16 @@ async fn run_instance<A, E, C>(
 
                 debug.event_processing_finished();
 
-                for (event, status) in
-                    events.drain(..).zip(statuses.into_iter())
-                {
+                let mut statuses = statuses.into_iter();
+
+                // Shortcuts are broadcast as captured, in their original order
+                for (event, is_shortcut) in events.drain(..).zip(is_shortcut) {
+                    let status = if is_shortcut {
+                        core::event::Status::Captured
+                    } else {
+                        statuses.next().unwrap_or(core::event::Status::Ignored)
+                    };
+
                     runtime.broadcast(event, status);
                 }
 
@@ -884,6 +1060,8 @@ async fn run_instance<A, E, C>(
                         &window,
                     );
 
+                    shortcuts = application.shortcuts();
+
                     user_interface = ManuallyDrop::new(build_user_interface(
                         &application,
                         cache,
@@ -924,6 +1102,8 @@ async fn run_instance<A, E, C>(
 
     // Manually drop the user interface
     drop(ManuallyDrop::into_inner(user_interface));
+
+    result
 }
 
 /// Returns true if the provided event should cause an [`Application`] to
@@ -950,6 +1130,88 @@ pub fn requests_exit(
     }
 }
 
+/// Enables, moves, or disables the input method editor of the given window,
+/// following the latest request of its widgets.
+pub fn update_ime(
+    window: &winit::window::Window,
+    scale_factor: f64,
+    current: &mut Option<Rectangle>,
+    request: Option<Rectangle>,
+) {
+    if *current == request {
+        return;
+    }
+
+    match request {
+        Some(cursor) => {
+            if current.is_none() {
+                window.set_ime_allowed(true);
+            }
+
+            window.set_ime_cursor_area(
+                winit::dpi::PhysicalPosition::new(
+                    f64::from(cursor.x) * scale_factor,
+                    f64::from(cursor.y) * scale_factor,
+                ),
+                winit::dpi::PhysicalSize::new(
+                    f64::from(cursor.width) * scale_factor,
+                    f64::from(cursor.height) * scale_factor,
+                ),
+            );
+        }
+        None => {
+            window.set_ime_allowed(false);
+        }
+    }
+
+    *current = request;
+}
+
+/// Updates the [`mouse::Interaction`] of the window, locking the cursor in
+/// place while it is [`mouse::Interaction::Locked`].
+pub fn update_mouse_interaction(
+    window: &winit::window::Window,
+    current: &mut mouse::Interaction,
+    interaction: mouse::Interaction,
+) {
+    if *current == interaction {
+        return;
+    }
+
+    let is_locked = interaction == mouse::Interaction::Locked;
+
+    if is_locked != (*current == mouse::Interaction::Locked) {
+        use winit::window::CursorGrabMode;
+
+        let _ = if is_locked {
+            // Platforms that cannot lock the cursor confine it instead,
+            // and it is centered again after every motion
+            window
+                .set_cursor_grab(CursorGrabMode::Locked)
+                .or_else(|_| window.set_cursor_grab(CursorGrabMode::Confined))
+        } else {
+            window.set_cursor_grab(CursorGrabMode::None)
+        };
+
+        window.set_cursor_visible(!is_locked);
+    }
+
+    window.set_cursor_icon(conversion::mouse_interaction(interaction));
+
+    *current = interaction;
+}
+
+/// Moves the cursor to the center of the window, so a locked cursor never
+/// reaches its edges.
+pub fn center_cursor(window: &winit::window::Window) {
+    let size = window.inner_size();
+
+    let _ = window.set_cursor_position(winit::dpi::PhysicalPosition::new(
+        size.width / 2,
+        size.height / 2,
+    ));
+}
+
 /// Builds a [`UserInterface`] for the provided [`Application`], logging
 /// [`struct@Debug`] information accordingly.
 pub fn build_user_interface<'a, A: Application>(
@@ -1121,6 +1383,11 @@ pub fn run_command<A, C, E>(
                 window::Action::Drag(_id) => {
                     let _res = window.drag_window();
                 }
+                window::Action::DragResize(_id, direction) => {
+                    let _res = window.drag_resize_window(
+                        conversion::resize_direction(direction),
+                    );
+                }
                 window::Action::Spawn { .. } => {
                     log::warn!(
                         "Spawning a window is only available with \
@@ -1134,6 +1401,22 @@ pub fn run_command<A, C, E>(
                             height: size.height,
                         });
                 }
+                window::Action::ChangeMinSize(_id, size) => {
+                    window.set_min_inner_size(size.map(|size| {
+                        winit::dpi::LogicalSize {
+                            width: size.width,
+                            height: size.height,
+                        }
+                    }));
+                }
+                window::Action::ChangeMaxSize(_id, size) => {
+                    window.set_max_inner_size(size.map(|size| {
+                        winit::dpi::LogicalSize {
+                            width: size.width,
+                            height: size.height,
+                        }
+                    }));
+                }
                 window::Action::FetchSize(_id, callback) => {
                     let size =
                         window.inner_size().to_logical(window.scale_factor());
@@ -1241,6 +1524,24 @@ pub fn run_command<A, C, E>(
                         )))
                         .expect("Send message to event loop.");
                 }
+                window::Action::ChangeClickThrough(_id, click_through) => {
+                    let _ = window.set_cursor_hittest(!click_through);
+                }
+                window::Action::FetchCursorPosition(_id, callback) => {
+                    proxy
+                        .send_event(UserEventWrapper::Message(callback(
+                            state.cursor().position(),
+                        )))
+                        .expect("Send message to event loop");
+                }
+                window::Action::SetCursorPosition(_id, position) => {
+                    let _ = window.set_cursor_position(
+                        winit::dpi::LogicalPosition {
+                            x: position.x,
+                            y: position.y,
+                        },
+                    );
+                }
             },
             command::Action::System(action) => match action {
                 system::Action::QueryInformation(_tag) => {
@@ -1262,6 +1563,16 @@ pub fn run_command<A, C, E>(
                     }
                 }
             },
+            command::Action::Audio(sound) => {
+                if let Some(audio) = application.audio() {
+                    audio.play(sound);
+                }
+            }
+            command::Action::Haptics(strength) => {
+                if let Some(haptics) = application.haptics() {
+                    haptics.impulse(strength);
+                }
+            }
             command::Action::Widget(action) => {
                 let mut current_cache = std::mem::take(cache);
                 let mut current_operation =
@@ -251,6 +251,9 @@ pub fn window_event(
                 self::modifiers(new_modifiers.state()),
             )))
         }
+        WindowEvent::Ime(ime) => {
+            Some(Event::Keyboard(keyboard::Event::Ime(self::ime(ime))))
+        }
         WindowEvent::Focused(focused) => Some(Event::Window(
             id,
             if focused {
@@ -277,10 +280,22 @@ pub fn window_event(
 
             Some(Event::Window(id, window::Event::Moved { x, y }))
         }
+        WindowEvent::ThemeChanged(theme) => Some(Event::Window(
+            id,
+            window::Event::ColorSchemeChanged(color_scheme(theme)),
+        )),
         _ => None,
     }
 }
 
+/// Converts a [`winit`] window theme to a [`window::ColorScheme`].
+pub fn color_scheme(theme: winit::window::Theme) -> window::ColorScheme {
+    match theme {
+        winit::window::Theme::Light => window::ColorScheme::Light,
+        winit::window::Theme::Dark => window::ColorScheme::Dark,
+    }
+}
+
 /// Converts a [`window::Level`] to a [`winit`] window level.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -390,7 +405,18 @@ pub fn mouse_interaction(
             winit::window::CursorIcon::EwResize
         }
         Interaction::ResizingVertically => winit::window::CursorIcon::NsResize,
+        Interaction::ResizingDiagonallyUp => {
+            winit::window::CursorIcon::NeswResize
+        }
+        Interaction::ResizingDiagonallyDown => {
+            winit::window::CursorIcon::NwseResize
+        }
+        Interaction::ResizingAll => winit::window::CursorIcon::AllScroll,
+        Interaction::Move => winit::window::CursorIcon::Move,
         Interaction::NotAllowed => winit::window::CursorIcon::NotAllowed,
+        Interaction::ZoomIn => winit::window::CursorIcon::ZoomIn,
+        Interaction::ZoomOut => winit::window::CursorIcon::ZoomOut,
+        Interaction::Locked => winit::window::CursorIcon::Default,
     }
 }
 
@@ -427,6 +453,22 @@ pub fn modifiers(
     result
 }
 
+/// Converts an input method event from [`winit`] to an [`iced`] input
+/// method event.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced`]: https://github.com/iced-rs/iced/tree/0.10
+pub fn ime(ime: winit::event::Ime) -> keyboard::Ime {
+    match ime {
+        winit::event::Ime::Enabled => keyboard::Ime::Enabled,
+        winit::event::Ime::Preedit(text, cursor) => {
+            keyboard::Ime::Preedit { text, cursor }
+        }
+        winit::event::Ime::Commit(text) => keyboard::Ime::Commit(text),
+        winit::event::Ime::Disabled => keyboard::Ime::Disabled,
+    }
+}
+
 /// Converts a physical cursor position to a logical `Point`.
 pub fn cursor_position(
     position: winit::dpi::PhysicalPosition<f64>,
@@ -813,6 +855,30 @@ pub fn user_attention(
     }
 }
 
+/// Converts a [`window::Direction`] into its `winit` counterpart.
+pub fn resize_direction(
+    direction: window::Direction,
+) -> winit::window::ResizeDirection {
+    match direction {
+        window::Direction::North => winit::window::ResizeDirection::North,
+        window::Direction::South => winit::window::ResizeDirection::South,
+        window::Direction::East => winit::window::ResizeDirection::East,
+        window::Direction::West => winit::window::ResizeDirection::West,
+        window::Direction::NorthEast => {
+            winit::window::ResizeDirection::NorthEast
+        }
+        window::Direction::NorthWest => {
+            winit::window::ResizeDirection::NorthWest
+        }
+        window::Direction::SouthEast => {
+            winit::window::ResizeDirection::SouthEast
+        }
+        window::Direction::SouthWest => {
+            winit::window::ResizeDirection::SouthWest
+        }
+    }
+}
+
 /// Converts some [`window::Icon`] into it's `winit` counterpart.
 ///
 /// Returns `None` if there is an error during the conversion.
@@ -4,9 +4,11 @@ mod drag_resize;
 mod state;
 mod window_manager;
 
-use crate::application::UserEventWrapper;
+use crate::application::{self, UserEventWrapper};
 use crate::conversion;
 use crate::core;
+use crate::core::audio::Audio;
+use crate::core::haptics::Haptics;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget::operation;
@@ -17,7 +19,7 @@ use crate::core::Size;
 use crate::futures::futures::channel::mpsc;
 use crate::futures::futures::{task, Future, StreamExt};
 use crate::futures::{Executor, Runtime, Subscription};
-use crate::graphics::{compositor, Compositor};
+use crate::graphics::{self, compositor, Compositor};
 use crate::multi_window::operation::OperationWrapper;
 use crate::multi_window::window_manager::WindowManager;
 use crate::runtime::command::{self, Command};
@@ -119,6 +121,26 @@ where
     fn scale_factor(&self, window: window::Id) -> f64 {
         1.0
     }
+
+    /// Returns the [`Audio`] backend used to play the sounds requested with
+    /// [`audio::play`].
+    ///
+    /// By default, it returns `None` and no sounds are played.
+    ///
+    /// [`audio::play`]: crate::runtime::audio::play
+    fn audio(&self) -> Option<&dyn Audio> {
+        None
+    }
+
+    /// Returns the [`Haptics`] backend used to trigger the impulses requested
+    /// with [`haptics::impulse`].
+    ///
+    /// By default, it returns `None` and no impulses are triggered.
+    ///
+    /// [`haptics::impulse`]: crate::runtime::haptics::impulse
+    fn haptics(&self) -> Option<&dyn Haptics> {
+        None
+    }
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -208,7 +230,8 @@ where
         };
     }
 
-    let mut compositor = C::new(compositor_settings, main_window.clone())?;
+    let mut compositor =
+        C::new(compositor_settings.clone(), main_window.clone())?;
 
     let mut window_manager = WindowManager::new();
     let _ = window_manager.insert(
@@ -226,6 +249,7 @@ where
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
         compositor,
+        compositor_settings,
         runtime,
         proxy,
         debug,
@@ -238,8 +262,9 @@ where
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
+    let mut result = Ok(());
 
-    let _ = event_loop.run(move |event, event_loop| {
+    let _ = event_loop.run(|event, event_loop| {
         if event_loop.exiting() {
             return;
         }
@@ -261,7 +286,8 @@ where
                                 (
                                     ControlFlow::WaitUntil(current),
                                     ControlFlow::WaitUntil(new),
-                                ) if new < current => {}
+                                ) if Instant::now() < current
+                                    && current < new => {}
                                 (
                                     ControlFlow::WaitUntil(target),
                                     ControlFlow::Wait,
@@ -302,7 +328,9 @@ where
                         break;
                     }
                 },
-                task::Poll::Ready(_) => {
+                task::Poll::Ready(outcome) => {
+                    result = outcome;
+
                     event_loop.exit();
                     break;
                 }
@@ -310,7 +338,7 @@ where
         }
     });
 
-    Ok(())
+    result
 }
 
 enum Event<Message: 'static> {
@@ -336,6 +364,7 @@ enum Control {
 async fn run_instance<A, E, C>(
     mut application: A,
     mut compositor: C,
+    compositor_settings: C::Settings,
     mut runtime: Runtime<
         E,
         Proxy<UserEventWrapper<A::Message>>,
@@ -351,7 +380,8 @@ async fn run_instance<A, E, C>(
     mut window_manager: WindowManager<A, C>,
     should_main_window_be_visible: bool,
     resize_border: u32,
-) where
+) -> Result<(), Error>
+where
     A: Application + 'static,
     E: Executor + 'static,
     C: Compositor<Renderer = A::Renderer> + 'static,
@@ -406,7 +436,7 @@ async fn run_instance<A, E, C>(
         )
     };
     let mut events = {
-        vec![(
+        let mut events = vec![(
             Some(window::Id::MAIN),
             core::Event::Window(
                 window::Id::MAIN,
@@ -415,7 +445,21 @@ async fn run_instance<A, E, C>(
                     size: main_window.size(),
                 },
             ),
-        )]
+        )];
+
+        if let Some(theme) = main_window.raw.theme() {
+            events.push((
+                Some(window::Id::MAIN),
+                core::Event::Window(
+                    window::Id::MAIN,
+                    window::Event::ColorSchemeChanged(
+                        conversion::color_scheme(theme),
+                    ),
+                ),
+            ));
+        }
+
+        events
     };
 
     let mut ui_caches = HashMap::new();
@@ -451,6 +495,10 @@ async fn run_instance<A, E, C>(
     );
 
     let mut messages = Vec::new();
+    let mut shortcuts = application.shortcuts();
+    let mut out_of_memory_errors = 0;
+    let mut is_device_lost = false;
+    let mut result = Ok(());
 
     debug.startup_finished();
 
@@ -500,14 +548,22 @@ async fn run_instance<A, E, C>(
             }
             Event::EventLoopAwakened(event) => {
                 match event {
+                    event::Event::NewEvents(event::StartCause::Init) => {
+                        for (_id, window) in window_manager.iter_mut() {
+                            window.raw.request_redraw();
+                        }
+                    }
                     event::Event::NewEvents(
-                        event::StartCause::Init
-                        | event::StartCause::ResumeTimeReached { .. },
+                        event::StartCause::ResumeTimeReached { .. },
                     ) => {
+                        let now = Instant::now();
+
+                        // Only the windows that asked to be redrawn by now
                         for (_id, window) in window_manager.iter_mut() {
-                            // TODO once widgets can request to be redrawn, we can avoid always requesting a
-                            // redraw
-                            window.raw.request_redraw();
+                            if window.redraw_at.is_some_and(|at| at <= now) {
+                                window.redraw_at = None;
+                                window.raw.request_redraw();
+                            }
                         }
                     }
                     event::Event::PlatformSpecific(
@@ -531,6 +587,34 @@ async fn run_instance<A, E, C>(
                         event: event::WindowEvent::RedrawRequested,
                         ..
                     } => {
+                        if is_device_lost || compositor.is_lost() {
+                            log::warn!(
+                                "The graphics device was lost, recreating it."
+                            );
+
+                            let Some((_, window)) =
+                                window_manager.get_mut_alias(id)
+                            else {
+                                continue;
+                            };
+
+                            match C::new(
+                                compositor_settings.clone(),
+                                window.raw.clone(),
+                            ) {
+                                Ok(new_compositor) => {
+                                    compositor = new_compositor;
+                                    window_manager.recreate(&mut compositor);
+
+                                    is_device_lost = false;
+                                }
+                                Err(error) => {
+                                    result = Err(error.into());
+                                    break 'main;
+                                }
+                            }
+                        }
+
                         let Some((id, window)) =
                             window_manager.get_mut_alias(id)
                         else {
@@ -561,6 +645,19 @@ async fn run_instance<A, E, C>(
                             &mut messages,
                         );
 
+                        if let user_interface::State::Updated {
+                            ime_request,
+                            ..
+                        } = ui_state
+                        {
+                            application::update_ime(
+                                &window.raw,
+                                window.state.scale_factor(),
+                                &mut window.ime_request,
+                                ime_request,
+                            );
+                        }
+
                         debug.draw_started();
                         let new_mouse_interaction = ui.draw(
                             &mut window.renderer,
@@ -574,15 +671,11 @@ async fn run_instance<A, E, C>(
                         );
                         debug.draw_finished();
 
-                        if new_mouse_interaction != window.mouse_interaction {
-                            window.raw.set_cursor_icon(
-                                conversion::mouse_interaction(
-                                    new_mouse_interaction,
-                                ),
-                            );
-
-                            window.mouse_interaction = new_mouse_interaction;
-                        }
+                        application::update_mouse_interaction(
+                            &window.raw,
+                            &mut window.mouse_interaction,
+                            new_mouse_interaction,
+                        );
 
                         runtime.broadcast(
                             redraw_event.clone(),
@@ -593,6 +686,7 @@ async fn run_instance<A, E, C>(
                             match ui_state {
                                 user_interface::State::Updated {
                                     redraw_request: Some(redraw_request),
+                                    ..
                                 } => match redraw_request {
                                     window::RedrawRequest::NextFrame => {
                                         window.raw.request_redraw();
@@ -600,6 +694,8 @@ async fn run_instance<A, E, C>(
                                         ControlFlow::Wait
                                     }
                                     window::RedrawRequest::At(at) => {
+                                        window.redraw_at = Some(at);
+
                                         ControlFlow::WaitUntil(at)
                                     }
                                 },
@@ -648,17 +744,11 @@ async fn run_instance<A, E, C>(
                                 );
                             debug.draw_finished();
 
-                            if new_mouse_interaction != window.mouse_interaction
-                            {
-                                window.raw.set_cursor_icon(
-                                    conversion::mouse_interaction(
-                                        new_mouse_interaction,
-                                    ),
-                                );
-
-                                window.mouse_interaction =
-                                    new_mouse_interaction;
-                            }
+                            application::update_mouse_interaction(
+                                &window.raw,
+                                &mut window.mouse_interaction,
+                                new_mouse_interaction,
+                            );
 
                             compositor.configure_surface(
                                 &mut window.surface,
@@ -681,30 +771,78 @@ async fn run_instance<A, E, C>(
                             Ok(()) => {
                                 debug.render_finished();
 
+                                out_of_memory_errors = 0;
+
                                 // TODO: Handle animations!
                                 // Maybe we can use `ControlFlow::WaitUntil` for this.
                             }
-                            Err(error) => match error {
-                                // This is an unrecoverable error.
-                                compositor::SurfaceError::OutOfMemory => {
-                                    panic!("{:?}", error);
+                            Err(
+                                error @ compositor::SurfaceError::OutOfMemory,
+                            ) => {
+                                debug.render_finished();
+
+                                out_of_memory_errors += 1;
+
+                                log::error!(
+                                    "Error {error:?} when presenting surface \
+                                    ({out_of_memory_errors} in a row)."
+                                );
+
+                                // Recreate the surface first, then the whole
+                                // device, before giving up
+                                match out_of_memory_errors {
+                                    1 => {
+                                        window_manager.recreate_surface(
+                                            id,
+                                            &mut compositor,
+                                        );
+                                    }
+                                    2 => {
+                                        is_device_lost = true;
+                                    }
+                                    _ => {
+                                        result =
+                                            Err(Error::GraphicsCreationFailed(
+                                                graphics::Error::DeviceLost,
+                                            ));
+                                        break 'main;
+                                    }
                                 }
-                                _ => {
-                                    debug.render_finished();
 
-                                    log::error!(
-                                        "Error {error:?} when \
-                                        presenting surface."
+                                for (_id, window) in window_manager.iter_mut() {
+                                    window.raw.request_redraw();
+                                }
+                            }
+                            Err(error) => {
+                                debug.render_finished();
+
+                                // Recreate the swap chain if it is no
+                                // longer usable.
+                                if matches!(
+                                    error,
+                                    compositor::SurfaceError::Outdated
+                                        | compositor::SurfaceError::Lost
+                                ) {
+                                    let physical_size =
+                                        window.state.physical_size();
+
+                                    compositor.configure_surface(
+                                        &mut window.surface,
+                                        physical_size.width,
+                                        physical_size.height,
                                     );
+                                }
 
-                                    // Try rendering all windows again next frame.
-                                    for (_id, window) in
-                                        window_manager.iter_mut()
-                                    {
-                                        window.raw.request_redraw();
-                                    }
+                                log::error!(
+                                    "Error {error:?} when \
+                                        presenting surface."
+                                );
+
+                                // Try rendering all windows again next frame.
+                                for (_id, window) in window_manager.iter_mut() {
+                                    window.raw.request_redraw();
                                 }
-                            },
+                            }
                         }
                     }
                     event::Event::WindowEvent {
@@ -760,6 +898,28 @@ async fn run_instance<A, E, C>(
                             }
                         }
                     }
+                    event::Event::DeviceEvent {
+                        event: event::DeviceEvent::MouseMotion { delta: (x, y) },
+                        ..
+                    } => {
+                        let Some((id, window)) =
+                            window_manager.iter_mut().find(|(_, window)| {
+                                window.mouse_interaction
+                                    == mouse::Interaction::Locked
+                            })
+                        else {
+                            continue;
+                        };
+
+                        application::center_cursor(&window.raw);
+
+                        events.push((
+                            Some(id),
+                            core::Event::Mouse(mouse::Event::Motion {
+                                delta: core::Vector::new(x as f32, y as f32),
+                            }),
+                        ));
+                    }
                     event::Event::AboutToWait => {
                         if events.is_empty() && messages.is_empty() {
                             continue;
@@ -768,28 +928,57 @@ async fn run_instance<A, E, C>(
                         debug.event_processing_started();
                         let mut uis_stale = false;
 
+                        let mut pending: Vec<_> = events
+                            .drain(..)
+                            .map(|(window_id, event)| {
+                                let is_shortcut = match shortcuts.handle(&event)
+                                {
+                                    Some(message) => {
+                                        messages.push(message);
+
+                                        true
+                                    }
+                                    None => false,
+                                };
+
+                                (window_id, event, is_shortcut)
+                            })
+                            .collect();
+
                         for (id, window) in window_manager.iter_mut() {
                             let mut window_events = vec![];
 
-                            events.retain(|(window_id, event)| {
-                                if *window_id == Some(id) || window_id.is_none()
-                                {
-                                    window_events.push(event.clone());
-                                    false
-                                } else {
-                                    true
-                                }
-                            });
+                            pending.retain(
+                                |(window_id, event, is_shortcut)| {
+                                    if *window_id == Some(id)
+                                        || window_id.is_none()
+                                    {
+                                        window_events.push((
+                                            event.clone(),
+                                            *is_shortcut,
+                                        ));
+                                        false
+                                    } else {
+                                        true
+                                    }
+                                },
+                            );
 
                             if window_events.is_empty() && messages.is_empty() {
                                 continue;
                             }
 
+                            let widget_events: Vec<_> = window_events
+                                .iter()
+                                .filter(|(_, is_shortcut)| !is_shortcut)
+                                .map(|(event, _)| event.clone())
+                                .collect();
+
                             let (ui_state, statuses) = user_interfaces
                                 .get_mut(&id)
                                 .expect("Get user interface")
                                 .update(
-                                    &window_events,
+                                    &widget_events,
                                     window.state.cursor(),
                                     &mut window.renderer,
                                     &mut clipboard,
@@ -805,10 +994,19 @@ async fn run_instance<A, E, C>(
                                 );
                             }
 
-                            for (event, status) in window_events
-                                .into_iter()
-                                .zip(statuses.into_iter())
-                            {
+                            let mut statuses = statuses.into_iter();
+
+                            // Shortcuts are broadcast as captured, in their
+                            // original order
+                            for (event, is_shortcut) in window_events {
+                                let status = if is_shortcut {
+                                    core::event::Status::Captured
+                                } else {
+                                    statuses
+                                        .next()
+                                        .unwrap_or(core::event::Status::Ignored)
+                                };
+
                                 runtime.broadcast(event, status);
                             }
                         }
@@ -839,6 +1037,8 @@ async fn run_instance<A, E, C>(
                                 &mut cached_interfaces,
                             );
 
+                            shortcuts = application.shortcuts();
+
                             // we must synchronize all window states with application state after an
                             // application update since we don't know what changed
                             for (id, window) in window_manager.iter_mut() {
@@ -863,88 +1063,6 @@ async fn run_instance<A, E, C>(
                                     &mut clipboard,
                                 ));
                         }
-
-                        debug.draw_started();
-
-                        for (id, window) in window_manager.iter_mut() {
-                            // TODO: Avoid redrawing all the time by forcing widgets to
-                            //  request redraws on state changes
-                            //
-                            // Then, we can use the `interface_state` here to decide if a redraw
-                            // is needed right away, or simply wait until a specific time.
-                            let redraw_event = core::Event::Window(
-                                id,
-                                window::Event::RedrawRequested(Instant::now()),
-                            );
-
-                            let cursor = window.state.cursor();
-
-                            let ui = user_interfaces
-                                .get_mut(&id)
-                                .expect("Get user interface");
-
-                            let (ui_state, _) = ui.update(
-                                &[redraw_event.clone()],
-                                cursor,
-                                &mut window.renderer,
-                                &mut clipboard,
-                                &mut messages,
-                            );
-
-                            let new_mouse_interaction = {
-                                let state = &window.state;
-
-                                ui.draw(
-                                    &mut window.renderer,
-                                    state.theme(),
-                                    &renderer::Style {
-                                        icon_color: state.icon_color(),
-                                        text_color: state.text_color(),
-                                        scale_factor: state.scale_factor(),
-                                    },
-                                    cursor,
-                                )
-                            };
-
-                            if new_mouse_interaction != window.mouse_interaction
-                            {
-                                window.raw.set_cursor_icon(
-                                    conversion::mouse_interaction(
-                                        new_mouse_interaction,
-                                    ),
-                                );
-
-                                window.mouse_interaction =
-                                    new_mouse_interaction;
-                            }
-
-                            // TODO once widgets can request to be redrawn, we can avoid always requesting a
-                            // redraw
-                            window.raw.request_redraw();
-
-                            runtime.broadcast(
-                                redraw_event.clone(),
-                                core::event::Status::Ignored,
-                            );
-
-                            let _ = control_sender.start_send(
-                                Control::ChangeFlow(match ui_state {
-                                    user_interface::State::Updated {
-                                        redraw_request: Some(redraw_request),
-                                    } => match redraw_request {
-                                        window::RedrawRequest::NextFrame => {
-                                            ControlFlow::Poll
-                                        }
-                                        window::RedrawRequest::At(at) => {
-                                            ControlFlow::WaitUntil(at)
-                                        }
-                                    },
-                                    _ => ControlFlow::Wait,
-                                }),
-                            );
-                        }
-
-                        debug.draw_finished();
                     }
                     event::Event::PlatformSpecific(
                         event::PlatformSpecific::MacOS(
@@ -1242,6 +1360,8 @@ async fn run_instance<A, E, C>(
     }
 
     let _ = ManuallyDrop::into_inner(user_interfaces);
+
+    result
 }
 
 /// Builds a window's [`UserInterface`] for the [`Application`].
@@ -1419,6 +1539,13 @@ fn run_command<A, C, E>(
                         let _ = window.raw.drag_window();
                     }
                 }
+                window::Action::DragResize(id, direction) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        let _ = window.raw.drag_resize_window(
+                            conversion::resize_direction(direction),
+                        );
+                    }
+                }
                 window::Action::Resize(id, size) => {
                     if let Some(window) = window_manager.get_mut(id) {
                         let _ = window.raw.request_inner_size(
@@ -1429,6 +1556,26 @@ fn run_command<A, C, E>(
                         );
                     }
                 }
+                window::Action::ChangeMinSize(id, size) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.set_min_inner_size(size.map(|size| {
+                            winit::dpi::LogicalSize {
+                                width: size.width,
+                                height: size.height,
+                            }
+                        }));
+                    }
+                }
+                window::Action::ChangeMaxSize(id, size) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.set_max_inner_size(size.map(|size| {
+                            winit::dpi::LogicalSize {
+                                width: size.width,
+                                height: size.height,
+                            }
+                        }));
+                    }
+                }
                 window::Action::FetchSize(id, callback) => {
                     if let Some(window) = window_manager.get_mut(id) {
                         let size = window
@@ -1581,6 +1728,30 @@ fn run_command<A, C, E>(
                             .expect("Event loop doesn't exist.");
                     }
                 }
+                window::Action::ChangeClickThrough(id, click_through) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        let _ = window.raw.set_cursor_hittest(!click_through);
+                    }
+                }
+                window::Action::FetchCursorPosition(id, callback) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        proxy
+                            .send_event(UserEventWrapper::Message(callback(
+                                window.state.cursor().position(),
+                            )))
+                            .expect("Event loop doesn't exist.");
+                    }
+                }
+                window::Action::SetCursorPosition(id, position) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        let _ = window.raw.set_cursor_position(
+                            winit::dpi::LogicalPosition {
+                                x: position.x,
+                                y: position.y,
+                            },
+                        );
+                    }
+                }
             },
             command::Action::System(action) => match action {
                 system::Action::QueryInformation(_tag) => {
@@ -1602,6 +1773,16 @@ fn run_command<A, C, E>(
                     }
                 }
             },
+            command::Action::Audio(sound) => {
+                if let Some(audio) = application.audio() {
+                    audio.play(sound);
+                }
+            }
+            command::Action::Haptics(strength) => {
+                if let Some(haptics) = application.haptics() {
+                    haptics.impulse(strength);
+                }
+            }
             command::Action::Widget(action) => {
                 let mut current_operation =
                     Some(Box::new(OperationWrapper::Message(action)));
@@ -1,12 +1,13 @@
 use crate::core::mouse;
 use crate::core::window::Id;
-use crate::core::{Point, Size};
+use crate::core::{Point, Rectangle, Size};
 use crate::graphics::Compositor;
 use crate::multi_window::{Application, State};
 use crate::style::application::StyleSheet;
 
 use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::Instant;
 use winit::monitor::MonitorHandle;
 
 #[allow(missing_debug_implementations)]
@@ -69,6 +70,8 @@ where
                 surface,
                 renderer,
                 mouse_interaction: mouse::Interaction::Idle,
+                ime_request: None,
+                redraw_at: None,
                 prev_dnd_destination_rectangles_count: 0,
             },
         );
@@ -105,6 +108,50 @@ where
         self.entries.values().last()?.raw.current_monitor()
     }
 
+    /// Creates the surface of the window with the given [`Id`] again.
+    pub fn recreate_surface(&mut self, id: Id, compositor: &mut C) {
+        let Some(mut window) = self.entries.remove(&id) else {
+            return;
+        };
+
+        let physical_size = window.state.physical_size();
+
+        // The old surface must be released before the window gets a new one
+        drop(window.surface);
+
+        window.surface = compositor.create_surface(
+            window.raw.clone(),
+            physical_size.width,
+            physical_size.height,
+        );
+
+        let _ = self.entries.insert(id, window);
+    }
+
+    /// Creates the surfaces and renderers of every window again with the
+    /// given [`Compositor`], like after its device was lost.
+    pub fn recreate(&mut self, compositor: &mut C) {
+        let entries = std::mem::take(&mut self.entries);
+
+        self.entries = entries
+            .into_iter()
+            .map(|(id, mut window)| {
+                let physical_size = window.state.physical_size();
+
+                drop(window.surface);
+
+                window.renderer = compositor.create_renderer();
+                window.surface = compositor.create_surface(
+                    window.raw.clone(),
+                    physical_size.width,
+                    physical_size.height,
+                );
+
+                (id, window)
+            })
+            .collect();
+    }
+
     pub fn remove(&mut self, id: Id) -> Option<Window<A, C>> {
         let window = self.entries.remove(&id)?;
         let _ = self.aliases.remove(&window.raw.id());
@@ -145,6 +192,8 @@ where
     >,
     pub prev_dnd_destination_rectangles_count: usize,
     pub mouse_interaction: mouse::Interaction,
+    pub ime_request: Option<Rectangle>,
+    pub redraw_at: Option<Instant>,
     pub surface: C::Surface,
     pub renderer: A::Renderer,
 }